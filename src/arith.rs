@@ -18,11 +18,12 @@ use crate::{
     traits::ToFixed,
     types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8,
+    FixedU8, RoundingMode,
 };
 #[cfg(not(debug_assertions))]
 use core::hint;
 use core::{
+    cmp::Ordering,
     iter::{Product, Sum},
     num::{
         NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU128, NonZeroU16,
@@ -593,6 +594,22 @@ pub(crate) trait OverflowingMulDiv: Sized {
     fn overflowing_mul_add(self, mul: Self, add: Self, frac_nbits: i32) -> (Self, bool);
     // 0 <= frac_nbits <= NBITS
     fn overflowing_div(self, rhs: Self, frac_nbits: u32) -> (Self, bool);
+    // 0 <= frac_nbits <= NBITS, rounded once using mode, also returns whether inexact
+    fn overflowing_mul_round(
+        self,
+        rhs: Self,
+        frac_nbits: u32,
+        mode: RoundingMode,
+    ) -> (Self, bool, bool);
+    // 0 <= frac_nbits <= NBITS, rounded once using mode, also returns whether inexact
+    fn overflowing_div_round(
+        self,
+        rhs: Self,
+        frac_nbits: u32,
+        mode: RoundingMode,
+    ) -> (Self, bool, bool);
+    // self * mul / div with a double-width product, rounded once using mode
+    fn overflowing_mul_div_round(self, mul: Self, div: Self, mode: RoundingMode) -> (Self, bool);
 }
 
 #[inline]
@@ -615,6 +632,110 @@ pub(crate) fn overflowing_div<O: OverflowingMulDiv>(lhs: O, rhs: O, frac_nbits:
     lhs.overflowing_div(rhs, frac_nbits)
 }
 
+#[inline]
+pub(crate) fn overflowing_mul_round<O: OverflowingMulDiv>(
+    lhs: O,
+    rhs: O,
+    frac_nbits: u32,
+    mode: RoundingMode,
+) -> (O, bool) {
+    let (ans, overflow, _) = lhs.overflowing_mul_round(rhs, frac_nbits, mode);
+    (ans, overflow)
+}
+
+// like overflowing_mul_round, but also returns whether the result is inexact
+#[inline]
+pub(crate) fn overflowing_mul_exact<O: OverflowingMulDiv>(
+    lhs: O,
+    rhs: O,
+    frac_nbits: u32,
+    mode: RoundingMode,
+) -> (O, bool, bool) {
+    lhs.overflowing_mul_round(rhs, frac_nbits, mode)
+}
+
+#[inline]
+pub(crate) fn overflowing_div_round<O: OverflowingMulDiv>(
+    lhs: O,
+    rhs: O,
+    frac_nbits: u32,
+    mode: RoundingMode,
+) -> (O, bool) {
+    let (ans, overflow, _) = lhs.overflowing_div_round(rhs, frac_nbits, mode);
+    (ans, overflow)
+}
+
+// like overflowing_div_round, but also returns whether the result is inexact
+#[inline]
+pub(crate) fn overflowing_div_exact<O: OverflowingMulDiv>(
+    lhs: O,
+    rhs: O,
+    frac_nbits: u32,
+    mode: RoundingMode,
+) -> (O, bool, bool) {
+    lhs.overflowing_div_round(rhs, frac_nbits, mode)
+}
+
+#[inline]
+pub(crate) fn overflowing_mul_div_round<O: OverflowingMulDiv>(
+    lhs: O,
+    mul: O,
+    div: O,
+    mode: RoundingMode,
+) -> (O, bool) {
+    lhs.overflowing_mul_div_round(mul, div, mode)
+}
+
+// Returns true if an inexact value should be rounded up from its floor rather
+// than down to it. `odd` is true if the floor is odd, and `rem` compares the
+// discarded part, which is the value minus its floor, with one half.
+#[inline]
+fn round_up_floor(mode: RoundingMode, neg: bool, odd: bool, rem: Ordering) -> bool {
+    if neg {
+        // the absolute value is truncated to −(floor + 1), discarding 1 − rem
+        !mode.round_up_abs(true, !odd, rem.reverse())
+    } else {
+        mode.round_up_abs(false, odd, rem)
+    }
+}
+
+// Rounds the double-width quotient $num2 / $div2 once using $mode, and returns
+// it narrowed to $Single with an overflow flag and an inexact flag. $div2 must
+// not be zero.
+macro_rules! div2_round {
+    ($Single:ty, $Signedness:tt, $num2:expr, $div2:expr, $mode:expr) => {{
+        const NBITS: u32 = <$Single>::BITS;
+        let (num2, div2) = ($num2, $div2);
+        // wrapping only for MIN / −1, which is exact and still detected as
+        // overflow below
+        let mut quot2 = num2.wrapping_div(div2);
+        let rem2 = num2.wrapping_rem(div2);
+        if rem2 != 0 {
+            // quot2 was truncated towards zero
+            let (neg, rem_abs, div_abs) = if_signed_unsigned!(
+                $Signedness,
+                ((rem2 < 0) != (div2 < 0), rem2.abs(), div2.abs()),
+                (false, rem2, div2),
+            );
+            let odd = quot2 & 1 != 0;
+            if $mode.round_up_abs(neg, odd, rem_abs.cmp(&(div_abs - rem_abs))) {
+                if neg {
+                    quot2 -= 1;
+                } else {
+                    quot2 += 1;
+                }
+            }
+        }
+        let quot = quot2 as $Single;
+        let overflow = if_signed_unsigned!(
+            $Signedness,
+            quot2 >> NBITS != if quot < 0 { -1 } else { 0 },
+            quot2 >> NBITS != 0
+        );
+        (quot, overflow, rem2 != 0)
+    }};
+}
+
 macro_rules! mul_div_widen {
     ($Single:ty, $Double:ty, $Signedness:tt, $Unsigned:ty) => {
         impl OverflowingMulDiv for $Single {
@@ -685,6 +806,49 @@ macro_rules! mul_div_widen {
                 );
                 (quot, overflow)
             }
+
+            #[inline]
+            fn overflowing_mul_round(
+                self,
+                rhs: $Single,
+                frac_nbits: u32,
+                mode: RoundingMode,
+            ) -> ($Single, bool, bool) {
+                // the double-width product cannot overflow
+                let prod2 = <$Double>::from(self) * <$Double>::from(rhs);
+                div2_round!(
+                    $Single,
+                    $Signedness,
+                    prod2,
+                    <$Double>::from(1u8) << frac_nbits,
+                    mode
+                )
+            }
+
+            #[inline]
+            fn overflowing_div_round(
+                self,
+                rhs: $Single,
+                frac_nbits: u32,
+                mode: RoundingMode,
+            ) -> ($Single, bool, bool) {
+                let lhs2 = <$Double>::from(self) << frac_nbits;
+                div2_round!($Single, $Signedness, lhs2, <$Double>::from(rhs), mode)
+            }
+
+            #[inline]
+            fn overflowing_mul_div_round(
+                self,
+                mul: $Single,
+                div: $Single,
+                mode: RoundingMode,
+            ) -> ($Single, bool) {
+                // the double-width product cannot overflow
+                let prod2 = <$Double>::from(self) * <$Double>::from(mul);
+                let (ans, overflow, _) =
+                    div2_round!($Single, $Signedness, prod2, <$Double>::from(div), mode);
+                (ans, overflow)
+            }
         }
     };
 }
@@ -748,6 +912,71 @@ impl OverflowingMulDiv for u128 {
             (quot, overflow)
         }
     }
+
+    #[inline]
+    fn overflowing_mul_round(
+        self,
+        rhs: u128,
+        frac_nbits: u32,
+        mode: RoundingMode,
+    ) -> (u128, bool, bool) {
+        if frac_nbits == 0 {
+            let (ans, overflow) = self.overflowing_mul(rhs);
+            return (ans, overflow, false);
+        }
+        let prod = int256::wide_mul_u128(self, rhs);
+        let mut quot = int256::shr_u256(prod, frac_nbits);
+        let rem = prod.lo & (!0 >> (128 - frac_nbits));
+        if rem != 0 {
+            let odd = quot.lo & 1 != 0;
+            let half = 1 << (frac_nbits - 1);
+            if mode.round_up_abs(false, odd, rem.cmp(&half)) {
+                // quot < 2^256 − 1 as it was shifted right, so this cannot wrap
+                quot = int256::wrapping_add_u256_u128(quot, 1);
+            }
+        }
+        (quot.lo, quot.hi != 0, rem != 0)
+    }
+
+    #[inline]
+    fn overflowing_div_round(
+        self,
+        rhs: u128,
+        frac_nbits: u32,
+        mode: RoundingMode,
+    ) -> (u128, bool, bool) {
+        let lhs2 = match frac_nbits {
+            0 => U256 { lo: self, hi: 0 },
+            128 => U256 { lo: 0, hi: self },
+            _ => U256 {
+                lo: self << frac_nbits,
+                hi: self >> (128 - frac_nbits),
+            },
+        };
+        let (mut quot2, rem) = int256::div_rem_u256_u128(lhs2, rhs);
+        if rem != 0 {
+            let odd = quot2.lo & 1 != 0;
+            if mode.round_up_abs(false, odd, rem.cmp(&(rhs - rem))) {
+                // quot2 < 2^256 − 1 as lhs2 < 2^256 − 1, so this cannot wrap
+                quot2 = int256::wrapping_add_u256_u128(quot2, 1);
+            }
+        }
+        (quot2.lo, quot2.hi != 0, rem != 0)
+    }
+
+    #[inline]
+    fn overflowing_mul_div_round(self, mul: u128, div: u128, mode: RoundingMode) -> (u128, bool) {
+        let prod = int256::wide_mul_u128(self, mul);
+        let (mut quot, rem) = int256::div_rem_u256_u128(prod, div);
+        if rem != 0 {
+            let odd = quot.lo & 1 != 0;
+            if mode.round_up_abs(false, odd, rem.cmp(&(div - rem))) {
+                // quot < 2^256 − 1 as prod ≤ (2^128 − 1)^2, so this cannot wrap
+                quot = int256::wrapping_add_u256_u128(quot, 1);
+            }
+        }
+        (quot.lo, quot.hi != 0)
+    }
 }
 
 impl OverflowingMulDiv for i128 {
@@ -808,6 +1037,89 @@ impl OverflowingMulDiv for i128 {
             (quot, overflow)
         }
     }
+
+    #[inline]
+    fn overflowing_mul_round(
+        self,
+        rhs: i128,
+        frac_nbits: u32,
+        mode: RoundingMode,
+    ) -> (i128, bool, bool) {
+        if frac_nbits == 0 {
+            let (ans, overflow) = self.overflowing_mul(rhs);
+            return (ans, overflow, false);
+        }
+        let prod = int256::wide_mul_i128(self, rhs);
+        // the arithmetic shift rounds towards −∞, leaving a non-negative remainder
+        let mut quot = int256::shl_i256_max_128(prod, frac_nbits);
+        let rem = prod.lo & (!0 >> (128 - frac_nbits));
+        if rem != 0 {
+            let neg = quot.hi < 0;
+            let odd = quot.lo & 1 != 0;
+            let half = 1 << (frac_nbits - 1);
+            if round_up_floor(mode, neg, odd, rem.cmp(&half)) {
+                quot = int256::overflowing_add_i256_i128(quot, 1).0;
+            }
+        }
+        let ans = quot.lo as i128;
+        (ans, quot.hi != ans >> 127, rem != 0)
+    }
+
+    #[inline]
+    fn overflowing_div_round(
+        self,
+        rhs: i128,
+        frac_nbits: u32,
+        mode: RoundingMode,
+    ) -> (i128, bool, bool) {
+        let (mut quot2, rem) = if frac_nbits == 0 {
+            let (quot, overflow) = self.overflowing_div(rhs);
+            let quot2 = I256 {
+                lo: quot as u128,
+                // MIN / −1 overflows to +2^127, which has a zero high part
+                hi: if overflow { 0 } else { quot >> 127 },
+            };
+            (quot2, self.wrapping_rem(rhs))
+        } else {
+            let lhs2 = if frac_nbits == 128 {
+                I256 { lo: 0, hi: self }
+            } else {
+                I256 {
+                    lo: (self << frac_nbits) as u128,
+                    hi: self >> (128 - frac_nbits),
+                }
+            };
+            int256::div_rem_i256_i128(lhs2, rhs)
+        };
+        if rem != 0 {
+            // quot2 was truncated towards zero
+            let neg = (rem < 0) != (rhs < 0);
+            let (rem_abs, rhs_abs) = (rem.unsigned_abs(), rhs.unsigned_abs());
+            let odd = quot2.lo & 1 != 0;
+            if mode.round_up_abs(neg, odd, rem_abs.cmp(&(rhs_abs - rem_abs))) {
+                quot2 = int256::overflowing_add_i256_i128(quot2, if neg { -1 } else { 1 }).0;
+            }
+        }
+        let quot = quot2.lo as i128;
+        (quot, quot2.hi != quot >> 127, rem != 0)
+    }
+
+    #[inline]
+    fn overflowing_mul_div_round(self, mul: i128, div: i128, mode: RoundingMode) -> (i128, bool) {
+        let prod = int256::wide_mul_i128(self, mul);
+        let (mut quot, rem) = int256::div_rem_i256_i128(prod, div);
+        if rem != 0 {
+            // quot was truncated towards zero
+            let neg = (rem < 0) != (div < 0);
+            let (rem_abs, div_abs) = (rem.unsigned_abs(), div.unsigned_abs());
+            let odd = quot.lo & 1 != 0;
+            if mode.round_up_abs(neg, odd, rem_abs.cmp(&(div_abs - rem_abs))) {
+                quot = int256::overflowing_add_i256_i128(quot, if neg { -1 } else { 1 }).0;
+            }
+        }
+        let ans = quot.lo as i128;
+        (ans, quot.hi != ans >> 127)
+    }
 }
 
 #[cfg(test)]
@@ -1540,4 +1852,796 @@ mod tests {
         let x: Result<FixedI128<U125>, _> = "9.079999999999999999999".parse();
         assert!(x.is_err());
     }
+
+    #[test]
+    fn mul_div_round_exhaustive_8() {
+        for frac in 0..=8u32 {
+            for a in 0..=255u8 {
+                for b in 0..=255u8 {
+                    for &mode in &MODES {
+                        // unsigned
+                        let (ua, ub) = (i32::from(a), i32::from(b));
+                        let prod = ref_mul_div(ua * ub, 1 << frac, mode);
+                        let ans = arith::overflowing_mul_round(a, b, frac, mode);
+                        assert_eq!(ans, (prod as u8, prod > 255));
+                        let (ans128, overflow128) = arith::overflowing_mul_round(
+                            u128::from(a) << 120,
+                            u128::from(b),
+                            frac + 120,
+                            mode,
+                        );
+                        assert_eq!((ans128, overflow128), (prod as u128, false));
+                        if b != 0 {
+                            let quot = ref_mul_div(ua << frac, ub, mode);
+                            let ans = arith::overflowing_div_round(a, b, frac, mode);
+                            assert_eq!(ans, (quot as u8, quot > 255));
+                            let ans128 = arith::overflowing_div_round(
+                                u128::from(a) << 120,
+                                u128::from(b) << 120,
+                                frac,
+                                mode,
+                            );
+                            assert_eq!(ans128, (quot as u128, false));
+                        }
+
+                        // signed
+                        let (a, b) = (a as i8, b as i8);
+                        let (sa, sb) = (i32::from(a), i32::from(b));
+                        let overflows = |val: i32| !(-128..=127).contains(&val);
+                        let prod = ref_mul_div(sa * sb, 1 << frac, mode);
+                        let ans = arith::overflowing_mul_round(a, b, frac, mode);
+                        assert_eq!(ans, (prod as i8, overflows(prod)));
+                        let ans128 = arith::overflowing_mul_round(
+                            i128::from(a) << 120,
+                            i128::from(b),
+                            frac + 120,
+                            mode,
+                        );
+                        assert_eq!(ans128, (i128::from(prod), false));
+                        if b != 0 {
+                            let quot = ref_mul_div(sa << frac, sb, mode);
+                            let ans = arith::overflowing_div_round(a, b, frac, mode);
+                            assert_eq!(ans, (quot as i8, overflows(quot)));
+                            let ans128 = arith::overflowing_div_round(
+                                i128::from(a) << 120,
+                                i128::from(b) << 120,
+                                frac,
+                                mode,
+                            );
+                            assert_eq!(ans128, (i128::from(quot), false));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mul_div_round_128() {
+        // 2^128 / 3 = 113427455640312821154458202477256070485.33…
+        let third = 113_427_455_640_312_821_154_458_202_477_256_070_485;
+        assert_eq!(
+            arith::overflowing_div_round(1u128, 3, 128, RoundingMode::Floor),
+            (third, false)
+        );
+        assert_eq!(
+            arith::overflowing_div_round(1u128, 3, 128, RoundingMode::Ceil),
+            (third + 1, false)
+        );
+        assert_eq!(
+            arith::overflowing_div_round(1u128, 1, 128, RoundingMode::Floor),
+            (0, true)
+        );
+        assert_eq!(
+            arith::overflowing_div_round(1i128, -3, 127, RoundingMode::Floor),
+            (-((third / 2) as i128) - 1, false)
+        );
+        assert_eq!(
+            arith::overflowing_div_round(1i128, -3, 127, RoundingMode::Ceil),
+            (-((third / 2) as i128), false)
+        );
+        assert_eq!(
+            arith::overflowing_div_round(-1i128, -1, 127, RoundingMode::Ceil),
+            (i128::MIN, true)
+        );
+        assert_eq!(
+            arith::overflowing_div_round(-1i128, 1, 127, RoundingMode::Ceil),
+            (i128::MIN, false)
+        );
+        assert_eq!(
+            arith::overflowing_div_round(i128::MIN, -1, 0, RoundingMode::Floor),
+            (i128::MIN, true)
+        );
+        assert_eq!(
+            arith::overflowing_div_round(-7i128, 2, 0, RoundingMode::Floor),
+            (-4, false)
+        );
+        assert_eq!(
+            arith::overflowing_div_round(-7i128, 2, 0, RoundingMode::Ceil),
+            (-3, false)
+        );
+        assert_eq!(
+            arith::overflowing_div_round(7u128, 2, 0, RoundingMode::Ceil),
+            (4, false)
+        );
+
+        // MAX × MAX / 2^128 is just below MAX − 1
+        assert_eq!(
+            arith::overflowing_mul_round(u128::MAX, u128::MAX, 128, RoundingMode::Floor),
+            (u128::MAX - 1, false)
+        );
+        assert_eq!(
+            arith::overflowing_mul_round(u128::MAX, u128::MAX, 128, RoundingMode::Ceil),
+            (u128::MAX, false)
+        );
+        assert_eq!(
+            arith::overflowing_mul_round(u128::MAX, 2, 0, RoundingMode::Ceil),
+            (u128::MAX - 1, true)
+        );
+        // −MAX × (2^126 + 1) / 2^126 = MIN − 1 + 2^−126, so only the floor overflows
+        let b = (1 << 126) + 1;
+        assert_eq!(
+            arith::overflowing_mul_round(-i128::MAX, b, 126, RoundingMode::Floor),
+            (i128::MAX, true)
+        );
+        assert_eq!(
+            arith::overflowing_mul_round(-i128::MAX, b, 126, RoundingMode::Ceil),
+            (i128::MIN, false)
+        );
+
+        // 5 / 2 = 2.5 is a tie
+        let modes = [
+            (RoundingMode::TowardZero, -2),
+            (RoundingMode::AwayFromZero, -3),
+            (RoundingMode::NearestTiesEven, -2),
+            (RoundingMode::NearestTiesAway, -3),
+        ];
+        for &(mode, ans) in &modes {
+            assert_eq!(
+                arith::overflowing_mul_round(-5i128, 1, 1, mode),
+                (ans, false)
+            );
+            assert_eq!(
+                arith::overflowing_div_round(-5i128, 2, 0, mode),
+                (ans, false)
+            );
+            assert_eq!(
+                arith::overflowing_div_round(5i128, -2, 0, mode),
+                (ans, false)
+            );
+            let uns = -ans as u128;
+            assert_eq!(
+                arith::overflowing_mul_round(5u128, 1, 1, mode),
+                (uns, false)
+            );
+            assert_eq!(
+                arith::overflowing_div_round(5u128, 2, 0, mode),
+                (uns, false)
+            );
+        }
+
+        type F = FixedI128<types::extra::U64>;
+        let a = F::from_bits(-3);
+        let b = F::from_num(0.5);
+        assert_eq!(a.mul_floor(b), F::from_bits(-2));
+        assert_eq!(a.mul_ceil(b), F::from_bits(-1));
+        assert_eq!(a.div_floor(F::from_num(-4)), F::ZERO);
+        assert_eq!(a.div_ceil(F::from_num(-4)), F::DELTA);
+        assert_eq!(F::MAX.checked_mul_ceil(F::from_num(2)), None);
+        assert_eq!(F::MAX.saturating_div_floor(-b), F::MIN);
+        assert_eq!(F::ONE.checked_div_ceil(F::ZERO), None);
+        assert_eq!(a.mul_exact(b), (F::from_bits(-2), true));
+        assert_eq!(a.mul_exact(F::from_num(2)), (F::from_bits(-6), false));
+        assert_eq!(a.div_exact(F::from_num(-4)), (F::ZERO, true));
+        assert_eq!(a.div_exact(b), (F::from_bits(-6), false));
+        type U = FixedU128<types::extra::U64>;
+        let c = U::from_bits(3);
+        assert_eq!(c.mul_exact(U::from_num(0.5)), (U::from_bits(1), true));
+        assert_eq!(c.div_exact(U::from_num(3)), (U::from_bits(1), false));
+        assert!(U::ONE.div_exact(U::from_num(3)).1);
+    }
+
+    #[test]
+    fn mul_div_exact_exhaustive_8() {
+        use crate::types::{I4F4, U4F4};
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let (fa, fb) = (I4F4::from_bits(a), I4F4::from_bits(b));
+                let (sa, sb) = (i32::from(a), i32::from(b));
+                if let Some(prod) = fa.checked_mul(fb) {
+                    assert_eq!(fa.mul_exact(fb), (prod, sa * sb % 16 != 0));
+                }
+                if let Some(quot) = fa.checked_div(fb) {
+                    assert_eq!(fa.div_exact(fb), (quot, sa * 16 % sb != 0));
+                }
+
+                let (ua, ub) = (a as u8, b as u8);
+                let (fa, fb) = (U4F4::from_bits(ua), U4F4::from_bits(ub));
+                let (sa, sb) = (u32::from(ua), u32::from(ub));
+                if let Some(prod) = fa.checked_mul(fb) {
+                    assert_eq!(fa.mul_exact(fb), (prod, sa * sb % 16 != 0));
+                }
+                if let Some(quot) = fa.checked_div(fb) {
+                    assert_eq!(fa.div_exact(fb), (quot, sa * 16 % sb != 0));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mul_into_exhaustive_8() {
+        use crate::types::{I3F5, I4F4, I8F0, U2F6, U4F4};
+        use core::convert::TryFrom;
+        for a in i8::MIN..=i8::MAX {
+            for b in u8::MIN..=u8::MAX {
+                let (fa, fb) = (I4F4::from_bits(a), U2F6::from_bits(b));
+                // exact product has 10 fractional bits, floor with >>
+                let prod = i32::from(a) * i32::from(b);
+
+                let exp = prod >> 10;
+                let ok = i8::try_from(exp).ok().map(I8F0::from_bits);
+                assert_eq!(fa.checked_mul_into::<I8F0, _>(fb), ok);
+                let sat = exp.max(i32::from(i8::MIN)).min(i32::from(i8::MAX));
+                let sat = I8F0::from_bits(sat as i8);
+                assert_eq!(fa.saturating_mul_into::<I8F0, _>(fb), sat);
+                if let Some(ok) = ok {
+                    assert_eq!(fa.mul_into::<I8F0, _>(fb), ok);
+                }
+
+                let exp = prod >> 5;
+                let ok = i8::try_from(exp).ok().map(I3F5::from_bits);
+                assert_eq!(fa.checked_mul_into::<I3F5, _>(fb), ok);
+                let sat = exp.max(i32::from(i8::MIN)).min(i32::from(i8::MAX));
+                let sat = I3F5::from_bits(sat as i8);
+                assert_eq!(fa.saturating_mul_into::<I3F5, _>(fb), sat);
+
+                let exp = prod >> 6;
+                let ok = u8::try_from(exp).ok().map(U4F4::from_bits);
+                assert_eq!(fa.checked_mul_into::<U4F4, _>(fb), ok);
+                let sat = exp.max(0).min(i32::from(u8::MAX));
+                let sat = U4F4::from_bits(sat as u8);
+                assert_eq!(fa.saturating_mul_into::<U4F4, _>(fb), sat);
+            }
+        }
+    }
+
+    #[test]
+    fn mul_into_wide() {
+        use crate::types::{I0F128, I128F0, I64F0, I80F48, U0F128, U128F0};
+        let price = I80F48::from_num(12.375);
+        let qty = I64F0::from_num(-1_000_000);
+        assert_eq!(price.mul_into::<I80F48, _>(qty), -12_375_000);
+        assert_eq!(qty.mul_into::<I80F48, _>(price), -12_375_000);
+        let big = I64F0::MIN.mul_into::<I128F0, _>(I64F0::MIN);
+        assert_eq!(big, I128F0::from_bits(1 << 126));
+        assert_eq!(I80F48::MAX.checked_mul_into::<I80F48, _>(qty), None);
+        assert_eq!(
+            I80F48::MAX.saturating_mul_into::<I80F48, _>(qty),
+            I80F48::MIN
+        );
+
+        let max = U0F128::MAX;
+        let prod = max.mul_into::<U0F128, _>(max);
+        assert_eq!(prod, U0F128::from_bits(u128::MAX - 1));
+        assert_eq!(max.checked_mul_into::<U128F0, _>(max), Some(U128F0::ZERO));
+        let delta = I0F128::DELTA;
+        assert_eq!(delta.mul_into::<I0F128, _>(delta), I0F128::ZERO);
+        assert_eq!((-delta).mul_into::<I0F128, _>(delta), -delta);
+        assert_eq!((-delta).checked_mul_into::<U0F128, _>(delta), None);
+        assert_eq!(
+            I0F128::MIN.checked_mul_into::<U0F128, _>(I0F128::MIN),
+            Some(U0F128::from_bits(1 << 126))
+        );
+    }
+
+    #[test]
+    fn mul_output_mixed() {
+        use crate::{
+            traits::MulOutput,
+            types::{extra::U0, I0F8, I16F0, I32F32, I8F0, U0F16, U128F0, U64F0, U64F64, U8F0},
+            FixedI256, FixedU256,
+        };
+        use std::string::ToString;
+        // extremes of mixed signedness and width fit the exact product
+        type A = <I0F8 as MulOutput<U8F0>>::Output;
+        let a: A = I0F8::MIN.mul_into(U8F0::MAX);
+        assert_eq!(a, -127.5);
+        assert_eq!(a, I0F8::MIN.checked_mul_into::<A, _>(U8F0::MAX).unwrap());
+        type B = <U0F16 as MulOutput<I16F0>>::Output;
+        let b: B = U0F16::MAX.mul_into(I16F0::MIN);
+        assert_eq!(b, B::from_bits(-(0xFFFF << 15)));
+        type C = <U64F0 as MulOutput<I32F32>>::Output;
+        let c: C = U64F0::MAX.mul_into(I32F32::MIN);
+        assert_eq!(c, C::from_bits(-(i128::from(u64::MAX) << 63)));
+        let d: C = U64F0::MAX.mul_into(I32F32::MAX);
+        assert_eq!(d.to_bits(), i128::from(u64::MAX) * i128::from(i64::MAX));
+
+        // a 128-bit operand gives a 256-bit product
+        let e: <I8F0 as MulOutput<U128F0>>::Output = FixedI256::<U0>::ZERO;
+        let f: <U128F0 as MulOutput<U8F0>>::Output = FixedU256::<U0>::ZERO;
+        let x = U64F64::from_num(1.5);
+        let g: <U64F64 as MulOutput<U64F64>>::Output = x.wide_mul(x);
+        assert_eq!((e, f), (FixedI256::ZERO, FixedU256::ZERO));
+        assert_eq!(g.to_string(), "2.25");
+    }
+
+    #[test]
+    fn add_sub_wide_exhaustive_8() {
+        use crate::types::{I0F8, I4F4, I8F0, U0F8, U4F4, U8F0};
+        use core::convert::TryFrom;
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let (sa, sb) = (i32::from(a), i32::from(b));
+                let (fa, fb) = (I4F4::from_bits(a), I0F8::from_bits(b));
+                assert_eq!(fa.add_wide(fb).to_bits(), (sa * 16 + sb) as i16);
+                assert_eq!(fa.sub_wide(fb).to_bits(), (sa * 16 - sb) as i16);
+                assert_eq!(fb.sub_wide(fa).to_bits(), (sb - sa * 16) as i16);
+                let (fa, fb) = (I8F0::from_bits(a), I0F8::from_bits(b));
+                let sum = sa * 256 + sb;
+                let exp = i16::try_from(sum).ok();
+                assert_eq!(fa.checked_add_wide(fb).map(|x| x.to_bits()), exp);
+                let exp = i16::try_from(sa * 256 - sb).ok();
+                assert_eq!(fa.checked_sub_wide(fb).map(|x| x.to_bits()), exp);
+
+                let (ua, ub) = (a as u8, b as u8);
+                let (sa, sb) = (i32::from(ua), i32::from(ub));
+                let (fa, fb) = (U8F0::from_bits(ua), U0F8::from_bits(ub));
+                assert_eq!(fa.add_wide(fb).to_bits(), (sa * 256 + sb) as u16);
+                let exp = u16::try_from(sa * 256 - sb).ok();
+                assert_eq!(fa.checked_sub_wide(fb).map(|x| x.to_bits()), exp);
+                let (fa, fb) = (U4F4::from_bits(ua), U0F8::from_bits(ub));
+                let exp = u16::try_from(sb - sa * 16).ok();
+                assert_eq!(fb.checked_sub_wide(fa).map(|x| x.to_bits()), exp);
+            }
+        }
+    }
+
+    #[test]
+    fn add_sub_wide_128() {
+        use crate::{
+            types::{I0F128, I128F0, I64F64, U0F128, U128F0},
+            FixedI256, FixedU256,
+        };
+        let (int, frac) = (I128F0::MAX, I0F128::MAX);
+        let sum = int.add_wide(frac);
+        assert_eq!(sum.frac(), FixedI256::from(frac));
+        assert_eq!(sum.int(), int.add_wide(I0F128::ZERO));
+        assert_eq!(I128F0::MIN.checked_add_wide(I0F128::MIN), None);
+        assert_eq!(I128F0::MIN.checked_sub_wide(I0F128::DELTA), None);
+        assert_eq!(
+            I128F0::ZERO.checked_add_wide(I0F128::MIN),
+            Some(FixedI256::from(I0F128::MIN))
+        );
+        let one = I128F0::ONE.add_wide(I0F128::ZERO);
+        let delta = FixedI256::from(I0F128::DELTA);
+        assert_eq!(I128F0::ONE.sub_wide(I0F128::DELTA) + delta, one);
+        let half = I64F64::from_num(-0.5);
+        assert_eq!(
+            half.add_wide(I0F128::DELTA),
+            FixedI256::from(I0F128::from_num(-0.5) + I0F128::DELTA)
+        );
+        let (int, frac) = (U128F0::MAX, U0F128::MAX);
+        let sum = int.add_wide(frac);
+        assert_eq!(sum.frac(), FixedU256::from(frac));
+        assert_eq!(sum.int(), int.add_wide(U0F128::ZERO));
+        assert_eq!(U0F128::MAX.checked_sub_wide(U128F0::ONE), None);
+        assert_eq!(
+            U128F0::ONE.checked_sub_wide(U0F128::MAX),
+            Some(FixedU256::from(U0F128::DELTA))
+        );
+    }
+
+    #[test]
+    fn wide_div_exhaustive_8() {
+        use crate::types::{I2F6, I4F4, I6F2, U2F6, U4F4};
+        use core::convert::TryFrom;
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let (sa, sb) = (i32::from(a), i32::from(b));
+                let fa = I4F4::from_bits(a);
+                let (fb, fc) = (I6F2::from_bits(b), I2F6::from_bits(b));
+                if b == 0 {
+                    assert_eq!(fa.checked_wide_div(fb), None);
+                    continue;
+                }
+                // division of i32 truncates like the / operator
+                let exp = i16::try_from(sa * 16 / sb).ok();
+                assert_eq!(fa.checked_wide_div(fb).map(|x| x.to_bits()), exp);
+                if let Some(exp) = exp {
+                    assert_eq!(fa.wide_div(fb).to_bits(), exp);
+                }
+                let exp = i16::try_from(sa * 4096 / sb).ok();
+                assert_eq!(fa.checked_wide_div(fc).map(|x| x.to_bits()), exp);
+
+                let (ua, ub) = (a as u8, b as u8);
+                let (sa, sb) = (u32::from(ua), u32::from(ub));
+                let (fa, fc) = (U4F4::from_bits(ua), U2F6::from_bits(ub));
+                let exp = u16::try_from(sa * 4096 / sb).ok();
+                assert_eq!(fa.checked_wide_div(fc).map(|x| x.to_bits()), exp);
+            }
+        }
+    }
+
+    #[test]
+    fn wide_div_64() {
+        use crate::types::{I0F64, I128F0, I64F0, U0F64, U64F0};
+        let min = I64F0::MIN;
+        assert_eq!(
+            min.wide_div(I64F0::from_num(-1)),
+            I128F0::from_num(1u64 << 63)
+        );
+        assert_eq!(min.checked_wide_div(I0F64::DELTA), None);
+        assert_eq!(I64F0::from_num(-1).checked_wide_div(I0F64::DELTA), None);
+        let quot = I64F0::from_num(-1).wide_div(I0F64::from_num(0.25));
+        assert_eq!(quot.to_bits(), -1 << 66);
+        let third = U64F0::ONE.wide_div(U64F0::from_num(3));
+        assert_eq!(third.to_bits(), 0);
+        let two_thirds = U0F64::from_num(0.5).wide_div(U0F64::from_num(0.75));
+        assert_eq!(two_thirds.to_bits(), u128::MAX / 3 * 2);
+    }
+
+    fn ref_mul_div(prod: i32, div: i32, mode: RoundingMode) -> i32 {
+        use core::cmp::Ordering;
+        let (prod, div) = if div < 0 { (-prod, -div) } else { (prod, div) };
+        let floor = prod.div_euclid(div);
+        let rem = prod.rem_euclid(div);
+        if rem == 0 {
+            return floor;
+        }
+        let ceil = floor + 1;
+        let tie = if mode == RoundingMode::NearestTiesEven {
+            floor % 2 != 0
+        } else {
+            floor >= 0
+        };
+        match mode {
+            RoundingMode::Floor => floor,
+            RoundingMode::Ceil => ceil,
+            RoundingMode::TowardZero => {
+                if floor < 0 {
+                    ceil
+                } else {
+                    floor
+                }
+            }
+            RoundingMode::AwayFromZero => {
+                if floor < 0 {
+                    floor
+                } else {
+                    ceil
+                }
+            }
+            RoundingMode::NearestTiesEven | RoundingMode::NearestTiesAway => {
+                match (2 * rem).cmp(&div) {
+                    Ordering::Less => floor,
+                    Ordering::Greater => ceil,
+                    Ordering::Equal => {
+                        if tie {
+                            ceil
+                        } else {
+                            floor
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    const MODES: [RoundingMode; 6] = [
+        RoundingMode::Floor,
+        RoundingMode::Ceil,
+        RoundingMode::TowardZero,
+        RoundingMode::AwayFromZero,
+        RoundingMode::NearestTiesEven,
+        RoundingMode::NearestTiesAway,
+    ];
+
+    #[test]
+    fn mul_div_8() {
+        use crate::types::{I4F4, U4F4};
+        use core::convert::TryFrom;
+        let muls = [-128, -77, -16, -3, -1, 0, 1, 2, 5, 16, 77, 127];
+        for a in i8::MIN..=i8::MAX {
+            for c in i8::MIN..=i8::MAX {
+                if c == 0 {
+                    continue;
+                }
+                for &b in &muls {
+                    let (fa, fb, fc) = (I4F4::from_bits(a), I4F4::from_bits(b), I4F4::from_bits(c));
+                    let prod = i32::from(a) * i32::from(b);
+                    for &mode in &MODES {
+                        let exp = i8::try_from(ref_mul_div(prod, i32::from(c), mode)).ok();
+                        let ans = fa.checked_mul_div_round(fb, fc, mode);
+                        assert_eq!(ans.map(I4F4::to_bits), exp);
+                    }
+                    let exp = i8::try_from(prod / i32::from(c)).ok();
+                    assert_eq!(fa.checked_mul_div(fb, fc).map(I4F4::to_bits), exp);
+
+                    let (ua, ub, uc) = (a as u8, b as u8, c as u8);
+                    let (fa, fb, fc) = (
+                        U4F4::from_bits(ua),
+                        U4F4::from_bits(ub),
+                        U4F4::from_bits(uc),
+                    );
+                    let prod = i32::from(ua) * i32::from(ub);
+                    for &mode in &MODES {
+                        let exp = u8::try_from(ref_mul_div(prod, i32::from(uc), mode)).ok();
+                        let ans = fa.checked_mul_div_round(fb, fc, mode);
+                        assert_eq!(ans.map(U4F4::to_bits), exp);
+                    }
+                }
+            }
+        }
+        let two = I4F4::from_num(2);
+        assert_eq!(I4F4::MAX.saturating_mul_div(two, -I4F4::ONE), I4F4::MIN);
+        assert_eq!(I4F4::MIN.saturating_mul_div(two, -I4F4::ONE), I4F4::MAX);
+        assert_eq!(I4F4::ZERO.checked_mul_div(two, I4F4::ZERO), None);
+    }
+
+    #[test]
+    fn mul_div_128() {
+        use crate::types::{I128F0, I64F0, U128F0, U64F0};
+        use core::convert::TryFrom;
+        let mut x = 0x1234_5678_9abc_def0_u64;
+        let mut next = || {
+            x = x.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            // vary the magnitude so that some results fit in 64 bits
+            x >> (x % 64)
+        };
+        for _ in 0..10_000 {
+            let (a, b, c) = (next(), next(), next().max(1));
+            for &mode in &MODES {
+                let narrow = U64F0::from_bits(a).checked_mul_div_round(
+                    U64F0::from_bits(b),
+                    U64F0::from_bits(c),
+                    mode,
+                );
+                let wide = U128F0::from_bits(u128::from(a)).checked_mul_div_round(
+                    U128F0::from_bits(u128::from(b)),
+                    U128F0::from_bits(u128::from(c)),
+                    mode,
+                );
+                let wide = wide.and_then(|w| u64::try_from(w.to_bits()).ok());
+                assert_eq!(narrow.map(U64F0::to_bits), wide);
+
+                let (a, b, c) = (a as i64, b.wrapping_neg() as i64, c as i64);
+                let narrow = I64F0::from_bits(a).checked_mul_div_round(
+                    I64F0::from_bits(b),
+                    I64F0::from_bits(c),
+                    mode,
+                );
+                let wide = I128F0::from_bits(i128::from(a)).checked_mul_div_round(
+                    I128F0::from_bits(i128::from(b)),
+                    I128F0::from_bits(i128::from(c)),
+                    mode,
+                );
+                let wide = wide.and_then(|w| i64::try_from(w.to_bits()).ok());
+                assert_eq!(narrow.map(I64F0::to_bits), wide);
+            }
+        }
+        let max = U128F0::MAX;
+        assert_eq!(max.mul_div(max, max), max);
+        assert_eq!(max.checked_mul_div(max, max - U128F0::ONE), None);
+        let min = I128F0::MIN;
+        assert_eq!(min.mul_div(min, min), min);
+        assert_eq!(min.checked_mul_div(min, I128F0::ONE), None);
+    }
+
+    #[test]
+    fn add_sub_mixed_sign_exhaustive_8() {
+        use crate::types::{I4F4, U4F4};
+        for a in 0..=u8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let (af, bf) = (U4F4::from_bits(a), I4F4::from_bits(b));
+                let exact = i32::from(a) + i32::from(b);
+                let overflow = exact < 0 || exact > i32::from(u8::MAX);
+                let wrapped = U4F4::from_bits(exact as u8);
+                assert_eq!(af.overflowing_add_signed(bf), (wrapped, overflow));
+                assert_eq!(af.wrapping_add_signed(bf), wrapped);
+                assert_eq!(
+                    af.checked_add_signed(bf),
+                    Some(wrapped).filter(|_| !overflow)
+                );
+                let saturated = match exact {
+                    _ if !overflow => wrapped,
+                    e if e < 0 => U4F4::ZERO,
+                    _ => U4F4::MAX,
+                };
+                assert_eq!(af.saturating_add_signed(bf), saturated);
+            }
+        }
+        for a in i8::MIN..=i8::MAX {
+            for b in 0..=u8::MAX {
+                let (af, bf) = (I4F4::from_bits(a), U4F4::from_bits(b));
+                for &(sum, exact) in &[
+                    (true, i32::from(a) + i32::from(b)),
+                    (false, i32::from(a) - i32::from(b)),
+                ] {
+                    let overflow = exact < i32::from(i8::MIN) || exact > i32::from(i8::MAX);
+                    let wrapped = I4F4::from_bits(exact as i8);
+                    let saturated = match exact {
+                        _ if !overflow => wrapped,
+                        e if e < 0 => I4F4::MIN,
+                        _ => I4F4::MAX,
+                    };
+                    let results = if sum {
+                        (
+                            af.overflowing_add_unsigned(bf),
+                            af.wrapping_add_unsigned(bf),
+                            af.checked_add_unsigned(bf),
+                            af.saturating_add_unsigned(bf),
+                        )
+                    } else {
+                        (
+                            af.overflowing_sub_unsigned(bf),
+                            af.wrapping_sub_unsigned(bf),
+                            af.checked_sub_unsigned(bf),
+                            af.saturating_sub_unsigned(bf),
+                        )
+                    };
+                    assert_eq!(
+                        results,
+                        (
+                            (wrapped, overflow),
+                            wrapped,
+                            Some(wrapped).filter(|_| !overflow),
+                            saturated
+                        )
+                    );
+                }
+            }
+        }
+    }
+
+    fn check_mul_mixed_sign_8<MulFrac: LeEqU8>() {
+        use crate::types::extra::U4;
+        let frac = MulFrac::U32;
+        for a in 0..=u8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                for &c in &[0, 1, 0x7f, 0x80, 0xff] {
+                    let af = FixedU8::<U4>::from_bits(a);
+                    let bf = FixedI8::<MulFrac>::from_bits(b);
+                    let cf = FixedU8::<U4>::from_bits(c);
+                    let exact = ((i32::from(a) * i32::from(b)) >> frac) + i32::from(c);
+                    let overflow = exact < 0 || exact > i32::from(u8::MAX);
+                    let wrapped = FixedU8::from_bits(exact as u8);
+                    let saturated = match exact {
+                        _ if !overflow => wrapped,
+                        e if e < 0 => FixedU8::ZERO,
+                        _ => FixedU8::MAX,
+                    };
+                    let expected = (wrapped, overflow);
+                    assert_eq!(af.overflowing_mul_add_signed(bf, cf), expected);
+                    assert_eq!(af.wrapping_mul_add_signed(bf, cf), wrapped);
+                    let checked = Some(wrapped).filter(|_| !overflow);
+                    assert_eq!(af.checked_mul_add_signed(bf, cf), checked);
+                    assert_eq!(af.saturating_mul_add_signed(bf, cf), saturated);
+                    if c == 0 {
+                        assert_eq!(af.overflowing_mul_signed(bf), expected);
+                        assert_eq!(af.wrapping_mul_signed(bf), wrapped);
+                        assert_eq!(af.checked_mul_signed(bf), checked);
+                        assert_eq!(af.saturating_mul_signed(bf), saturated);
+                    }
+                }
+            }
+        }
+        for a in i8::MIN..=i8::MAX {
+            for b in 0..=u8::MAX {
+                for &c in &[0, 1, -1, i8::MIN, i8::MAX] {
+                    let af = FixedI8::<U4>::from_bits(a);
+                    let bf = FixedU8::<MulFrac>::from_bits(b);
+                    let cf = FixedI8::<U4>::from_bits(c);
+                    let exact = ((i32::from(a) * i32::from(b)) >> frac) + i32::from(c);
+                    let overflow = exact < i32::from(i8::MIN) || exact > i32::from(i8::MAX);
+                    let wrapped = FixedI8::from_bits(exact as i8);
+                    let saturated = match exact {
+                        _ if !overflow => wrapped,
+                        e if e < 0 => FixedI8::MIN,
+                        _ => FixedI8::MAX,
+                    };
+                    let expected = (wrapped, overflow);
+                    assert_eq!(af.overflowing_mul_add_unsigned(bf, cf), expected);
+                    assert_eq!(af.wrapping_mul_add_unsigned(bf, cf), wrapped);
+                    let checked = Some(wrapped).filter(|_| !overflow);
+                    assert_eq!(af.checked_mul_add_unsigned(bf, cf), checked);
+                    assert_eq!(af.saturating_mul_add_unsigned(bf, cf), saturated);
+                    if c == 0 {
+                        assert_eq!(af.overflowing_mul_unsigned(bf), expected);
+                        assert_eq!(af.wrapping_mul_unsigned(bf), wrapped);
+                        assert_eq!(af.checked_mul_unsigned(bf), checked);
+                        assert_eq!(af.saturating_mul_unsigned(bf), saturated);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mul_mixed_sign_exhaustive_8() {
+        use crate::types::extra::{U0, U3, U8};
+        check_mul_mixed_sign_8::<U0>();
+        check_mul_mixed_sign_8::<U3>();
+        check_mul_mixed_sign_8::<U8>();
+    }
+
+    fn check_add_sub_int_8<Frac: LeEqU8>() {
+        let frac = Frac::U32;
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let af = FixedI8::<Frac>::from_bits(a);
+                for &(sum, exact) in &[
+                    (true, i32::from(a) + (i32::from(b) << frac)),
+                    (false, i32::from(a) - (i32::from(b) << frac)),
+                ] {
+                    let overflow = exact < i32::from(i8::MIN) || exact > i32::from(i8::MAX);
+                    let wrapped = FixedI8::from_bits(exact as i8);
+                    let saturated = match exact {
+                        _ if !overflow => wrapped,
+                        e if e < 0 => FixedI8::MIN,
+                        _ => FixedI8::MAX,
+                    };
+                    let results = if sum {
+                        (
+                            af.overflowing_add_int(b),
+                            af.wrapping_add_int(b),
+                            af.checked_add_int(b),
+                            af.saturating_add_int(b),
+                        )
+                    } else {
+                        (
+                            af.overflowing_sub_int(b),
+                            af.wrapping_sub_int(b),
+                            af.checked_sub_int(b),
+                            af.saturating_sub_int(b),
+                        )
+                    };
+                    let checked = Some(wrapped).filter(|_| !overflow);
+                    assert_eq!(results, ((wrapped, overflow), wrapped, checked, saturated));
+                }
+            }
+        }
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                let af = FixedU8::<Frac>::from_bits(a);
+                for &(sum, exact) in &[
+                    (true, i32::from(a) + (i32::from(b) << frac)),
+                    (false, i32::from(a) - (i32::from(b) << frac)),
+                ] {
+                    let overflow = exact < 0 || exact > i32::from(u8::MAX);
+                    let wrapped = FixedU8::from_bits(exact as u8);
+                    let saturated = match exact {
+                        _ if !overflow => wrapped,
+                        e if e < 0 => FixedU8::ZERO,
+                        _ => FixedU8::MAX,
+                    };
+                    let results = if sum {
+                        (
+                            af.overflowing_add_int(b),
+                            af.wrapping_add_int(b),
+                            af.checked_add_int(b),
+                            af.saturating_add_int(b),
+                        )
+                    } else {
+                        (
+                            af.overflowing_sub_int(b),
+                            af.wrapping_sub_int(b),
+                            af.checked_sub_int(b),
+                            af.saturating_sub_int(b),
+                        )
+                    };
+                    let checked = Some(wrapped).filter(|_| !overflow);
+                    assert_eq!(results, ((wrapped, overflow), wrapped, checked, saturated));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn add_sub_int_exhaustive_8() {
+        use crate::types::extra::{U0, U3, U7, U8};
+        check_add_sub_int_8::<U0>();
+        check_add_sub_int_8::<U3>();
+        check_add_sub_int_8::<U7>();
+        check_add_sub_int_8::<U8>();
+    }
 }
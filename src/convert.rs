@@ -1080,7 +1080,7 @@ fn _compile_fail_tests() {}
 #[cfg(test)]
 #[allow(clippy::float_cmp)]
 mod tests {
-    use crate::types::*;
+    use crate::{types::*, RoundingMode};
 
     #[test]
     fn expanding_from_unsigned() {
@@ -1739,4 +1739,267 @@ mod tests {
         assert_eq!(U0F128::from_num(I128F0::ZERO), 0);
         assert_eq!(U0F128::from_num(U128F0::ZERO), 0);
     }
+
+    fn round_ref(t: f64, mode: RoundingMode) -> f64 {
+        match mode {
+            RoundingMode::Floor => t.floor(),
+            RoundingMode::Ceil => t.ceil(),
+            RoundingMode::TowardZero => t.trunc(),
+            RoundingMode::AwayFromZero => {
+                if t < 0.0 {
+                    t.floor()
+                } else {
+                    t.ceil()
+                }
+            }
+            RoundingMode::NearestTiesEven => {
+                let floor = t.floor();
+                let diff = t - floor;
+                if diff < 0.5 || (diff == 0.5 && floor % 2.0 == 0.0) {
+                    floor
+                } else {
+                    floor + 1.0
+                }
+            }
+            RoundingMode::NearestTiesAway => t.round(),
+        }
+    }
+
+    const MODES: [RoundingMode; 6] = [
+        RoundingMode::Floor,
+        RoundingMode::Ceil,
+        RoundingMode::TowardZero,
+        RoundingMode::AwayFromZero,
+        RoundingMode::NearestTiesEven,
+        RoundingMode::NearestTiesAway,
+    ];
+
+    #[test]
+    fn round_modes_exhaustive() {
+        for &mode in &MODES {
+            for bits in i16::MIN..=i16::MAX {
+                let src = I8F8::from_bits(bits);
+                let val = f64::from(bits) / 256.0;
+
+                let r = round_ref(val * 4.0, mode);
+                let fits = (-128.0..=127.0).contains(&r);
+                // casting from float to integer saturates
+                let expected = I6F2::from_bits(r as i8);
+                assert_eq!(
+                    src.checked_to_num_round::<I6F2>(mode),
+                    Some(expected).filter(|_| fits)
+                );
+                assert_eq!(src.saturating_to_num_round::<I6F2>(mode), expected);
+                let from_float = I6F2::checked_from_num_round(val as f32, mode);
+                assert_eq!(from_float, Some(expected).filter(|_| fits));
+                let from_float = I6F2::saturating_from_num_round(val, mode);
+                assert_eq!(from_float, expected);
+
+                let r = round_ref(val, mode);
+                let fits = (0.0..=255.0).contains(&r);
+                let expected = r as u8;
+                assert_eq!(
+                    src.checked_to_num_round::<u8>(mode),
+                    Some(expected).filter(|_| fits)
+                );
+                assert_eq!(src.saturating_to_num_round::<u8>(mode), expected);
+                let expected = U8F0::from_bits(expected);
+                assert_eq!(
+                    U8F0::checked_from_num_round(src, mode),
+                    Some(expected).filter(|_| fits)
+                );
+                assert_eq!(U8F0::saturating_from_num_round(src, mode), expected);
+            }
+            for bits in u16::MIN..=u16::MAX {
+                let src = U6F10::from_bits(bits);
+                let val = f64::from(bits) / 1024.0;
+
+                let r = round_ref(val * 8.0, mode);
+                let fits = r <= 255.0;
+                let expected = U5F3::from_bits(r as u8);
+                assert_eq!(
+                    src.checked_to_num_round::<U5F3>(mode),
+                    Some(expected).filter(|_| fits)
+                );
+                assert_eq!(src.saturating_to_num_round::<U5F3>(mode), expected);
+
+                let r = round_ref(val, mode);
+                let expected = r as i8;
+                assert_eq!(src.to_num_round::<i8>(mode), expected);
+                assert_eq!(I8F0::from_num_round(src, mode), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn round_modes_wide_shift() {
+        use RoundingMode::*;
+        // −0.5 is a tie, and the whole 128 bits are shifted out
+        let minus_half = I0F128::MIN;
+        let check =
+            |mode, expected: i32| assert_eq!(minus_half.to_num_round::<i32>(mode), expected);
+        check(Floor, -1);
+        check(Ceil, 0);
+        check(TowardZero, 0);
+        check(AwayFromZero, -1);
+        check(NearestTiesEven, 0);
+        check(NearestTiesAway, -1);
+        let above = I0F128::from_bits(i128::MIN + 1);
+        assert_eq!(above.to_num_round::<i32>(NearestTiesAway), 0);
+        assert_eq!(above.to_num_round::<i32>(AwayFromZero), -1);
+
+        let half = U0F128::from_bits(1 << 127);
+        assert_eq!(half.to_num_round::<u8>(NearestTiesEven), 0);
+        assert_eq!(half.to_num_round::<u8>(NearestTiesAway), 1);
+        assert_eq!(
+            (half + U0F128::DELTA).to_num_round::<u8>(NearestTiesEven),
+            1
+        );
+        assert_eq!(U0F128::DELTA.to_num_round::<u8>(Ceil), 1);
+        assert_eq!(U0F128::MAX.to_num_round::<u8>(TowardZero), 0);
+
+        // tiny floats lose all their bits
+        assert_eq!(I16F16::from_num_round(1e-30f32, Floor), 0);
+        assert_eq!(I16F16::from_num_round(1e-30f32, Ceil), I16F16::DELTA);
+        assert_eq!(I16F16::from_num_round(-1e-30f32, Floor), -I16F16::DELTA);
+        assert_eq!(
+            I16F16::from_num_round(-1e-30f32, AwayFromZero),
+            -I16F16::DELTA
+        );
+        assert_eq!(I16F16::from_num_round(-1e-30f32, NearestTiesAway), 0);
+        assert_eq!(U16F16::checked_from_num_round(-1e-30f32, Floor), None);
+        assert_eq!(
+            U16F16::checked_from_num_round(-1e-30f32, Ceil),
+            Some(U16F16::ZERO)
+        );
+    }
+
+    #[test]
+    fn round_modes_to_float() {
+        use half::f16;
+        use RoundingMode::*;
+        // 1 + 2↑−11 is half-way between two f16 values
+        let tie = U16F16::from_bits((1 << 16) + (1 << 5));
+        let below = f16::from_f32(1.0);
+        let above = f16::from_f32(1.0 + 1.0 / 1024.0);
+        assert_eq!(tie.to_num_round::<f16>(Floor), below);
+        assert_eq!(tie.to_num_round::<f16>(Ceil), above);
+        assert_eq!(tie.to_num_round::<f16>(NearestTiesEven), below);
+        assert_eq!(tie.to_num_round::<f16>(NearestTiesAway), above);
+        assert_eq!((-tie.to_num::<I16F16>()).to_num_round::<f16>(Ceil), -below);
+        assert_eq!(
+            (-tie.to_num::<I16F16>()).to_num_round::<f16>(AwayFromZero),
+            -above
+        );
+
+        // subnormal f16 with bits lost below the smallest subnormal
+        let tiny = U0F32::from_bits((1 << 8) + 1);
+        let smallest = f16::from_bits(1);
+        assert_eq!(tiny.to_num_round::<f16>(TowardZero), smallest);
+        assert_eq!(tiny.to_num_round::<f16>(NearestTiesEven), smallest);
+        assert_eq!(tiny.to_num_round::<f16>(Ceil), f16::from_bits(2));
+        assert_eq!(U0F32::from_bits(1).to_num_round::<f16>(Ceil), smallest);
+        assert_eq!(
+            U0F32::from_bits(1).to_num_round::<f16>(NearestTiesEven),
+            f16::ZERO
+        );
+
+        // 70000 is larger than f16::MAX
+        let large = I32F0::from_num(-70000);
+        assert_eq!(large.to_num_round::<f16>(Ceil), f16::MIN);
+        assert_eq!(large.to_num_round::<f16>(TowardZero), f16::MIN);
+        assert_eq!(large.to_num_round::<f16>(Floor), f16::NEG_INFINITY);
+        assert_eq!(
+            large.to_num_round::<f16>(NearestTiesEven),
+            f16::NEG_INFINITY
+        );
+        // U128F0::MAX is larger than f32::MAX, but less than 2↑128
+        assert_eq!(U128F0::MAX.to_num_round::<f32>(TowardZero), f32::MAX);
+        assert_eq!(
+            U128F0::MAX.to_num_round::<f32>(NearestTiesEven),
+            f32::INFINITY
+        );
+    }
+
+    #[test]
+    fn stochastic_exhaustive() {
+        for bits in i16::MIN..=i16::MAX {
+            let src = I8F8::from_bits(bits);
+            let floor = i32::from(bits >> 4);
+            let mut sum = 0;
+            for random in 0..16u128 {
+                let dst = I12F4::from_num_stochastic(src, random);
+                let dst_bits = i32::from(dst.to_bits());
+                assert!(dst_bits == floor || dst_bits == floor + 1);
+                // high bits of random are ignored
+                assert_eq!(src.to_num_stochastic::<I12F4>(random | !0xf), dst);
+                sum += dst_bits;
+            }
+            // the expected value is exact
+            assert_eq!(sum, i32::from(bits));
+        }
+        for bits in u16::MIN..=u16::MAX {
+            let src = U8F8::from_bits(bits);
+            let mut sum = 0;
+            for random in 0..256u128 {
+                sum += u32::from(U16F0::from_num_stochastic(src, random).to_bits());
+            }
+            assert_eq!(sum, u32::from(bits));
+        }
+    }
+
+    #[test]
+    fn stochastic_wide() {
+        // all 128 bits are discarded
+        let minus_half = I0F128::MIN;
+        assert_eq!(minus_half.to_num_stochastic::<I8F0>(0), 0);
+        assert_eq!(minus_half.to_num_stochastic::<I8F0>((1 << 127) - 1), 0);
+        assert_eq!(minus_half.to_num_stochastic::<I8F0>(1 << 127), -1);
+        assert_eq!(minus_half.to_num_stochastic::<I8F0>(!0), -1);
+        assert_eq!(U0F128::MAX.to_num_stochastic::<U8F0>(!0 - 1), 1);
+        assert_eq!(U0F128::MAX.to_num_stochastic::<U8F0>(!0), 0);
+        assert_eq!(U0F128::DELTA.to_num_stochastic::<U8F0>(0), 1);
+        assert_eq!(U0F128::DELTA.to_num_stochastic::<U8F0>(1), 0);
+
+        // no bits are discarded, so random is ignored
+        let src = I16F16::from_num(-1.75);
+        assert_eq!(src.to_num_stochastic::<I16F16>(0), src);
+        assert_eq!(src.to_num_stochastic::<I8F24>(0), src);
+        assert_eq!(src.to_num_stochastic::<I8F8>(0), src);
+        assert_eq!(src.to_num_stochastic::<I16F0>(0), -1);
+        assert_eq!(src.to_num_stochastic::<I16F0>(1 << 14), -2);
+    }
+
+    #[test]
+    fn exact_conversions() {
+        let (f, inexact) = I16F16::from_num_exact(I8F24::from_bits(0x0180_0000));
+        assert_eq!((f, inexact), (I16F16::from_num(1.5), false));
+        let (f, inexact) = I16F16::from_num_exact(I8F24::from_bits(-0x0180_0001));
+        assert_eq!((f, inexact), (I16F16::from_num(-1.5) - I16F16::DELTA, true));
+        assert_eq!(I16F16::from_num_exact(-7i64), (I16F16::from_num(-7), false));
+        assert_eq!(I16F16::from_num_exact(true), (I16F16::ONE, false));
+        assert_eq!(
+            I16F16::from_num_exact(0.1f64),
+            (I16F16::from_num(0.1), true)
+        );
+        assert_eq!(
+            I16F16::from_num_exact(-0.25f32),
+            (I16F16::from_num(-0.25), false)
+        );
+        assert_eq!(U0F32::from_num_exact(1e-30f64), (U0F32::ZERO, true));
+        assert_eq!(U0F32::from_num_exact(0f64), (U0F32::ZERO, false));
+
+        let src = I16F16::from_num(-2.75);
+        assert_eq!(src.to_num_exact::<i32>(), (-3, true));
+        assert_eq!(src.to_num_exact::<I30F2>(), (I30F2::from_num(-2.75), false));
+        assert_eq!(src.to_num_exact::<I31F1>(), (I31F1::from_num(-3), true));
+        assert_eq!(src.to_num_exact::<f32>(), (-2.75, false));
+        assert_eq!(I16F16::from_num(3).to_num_exact::<u8>(), (3, false));
+        // 24 significant bits fit in f32, 25 do not
+        let src = U32F0::from_bits(0x00ff_ffff);
+        assert_eq!(src.to_num_exact::<f32>(), (16_777_215.0, false));
+        let src = U32F0::from_bits(0x01ff_ffff);
+        assert_eq!(src.to_num_exact::<f32>(), (33_554_432.0, true));
+        assert_eq!(U128F0::MAX.to_num_exact::<f32>(), (f32::INFINITY, true));
+    }
 }
@@ -15,10 +15,11 @@
 
 use crate::{
     debug_hex::{self, IsDebugHex},
+    int256::{self, I256, U256},
     int_helper,
-    types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
-    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8,
+    types::extra::{LeEqU128, LeEqU16, LeEqU256, LeEqU32, LeEqU64, LeEqU8},
+    FixedI128, FixedI16, FixedI256, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU256,
+    FixedU32, FixedU64, FixedU8,
 };
 use az_crate::{WrappingAs, WrappingCast};
 use core::{
@@ -31,7 +32,8 @@ use core::{
     str,
 };
 
-// We need 130 bytes: 128 digits, one radix point, one leading zero.
+// We need N = BITS + 2 bytes: BITS digits, one radix point, one leading zero.
+// That is 130 bytes for up to 128 bits, and 258 bytes for 256 bits.
 //
 // The leading zero has two purposes:
 //
@@ -43,25 +45,25 @@ use core::{
 //   * data[0..int_digits + 1]: integer digits with potentially one extra zero
 //   * data[int_digits + 1..int_digits + 2]: '.'
 //   * data[int_digits + 2..int_digits + frac_digits + 2]: fractional digits
-struct Buffer {
+struct Buffer<const N: usize> {
     int_digits: usize,
     frac_digits: usize,
-    data: [u8; 130],
+    data: [u8; N],
 }
 
-impl Buffer {
-    fn new() -> Buffer {
+impl<const N: usize> Buffer<N> {
+    fn new() -> Buffer<N> {
         Buffer {
             int_digits: 0,
             frac_digits: 0,
-            data: [0; 130],
+            data: [0; N],
         }
     }
 
     // Do not combine with new to avoid copying data, otherwise the
     // buffer will be created, modified with the '.', then copied.
     fn set_len(&mut self, int_digits: u32, frac_digits: u32) {
-        assert!(int_digits + frac_digits < 130, "out of bounds");
+        assert!(((int_digits + frac_digits) as usize) < N, "out of bounds");
         self.int_digits = int_digits as usize;
         self.frac_digits = frac_digits as usize;
         self.data[1 + self.int_digits] = b'.';
@@ -270,7 +272,7 @@ where
     fn div_rem_10(val: Self) -> (Self, u8);
     fn wrapping_neg(val: Self) -> Self;
 
-    fn write_int(mut int: Self, radix: Radix, nbits: u32, buf: &mut Buffer) {
+    fn write_int<const N: usize>(mut int: Self, radix: Radix, nbits: u32, buf: &mut Buffer<N>) {
         if Self::Half::BITS == Self::BITS / 2 && nbits <= Self::Half::BITS {
             return FmtHelper::write_int(Self::as_half(int), radix, nbits, buf);
         }
@@ -284,7 +286,12 @@ where
         debug_assert!(int == Self::ZERO);
     }
 
-    fn write_frac(mut frac: Self, radix: Radix, nbits: u32, buf: &mut Buffer) -> Ordering {
+    fn write_frac<const N: usize>(
+        mut frac: Self,
+        radix: Radix,
+        nbits: u32,
+        buf: &mut Buffer<N>,
+    ) -> Ordering {
         if Self::Half::BITS == Self::BITS / 2 && nbits <= Self::Half::BITS {
             return FmtHelper::write_frac(
                 Self::as_half(frac >> Self::Half::BITS),
@@ -303,7 +310,7 @@ where
         frac.cmp(&Self::MSB)
     }
 
-    fn write_int_dec(mut int: Self, nbits: u32, buf: &mut Buffer) {
+    fn write_int_dec<const N: usize>(mut int: Self, nbits: u32, buf: &mut Buffer<N>) {
         if Self::Half::BITS == Self::BITS / 2 && nbits <= Self::Half::BITS {
             return FmtHelper::write_int_dec(Self::as_half(int), nbits, buf);
         }
@@ -315,7 +322,12 @@ where
         debug_assert!(int == Self::ZERO);
     }
 
-    fn write_frac_dec(mut frac: Self, nbits: u32, auto_prec: bool, buf: &mut Buffer) -> Ordering {
+    fn write_frac_dec<const N: usize>(
+        mut frac: Self,
+        nbits: u32,
+        auto_prec: bool,
+        buf: &mut Buffer<N>,
+    ) -> Ordering {
         if Self::Half::BITS == Self::BITS / 2 && nbits <= Self::Half::BITS {
             return FmtHelper::write_frac_dec(
                 Self::as_half(frac >> Self::Half::BITS),
@@ -401,7 +413,43 @@ impl_radix_helper! { u32, u16 }
 impl_radix_helper! { u64, u32 }
 impl_radix_helper! { u128, u64 }
 
-fn fmt_dec<U: FmtHelper>((neg, abs): (bool, U), frac_nbits: u32, fmt: &mut Formatter) -> FmtResult {
+impl FmtHelper for U256 {
+    const ZERO: U256 = U256::ZERO;
+    const MSB: U256 = U256 {
+        lo: 0,
+        hi: 1 << 127,
+    };
+    const BITS: u32 = 256;
+
+    type Half = u128;
+
+    fn int_used_nbits(int: U256) -> u32 {
+        256 - int256::leading_zeros_u256(int)
+    }
+
+    fn frac_used_nbits(frac: U256) -> u32 {
+        256 - int256::trailing_zeros_u256(frac)
+    }
+
+    fn as_half(val: U256) -> u128 {
+        val.lo
+    }
+
+    fn div_rem_10(val: U256) -> (U256, u8) {
+        let (q, r) = int256::div_rem_u256_u128(val, 10);
+        (q, r as u8)
+    }
+
+    fn wrapping_neg(val: U256) -> U256 {
+        int256::wrapping_neg_u256(val)
+    }
+}
+
+fn fmt_dec<U: FmtHelper, const N: usize>(
+    (neg, abs): (bool, U),
+    frac_nbits: u32,
+    fmt: &mut Formatter,
+) -> FmtResult {
     let (int, frac) = if frac_nbits == 0 {
         (abs, U::ZERO)
     } else if frac_nbits == U::BITS {
@@ -419,14 +467,15 @@ fn fmt_dec<U: FmtHelper>((neg, abs): (bool, U), frac_nbits: u32, fmt: &mut Forma
         (ceil_log10_2_times(frac_nbits), true)
     };
 
-    let mut buf = Buffer::new();
+    let mut buf = Buffer::<N>::new();
     buf.set_len(int_digits, frac_digits);
     FmtHelper::write_int_dec(int, int_used_nbits, &mut buf);
     let frac_rem_cmp_msb = FmtHelper::write_frac_dec(frac, frac_nbits, auto_prec, &mut buf);
     buf.finish(Radix::Dec, neg, frac_rem_cmp_msb, fmt)
 }
 
-fn fmt_radix2<U: FmtHelper>(
+#[allow(clippy::manual_div_ceil)]
+fn fmt_radix2<U: FmtHelper, const N: usize>(
     (neg, abs): (bool, U),
     frac_nbits: u32,
     radix: Radix,
@@ -449,7 +498,7 @@ fn fmt_radix2<U: FmtHelper>(
         frac_digits = cmp::min(frac_digits as usize, precision) as u32;
     }
 
-    let mut buf = Buffer::new();
+    let mut buf = Buffer::<N>::new();
     buf.set_len(int_digits, frac_digits);
     FmtHelper::write_int(int, radix, int_used_nbits, &mut buf);
     // for bin, oct, hex, we can simply pass frac_used_bits to write_frac
@@ -458,11 +507,11 @@ fn fmt_radix2<U: FmtHelper>(
 }
 
 macro_rules! impl_fmt {
-    ($Fixed:ident($LeEqU:ident, $Inner:ident)) => {
+    ($Fixed:ident($LeEqU:ident, $Inner:ident, $N:expr)) => {
         impl<Frac: $LeEqU> Display for $Fixed<Frac> {
             fn fmt(&self, f: &mut Formatter) -> FmtResult {
                 let neg_abs = int_helper::$Inner::neg_abs(self.to_bits());
-                fmt_dec(neg_abs, Self::FRAC_NBITS, f)
+                fmt_dec::<_, $N>(neg_abs, Self::FRAC_NBITS, f)
             }
         }
 
@@ -470,9 +519,13 @@ macro_rules! impl_fmt {
             fn fmt(&self, f: &mut Formatter) -> FmtResult {
                 let neg_abs = int_helper::$Inner::neg_abs(self.to_bits());
                 match debug_hex::is_debug_hex(f) {
-                    IsDebugHex::Lower => fmt_radix2(neg_abs, Self::FRAC_NBITS, Radix::LowHex, f),
-                    IsDebugHex::Upper => fmt_radix2(neg_abs, Self::FRAC_NBITS, Radix::UpHex, f),
-                    IsDebugHex::No => fmt_dec(neg_abs, Self::FRAC_NBITS, f),
+                    IsDebugHex::Lower => {
+                        fmt_radix2::<_, $N>(neg_abs, Self::FRAC_NBITS, Radix::LowHex, f)
+                    }
+                    IsDebugHex::Upper => {
+                        fmt_radix2::<_, $N>(neg_abs, Self::FRAC_NBITS, Radix::UpHex, f)
+                    }
+                    IsDebugHex::No => fmt_dec::<_, $N>(neg_abs, Self::FRAC_NBITS, f),
                 }
             }
         }
@@ -480,43 +533,57 @@ macro_rules! impl_fmt {
         impl<Frac: $LeEqU> Binary for $Fixed<Frac> {
             fn fmt(&self, f: &mut Formatter) -> FmtResult {
                 let neg_abs = int_helper::$Inner::neg_abs(self.to_bits());
-                fmt_radix2(neg_abs, Self::FRAC_NBITS, Radix::Bin, f)
+                fmt_radix2::<_, $N>(neg_abs, Self::FRAC_NBITS, Radix::Bin, f)
             }
         }
 
         impl<Frac: $LeEqU> Octal for $Fixed<Frac> {
             fn fmt(&self, f: &mut Formatter) -> FmtResult {
                 let neg_abs = int_helper::$Inner::neg_abs(self.to_bits());
-                fmt_radix2(neg_abs, Self::FRAC_NBITS, Radix::Oct, f)
+                fmt_radix2::<_, $N>(neg_abs, Self::FRAC_NBITS, Radix::Oct, f)
             }
         }
 
         impl<Frac: $LeEqU> LowerHex for $Fixed<Frac> {
             fn fmt(&self, f: &mut Formatter) -> FmtResult {
                 let neg_abs = int_helper::$Inner::neg_abs(self.to_bits());
-                fmt_radix2(neg_abs, Self::FRAC_NBITS, Radix::LowHex, f)
+                fmt_radix2::<_, $N>(neg_abs, Self::FRAC_NBITS, Radix::LowHex, f)
             }
         }
 
         impl<Frac: $LeEqU> UpperHex for $Fixed<Frac> {
             fn fmt(&self, f: &mut Formatter) -> FmtResult {
                 let neg_abs = int_helper::$Inner::neg_abs(self.to_bits());
-                fmt_radix2(neg_abs, Self::FRAC_NBITS, Radix::UpHex, f)
+                fmt_radix2::<_, $N>(neg_abs, Self::FRAC_NBITS, Radix::UpHex, f)
             }
         }
     };
 }
 
-impl_fmt! { FixedU8(LeEqU8, u8) }
-impl_fmt! { FixedU16(LeEqU16, u16) }
-impl_fmt! { FixedU32(LeEqU32, u32) }
-impl_fmt! { FixedU64(LeEqU64, u64) }
-impl_fmt! { FixedU128(LeEqU128, u128) }
-impl_fmt! { FixedI8(LeEqU8, i8) }
-impl_fmt! { FixedI16(LeEqU16, i16) }
-impl_fmt! { FixedI32(LeEqU32, i32) }
-impl_fmt! { FixedI64(LeEqU64, i64) }
-impl_fmt! { FixedI128(LeEqU128, i128) }
+impl_fmt! { FixedU8(LeEqU8, u8, 130) }
+impl_fmt! { FixedU16(LeEqU16, u16, 130) }
+impl_fmt! { FixedU32(LeEqU32, u32, 130) }
+impl_fmt! { FixedU64(LeEqU64, u64, 130) }
+impl_fmt! { FixedU128(LeEqU128, u128, 130) }
+impl_fmt! { FixedI8(LeEqU8, i8, 130) }
+impl_fmt! { FixedI16(LeEqU16, i16, 130) }
+impl_fmt! { FixedI32(LeEqU32, i32, 130) }
+impl_fmt! { FixedI64(LeEqU64, i64, 130) }
+impl_fmt! { FixedI128(LeEqU128, i128, 130) }
+impl_fmt! { FixedU256(LeEqU256, u256, 258) }
+impl_fmt! { FixedI256(LeEqU256, i256, 258) }
+
+impl Display for U256 {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        fmt_dec::<_, 258>((false, *self), 0, f)
+    }
+}
+
+impl Display for I256 {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        fmt_dec::<_, 258>(int_helper::i256::neg_abs(*self), 0, f)
+    }
+}
 
 // ceil(i × log_10 2), works for input < 112_816
 fn ceil_log10_2_times(int_bits: u32) -> u32 {
@@ -559,6 +626,16 @@ impl Mul10 for u128 {
         hi_hi as u8 + u8::from(overflow)
     }
 }
+impl Mul10 for U256 {
+    #[inline]
+    fn mul10_assign(x: &mut U256) -> u8 {
+        let lo_carry = Mul10::mul10_assign(&mut x.lo);
+        let hi_carry = Mul10::mul10_assign(&mut x.hi);
+        let (hi, overflow) = x.hi.overflowing_add(u128::from(lo_carry));
+        x.hi = hi;
+        hi_carry + u8::from(overflow)
+    }
+}
 
 #[cfg(test)]
 #[allow(clippy::float_cmp)]
@@ -0,0 +1,253 @@
+// Copyright © 2018–2021 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    exp_log,
+    wide_float::{WideBits, WideFloat},
+};
+
+// For |x| ≥ 16, erfc |x| < 2↑−370, so erf x is ±1 to the precision of
+// WideFloat.
+const ERF_ONE_EXP: i32 = 4;
+
+// Returns erf x.
+//
+// The series
+//
+//     erf x = 2/√π × e↑−x² × (x + 2x³/3 + 4x⁵/(3 × 5) + 8x⁷/(3 × 5 × 7) + …)
+//
+// has terms that all have the same sign, so there is no cancellation even when
+// x is large.
+pub fn erf(x: WideFloat) -> WideFloat {
+    if x.is_zero() {
+        return x;
+    }
+    if x.exp >= ERF_ONE_EXP {
+        return if x.neg {
+            WideFloat::ONE.neg()
+        } else {
+            WideFloat::ONE
+        };
+    }
+    let two_x2 = x.mul(x).mul_pow2(1);
+    let mut sum = x;
+    let mut term = x;
+    let mut k = 3;
+    loop {
+        term = term.mul(two_x2).div_small(k);
+        // the terms are only this small once they are decreasing
+        if term.exp < sum.exp - 256 {
+            break;
+        }
+        sum = sum.add(term);
+        k += 2;
+    }
+    let exp_minus_x2 = exp_log::exp(x.mul(x).neg());
+    sum.mul(exp_minus_x2).mul(WideFloat::FRAC_2_SQRT_PI)
+}
+
+// Returns erfc x = 1 − erf x.
+//
+// The absolute error is small, which is enough for fixed-point results.
+#[inline]
+pub fn erfc(x: WideFloat) -> WideFloat {
+    WideFloat::ONE.add(erf(x).neg())
+}
+
+// Returns the cumulative distribution function of the standard normal
+// distribution, (1 + erf (x/√2)) / 2.
+#[inline]
+pub fn norm_cdf(x: WideFloat) -> WideFloat {
+    let erf = erf(x.mul(WideFloat::FRAC_1_SQRT_2));
+    WideFloat::ONE.add(erf).mul_pow2(-1)
+}
+
+// Returns the probability density function of the standard normal
+// distribution, e↑(−x²/2) / √(2π) = e↑(−x²/2) × 1/√2 × 2/√π / 2.
+#[inline]
+pub fn norm_pdf(x: WideFloat) -> WideFloat {
+    let exp = exp_log::exp(x.mul(x).mul_pow2(-1).neg());
+    exp.mul(WideFloat::FRAC_1_SQRT_2)
+        .mul(WideFloat::FRAC_2_SQRT_PI)
+        .mul_pow2(-1)
+}
+
+#[inline]
+pub fn erf_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide(erf(val.to_wide(frac_nbits)), frac_nbits)
+}
+
+#[inline]
+pub fn erfc_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide(erfc(val.to_wide(frac_nbits)), frac_nbits)
+}
+
+#[inline]
+pub fn norm_cdf_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide(norm_cdf(val.to_wide(frac_nbits)), frac_nbits)
+}
+
+#[inline]
+pub fn erf_bits_wrapping<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide_wrapping(erf(val.to_wide(frac_nbits)), frac_nbits)
+}
+
+#[inline]
+pub fn erfc_bits_wrapping<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide_wrapping(erfc(val.to_wide(frac_nbits)), frac_nbits)
+}
+
+#[inline]
+pub fn norm_cdf_bits_wrapping<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide_wrapping(norm_cdf(val.to_wide(frac_nbits)), frac_nbits)
+}
+
+#[inline]
+pub fn norm_pdf_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide(norm_pdf(val.to_wide(frac_nbits)), frac_nbits)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::erf;
+
+    #[test]
+    fn i64_frac_48() {
+        // reference values are round(f(x / 2↑48) × 2↑48), computed using
+        // mpmath with 600 bits of precision
+        let check = |x: i64, erf: i64, erfc: i64, cdf: i64, pdf: i64| {
+            assert_eq!(erf::erf_bits(x, 48), (erf, false));
+            assert_eq!(erf::erfc_bits(x, 48), (erfc, false));
+            assert_eq!(erf::norm_cdf_bits(x, 48), (cdf, false));
+            assert_eq!(erf::norm_pdf_bits(x, 48), (pdf, false));
+        };
+        let half = 1 << 47;
+        check(
+            half,
+            146_507_690_985_327,
+            134_967_285_725_329,
+            194_629_380_183_396,
+            99_097_579_651_611,
+        );
+        let x = -351_843_720_888_320;
+        check(
+            x,
+            -259_773_292_107_248,
+            541_248_268_817_904,
+            29_737_767_582_364,
+            51_411_147_056_098,
+        );
+        check(
+            3 << 48,
+            281_468_758_788_528,
+            6_217_922_128,
+            281_095_014_193_641,
+            1_247_454_428_535,
+        );
+        check(7 << 48, 1 << 48, 0, 281_474_976_710_296, 2571);
+    }
+
+    #[test]
+    fn i128_frac_64() {
+        // reference values computed like those for i64_frac_48
+        let check = |x: i128, erf: i128, erfc: i128, cdf: i128, pdf: i128| {
+            assert_eq!(erf::erf_bits(x, 64), (erf, false));
+            assert_eq!(erf::erfc_bits(x, 64), (erfc, false));
+            assert_eq!(erf::norm_cdf_bits(x, 64), (cdf, false));
+            assert_eq!(erf::norm_pdf_bits(x, 64), (pdf, false));
+        };
+        check(
+            1 << 63,
+            9_601_528_036_414_361_951,
+            8_845_216_037_295_189_665,
+            12_755_231_059_699_021_555,
+            6_494_458_980_047_958_181,
+        );
+        check(
+            -2 << 64,
+            -18_360_455_093_669_534_103,
+            36_807_199_167_379_085_719,
+            419_665_861_691_185_126,
+            995_957_541_561_002_551,
+        );
+        check(
+            6 << 64,
+            18_446_744_073_709_551_219,
+            397,
+            18_446_744_055_510_221_822,
+            112_080_255_953,
+        );
+        check(10 << 64, 1 << 64, 0, 1 << 64, 0);
+        // erfc 8 = 1.1…e−29 has 27 significant bits with 123 fractional bits
+        let erfc_8 = 119_357_200;
+        assert_eq!(erf::erfc_bits::<i128>(8 << 123, 123), (erfc_8, false));
+        assert_eq!(
+            erf::erfc_bits::<i128>(-8 << 123, 123),
+            ((2 << 123) - erfc_8, false)
+        );
+    }
+
+    #[test]
+    fn identities() {
+        // erf(−x) = −erf x exactly, as rounding is symmetric, and erf x +
+        // erfc x = 1 within one DELTA, as each is rounded once
+        let one = 1i64 << 48;
+        for i in -2000..=2000 {
+            let x = i * 844_424_930_131;
+            let (erf, _) = erf::erf_bits(x, 48);
+            let (erfc, _) = erf::erfc_bits(x, 48);
+            assert_eq!(erf::erf_bits(-x, 48), (-erf, false));
+            assert!((erf + erfc - one).abs() <= 1, "{}", x);
+        }
+        // Φ(0) = 0.5 exactly
+        assert_eq!(erf::norm_cdf_bits::<i8>(0, 6), (1 << 5, false));
+        assert_eq!(erf::norm_cdf_bits::<i32>(0, 16), (1 << 15, false));
+        assert_eq!(erf::norm_cdf_bits::<i64>(0, 48), (1 << 47, false));
+        assert_eq!(erf::norm_cdf_bits::<i128>(0, 126), (1 << 125, false));
+    }
+
+    #[test]
+    fn symmetry_and_limits() {
+        for val in -1000..=1000 {
+            let (erf, _) = erf::erf_bits::<i32>(val * 1000, 16);
+            assert_eq!(erf::erf_bits::<i32>(-val * 1000, 16), (-erf, false));
+            let (cdf, _) = erf::norm_cdf_bits::<i32>(val * 1000, 16);
+            let (cdf_neg, _) = erf::norm_cdf_bits::<i32>(-val * 1000, 16);
+            assert!((cdf + cdf_neg - (1 << 16)).abs() <= 1);
+        }
+        assert_eq!(erf::erf_bits::<i32>(i32::MAX, 0), (1, false));
+        assert_eq!(erf::erf_bits::<i32>(i32::MIN, 0), (-1, false));
+        assert_eq!(erf::erfc_bits::<i32>(i32::MIN, 0), (2, false));
+        assert_eq!(erf::norm_pdf_bits::<i32>(i32::MIN, 0), (0, false));
+        // 1 does not fit when there is only one integer bit, and 2 does not fit
+        // when there are only two
+        assert_eq!(erf::erf_bits::<i8>(i8::MAX, 7), (107, false));
+        assert_eq!(erf::erf_bits::<i8>(i8::MAX, 5), (32, false));
+        assert_eq!(erf::erfc_bits::<i8>(i8::MIN, 6), (i8::MAX, true));
+        assert_eq!(erf::erfc_bits::<i8>(i8::MIN, 5), (64, false));
+        assert_eq!(erf::norm_pdf_bits::<i8>(0, 7), (51, false));
+        // the wrapping versions wrap modulo 2↑8 instead of saturating: 2 wraps
+        // to −2, and with no integer bits erf 0.496… = 132.3…/256 wraps to
+        // (132 − 256)/256, erf −0.5 = −133.2…/256 wraps to (256 − 133)/256, and
+        // Φ(0.496…) = 176.6…/256 and Φ(0) = 128/256 wrap similarly
+        assert_eq!(erf::erfc_bits_wrapping::<i8>(i8::MIN, 6), (i8::MIN, true));
+        assert_eq!(erf::erfc_bits_wrapping::<i8>(i8::MIN, 5), (64, false));
+        assert_eq!(erf::erf_bits_wrapping::<i8>(i8::MAX, 8), (-124, true));
+        assert_eq!(erf::erf_bits::<i8>(i8::MAX, 8), (i8::MAX, true));
+        assert_eq!(erf::erf_bits_wrapping::<i8>(i8::MIN, 8), (123, true));
+        assert_eq!(erf::norm_cdf_bits_wrapping::<i8>(i8::MAX, 8), (-79, true));
+        assert_eq!(erf::norm_cdf_bits_wrapping::<i8>(0, 8), (i8::MIN, true));
+    }
+}
@@ -0,0 +1,455 @@
+// Copyright © 2018–2021 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    int256::{self, U256},
+    wide_float::{WideBits, WideFloat},
+};
+
+// Number of bits computed for the logarithm in addition to the fractional bits
+// of the result, so that the rounding of the result is practically always
+// correct.
+const LOG_GUARD_NBITS: u32 = 32;
+
+// Number of times the argument of the exponential series is halved before the
+// series is summed; the result is then squared the same number of times.
+const EXP_HALVINGS: u32 = 8;
+
+const ONE_MANT: U256 = U256 {
+    lo: 0,
+    hi: 1 << 127,
+};
+
+#[inline]
+fn is_zero(a: U256) -> bool {
+    a.hi == 0 && a.lo == 0
+}
+
+// Returns log₂ x with an absolute error less than 2↑−nbits. x must be positive.
+//
+// If x = m × 2↑e with 1 ≤ m < 2, log₂ x = e + log₂ m. The bits of log₂ m are
+// found one at a time: if log₂ m = 0.b₁b₂b₃…, then log₂ m² = b₁.b₂b₃…, so b₁ is
+// set if and only if m² ≥ 2, and the process is repeated for m² or m² / 2.
+pub fn log2(x: WideFloat, nbits: u32) -> WideFloat {
+    debug_assert!(!x.neg && !x.is_zero());
+    let nbits = nbits.min(256);
+    // m is stored with 255 fractional bits
+    let mut m = x.mant;
+    let mut frac = U256 { lo: 0, hi: 0 };
+    for i in 1..=nbits {
+        if m.hi == ONE_MANT.hi && m.lo == ONE_MANT.lo {
+            break;
+        }
+        // m² is in [1, 4) with 254 fractional bits
+        let sq = int256::mul_hi_u256(m, m);
+        if sq.hi >> 127 != 0 {
+            m = sq;
+            let bit = 256 - i;
+            if bit >= 128 {
+                frac.hi |= 1 << (bit - 128);
+            } else {
+                frac.lo |= 1 << bit;
+            }
+        } else {
+            m = int256::shl_u256(sq, 1);
+        }
+    }
+    WideFloat::from_i32(x.exp).add(WideFloat::from_u256(false, frac, 256))
+}
+
+// Returns log₂ x with a relative error less than 2↑−220. x must be positive.
+//
+// When x is close to one, log₂ x is small, and the absolute error of log2 is
+// large in relative terms. In that case ln x = 2 artanh t, where
+// t = (x − 1) / (x + 1), and the series t + t³/3 + t⁵/5 + … is summed instead.
+pub fn log2_rel(x: WideFloat) -> WideFloat {
+    debug_assert!(!x.neg && !x.is_zero());
+    let delta = x.add(WideFloat::ONE.neg());
+    if delta.is_zero() {
+        return WideFloat::ZERO;
+    }
+    if delta.exp >= -16 {
+        return log2(x, 256);
+    }
+    let t = delta.div(WideFloat::ONE.mul_pow2(1).add(delta));
+    let t2 = t.mul(t);
+    let mut sum = t;
+    let mut term = t;
+    let mut k = 3;
+    loop {
+        term = term.mul(t2);
+        let next = term.div_small(k);
+        if next.exp < sum.exp - 256 {
+            break;
+        }
+        sum = sum.add(next);
+        k += 2;
+    }
+    sum.mul(WideFloat::LOG2_E).mul_pow2(1)
+}
+
+// Returns 2↑x. The result has exp ≥ 2↑30 for large x, which overflows any
+// fixed-point number, and is zero for very small x.
+//
+// If x = n + r with n an integer and 0 ≤ r < 1, 2↑x = 2↑n × e↑(r ln 2). For
+// y = r ln 2, the series for e↑y − 1 is summed for y / 2↑EXP_HALVINGS, and the
+// result is squared EXP_HALVINGS times using (1 + s)² − 1 = 2s + s².
+pub fn exp2(x: WideFloat) -> WideFloat {
+    if x.exp >= 30 {
+        return if x.neg {
+            WideFloat::ZERO
+        } else {
+            WideFloat::ONE.mul_pow2(1 << 30)
+        };
+    }
+    let (n, r) = x.floor_frac();
+    if is_zero(r) {
+        return WideFloat::ONE.mul_pow2(n);
+    }
+    // y = r ln 2, with 256 fractional bits
+    let y = int256::mul_hi_u256(r, WideFloat::LN_2.mant);
+    let z = int256::shr_u256(y, EXP_HALVINGS);
+    // s = e↑z − 1 = z + z²/2! + z³/3! + …
+    let mut s = z;
+    let mut term = z;
+    let mut i = 2;
+    loop {
+        term = int256::div_rem_u256_u128(int256::mul_hi_u256(term, z), i).0;
+        if is_zero(term) {
+            break;
+        }
+        s = int256::overflowing_add_u256(s, term).0;
+        i += 1;
+    }
+    for _ in 0..EXP_HALVINGS {
+        let sq = int256::mul_hi_u256(s, s);
+        s = int256::overflowing_add_u256(int256::shl_u256(s, 1), sq).0;
+    }
+    // 2↑r = 1 + s with 1 ≤ 2↑r < 2
+    let mut mant = int256::shr_u256(s, 1);
+    mant.hi |= ONE_MANT.hi;
+    WideFloat {
+        neg: false,
+        exp: n,
+        mant,
+    }
+}
+
+// Returns e↑x.
+#[inline]
+pub fn exp(x: WideFloat) -> WideFloat {
+    exp2(x.mul(WideFloat::LOG2_E))
+}
+
+// Returns ln x with an absolute error less than 2↑−nbits. x must be positive.
+#[inline]
+pub fn ln(x: WideFloat, nbits: u32) -> WideFloat {
+    log2(x, nbits + 1).mul(WideFloat::LN_2)
+}
+
+// Returns log₁₀ x with an absolute error less than 2↑−nbits. x must be positive.
+#[inline]
+pub fn log10(x: WideFloat, nbits: u32) -> WideFloat {
+    log2(x, nbits).mul(WideFloat::LOG10_2)
+}
+
+// Returns sinh x = (e↑x − e↑−x) / 2.
+//
+// For small x there is cancellation, but the absolute error stays well below
+// the precision of any fixed-point number.
+pub fn sinh(x: WideFloat) -> WideFloat {
+    if x.is_zero() {
+        return x;
+    }
+    let abs = WideFloat { neg: false, ..x };
+    let sinh_abs = exp(abs).add(exp(abs.neg()).neg()).mul_pow2(-1);
+    if x.neg {
+        sinh_abs.neg()
+    } else {
+        sinh_abs
+    }
+}
+
+// Returns cosh x = (e↑x + e↑−x) / 2.
+pub fn cosh(x: WideFloat) -> WideFloat {
+    let abs = WideFloat { neg: false, ..x };
+    exp(abs).add(exp(abs.neg())).mul_pow2(-1)
+}
+
+// Returns tanh x = (e↑2x − 1) / (e↑2x + 1).
+//
+// For large |x|, e↑2|x| is so large that the quotient is exactly one.
+pub fn tanh(x: WideFloat) -> WideFloat {
+    if x.is_zero() {
+        return x;
+    }
+    let abs = WideFloat { neg: false, ..x };
+    let exp_2x = exp(abs.mul_pow2(1));
+    let tanh_abs = exp_2x
+        .add(WideFloat::ONE.neg())
+        .div(exp_2x.add(WideFloat::ONE));
+    if x.neg {
+        tanh_abs.neg()
+    } else {
+        tanh_abs
+    }
+}
+
+#[inline]
+pub fn exp_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide(exp(val.to_wide(frac_nbits)), frac_nbits)
+}
+
+#[inline]
+pub fn exp2_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide(exp2(val.to_wide(frac_nbits)), frac_nbits)
+}
+
+#[inline]
+pub fn sinh_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide(sinh(val.to_wide(frac_nbits)), frac_nbits)
+}
+
+#[inline]
+pub fn cosh_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide(cosh(val.to_wide(frac_nbits)), frac_nbits)
+}
+
+#[inline]
+pub fn tanh_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide(tanh(val.to_wide(frac_nbits)), frac_nbits)
+}
+
+// val must be positive
+#[inline]
+pub fn ln_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    let nbits = frac_nbits + LOG_GUARD_NBITS;
+    T::from_wide(ln(val.to_wide(frac_nbits), nbits), frac_nbits)
+}
+
+// val must be positive
+#[inline]
+pub fn log2_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    let nbits = frac_nbits + LOG_GUARD_NBITS;
+    T::from_wide(log2(val.to_wide(frac_nbits), nbits), frac_nbits)
+}
+
+// Returns 10↑n exactly; n must not be greater than 76.
+fn pow10(n: u32) -> WideFloat {
+    let (a, b) = if n > 38 { (n - 38, 38) } else { (0, n) };
+    let pow = int256::wide_mul_u128(10u128.pow(a), 10u128.pow(b));
+    WideFloat::from_u256(false, pow, 0)
+}
+
+// val must be positive
+//
+// The integer part k = ⌊log₁₀ val⌋ is found exactly, and only the logarithm of
+// val / 10↑k, which lies in [1, 10), is approximated, so the logarithm of an
+// exact power of ten is exact.
+pub fn log10_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    let nbits = frac_nbits + LOG_GUARD_NBITS;
+    let x = val.to_wide(frac_nbits);
+    let k = val.int_log10(frac_nbits);
+    let scaled = if k >= 0 {
+        x.div(pow10(k as u32))
+    } else {
+        x.mul(pow10(k.unsigned_abs()))
+    };
+    let log = WideFloat::from_i32(k).add(log10(scaled, nbits));
+    T::from_wide(log, frac_nbits)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::exp_log;
+
+    // Checks that the result is within ½ + slack of the floating-point
+    // reference, in units of the last place.
+    fn check_i32(f: fn(i32, u32) -> (i32, bool), reference: fn(f64) -> f64, slack: f64) {
+        for &frac_nbits in &[0, 8, 16, 24, 31] {
+            let scale = f64::from(1u32 << frac_nbits);
+            let mut val = 1i32;
+            while val < i32::MAX / 3 {
+                let expected = reference(f64::from(val) / scale) * scale;
+                let (ans, overflow) = f(val, frac_nbits);
+                if expected.abs() < 2e9 {
+                    assert!(!overflow, "{} {}", val, frac_nbits);
+                    let err = (f64::from(ans) - expected).abs();
+                    assert!(err <= 0.5 + slack, "{} {}", val, frac_nbits);
+                } else if expected.abs() > 2.2e9 {
+                    assert!(overflow, "{} {}", val, frac_nbits);
+                }
+                val = val * 3 + 1;
+            }
+        }
+    }
+
+    #[test]
+    fn log_i32() {
+        check_i32(exp_log::log2_bits::<i32>, f64::log2, 1e-6);
+        check_i32(exp_log::ln_bits::<i32>, f64::ln, 1e-6);
+        check_i32(exp_log::log10_bits::<i32>, f64::log10, 1e-6);
+    }
+
+    #[test]
+    fn exp_exp2_i32() {
+        check_i32(exp_log::exp2_bits::<i32>, f64::exp2, 1e-6);
+        check_i32(exp_log::exp_bits::<i32>, f64::exp, 1e-6);
+        let neg_exp2 = |val: i32, frac_nbits| exp_log::exp2_bits::<i32>(-val, frac_nbits);
+        check_i32(neg_exp2, |x| (-x).exp2(), 1e-6);
+    }
+
+    #[test]
+    fn hyperbolic_i32() {
+        check_i32(exp_log::sinh_bits::<i32>, f64::sinh, 1e-6);
+        check_i32(exp_log::cosh_bits::<i32>, f64::cosh, 1e-6);
+        check_i32(exp_log::tanh_bits::<i32>, f64::tanh, 1e-6);
+        let neg_sinh = |val: i32, frac_nbits| exp_log::sinh_bits::<i32>(-val, frac_nbits);
+        check_i32(neg_sinh, |x| (-x).sinh(), 1e-6);
+        let neg_cosh = |val: i32, frac_nbits| exp_log::cosh_bits::<i32>(-val, frac_nbits);
+        check_i32(neg_cosh, |x| (-x).cosh(), 1e-6);
+        let neg_tanh = |val: i32, frac_nbits| exp_log::tanh_bits::<i32>(-val, frac_nbits);
+        check_i32(neg_tanh, |x| (-x).tanh(), 1e-6);
+    }
+
+    #[test]
+    fn hyperbolic_limits() {
+        // tanh is exactly ±1 for large arguments, and never overflows
+        let one = 1 << 64;
+        assert_eq!(exp_log::tanh_bits::<i128>(i128::MAX, 64), (one, false));
+        assert_eq!(exp_log::tanh_bits::<i128>(i128::MIN, 64), (-one, false));
+        assert_eq!(exp_log::tanh_bits::<i32>(i32::MAX, 0), (1, false));
+        assert_eq!(exp_log::tanh_bits::<i32>(i32::MIN, 0), (-1, false));
+        // with two integer bits, tanh (2 − 2↑−30) = 0.964…
+        let tanh_max = exp_log::tanh_bits::<i32>(i32::MAX, 30);
+        assert_eq!(tanh_max, (1_035_116_732, false));
+        assert_eq!(exp_log::tanh_bits::<u8>(u8::MAX, 8), (195, false));
+        // cosh 0 = 1 does not fit when there are no integer bits
+        assert_eq!(exp_log::cosh_bits::<i8>(0, 6), (64, false));
+        assert_eq!(exp_log::cosh_bits::<i8>(0, 7), (i8::MAX, true));
+        assert_eq!(exp_log::cosh_bits::<u8>(0, 8), (u8::MAX, true));
+        assert_eq!(exp_log::sinh_bits::<i128>(i128::MIN, 0), (i128::MIN, true));
+        assert_eq!(exp_log::sinh_bits::<i128>(i128::MAX, 0), (i128::MAX, true));
+        assert_eq!(exp_log::cosh_bits::<i128>(i128::MIN, 0), (i128::MAX, true));
+        // sinh 2↑−100 = 2↑−100 + 2↑−300 / 6 + …
+        assert_eq!(exp_log::sinh_bits::<u128>(1 << 28, 128), (1 << 28, false));
+        assert_eq!(exp_log::tanh_bits::<u128>(1 << 28, 128), (1 << 28, false));
+        assert_eq!(exp_log::cosh_bits::<u128>(1 << 28, 127), (1 << 127, false));
+    }
+
+    #[test]
+    fn exact() {
+        // log₂ 2↑k = k
+        for k in 0..128 {
+            assert_eq!(exp_log::log2_bits::<u128>(1 << k, 0), (k, false));
+            assert_eq!(exp_log::exp2_bits::<u128>(k, 0), (1 << k, false));
+        }
+        for k in 0..127 {
+            let log = i128::from(k as i8 - 120) << 120;
+            assert_eq!(exp_log::log2_bits::<i128>(1 << k, 120), (log, false));
+        }
+        assert_eq!(exp_log::exp2_bits::<u128>(128, 0), (u128::MAX, true));
+        // 2↑−1 = 0.5 rounds to even 0, 2↑−0.5 rounds to 1
+        assert_eq!(exp_log::exp2_bits::<i8>(-2, 1), (1, false));
+        assert_eq!(exp_log::exp2_bits::<i8>(-1, 0), (0, false));
+        assert_eq!(exp_log::exp2_bits::<i8>(-1, 1), (1, false));
+        assert_eq!(exp_log::exp_bits::<u8>(0, 8), (u8::MAX, true));
+        assert_eq!(exp_log::ln_bits::<u8>(1, 0), (0, false));
+        assert_eq!(exp_log::ln_bits::<u8>(1, 8), (0, true));
+        // log₁₀ 10↑k = k
+        let mut pow = 1u128;
+        for k in 0..=38 {
+            assert_eq!(exp_log::log10_bits::<u128>(pow, 0), (k, false));
+            if k <= 18 {
+                let bits = (pow as i128) << 64;
+                let log = (k as i128) << 64;
+                assert_eq!(exp_log::log10_bits::<i128>(bits, 64), (log, false));
+            }
+            pow = pow.wrapping_mul(10);
+        }
+        // with no spare bits below the integer part of 10↑k
+        assert_eq!(
+            exp_log::log10_bits::<u128>(10 << 124, 124),
+            (1 << 124, false)
+        );
+        assert_eq!(
+            exp_log::log10_bits::<u128>(100 << 121, 121),
+            (2 << 121, false)
+        );
+        assert_eq!(exp_log::log10_bits::<i8>(10 << 3, 3), (1 << 3, false));
+        assert_eq!(exp_log::log10_bits::<u8>(1, 8), (0, true));
+    }
+
+    // Checks that log₁₀ 10↑k is exactly k, and that one unit in the last
+    // place below and above 10↑k the integer part is still found exactly.
+    macro_rules! check_log10_pow10 {
+        ($T:ident; $($frac_nbits:expr),*) => {
+            $(
+                let frac_nbits: u32 = $frac_nbits;
+                let one: $T = 1 << frac_nbits;
+                let mut k: $T = 0;
+                let mut pow = one;
+                loop {
+                    let log = k << frac_nbits;
+                    let (exact, overflow) = exp_log::log10_bits::<$T>(pow, frac_nbits);
+                    assert_eq!((exact, overflow), (log, false), "{}", pow);
+                    // for unsigned numbers, log₁₀ of a number below 1 is negative
+                    if pow > 1 && (k > 0 || $T::MIN != 0) {
+                        let (below, overflow) = exp_log::log10_bits::<$T>(pow - 1, frac_nbits);
+                        assert!(!overflow && below <= log && log - below < one, "{}", pow);
+                    }
+                    let (above, overflow) = exp_log::log10_bits::<$T>(pow + 1, frac_nbits);
+                    assert!(!overflow && above >= log && above - log < one, "{}", pow);
+                    pow = match pow.checked_mul(10) {
+                        Some(next) => next,
+                        None => break,
+                    };
+                    k += 1;
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn log10_pow10() {
+        check_log10_pow10! { i8; 0, 3 }
+        check_log10_pow10! { u8; 0, 4 }
+        check_log10_pow10! { i16; 0, 8 }
+        check_log10_pow10! { u16; 0, 8 }
+        check_log10_pow10! { i32; 0, 16 }
+        check_log10_pow10! { u32; 0, 16 }
+        check_log10_pow10! { i64; 0, 32 }
+        check_log10_pow10! { u64; 0, 32 }
+        check_log10_pow10! { i128; 0, 64 }
+        check_log10_pow10! { u128; 0, 64, 120 }
+    }
+
+    #[test]
+    fn wide_128() {
+        // e with 126 fractional bits, rounded to the nearest
+        let e = exp_log::exp_bits::<u128>(1 << 126, 126).0;
+        let e_ref = crate::consts::E.to_bits();
+        assert!(e == e_ref || e == e_ref + 1);
+        // ln 2 rounded to an integer, and with 120 fractional bits
+        let ln_2 = exp_log::ln_bits::<u128>(2, 0).0;
+        assert_eq!(ln_2, 1);
+        let ln_2 = exp_log::ln_bits::<i128>(2 << 120, 120).0;
+        assert_eq!(ln_2, (crate::consts::LN_2.to_bits() >> 8) as i128 + 1);
+        // log₂ 10 with 126 fractional bits
+        let log2_10 = exp_log::log2_bits::<u128>(10 << 124, 124).0;
+        let log2_10_ref = crate::consts::LOG2_10.to_bits() >> 2;
+        assert!(log2_10 == log2_10_ref || log2_10 == log2_10_ref + 1);
+    }
+}
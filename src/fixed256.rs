@@ -0,0 +1,1944 @@
+// Copyright © 2018–2021 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    float_helper,
+    helpers::{Kind256, Sealed, Sealed256, ToFixedHelper, ToFloatHelper},
+    int256::{self, I256, U256},
+    int_helper::IntFixed,
+    traits::{FromFixed256, ToFixed256},
+    types::extra::{LeEqU128, LeEqU16, LeEqU256, LeEqU32, LeEqU64, LeEqU8, Max, Maximum, Sum},
+    F128Bits, FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32,
+    FixedU64, FixedU8,
+};
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use half::{bf16, f16};
+
+// The helpers below work on the raw two’s-complement bit pattern as a U256,
+// with signed selecting between the signed and unsigned overflow rules.
+
+const SIGNED_MIN: U256 = U256 {
+    lo: 0,
+    hi: 1 << 127,
+};
+
+#[inline]
+fn msb(a: U256) -> bool {
+    a.hi >> 127 != 0
+}
+
+#[inline]
+fn neg_abs(a: U256, signed: bool) -> (bool, U256) {
+    if signed && msb(a) {
+        (true, int256::wrapping_neg_u256(a))
+    } else {
+        (false, a)
+    }
+}
+
+// Truncates a 512-bit value to 256 bits, checking whether it fits.
+#[inline]
+fn truncate((hi, lo): (U256, U256), signed: bool) -> (U256, bool) {
+    let extension = if signed && msb(lo) {
+        U256::MAX
+    } else {
+        U256::ZERO
+    };
+    (lo, hi != extension)
+}
+
+#[inline]
+fn overflowing_add(a: U256, b: U256, signed: bool) -> (U256, bool) {
+    let (sum, carry) = int256::overflowing_add_u256(a, b);
+    if signed {
+        (sum, msb(a) == msb(b) && msb(sum) != msb(a))
+    } else {
+        (sum, carry)
+    }
+}
+
+#[inline]
+fn overflowing_sub(a: U256, b: U256, signed: bool) -> (U256, bool) {
+    let (diff, borrow) = int256::overflowing_sub_u256(a, b);
+    if signed {
+        (diff, msb(a) != msb(b) && msb(diff) != msb(a))
+    } else {
+        (diff, borrow)
+    }
+}
+
+#[inline]
+fn overflowing_neg(a: U256, signed: bool) -> (U256, bool) {
+    let neg = int256::wrapping_neg_u256(a);
+    if signed {
+        (neg, a == SIGNED_MIN)
+    } else {
+        (neg, a != U256::ZERO)
+    }
+}
+
+// The product is rounded towards −∞. Returns the result and whether it
+// overflowed, and whether the exact result is negative for saturation.
+fn overflowing_mul(a: U256, b: U256, frac_nbits: u32, signed: bool) -> (U256, bool, bool) {
+    let (a_neg, a_abs) = neg_abs(a, signed);
+    let (b_neg, b_abs) = neg_abs(b, signed);
+    let neg = a_neg != b_neg;
+    let mut prod = int256::wide_mul_u256(a_abs, b_abs);
+    if neg {
+        prod = int256::wrapping_neg_u512(prod);
+    }
+    let shifted = int256::shr_u512(prod, frac_nbits, neg && msb(prod.0));
+    let (ans, overflow) = truncate(shifted, signed);
+    (ans, overflow, neg)
+}
+
+// The quotient is rounded towards zero. Returns the result and whether it
+// overflowed, and whether the exact result is negative for saturation.
+fn overflowing_div(a: U256, b: U256, frac_nbits: u32, signed: bool) -> (U256, bool, bool) {
+    let (a_neg, a_abs) = neg_abs(a, signed);
+    let (b_neg, b_abs) = neg_abs(b, signed);
+    let neg = a_neg != b_neg;
+    let dividend = (
+        int256::shr_u256(a_abs, 256 - frac_nbits),
+        int256::shl_u256(a_abs, frac_nbits),
+    );
+    let (mut quot, _) = int256::div_rem_u512_u256(dividend, b_abs);
+    if neg {
+        quot = int256::wrapping_neg_u512(quot);
+    }
+    let (ans, overflow) = truncate(quot, signed);
+    (ans, overflow, neg)
+}
+
+// Divides a by b, which has rhs_frac_nbits fractional bits, so that the
+// quotient keeps the fractional bits of both operands. The quotient is rounded
+// towards zero. Returns None if b is zero or on overflow.
+fn checked_wide_div(a: U256, b: U256, rhs_frac_nbits: u32, signed: bool) -> Option<U256> {
+    if b == U256::ZERO {
+        return None;
+    }
+    match overflowing_div(a, b, 2 * rhs_frac_nbits, signed) {
+        (ans, false, _) => Some(ans),
+        (_, true, _) => None,
+    }
+}
+
+// Adds or subtracts b to or from a after shifting both left to the larger of
+// their numbers of fractional bits. As a and b are extended from 128 bits,
+// the shifts cannot overflow. Returns None if the result overflows.
+fn checked_add_sub_wide(
+    a: U256,
+    a_frac_nbits: u32,
+    b: U256,
+    b_frac_nbits: u32,
+    sub: bool,
+    signed: bool,
+) -> Option<U256> {
+    let frac_nbits = a_frac_nbits.max(b_frac_nbits);
+    let a = int256::shl_u256(a, frac_nbits - a_frac_nbits);
+    let b = int256::shl_u256(b, frac_nbits - b_frac_nbits);
+    let (ans, overflow) = if sub {
+        overflowing_sub(a, b, signed)
+    } else {
+        overflowing_add(a, b, signed)
+    };
+    if overflow {
+        None
+    } else {
+        Some(ans)
+    }
+}
+
+// Shifts an absolute value from src_frac_nbits to dst_frac_nbits fractional
+// bits, rounding the value towards −∞, where the value is negative if neg is
+// true. Returns the wrapped absolute value and whether it overflowed.
+fn shift_floor(neg: bool, abs: U256, src_frac_nbits: u32, dst_frac_nbits: u32) -> (U256, bool) {
+    if dst_frac_nbits >= src_frac_nbits {
+        let shift = dst_frac_nbits - src_frac_nbits;
+        let overflow = abs != U256::ZERO && shift > int256::leading_zeros_u256(abs);
+        (int256::shl_u256(abs, shift), overflow)
+    } else {
+        let shift = src_frac_nbits - dst_frac_nbits;
+        let trunc = int256::shr_u256(abs, shift);
+        // for negative numbers, the floor has an absolute value one more
+        // than the truncated absolute value
+        let inexact = int256::shl_u256(trunc, shift) != abs;
+        if neg && inexact {
+            (int256::wrapping_add_u256_u128(trunc, 1), false)
+        } else {
+            (trunc, false)
+        }
+    }
+}
+
+// Converts a finite rounded value to raw bits, checking whether it fits.
+fn overflowing_from_kind(kind: Kind256, signed: bool) -> (U256, bool) {
+    match kind {
+        Kind256::NaN => panic!("NaN"),
+        Kind256::Infinite { .. } => panic!("infinite"),
+        Kind256::Finite { neg, abs, overflow } => {
+            let fits = if !signed {
+                !neg || abs == U256::ZERO
+            } else if neg {
+                abs <= SIGNED_MIN
+            } else {
+                abs < SIGNED_MIN
+            };
+            let raw = if neg {
+                int256::wrapping_neg_u256(abs)
+            } else {
+                abs
+            };
+            (raw, overflow || !fits)
+        }
+    }
+}
+
+macro_rules! impl_sealed256_fixed {
+    ($Fixed:ident($LeEqU:ident)) => {
+        impl<Frac: $LeEqU> Sealed256 for $Fixed<Frac> {
+            #[inline]
+            fn private_to_kind256(self, dst_frac_nbits: u32) -> Kind256 {
+                let ToFloatHelper { neg, abs } = self.private_to_float_helper();
+                let abs = U256 { lo: abs, hi: 0 };
+                let (abs, overflow) = shift_floor(neg, abs, Self::FRAC_NBITS, dst_frac_nbits);
+                Kind256::Finite { neg, abs, overflow }
+            }
+            #[inline]
+            fn private_overflowing_from_raw256(
+                raw: U256,
+                src_frac_nbits: u32,
+                src_signed: bool,
+            ) -> ($Fixed<Frac>, bool) {
+                let (neg, abs) = neg_abs(raw, src_signed);
+                let conv = ToFixedHelper::from_u256_floor(
+                    neg,
+                    abs,
+                    src_frac_nbits,
+                    Self::FRAC_NBITS,
+                    Self::INT_NBITS,
+                );
+                Self::private_overflowing_from_helper(conv)
+            }
+            #[inline]
+            fn private_saturating_from_raw256(
+                raw: U256,
+                src_frac_nbits: u32,
+                src_signed: bool,
+            ) -> $Fixed<Frac> {
+                let (neg, abs) = neg_abs(raw, src_signed);
+                let conv = ToFixedHelper::from_u256_floor(
+                    neg,
+                    abs,
+                    src_frac_nbits,
+                    Self::FRAC_NBITS,
+                    Self::INT_NBITS,
+                );
+                Self::private_saturating_from_helper(neg, conv)
+            }
+        }
+    };
+}
+
+impl_sealed256_fixed! { FixedI8(LeEqU8) }
+impl_sealed256_fixed! { FixedI16(LeEqU16) }
+impl_sealed256_fixed! { FixedI32(LeEqU32) }
+impl_sealed256_fixed! { FixedI64(LeEqU64) }
+impl_sealed256_fixed! { FixedI128(LeEqU128) }
+impl_sealed256_fixed! { FixedU8(LeEqU8) }
+impl_sealed256_fixed! { FixedU16(LeEqU16) }
+impl_sealed256_fixed! { FixedU32(LeEqU32) }
+impl_sealed256_fixed! { FixedU64(LeEqU64) }
+impl_sealed256_fixed! { FixedU128(LeEqU128) }
+
+macro_rules! impl_sealed256_int {
+    ($($Int:ident)*) => { $(
+        impl Sealed256 for $Int {
+            #[inline]
+            fn private_to_kind256(self, dst_frac_nbits: u32) -> Kind256 {
+                IntFixed(self).fixed().private_to_kind256(dst_frac_nbits)
+            }
+            #[inline]
+            fn private_overflowing_from_raw256(
+                raw: U256,
+                src_frac_nbits: u32,
+                src_signed: bool,
+            ) -> ($Int, bool) {
+                let (fixed, overflow) =
+                    Sealed256::private_overflowing_from_raw256(raw, src_frac_nbits, src_signed);
+                (IntFixed::<$Int>::int(fixed), overflow)
+            }
+            #[inline]
+            fn private_saturating_from_raw256(
+                raw: U256,
+                src_frac_nbits: u32,
+                src_signed: bool,
+            ) -> $Int {
+                let fixed =
+                    Sealed256::private_saturating_from_raw256(raw, src_frac_nbits, src_signed);
+                IntFixed::<$Int>::int(fixed)
+            }
+        }
+    )* };
+}
+
+impl_sealed256_int! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+
+macro_rules! impl_sealed256_float {
+    ($($Float:ident)*) => { $(
+        impl Sealed256 for $Float {
+            #[inline]
+            fn private_to_kind256(self, dst_frac_nbits: u32) -> Kind256 {
+                float_helper::$Float::to_kind256(self, dst_frac_nbits)
+            }
+            #[inline]
+            fn private_overflowing_from_raw256(
+                raw: U256,
+                src_frac_nbits: u32,
+                src_signed: bool,
+            ) -> ($Float, bool) {
+                let (neg, abs) = neg_abs(raw, src_signed);
+                (float_helper::$Float::from_u256(neg, abs, src_frac_nbits), false)
+            }
+            #[inline]
+            fn private_saturating_from_raw256(
+                raw: U256,
+                src_frac_nbits: u32,
+                src_signed: bool,
+            ) -> $Float {
+                let (neg, abs) = neg_abs(raw, src_signed);
+                float_helper::$Float::from_u256(neg, abs, src_frac_nbits)
+            }
+        }
+    )* };
+}
+
+impl_sealed256_float! { f16 bf16 f32 f64 F128Bits }
+
+macro_rules! fixed256 {
+    (
+        $description:expr,
+        $Fixed:ident[$s_fixed:expr]($Inner:ident[$s_inner:expr], $Signedness:tt),
+        $Fixed128:ident[$s_fixed128:expr]
+    ) => {
+        comment! {
+            $description,
+            "-bit fixed-point number with `Frac` fractional bits.
+
+The number has 256 bits, of which <i>f</i> = `Frac` are fractional bits
+and 256 − <i>f</i> are integer bits. The value <i>x</i> can lie in the
+range ",
+            if_signed_unsigned!(
+                $Signedness,
+                "−2<sup>255</sup>/2<sup><i>f</i></sup> ≤ <i>x</i> < 2<sup>255</sup>/2<sup><i>f</i></sup>",
+                "0 ≤ <i>x</i> < 2<sup>256</sup>/2<sup><i>f</i></sup>",
+            ),
+            ". The underlying bits are stored in a [`", $s_inner, "`].
+
+This type provides a subset of the methods of the narrower fixed-point
+numbers: the constants, conversions from and to integers, floating-point
+numbers and other fixed-point numbers, arithmetic with checked, saturating,
+wrapping and overflowing variants, formatting, parsing from decimal strings
+and [*serde*] support. The conversions use the [`ToFixed256`] and
+[`FromFixed256`] traits.
+
+The following are not provided:
+
+  * the [`Fixed`] trait, as that trait requires the full method set of the
+    narrower numbers and is built around the 128-bit conversion machinery,
+    so generic code over [`Fixed`] cannot use this type;
+  * parsing from binary, octal and hexadecimal strings;
+  * the method families beyond the basic arithmetic, such as rounding,
+    exact, multiply-add and integer-operand methods.
+
+A value of this type is returned by the `wide_mul`, `wide_div`, `add_wide`
+and `sub_wide` methods of [`", $s_fixed128, "`].
+
+# Examples
+
+```rust
+use fixed::{types::extra::U64, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U64>;
+// large integer part and 18 decimal places
+let balance: Fix = \"123456789012345678901234567890.123456789012345678\".parse().unwrap();
+let doubled = balance + balance;
+assert_eq!(doubled.int().to_string(), \"246913578024691357802469135780\");
+```
+
+[*serde*]: https://crates.io/crates/serde
+[`Fixed`]: crate::traits::Fixed
+[`FromFixed256`]: crate::traits::FromFixed256
+[`ToFixed256`]: crate::traits::ToFixed256
+";
+            #[repr(transparent)]
+            pub struct $Fixed<Frac> {
+                bits: $Inner,
+                phantom: PhantomData<Frac>,
+            }
+        }
+
+        impl<Frac> Clone for $Fixed<Frac> {
+            #[inline]
+            fn clone(&self) -> $Fixed<Frac> {
+                *self
+            }
+        }
+
+        impl<Frac> Copy for $Fixed<Frac> {}
+
+        impl<Frac> Default for $Fixed<Frac> {
+            #[inline]
+            fn default() -> Self {
+                $Fixed {
+                    bits: Default::default(),
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<Frac> Hash for $Fixed<Frac> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.bits.hash(state);
+            }
+        }
+
+        impl<Frac> PartialEq for $Fixed<Frac> {
+            #[inline]
+            fn eq(&self, rhs: &$Fixed<Frac>) -> bool {
+                self.bits == rhs.bits
+            }
+        }
+
+        impl<Frac> Eq for $Fixed<Frac> {}
+
+        impl<Frac> PartialOrd for $Fixed<Frac> {
+            #[inline]
+            fn partial_cmp(&self, rhs: &$Fixed<Frac>) -> Option<Ordering> {
+                Some(self.cmp(rhs))
+            }
+        }
+
+        impl<Frac> Ord for $Fixed<Frac> {
+            #[inline]
+            fn cmp(&self, rhs: &$Fixed<Frac>) -> Ordering {
+                self.bits.cmp(&rhs.bits)
+            }
+        }
+
+        impl<Frac> $Fixed<Frac> {
+            /// Zero.
+            pub const ZERO: $Fixed<Frac> = Self::from_bits($Inner::ZERO);
+
+            /// The difference between any two successive representable
+            /// numbers, <i>Δ</i> = 1/2<sup><i>f</i></sup>.
+            pub const DELTA: $Fixed<Frac> = Self::from_bits($Inner { lo: 1, hi: 0 });
+
+            /// The smallest value that can be represented.
+            pub const MIN: $Fixed<Frac> = Self::from_bits($Inner::MIN);
+
+            /// The largest value that can be represented.
+            pub const MAX: $Fixed<Frac> = Self::from_bits($Inner::MAX);
+
+            comment! {
+                if_signed_unsigned!($Signedness, "[`true`]", "[`false`]"),
+                "[`bool`] because the [`", $s_fixed, "`] type is ",
+                if_signed_unsigned!($Signedness, "signed", "unsigned"),
+                ".";
+                pub const IS_SIGNED: bool = if_signed_unsigned!($Signedness, true, false);
+            }
+
+            comment! {
+                "Creates a fixed-point number that has a bitwise
+representation identical to the given integer.
+
+# Examples
+
+```rust
+use fixed::{
+    int256::", $s_inner, ",
+    types::{extra::U4, I8F8},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+// 0010.0000 == 2
+assert_eq!(Fix::from_bits(", $s_inner, "::from(0b10_0000u8)).to_num::<I8F8>(), 2);
+```
+";
+                #[inline]
+                pub const fn from_bits(bits: $Inner) -> $Fixed<Frac> {
+                    $Fixed {
+                        bits,
+                        phantom: PhantomData,
+                    }
+                }
+            }
+
+            comment! {
+                "Creates an integer that has a bitwise representation
+identical to the given fixed-point number.
+
+# Examples
+
+```rust
+use fixed::{
+    int256::", $s_inner, ",
+    types::{extra::U4, I8F8},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+// 2 is 0010.0000
+assert_eq!(Fix::from_num(I8F8::from_num(2)).to_bits(), ", $s_inner, "::from(0b10_0000u8));
+```
+";
+                #[inline]
+                pub const fn to_bits(self) -> $Inner {
+                    self.bits
+                }
+            }
+
+            /// Returns [`true`] if the number is zero.
+            #[inline]
+            pub fn is_zero(self) -> bool {
+                self.bits == $Inner::ZERO
+            }
+
+            if_signed! {
+                $Signedness;
+
+                /// Returns [`true`] if the number is > 0.
+                #[inline]
+                pub fn is_positive(self) -> bool {
+                    self.bits > $Inner::ZERO
+                }
+
+                /// Returns [`true`] if the number is < 0.
+                #[inline]
+                pub fn is_negative(self) -> bool {
+                    self.bits.hi < 0
+                }
+            }
+
+            #[inline]
+            fn raw(self) -> U256 {
+                if_signed_unsigned!(
+                    $Signedness,
+                    int256::i256_wrapping_as_u256(self.bits),
+                    self.bits,
+                )
+            }
+
+            #[inline]
+            fn from_raw(raw: U256) -> $Fixed<Frac> {
+                Self::from_bits(if_signed_unsigned!(
+                    $Signedness,
+                    int256::u256_wrapping_as_i256(raw),
+                    raw,
+                ))
+            }
+        }
+
+        impl<Frac: LeEqU256> $Fixed<Frac> {
+            /// The number of integer bits.
+            pub const INT_NBITS: u32 = 256 - Self::FRAC_NBITS;
+
+            /// The number of fractional bits.
+            pub const FRAC_NBITS: u32 = Frac::U32;
+
+            comment! {
+                "Creates a fixed-point number from another number.
+
+The other number can be:
+
+  * Another fixed-point number, including a 256-bit one. Any extra
+    fractional bits are discarded, which rounds towards −∞.
+  * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
+    [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
+    [`usize`].
+  * A floating-point number of type [`f16`], [`bf16`], [`f32`],
+    [`f64`] or [`F128Bits`]. For this conversion, the method rounds
+    to the nearest, with ties rounding to even.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be returned,
+but it is not considered a breaking change if in the future it panics; if
+wrapping is required use [`wrapping_from_num`] instead.
+
+# Examples
+
+```rust
+use fixed::{
+    types::{extra::U200, I16F16},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U200>;
+let src = I16F16::from_num(1.75);
+assert_eq!(Fix::from_num(src).to_num::<I16F16>(), src);
+assert_eq!(Fix::from_num(",
+                if_signed_unsigned!($Signedness, "-3i64", "3i64"),
+                ").to_num::<i64>(), ",
+                if_signed_unsigned!($Signedness, "-3", "3"),
+                ");
+assert_eq!(Fix::from_num(",
+                if_signed_unsigned!($Signedness, "-1.75f32", "1.75f32"),
+                ").to_num::<f64>(), ",
+                if_signed_unsigned!($Signedness, "-1.75", "1.75"),
+                ");
+```
+
+[`F128Bits`]: crate::F128Bits
+[`bf16`]: half::bf16
+[`f16`]: half::f16
+[`wrapping_from_num`]: Self::wrapping_from_num
+[finite]: f64::is_finite
+";
+                #[inline]
+                pub fn from_num<Src: ToFixed256>(src: Src) -> $Fixed<Frac> {
+                    let (wrapped, overflow) = Self::overflowing_from_num(src);
+                    debug_assert!(!overflow, "overflow");
+                    wrapped
+                }
+            }
+
+            /// Creates a fixed-point number from another number if it fits,
+            /// otherwise returns [`None`].
+            ///
+            /// The rounding is the same as for [`from_num`], and [`None`] is
+            /// also returned for floating-point numbers that are not finite.
+            ///
+            /// [`from_num`]: Self::from_num
+            #[inline]
+            pub fn checked_from_num<Src: ToFixed256>(src: Src) -> Option<$Fixed<Frac>> {
+                match src.private_to_kind256(Self::FRAC_NBITS) {
+                    kind @ Kind256::Finite { .. } => {
+                        match overflowing_from_kind(kind, Self::IS_SIGNED) {
+                            (_, true) => None,
+                            (raw, false) => Some(Self::from_raw(raw)),
+                        }
+                    }
+                    _ => None,
+                }
+            }
+
+            /// Creates a fixed-point number from another number, saturating if
+            /// it does not fit.
+            ///
+            /// The rounding is the same as for [`from_num`].
+            ///
+            /// # Panics
+            ///
+            /// Panics if the value is a floating-point NaN.
+            ///
+            /// [`from_num`]: Self::from_num
+            #[inline]
+            pub fn saturating_from_num<Src: ToFixed256>(src: Src) -> $Fixed<Frac> {
+                let kind = src.private_to_kind256(Self::FRAC_NBITS);
+                let neg = match kind {
+                    Kind256::NaN => panic!("NaN"),
+                    Kind256::Infinite { neg } | Kind256::Finite { neg, .. } => neg,
+                };
+                if let Kind256::Finite { .. } = kind {
+                    if let (raw, false) = overflowing_from_kind(kind, Self::IS_SIGNED) {
+                        return Self::from_raw(raw);
+                    }
+                }
+                if neg {
+                    Self::MIN
+                } else {
+                    Self::MAX
+                }
+            }
+
+            /// Creates a fixed-point number from another number, wrapping if
+            /// it does not fit.
+            ///
+            /// The rounding is the same as for [`from_num`].
+            ///
+            /// # Panics
+            ///
+            /// For floating-point numbers, panics if the value is not finite.
+            ///
+            /// [`from_num`]: Self::from_num
+            #[inline]
+            pub fn wrapping_from_num<Src: ToFixed256>(src: Src) -> $Fixed<Frac> {
+                Self::overflowing_from_num(src).0
+            }
+
+            /// Creates a fixed-point number from another number.
+            ///
+            /// Returns a [tuple] of the fixed-point number and a [`bool`]
+            /// indicating whether an overflow has occurred. On overflow, the
+            /// wrapped value is returned.
+            ///
+            /// The rounding is the same as for [`from_num`].
+            ///
+            /// # Panics
+            ///
+            /// For floating-point numbers, panics if the value is not finite.
+            ///
+            /// [`from_num`]: Self::from_num
+            #[inline]
+            pub fn overflowing_from_num<Src: ToFixed256>(src: Src) -> ($Fixed<Frac>, bool) {
+                let kind = src.private_to_kind256(Self::FRAC_NBITS);
+                let (raw, overflow) = overflowing_from_kind(kind, Self::IS_SIGNED);
+                (Self::from_raw(raw), overflow)
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number.
+
+The other number can be:
+
+  * Another fixed-point number, including a 256-bit one. Any extra
+    fractional bits are discarded, which rounds towards −∞.
+  * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
+    [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
+    [`usize`]. Any fractional bits are discarded, which rounds towards
+    −∞.
+  * A floating-point number of type [`f16`], [`bf16`], [`f32`],
+    [`f64`] or [`F128Bits`]. For this conversion, the method rounds to
+    the nearest, with ties rounding to even.
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be returned,
+but it is not considered a breaking change if in the future it panics; if
+wrapping is required use [`wrapping_to_num`] instead.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U130},
+    ", $s_fixed, ", FixedU16,
+};
+type Fix = ", $s_fixed, "<U130>;
+let src = FixedU16::<U4>::from_num(3.5);
+assert_eq!(Fix::from_num(src).to_num::<FixedU16<U4>>(), 3.5);
+// 2^120 does not fit in 64 bits
+let large = Fix::from_num(1u128 << 120);
+assert_eq!(large.checked_to_num::<u64>(), None);
+assert_eq!(large.to_num::<f64>(), 2f64.powi(120));
+```
+
+[`F128Bits`]: crate::F128Bits
+[`bf16`]: half::bf16
+[`f16`]: half::f16
+[`wrapping_to_num`]: Self::wrapping_to_num
+";
+                #[inline]
+                pub fn to_num<Dst: FromFixed256>(self) -> Dst {
+                    let (wrapped, overflow) = self.overflowing_to_num::<Dst>();
+                    debug_assert!(!overflow, "{} overflows", self);
+                    wrapped
+                }
+            }
+
+            /// Converts a fixed-point number to another number if it fits,
+            /// otherwise returns [`None`].
+            ///
+            /// The rounding is the same as for [`to_num`].
+            ///
+            /// [`to_num`]: Self::to_num
+            #[inline]
+            pub fn checked_to_num<Dst: FromFixed256>(self) -> Option<Dst> {
+                match self.overflowing_to_num() {
+                    (_, true) => None,
+                    (wrapped, false) => Some(wrapped),
+                }
+            }
+
+            /// Converts a fixed-point number to another number, saturating if
+            /// it does not fit.
+            ///
+            /// The rounding is the same as for [`to_num`].
+            ///
+            /// [`to_num`]: Self::to_num
+            #[inline]
+            pub fn saturating_to_num<Dst: FromFixed256>(self) -> Dst {
+                Dst::private_saturating_from_raw256(self.raw(), Self::FRAC_NBITS, Self::IS_SIGNED)
+            }
+
+            /// Converts a fixed-point number to another number, wrapping if
+            /// it does not fit.
+            ///
+            /// The rounding is the same as for [`to_num`].
+            ///
+            /// [`to_num`]: Self::to_num
+            #[inline]
+            pub fn wrapping_to_num<Dst: FromFixed256>(self) -> Dst {
+                self.overflowing_to_num().0
+            }
+
+            /// Converts a fixed-point number to another number.
+            ///
+            /// Returns a [tuple] of the number and a [`bool`] indicating
+            /// whether an overflow has occurred. On overflow, the wrapped
+            /// value is returned.
+            ///
+            /// The rounding is the same as for [`to_num`].
+            ///
+            /// [`to_num`]: Self::to_num
+            #[inline]
+            pub fn overflowing_to_num<Dst: FromFixed256>(self) -> (Dst, bool) {
+                Dst::private_overflowing_from_raw256(self.raw(), Self::FRAC_NBITS, Self::IS_SIGNED)
+            }
+
+            /// Returns the integer part.
+            ///
+            /// Since the numbers are stored in two’s complement, negative
+            /// numbers with non-zero fractional parts will be rounded
+            /// towards −∞.
+            #[inline]
+            pub fn int(self) -> $Fixed<Frac> {
+                let int_mask = int256::shl_u256(U256::MAX, Self::FRAC_NBITS);
+                Self::from_raw(int256::and_u256(self.raw(), int_mask))
+            }
+
+            /// Returns the fractional part.
+            ///
+            /// Since the numbers are stored in two’s complement, the
+            /// returned fractional part is always non-negative.
+            #[inline]
+            pub fn frac(self) -> $Fixed<Frac> {
+                let int_mask = int256::shl_u256(U256::MAX, Self::FRAC_NBITS);
+                let frac_mask = U256 {
+                    lo: !int_mask.lo,
+                    hi: !int_mask.hi,
+                };
+                Self::from_raw(int256::and_u256(self.raw(), frac_mask))
+            }
+
+            if_signed! {
+                $Signedness;
+
+                /// Returns the absolute value.
+                ///
+                /// # Panics
+                ///
+                /// When debug assertions are enabled, panics if the result
+                /// does not fit. When debug assertions are not enabled, the
+                /// wrapped value can be returned.
+                #[inline]
+                pub fn abs(self) -> $Fixed<Frac> {
+                    if self.is_negative() {
+                        -self
+                    } else {
+                        self
+                    }
+                }
+            }
+
+            /// Checked negation. Returns the negated value, or [`None`] on
+            /// overflow.
+            #[inline]
+            pub fn checked_neg(self) -> Option<$Fixed<Frac>> {
+                match self.overflowing_neg() {
+                    (_, true) => None,
+                    (ans, false) => Some(ans),
+                }
+            }
+
+            /// Checked addition. Returns the sum, or [`None`] on overflow.
+            #[inline]
+            pub fn checked_add(self, rhs: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                match self.overflowing_add(rhs) {
+                    (_, true) => None,
+                    (ans, false) => Some(ans),
+                }
+            }
+
+            /// Checked subtraction. Returns the difference, or [`None`] on
+            /// overflow.
+            #[inline]
+            pub fn checked_sub(self, rhs: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                match self.overflowing_sub(rhs) {
+                    (_, true) => None,
+                    (ans, false) => Some(ans),
+                }
+            }
+
+            /// Checked multiplication. Returns the product, or [`None`] on
+            /// overflow.
+            #[inline]
+            pub fn checked_mul(self, rhs: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                match self.overflowing_mul(rhs) {
+                    (_, true) => None,
+                    (ans, false) => Some(ans),
+                }
+            }
+
+            /// Checked division. Returns the quotient, or [`None`] if the
+            /// divisor is zero or on overflow.
+            #[inline]
+            pub fn checked_div(self, rhs: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                if rhs.is_zero() {
+                    return None;
+                }
+                match self.overflowing_div(rhs) {
+                    (_, true) => None,
+                    (ans, false) => Some(ans),
+                }
+            }
+
+            /// Saturating addition. Returns the sum, saturating on overflow.
+            #[inline]
+            pub fn saturating_add(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                match self.overflowing_add(rhs) {
+                    (ans, false) => ans,
+                    (_, true) if msb(self.raw()) && Self::IS_SIGNED => Self::MIN,
+                    (_, true) => Self::MAX,
+                }
+            }
+
+            /// Saturating subtraction. Returns the difference, saturating on
+            /// overflow.
+            #[inline]
+            pub fn saturating_sub(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                match self.overflowing_sub(rhs) {
+                    (ans, false) => ans,
+                    (_, true) if msb(self.raw()) || !Self::IS_SIGNED => Self::MIN,
+                    (_, true) => Self::MAX,
+                }
+            }
+
+            /// Saturating multiplication. Returns the product, saturating on
+            /// overflow.
+            #[inline]
+            pub fn saturating_mul(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                match overflowing_mul(self.raw(), rhs.raw(), Self::FRAC_NBITS, Self::IS_SIGNED) {
+                    (ans, false, _) => Self::from_raw(ans),
+                    (_, true, true) => Self::MIN,
+                    (_, true, false) => Self::MAX,
+                }
+            }
+
+            /// Saturating division. Returns the quotient, saturating on
+            /// overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            #[inline]
+            pub fn saturating_div(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                match overflowing_div(self.raw(), rhs.raw(), Self::FRAC_NBITS, Self::IS_SIGNED) {
+                    (ans, false, _) => Self::from_raw(ans),
+                    (_, true, true) => Self::MIN,
+                    (_, true, false) => Self::MAX,
+                }
+            }
+
+            /// Wrapping negation. Returns the negated value, wrapping on
+            /// overflow.
+            #[inline]
+            pub fn wrapping_neg(self) -> $Fixed<Frac> {
+                self.overflowing_neg().0
+            }
+
+            /// Wrapping addition. Returns the sum, wrapping on overflow.
+            #[inline]
+            pub fn wrapping_add(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                self.overflowing_add(rhs).0
+            }
+
+            /// Wrapping subtraction. Returns the difference, wrapping on
+            /// overflow.
+            #[inline]
+            pub fn wrapping_sub(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                self.overflowing_sub(rhs).0
+            }
+
+            /// Wrapping multiplication. Returns the product, wrapping on
+            /// overflow.
+            #[inline]
+            pub fn wrapping_mul(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                self.overflowing_mul(rhs).0
+            }
+
+            /// Wrapping division. Returns the quotient, wrapping on overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            #[inline]
+            pub fn wrapping_div(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                self.overflowing_div(rhs).0
+            }
+
+            /// Overflowing negation.
+            ///
+            /// Returns a [tuple] of the negated value and a [`bool`]
+            /// indicating whether an overflow has occurred. On overflow, the
+            /// wrapped value is returned.
+            #[inline]
+            pub fn overflowing_neg(self) -> ($Fixed<Frac>, bool) {
+                let (ans, overflow) = overflowing_neg(self.raw(), Self::IS_SIGNED);
+                (Self::from_raw(ans), overflow)
+            }
+
+            /// Overflowing addition.
+            ///
+            /// Returns a [tuple] of the sum and a [`bool`] indicating whether
+            /// an overflow has occurred. On overflow, the wrapped value is
+            /// returned.
+            #[inline]
+            pub fn overflowing_add(self, rhs: $Fixed<Frac>) -> ($Fixed<Frac>, bool) {
+                let (ans, overflow) = overflowing_add(self.raw(), rhs.raw(), Self::IS_SIGNED);
+                (Self::from_raw(ans), overflow)
+            }
+
+            /// Overflowing subtraction.
+            ///
+            /// Returns a [tuple] of the difference and a [`bool`] indicating
+            /// whether an overflow has occurred. On overflow, the wrapped
+            /// value is returned.
+            #[inline]
+            pub fn overflowing_sub(self, rhs: $Fixed<Frac>) -> ($Fixed<Frac>, bool) {
+                let (ans, overflow) = overflowing_sub(self.raw(), rhs.raw(), Self::IS_SIGNED);
+                (Self::from_raw(ans), overflow)
+            }
+
+            comment! {
+                "Overflowing multiplication.
+
+Returns a [tuple] of the product and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+The product is rounded towards −∞, like for the narrower fixed-point
+numbers.
+
+# Examples
+
+```rust
+use fixed::{
+    types::{extra::U4, I8F8},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+let three = Fix::from_num(I8F8::from_num(3));
+let (ans, overflow) = three.overflowing_mul(three);
+assert_eq!((ans.to_num::<I8F8>(), overflow), (I8F8::from_num(9), false));
+assert!(Fix::MAX.overflowing_mul(three).1);
+```
+";
+                #[inline]
+                pub fn overflowing_mul(self, rhs: $Fixed<Frac>) -> ($Fixed<Frac>, bool) {
+                    let (ans, overflow, _) =
+                        overflowing_mul(self.raw(), rhs.raw(), Self::FRAC_NBITS, Self::IS_SIGNED);
+                    (Self::from_raw(ans), overflow)
+                }
+            }
+
+            /// Overflowing division.
+            ///
+            /// Returns a [tuple] of the quotient and a [`bool`] indicating
+            /// whether an overflow has occurred. On overflow, the wrapped
+            /// value is returned.
+            ///
+            /// The quotient is rounded towards zero, which is the rounding
+            /// used by the `/` operator.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            #[inline]
+            pub fn overflowing_div(self, rhs: $Fixed<Frac>) -> ($Fixed<Frac>, bool) {
+                let (ans, overflow, _) =
+                    overflowing_div(self.raw(), rhs.raw(), Self::FRAC_NBITS, Self::IS_SIGNED);
+                (Self::from_raw(ans), overflow)
+            }
+        }
+
+        impl<Frac: LeEqU256> Neg for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn neg(self) -> $Fixed<Frac> {
+                let (ans, overflow) = self.overflowing_neg();
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        fixed256_op! { $Fixed, Add add, AddAssign add_assign, overflowing_add }
+        fixed256_op! { $Fixed, Sub sub, SubAssign sub_assign, overflowing_sub }
+        fixed256_op! { $Fixed, Mul mul, MulAssign mul_assign, overflowing_mul }
+        fixed256_op! { $Fixed, Div div, DivAssign div_assign, overflowing_div }
+
+        impl<Frac: LeEqU128> From<$Fixed128<Frac>> for $Fixed<Frac> {
+            /// Converts a fixed-point number to a wider one with the same
+            /// number of fractional bits.
+            ///
+            /// This conversion never fails (infallible).
+            #[inline]
+            fn from(src: $Fixed128<Frac>) -> $Fixed<Frac> {
+                $Fixed::from_bits($Inner::from(src.to_bits()))
+            }
+        }
+
+        impl<Frac: LeEqU256> Sealed256 for $Fixed<Frac> {
+            #[inline]
+            fn private_to_kind256(self, dst_frac_nbits: u32) -> Kind256 {
+                let (neg, abs) = neg_abs(self.raw(), Self::IS_SIGNED);
+                let (abs, overflow) = shift_floor(neg, abs, Self::FRAC_NBITS, dst_frac_nbits);
+                Kind256::Finite { neg, abs, overflow }
+            }
+            #[inline]
+            fn private_overflowing_from_raw256(
+                raw: U256,
+                src_frac_nbits: u32,
+                src_signed: bool,
+            ) -> ($Fixed<Frac>, bool) {
+                let (neg, abs) = neg_abs(raw, src_signed);
+                let (abs, overflow) = shift_floor(neg, abs, src_frac_nbits, Self::FRAC_NBITS);
+                let kind = Kind256::Finite { neg, abs, overflow };
+                let (raw, overflow) = overflowing_from_kind(kind, Self::IS_SIGNED);
+                (Self::from_raw(raw), overflow)
+            }
+            #[inline]
+            fn private_saturating_from_raw256(
+                raw: U256,
+                src_frac_nbits: u32,
+                src_signed: bool,
+            ) -> $Fixed<Frac> {
+                let neg = src_signed && msb(raw);
+                match Self::private_overflowing_from_raw256(raw, src_frac_nbits, src_signed) {
+                    (_, true) if neg => Self::MIN,
+                    (_, true) => Self::MAX,
+                    (wrapped, false) => wrapped,
+                }
+            }
+        }
+    };
+}
+
+macro_rules! fixed256_op {
+    ($Fixed:ident, $Op:ident $op:ident, $OpAssign:ident $op_assign:ident, $overflowing:ident) => {
+        impl<Frac: LeEqU256> $Op for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn $op(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                let (ans, overflow) = self.$overflowing(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        impl<Frac: LeEqU256> $OpAssign for $Fixed<Frac> {
+            #[inline]
+            fn $op_assign(&mut self, rhs: $Fixed<Frac>) {
+                *self = $Op::$op(*self, rhs);
+            }
+        }
+    };
+}
+
+fixed256! {
+    "A signed 256",
+    FixedI256["FixedI256"](I256["I256"], Signed),
+    FixedI128["FixedI128"]
+}
+fixed256! {
+    "An unsigned 256",
+    FixedU256["FixedU256"](U256["U256"], Unsigned),
+    FixedU128["FixedU128"]
+}
+
+impl<Frac: LeEqU128> FixedI128<Frac> {
+    /// Multiplies two fixed-point numbers and returns a wider type to
+    /// retain all precision.
+    ///
+    /// If `self` has <i>f</i> fractional bits and 128 − <i>f</i> integer
+    /// bits, and `rhs` has <i>g</i> fractional bits and 128 − <i>g</i>
+    /// integer bits, then the returned fixed-point number will have
+    /// <i>f</i> + <i>g</i> fractional bits and 256 − <i>f</i> − <i>g</i>
+    /// integer bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{
+    ///     types::extra::{U2, U4, U6},
+    ///     FixedI128, FixedI256,
+    /// };
+    /// // decimal: 1.25 × −1.0625 = −1.328_125
+    /// let a = FixedI128::<U2>::from_num(1.25);
+    /// let b = FixedI128::<U4>::from_num(-1.0625);
+    /// let prod = a.wide_mul(b);
+    /// assert_eq!(prod, FixedI256::<U6>::from_num(FixedI128::<U6>::from_num(-1.328_125)));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn wide_mul<RhsFrac>(self, rhs: FixedI128<RhsFrac>) -> FixedI256<Sum<Frac, RhsFrac>>
+    where
+        Frac: Add<RhsFrac>,
+    {
+        FixedI256::from_bits(int256::wide_mul_i128(self.to_bits(), rhs.to_bits()))
+    }
+
+    /// Divides two fixed-point numbers and returns a wider type to retain
+    /// more precision.
+    ///
+    /// If `self` has <i>f</i> fractional bits and 128 − <i>f</i> integer
+    /// bits, and `rhs` has <i>g</i> fractional bits and 128 − <i>g</i>
+    /// integer bits, then the returned fixed-point number will have
+    /// <i>f</i> + <i>g</i> fractional bits and 256 − <i>f</i> − <i>g</i>
+    /// integer bits. The quotient is rounded towards zero, which is the
+    /// rounding used by the `/` operator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the quotient does not fit, which
+    /// can only happen if `rhs` has at least 64 fractional bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{
+    ///     types::extra::{U2, U4, U6},
+    ///     FixedI128, FixedI256,
+    /// };
+    /// // 1.25 ÷ −1.5 = −0.833…
+    /// let a = FixedI128::<U2>::from_num(1.25);
+    /// let b = FixedI128::<U4>::from_num(-1.5);
+    /// // six fractional bits, rounded towards zero: −0.828_125 = −53/64
+    /// let quot = a.wide_div(b);
+    /// assert_eq!(quot, FixedI256::<U6>::from_num(FixedI128::<U6>::from_num(-0.828_125)));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn wide_div<RhsFrac: LeEqU128>(
+        self,
+        rhs: FixedI128<RhsFrac>,
+    ) -> FixedI256<Sum<Frac, RhsFrac>>
+    where
+        Frac: Add<RhsFrac>,
+    {
+        assert!(rhs.to_bits() != 0, "division by zero");
+        match self.checked_wide_div(rhs) {
+            Some(quot) => quot,
+            None => panic!("overflow"),
+        }
+    }
+
+    /// Checked widening division. Returns the quotient as a wider type, or
+    /// [`None`] if the divisor is zero or if the quotient does not fit.
+    ///
+    /// See [`wide_div`][Self::wide_div] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{
+    ///     types::extra::{U2, U4, U6},
+    ///     FixedI128, FixedI256,
+    /// };
+    /// let a = FixedI128::<U2>::from_num(1.25);
+    /// let b = FixedI128::<U4>::from_num(-1.5);
+    /// let quot = FixedI256::<U6>::from_num(FixedI128::<U6>::from_num(-0.828_125));
+    /// assert_eq!(a.checked_wide_div(b), Some(quot));
+    /// assert!(a.checked_wide_div(FixedI128::<U4>::ZERO).is_none());
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_wide_div<RhsFrac: LeEqU128>(
+        self,
+        rhs: FixedI128<RhsFrac>,
+    ) -> Option<FixedI256<Sum<Frac, RhsFrac>>>
+    where
+        Frac: Add<RhsFrac>,
+    {
+        let lhs = FixedI256::<Frac>::from(self).raw();
+        let rhs = FixedI256::<RhsFrac>::from(rhs).raw();
+        let quot = checked_wide_div(lhs, rhs, RhsFrac::U32, true)?;
+        Some(FixedI256::from_raw(quot))
+    }
+
+    /// Adds two fixed-point numbers that can have different numbers of
+    /// fractional bits, and returns a wider type that holds the exact sum.
+    ///
+    /// If `self` has <i>f</i> fractional bits and `rhs` has <i>g</i>
+    /// fractional bits, then the returned fixed-point number will have 256
+    /// bits, max(<i>f</i>, <i>g</i>) of which are fractional.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sum does not fit. This can only happen if one operand
+    /// has no fractional bits and the other has no integer bits, and both are
+    /// negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{
+    ///     types::extra::{U4, U6},
+    ///     FixedI128, FixedI256,
+    /// };
+    /// let a = FixedI128::<U4>::from_num(1.25);
+    /// let b = FixedI128::<U6>::from_num(0.015625);
+    /// let exact = FixedI256::<U6>::from_num(FixedI128::<U6>::from_num(1.265625));
+    /// assert_eq!(a.add_wide(b), exact);
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn add_wide<RhsFrac: LeEqU128>(
+        self,
+        rhs: FixedI128<RhsFrac>,
+    ) -> FixedI256<Maximum<Frac, RhsFrac>>
+    where
+        Frac: Max<RhsFrac>,
+    {
+        match self.checked_add_wide(rhs) {
+            Some(sum) => sum,
+            None => panic!("overflow"),
+        }
+    }
+
+    /// Subtracts two fixed-point numbers that can have different numbers of
+    /// fractional bits, and returns a wider type that holds the exact
+    /// difference.
+    ///
+    /// If `self` has <i>f</i> fractional bits and `rhs` has <i>g</i>
+    /// fractional bits, then the returned fixed-point number will have 256
+    /// bits, max(<i>f</i>, <i>g</i>) of which are fractional.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the difference does not fit. This can only happen if one
+    /// operand has no fractional bits and the other has no integer bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{
+    ///     types::extra::{U4, U6},
+    ///     FixedI128, FixedI256,
+    /// };
+    /// let a = FixedI128::<U4>::from_num(1.25);
+    /// let b = FixedI128::<U6>::from_num(0.015625);
+    /// let exact = FixedI256::<U6>::from_num(FixedI128::<U6>::from_num(1.234375));
+    /// assert_eq!(a.sub_wide(b), exact);
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn sub_wide<RhsFrac: LeEqU128>(
+        self,
+        rhs: FixedI128<RhsFrac>,
+    ) -> FixedI256<Maximum<Frac, RhsFrac>>
+    where
+        Frac: Max<RhsFrac>,
+    {
+        match self.checked_sub_wide(rhs) {
+            Some(diff) => diff,
+            None => panic!("overflow"),
+        }
+    }
+
+    /// Checked addition of two fixed-point numbers that can have different
+    /// numbers of fractional bits. Returns the exact sum, or [`None`] if it
+    /// does not fit.
+    ///
+    /// See [`add_wide`][Self::add_wide] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{
+    ///     types::extra::{U0, U128, U4, U6},
+    ///     FixedI128, FixedI256,
+    /// };
+    /// let a = FixedI128::<U4>::from_num(1.25);
+    /// let b = FixedI128::<U6>::from_num(0.015625);
+    /// let sum = FixedI256::<U6>::from_num(FixedI128::<U6>::from_num(1.265625));
+    /// assert_eq!(a.checked_add_wide(b), Some(sum));
+    /// let int = FixedI128::<U0>::MIN;
+    /// let frac = FixedI128::<U128>::MIN;
+    /// assert!(int.checked_add_wide(frac).is_none());
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_add_wide<RhsFrac: LeEqU128>(
+        self,
+        rhs: FixedI128<RhsFrac>,
+    ) -> Option<FixedI256<Maximum<Frac, RhsFrac>>>
+    where
+        Frac: Max<RhsFrac>,
+    {
+        let lhs = FixedI256::<Frac>::from(self).raw();
+        let rhs = FixedI256::<RhsFrac>::from(rhs).raw();
+        let sum = checked_add_sub_wide(lhs, Frac::U32, rhs, RhsFrac::U32, false, true)?;
+        Some(FixedI256::from_raw(sum))
+    }
+
+    /// Checked subtraction of two fixed-point numbers that can have different
+    /// numbers of fractional bits. Returns the exact difference, or [`None`]
+    /// if it does not fit.
+    ///
+    /// See [`sub_wide`][Self::sub_wide] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{
+    ///     types::extra::{U4, U6},
+    ///     FixedI128, FixedI256,
+    /// };
+    /// let a = FixedI128::<U4>::from_num(1.25);
+    /// let b = FixedI128::<U6>::from_num(0.015625);
+    /// let diff = FixedI256::<U6>::from_num(FixedI128::<U6>::from_num(1.234375));
+    /// assert_eq!(a.checked_sub_wide(b), Some(diff));
+    /// assert_eq!(b.checked_sub_wide(a), Some(-diff));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_sub_wide<RhsFrac: LeEqU128>(
+        self,
+        rhs: FixedI128<RhsFrac>,
+    ) -> Option<FixedI256<Maximum<Frac, RhsFrac>>>
+    where
+        Frac: Max<RhsFrac>,
+    {
+        let lhs = FixedI256::<Frac>::from(self).raw();
+        let rhs = FixedI256::<RhsFrac>::from(rhs).raw();
+        let diff = checked_add_sub_wide(lhs, Frac::U32, rhs, RhsFrac::U32, true, true)?;
+        Some(FixedI256::from_raw(diff))
+    }
+}
+
+impl<Frac: LeEqU128> FixedU128<Frac> {
+    /// Multiplies two fixed-point numbers and returns a wider type to
+    /// retain all precision.
+    ///
+    /// If `self` has <i>f</i> fractional bits and 128 − <i>f</i> integer
+    /// bits, and `rhs` has <i>g</i> fractional bits and 128 − <i>g</i>
+    /// integer bits, then the returned fixed-point number will have
+    /// <i>f</i> + <i>g</i> fractional bits and 256 − <i>f</i> − <i>g</i>
+    /// integer bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{
+    ///     types::extra::{U2, U4, U6},
+    ///     FixedU128, FixedU256,
+    /// };
+    /// // decimal: 1.25 × 1.0625 = 1.328_125
+    /// let a = FixedU128::<U2>::from_num(1.25);
+    /// let b = FixedU128::<U4>::from_num(1.0625);
+    /// let prod = a.wide_mul(b);
+    /// assert_eq!(prod, FixedU256::<U6>::from_num(FixedU128::<U6>::from_num(1.328_125)));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn wide_mul<RhsFrac>(self, rhs: FixedU128<RhsFrac>) -> FixedU256<Sum<Frac, RhsFrac>>
+    where
+        Frac: Add<RhsFrac>,
+    {
+        FixedU256::from_bits(int256::wide_mul_u128(self.to_bits(), rhs.to_bits()))
+    }
+
+    /// Divides two fixed-point numbers and returns a wider type to retain
+    /// more precision.
+    ///
+    /// If `self` has <i>f</i> fractional bits and 128 − <i>f</i> integer
+    /// bits, and `rhs` has <i>g</i> fractional bits and 128 − <i>g</i>
+    /// integer bits, then the returned fixed-point number will have
+    /// <i>f</i> + <i>g</i> fractional bits and 256 − <i>f</i> − <i>g</i>
+    /// integer bits. The quotient is rounded towards zero, which is the
+    /// rounding used by the `/` operator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the quotient does not fit, which
+    /// can only happen if `rhs` has more than 64 fractional bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{
+    ///     types::extra::{U2, U4, U6},
+    ///     FixedU128, FixedU256,
+    /// };
+    /// // 1.25 ÷ 1.5 = 0.833…
+    /// let a = FixedU128::<U2>::from_num(1.25);
+    /// let b = FixedU128::<U4>::from_num(1.5);
+    /// // six fractional bits: 0.828_125 = 53/64
+    /// let quot = a.wide_div(b);
+    /// assert_eq!(quot, FixedU256::<U6>::from_num(FixedU128::<U6>::from_num(0.828_125)));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn wide_div<RhsFrac: LeEqU128>(
+        self,
+        rhs: FixedU128<RhsFrac>,
+    ) -> FixedU256<Sum<Frac, RhsFrac>>
+    where
+        Frac: Add<RhsFrac>,
+    {
+        assert!(rhs.to_bits() != 0, "division by zero");
+        match self.checked_wide_div(rhs) {
+            Some(quot) => quot,
+            None => panic!("overflow"),
+        }
+    }
+
+    /// Checked widening division. Returns the quotient as a wider type, or
+    /// [`None`] if the divisor is zero or if the quotient does not fit.
+    ///
+    /// See [`wide_div`][Self::wide_div] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{
+    ///     types::extra::{U2, U4, U6},
+    ///     FixedU128, FixedU256,
+    /// };
+    /// let a = FixedU128::<U2>::from_num(1.25);
+    /// let b = FixedU128::<U4>::from_num(1.5);
+    /// let quot = FixedU256::<U6>::from_num(FixedU128::<U6>::from_num(0.828_125));
+    /// assert_eq!(a.checked_wide_div(b), Some(quot));
+    /// assert!(a.checked_wide_div(FixedU128::<U4>::ZERO).is_none());
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_wide_div<RhsFrac: LeEqU128>(
+        self,
+        rhs: FixedU128<RhsFrac>,
+    ) -> Option<FixedU256<Sum<Frac, RhsFrac>>>
+    where
+        Frac: Add<RhsFrac>,
+    {
+        let lhs = FixedU256::<Frac>::from(self).raw();
+        let rhs = FixedU256::<RhsFrac>::from(rhs).raw();
+        let quot = checked_wide_div(lhs, rhs, RhsFrac::U32, false)?;
+        Some(FixedU256::from_raw(quot))
+    }
+
+    /// Adds two fixed-point numbers that can have different numbers of
+    /// fractional bits, and returns a wider type that holds the exact sum.
+    ///
+    /// If `self` has <i>f</i> fractional bits and `rhs` has <i>g</i>
+    /// fractional bits, then the returned fixed-point number will have 256
+    /// bits, max(<i>f</i>, <i>g</i>) of which are fractional.
+    ///
+    /// The sum always fits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{
+    ///     types::extra::{U4, U6},
+    ///     FixedU128, FixedU256,
+    /// };
+    /// let a = FixedU128::<U4>::from_num(1.25);
+    /// let b = FixedU128::<U6>::from_num(0.015625);
+    /// let exact = FixedU256::<U6>::from_num(FixedU128::<U6>::from_num(1.265625));
+    /// assert_eq!(a.add_wide(b), exact);
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn add_wide<RhsFrac: LeEqU128>(
+        self,
+        rhs: FixedU128<RhsFrac>,
+    ) -> FixedU256<Maximum<Frac, RhsFrac>>
+    where
+        Frac: Max<RhsFrac>,
+    {
+        match self.checked_add_wide(rhs) {
+            Some(sum) => sum,
+            None => panic!("overflow"),
+        }
+    }
+
+    /// Subtracts two fixed-point numbers that can have different numbers of
+    /// fractional bits, and returns a wider type that holds the exact
+    /// difference.
+    ///
+    /// If `self` has <i>f</i> fractional bits and `rhs` has <i>g</i>
+    /// fractional bits, then the returned fixed-point number will have 256
+    /// bits, max(<i>f</i>, <i>g</i>) of which are fractional.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the difference is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{
+    ///     types::extra::{U4, U6},
+    ///     FixedU128, FixedU256,
+    /// };
+    /// let a = FixedU128::<U4>::from_num(1.25);
+    /// let b = FixedU128::<U6>::from_num(0.015625);
+    /// let exact = FixedU256::<U6>::from_num(FixedU128::<U6>::from_num(1.234375));
+    /// assert_eq!(a.sub_wide(b), exact);
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn sub_wide<RhsFrac: LeEqU128>(
+        self,
+        rhs: FixedU128<RhsFrac>,
+    ) -> FixedU256<Maximum<Frac, RhsFrac>>
+    where
+        Frac: Max<RhsFrac>,
+    {
+        match self.checked_sub_wide(rhs) {
+            Some(diff) => diff,
+            None => panic!("overflow"),
+        }
+    }
+
+    /// Checked addition of two fixed-point numbers that can have different
+    /// numbers of fractional bits. Returns the exact sum, or [`None`] if it
+    /// does not fit.
+    ///
+    /// See [`add_wide`][Self::add_wide] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{
+    ///     types::extra::{U4, U6},
+    ///     FixedU128, FixedU256,
+    /// };
+    /// let a = FixedU128::<U4>::from_num(1.25);
+    /// let b = FixedU128::<U6>::from_num(0.015625);
+    /// let sum = FixedU256::<U6>::from_num(FixedU128::<U6>::from_num(1.265625));
+    /// assert_eq!(a.checked_add_wide(b), Some(sum));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_add_wide<RhsFrac: LeEqU128>(
+        self,
+        rhs: FixedU128<RhsFrac>,
+    ) -> Option<FixedU256<Maximum<Frac, RhsFrac>>>
+    where
+        Frac: Max<RhsFrac>,
+    {
+        let lhs = FixedU256::<Frac>::from(self).raw();
+        let rhs = FixedU256::<RhsFrac>::from(rhs).raw();
+        let sum = checked_add_sub_wide(lhs, Frac::U32, rhs, RhsFrac::U32, false, false)?;
+        Some(FixedU256::from_raw(sum))
+    }
+
+    /// Checked subtraction of two fixed-point numbers that can have different
+    /// numbers of fractional bits. Returns the exact difference, or [`None`]
+    /// if it does not fit.
+    ///
+    /// See [`sub_wide`][Self::sub_wide] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{
+    ///     types::extra::{U4, U6},
+    ///     FixedU128, FixedU256,
+    /// };
+    /// let a = FixedU128::<U4>::from_num(1.25);
+    /// let b = FixedU128::<U6>::from_num(0.015625);
+    /// let diff = FixedU256::<U6>::from_num(FixedU128::<U6>::from_num(1.234375));
+    /// assert_eq!(a.checked_sub_wide(b), Some(diff));
+    /// assert!(b.checked_sub_wide(a).is_none());
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_sub_wide<RhsFrac: LeEqU128>(
+        self,
+        rhs: FixedU128<RhsFrac>,
+    ) -> Option<FixedU256<Maximum<Frac, RhsFrac>>>
+    where
+        Frac: Max<RhsFrac>,
+    {
+        let lhs = FixedU256::<Frac>::from(self).raw();
+        let rhs = FixedU256::<RhsFrac>::from(rhs).raw();
+        let diff = checked_add_sub_wide(lhs, Frac::U32, rhs, RhsFrac::U32, true, false)?;
+        Some(FixedU256::from_raw(diff))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        int256::{self, I256},
+        types::{extra::*, I16F16, I8F8, U8F8},
+        FixedI128, FixedI256, FixedU256,
+    };
+    use std::{format, string::ToString};
+
+    #[test]
+    fn from_to_num_exhaustive_8() {
+        type I = FixedI256<U130>;
+        type U = FixedU256<U4>;
+        for bits in i16::MIN..=i16::MAX {
+            let src = I8F8::from_bits(bits);
+            assert_eq!(I::from_num(src).to_num::<I8F8>(), src);
+            assert_eq!(I::from_num(src).to_num::<I16F16>(), I16F16::from_num(src));
+            let floor = src.floor();
+            let u = U::checked_from_num(src);
+            if src < 0 {
+                assert!(u.is_none());
+                assert_eq!(U::saturating_from_num(src), U::ZERO);
+            } else {
+                let u = u.unwrap();
+                // four fractional bits, rounded towards −∞
+                assert_eq!(
+                    u.to_num::<U8F8>(),
+                    U8F8::from_bits(src.to_bits() as u16 & !0xF)
+                );
+            }
+            assert_eq!(FixedI256::<U0>::from_num(src).to_num::<I8F8>(), floor);
+        }
+    }
+
+    #[test]
+    fn from_to_int_float() {
+        type I = FixedI256<U16>;
+        for bits in (i32::MIN..=i32::MAX).step_by(0x1_0001) {
+            let src = I16F16::from_bits(bits);
+            let f = src.to_num::<f64>();
+            assert_eq!(I::from_num(f), I::from_num(src));
+            assert_eq!(I::from_num(src).to_num::<f64>(), f);
+            let f = src.to_num::<f32>();
+            assert_eq!(I::from_num(src).to_num::<f32>(), f);
+            if let Some(narrow) = I16F16::checked_from_num(f) {
+                assert_eq!(I::from_num(f), I::from_num(narrow));
+            }
+            assert_eq!(I::from_num(src).to_num::<i32>(), src.to_num::<i32>());
+            let int = bits >> 16;
+            assert_eq!(I::from_num(int), I::from_num(I16F16::from_num(int)));
+        }
+
+        // ties round to even
+        assert_eq!(FixedI256::<U0>::from_num(2.5f64).to_num::<i32>(), 2);
+        assert_eq!(FixedI256::<U0>::from_num(3.5f64).to_num::<i32>(), 4);
+        assert_eq!(FixedI256::<U0>::from_num(-2.5f64).to_num::<i32>(), -2);
+
+        // values beyond 128 bits
+        let large = FixedU256::<U0>::from_num(2f64.powi(200));
+        assert_eq!(large.to_num::<f64>(), 2f64.powi(200));
+        assert_eq!(large.checked_to_num::<u128>(), None);
+        assert_eq!(large.saturating_to_num::<u128>(), u128::MAX);
+        assert_eq!(large.to_num::<f32>(), f32::INFINITY);
+        let max = FixedU256::<U0>::from_num(u128::MAX);
+        assert_eq!(max.to_num::<u128>(), u128::MAX);
+        assert_eq!(FixedI256::<U0>::checked_from_num(2f64.powi(255)), None);
+        assert_eq!(
+            FixedI256::<U0>::from_num(-2f64.powi(255)),
+            FixedI256::<U0>::MIN
+        );
+        assert_eq!(FixedI256::<U0>::MIN.to_num::<f64>(), -2f64.powi(255));
+        assert_eq!(FixedI256::<U0>::MAX.to_num::<f64>(), 2f64.powi(255));
+
+        // small values and subnormals
+        type Tiny = FixedU256<U256>;
+        assert_eq!(
+            Tiny::from_num(2f64.powi(-200)).to_bits(),
+            int256::U256::from(1u64 << 56)
+        );
+        assert_eq!(Tiny::DELTA.to_num::<f64>(), 2f64.powi(-256));
+        assert_eq!(Tiny::DELTA.to_num::<f32>(), 0.0);
+        let f32_min = Tiny::from_bits(int256::U256::from(1u128 << 107));
+        assert_eq!(f32_min.to_num::<f32>(), f32::from_bits(1));
+        let half_min = Tiny::from_bits(int256::U256::from(1u128 << 106));
+        assert_eq!(half_min.to_num::<f32>(), 0.0);
+        let three_quarters_min = Tiny::from_bits(int256::U256::from(3u128 << 105));
+        assert_eq!(three_quarters_min.to_num::<f32>(), f32::from_bits(1));
+        assert_eq!(Tiny::from_num(f64::from_bits(1)), Tiny::ZERO);
+
+        // non-finite and negative values
+        assert_eq!(I::checked_from_num(f64::NAN), None);
+        assert_eq!(I::checked_from_num(f64::INFINITY), None);
+        assert_eq!(I::saturating_from_num(f64::NEG_INFINITY), I::MIN);
+        assert_eq!(FixedU256::<U0>::checked_from_num(-1i32), None);
+        assert_eq!(
+            FixedU256::<U0>::saturating_from_num(-1i32).to_num::<i32>(),
+            0
+        );
+        assert_eq!(FixedU256::<U0>::from_num(-0.0f32).to_num::<i32>(), 0);
+
+        // conversions between 256-bit numbers
+        let neg_half = FixedI256::<U8>::from_num(-0.5);
+        assert_eq!(FixedU256::<U0>::checked_from_num(neg_half), None);
+        assert_eq!(
+            FixedU256::<U0>::saturating_from_num(neg_half).to_num::<i32>(),
+            0
+        );
+        assert_eq!(FixedI256::<U0>::from_num(neg_half).to_num::<i32>(), -1);
+        assert_eq!(FixedI256::<U200>::from_num(neg_half).to_num::<f64>(), -0.5);
+        assert_eq!(
+            FixedI256::<U255>::saturating_from_num(FixedU256::<U0>::MAX),
+            FixedI256::<U255>::MAX
+        );
+    }
+
+    #[test]
+    fn arith_exhaustive_8() {
+        // four integer bits, values are a/16 for all i8 a
+        type F = FixedI256<U252>;
+        let fixed = |a: i8| F::from_num(I8F8::from_bits(i16::from(a) << 4));
+        for a in i8::MIN..=i8::MAX {
+            let (fa, a) = (fixed(a), i32::from(a));
+            for b in i8::MIN..=i8::MAX {
+                let (fb, b) = (fixed(b), i32::from(b));
+                let sum = a + b;
+                let (ans, overflow) = fa.overflowing_add(fb);
+                assert_eq!(overflow, !(-128..128).contains(&sum));
+                if !overflow {
+                    assert_eq!(ans.to_num::<I16F16>(), I16F16::from_bits(sum << 12));
+                }
+                let diff = a - b;
+                assert_eq!(fa.checked_sub(fb).is_none(), !(-128..128).contains(&diff));
+                let prod = a * b;
+                match fa.checked_mul(fb) {
+                    Some(ans) => assert_eq!(ans.to_num::<I16F16>(), I16F16::from_bits(prod << 8)),
+                    None => assert!(!(-2048..2048).contains(&prod)),
+                }
+                if b == 0 {
+                    assert!(fa.checked_div(fb).is_none());
+                    continue;
+                }
+                let (n, d) = if b < 0 { (-a, -b) } else { (a, b) };
+                let mut quot = (n << 16) / d;
+                if (n << 16) % d < 0 {
+                    quot -= 1;
+                }
+                match fa.checked_div(fb) {
+                    Some(ans) => assert_eq!(ans.to_num::<I16F16>(), I16F16::from_bits(quot)),
+                    None => assert!(n >= 8 * d || n < -8 * d),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wide_mul_and_wrapping() {
+        let a = FixedI128::<U64>::MIN;
+        let prod = a.wide_mul(a);
+        assert_eq!(
+            prod.to_bits(),
+            I256 {
+                lo: 0,
+                hi: 1 << 126
+            }
+        );
+        let neg_one = FixedI256::from_num(I8F8::from_num(-1));
+        assert_eq!(prod.wrapping_mul(neg_one), -prod);
+        assert_eq!(
+            FixedI256::<U128>::MIN.overflowing_neg(),
+            (FixedI256::MIN, true)
+        );
+        assert_eq!(FixedI256::<U128>::MIN.checked_div(neg_one), None);
+        let u = FixedU256::<U0>::MAX;
+        assert_eq!(u.to_bits(), int256::U256::MAX);
+        assert_eq!(u.overflowing_add(FixedU256::DELTA), (FixedU256::ZERO, true));
+        assert_eq!(u.saturating_mul(u), u);
+        assert_eq!(FixedU256::<U0>::ZERO.saturating_sub(u), FixedU256::ZERO);
+        assert_eq!(u.wrapping_mul(u), FixedU256::DELTA);
+    }
+
+    #[test]
+    fn wide_div_128() {
+        use crate::{int256::U256, FixedU128};
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let fa = FixedI128::<U4>::from_bits(a.into());
+                let fb = FixedI128::<U6>::from_bits(b.into());
+                let quot = fa.checked_wide_div(fb).map(|q| q.to_bits());
+                if b == 0 {
+                    assert_eq!(quot, None);
+                    continue;
+                }
+                // the quotient has 10 fractional bits, truncated like /
+                let exp = i32::from(a) * 4096 / i32::from(b);
+                assert_eq!(quot, Some(I256::from(i128::from(exp))));
+                let fa = FixedU128::<U4>::from_bits(u128::from(a as u8));
+                let fb = FixedU128::<U6>::from_bits(u128::from(b as u8));
+                let exp = u32::from(a as u8) * 4096 / u32::from(b as u8);
+                assert_eq!(fa.wide_div(fb).to_bits(), U256::from(exp));
+            }
+        }
+
+        let min = FixedI128::<U0>::MIN;
+        let neg_one = FixedI128::<U0>::from_num(-1);
+        assert_eq!(
+            min.wide_div(neg_one).to_bits(),
+            I256 {
+                lo: 1 << 127,
+                hi: 0
+            }
+        );
+        // 64 fractional bits is the first to overflow for signed numbers
+        let delta = FixedI128::<U64>::DELTA;
+        assert_eq!(min.checked_wide_div(-delta), None);
+        assert_eq!(
+            (min + FixedI128::ONE).wide_div(-delta).to_bits(),
+            I256 {
+                lo: 0,
+                hi: i128::MAX
+            }
+        );
+        assert!(min.checked_wide_div(FixedI128::<U63>::DELTA).is_some());
+        let max = FixedU128::<U0>::MAX;
+        assert_eq!(max.checked_wide_div(FixedU128::<U65>::DELTA), None);
+        assert_eq!(
+            max.wide_div(FixedU128::<U64>::DELTA).to_bits(),
+            U256 {
+                lo: 0,
+                hi: u128::MAX
+            }
+        );
+        // 2^256 / (2^128 − 1) = 2^128 + 1 + 1 / (2^128 − 1)
+        let quot = FixedU128::<U0>::ONE.wide_div(FixedU128::<U128>::MAX);
+        assert_eq!(quot.to_bits(), U256 { lo: 1, hi: 1 });
+    }
+
+    #[test]
+    fn fmt_and_parse() {
+        type F = FixedI256<U60>;
+        let s = "-123456789012345678901234567890123456789012345678901234567.125";
+        let f: F = s.parse().unwrap();
+        assert_eq!(f.to_string(), s);
+        assert_eq!(
+            f.int().to_string(),
+            "-123456789012345678901234567890123456789012345678901234568"
+        );
+        assert_eq!(f.frac().to_string(), "0.875");
+        assert_eq!(format!("{:.1}", F::from_num(I8F8::from_num(0.75))), "0.8");
+        assert_eq!(format!("{:x}", F::from_num(I8F8::from_num(-10.5))), "-a.8");
+        let half = FixedU256::<U256>::from_num(U8F8::from_num(0.5));
+        assert_eq!(half.to_string(), "0.5");
+        assert!("1e3".parse::<F>().is_err());
+        assert!("1".parse::<FixedU256<U256>>().is_err());
+        assert_eq!(
+            int256::U256::MAX.to_string(),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(
+            I256::MIN.to_string(),
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+        );
+    }
+}
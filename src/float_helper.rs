@@ -18,8 +18,9 @@ macro_rules! make_helper {
         #[allow(non_snake_case)]
         pub mod $Float {
             use crate::{
-                helpers::{FloatKind, ToFixedHelper, ToFloatHelper, Widest},
-                int_helper,
+                helpers::{FloatKind, Kind256, ToFixedHelper, ToFloatHelper, Widest},
+                int256::{self, U256},
+                int_helper, RoundingMode,
             };
             use core::cmp::Ordering;
             $(use $path;)?
@@ -48,6 +49,7 @@ macro_rules! make_helper {
                 val: ToFloatHelper,
                 frac_bits: u32,
                 int_bits: u32,
+                mode: RoundingMode,
             ) -> $Float {
                 let fix_bits = frac_bits + int_bits;
 
@@ -63,7 +65,13 @@ macro_rules! make_helper {
                 let mut mantissa = val.abs << leading_zeros << 1;
                 let exponent = int_bits as i32 - 1 - leading_zeros as i32;
                 let biased_exponent = if exponent > EXP_MAX {
-                    return $Float::from_bits(EXP_MASK | bits_sign);
+                    // rounding up from above the maximum gives infinity
+                    let exp_mantissa = if mode.round_up_abs(val.neg, true, Ordering::Greater) {
+                        EXP_MASK
+                    } else {
+                        EXP_MASK - 1
+                    };
+                    return $Float::from_bits(exp_mantissa | bits_sign);
                 } else if exponent < EXP_MIN {
                     let lost_prec = EXP_MIN - exponent;
                     if lost_prec as u32 >= (int_bits + frac_bits) {
@@ -71,7 +79,10 @@ macro_rules! make_helper {
                     } else {
                         // reinsert implicit one
                         mantissa = (mantissa >> 1) | !(!0 >> 1);
-                        mantissa >>= lost_prec - 1;
+                        // keep a sticky bit so that rounding can tell that
+                        // the value is inexact
+                        let sticky = mantissa & !(!0 << (lost_prec - 1)) != 0;
+                        mantissa = (mantissa >> (lost_prec - 1)) | u128::from(sticky);
                     }
                     0
                 } else {
@@ -81,15 +92,10 @@ macro_rules! make_helper {
                 let round_up = (fix_bits >= PREC) && {
                     let shift = PREC - 1;
                     let mid_bit = !(!0 >> 1) >> (shift + extra_zeros);
-                    let lower_bits = mid_bit - 1;
-                    if mantissa & mid_bit == 0 {
-                        false
-                    } else if mantissa & lower_bits != 0 {
-                        true
-                    } else {
-                        // round to even
-                        mantissa & (mid_bit << 1) != 0
-                    }
+                    let removed_bits = mantissa & (mid_bit | (mid_bit - 1));
+                    let odd = mantissa & (mid_bit << 1) != 0;
+                    removed_bits != 0
+                        && mode.round_up_abs(val.neg, odd, removed_bits.cmp(&mid_bit))
                 };
                 let bits_exp = biased_exponent << (PREC - 1);
                 let bits_mantissa = (if fix_bits >= PREC - 1 {
@@ -106,6 +112,16 @@ macro_rules! make_helper {
 
             #[inline]
             pub fn to_float_kind(val: $Float, dst_frac_bits: u32, dst_int_bits: u32) -> FloatKind {
+                to_float_kind_round(val, RoundingMode::NearestTiesEven, dst_frac_bits, dst_int_bits)
+            }
+
+            #[inline]
+            pub fn to_float_kind_round(
+                val: $Float,
+                mode: RoundingMode,
+                dst_frac_bits: u32,
+                dst_int_bits: u32,
+            ) -> FloatKind {
                 let prec = PREC as i32;
 
                 let (neg, exp, mut mantissa) = parts(val);
@@ -131,34 +147,30 @@ macro_rules! make_helper {
 
                 let mut src_frac_bits = prec - 1 - exp;
                 let need_to_shr = src_frac_bits - dst_frac_bits as i32;
+                let mut dir = Ordering::Equal;
                 if need_to_shr > prec {
-                    let dir = if neg {
-                        Ordering::Greater
+                    // all bits are removed, and they are less than one half
+                    if mode.round_up_abs(neg, false, Ordering::Less) {
+                        mantissa = 1;
+                        dir = Ordering::Greater;
                     } else {
-                        Ordering::Less
-                    };
-                    let conv = ToFixedHelper {
-                        bits: Widest::Unsigned(0),
-                        dir,
-                        overflow: false,
-                    };
-                    return FloatKind::Finite { neg, conv };
-                }
-                let mut dir = Ordering::Equal;
-                if need_to_shr > 0 {
+                        mantissa = 0;
+                        dir = Ordering::Less;
+                    }
+                    src_frac_bits = dst_frac_bits as i32;
+                } else if need_to_shr > 0 {
                     let removed_bits = mantissa & !(!0 << need_to_shr);
                     let will_be_lsb = 1 << need_to_shr;
                     let tie = will_be_lsb >> 1;
-                    if removed_bits == 0 {
-                        // removed nothing
-                    } else if removed_bits < tie {
-                        dir = Ordering::Less;
-                    } else if removed_bits > tie || mantissa & will_be_lsb != 0 {
-                        mantissa += will_be_lsb;
-                        dir = Ordering::Greater;
-                    } else {
-                        dir = Ordering::Less;
-                    };
+                    if removed_bits != 0 {
+                        let odd = mantissa & will_be_lsb != 0;
+                        if mode.round_up_abs(neg, odd, removed_bits.cmp(&tie)) {
+                            mantissa += will_be_lsb;
+                            dir = Ordering::Greater;
+                        } else {
+                            dir = Ordering::Less;
+                        }
+                    }
                     mantissa >>= need_to_shr;
                     src_frac_bits -= need_to_shr;
                 }
@@ -176,6 +188,106 @@ macro_rules! make_helper {
                 conv.dir = dir;
                 FloatKind::Finite { neg, conv }
             }
+
+            // Rounds to the nearest value with dst_frac_bits fractional bits,
+            // with ties rounded to even, for conversion to 256 bits.
+            #[inline]
+            pub fn to_kind256(val: $Float, dst_frac_bits: u32) -> Kind256 {
+                let mode = RoundingMode::NearestTiesEven;
+                let (neg, mut exp, mantissa) = parts(val);
+                if exp > EXP_MAX {
+                    if mantissa == 0 {
+                        return Kind256::Infinite { neg };
+                    } else {
+                        return Kind256::NaN;
+                    };
+                }
+                let mut mantissa = u128::from(mantissa);
+                // if not subnormal, add implicit bit
+                if exp >= EXP_MIN {
+                    mantissa |= 1 << (PREC - 1);
+                } else {
+                    exp = EXP_MIN;
+                }
+                let shl = exp + dst_frac_bits as i32 - (PREC as i32 - 1);
+                if shl >= 0 {
+                    let abs = U256 {
+                        lo: mantissa,
+                        hi: 0,
+                    };
+                    let shl = shl as u32;
+                    let overflow = mantissa != 0 && shl > int256::leading_zeros_u256(abs);
+                    let abs = int256::shl_u256(abs, shl);
+                    return Kind256::Finite { neg, abs, overflow };
+                }
+                let shr = (-shl) as u32;
+                let lo = if shr > PREC {
+                    // all bits are removed, and they are less than one half
+                    0
+                } else {
+                    let removed_bits = mantissa & !(!0 << shr);
+                    let tie = 1 << (shr - 1);
+                    let trunc = mantissa >> shr;
+                    let odd = trunc & 1 != 0;
+                    if removed_bits != 0 && mode.round_up_abs(neg, odd, removed_bits.cmp(&tie)) {
+                        trunc + 1
+                    } else {
+                        trunc
+                    }
+                };
+                let abs = U256 { lo, hi: 0 };
+                Kind256::Finite {
+                    neg,
+                    abs,
+                    overflow: false,
+                }
+            }
+
+            // Converts a 256-bit absolute value with src_frac_bits fractional
+            // bits, rounding to the nearest with ties rounded to even.
+            #[inline]
+            pub fn from_u256(neg: bool, abs: U256, src_frac_bits: u32) -> $Float {
+                let mode = RoundingMode::NearestTiesEven;
+                let bits_sign = if neg { SIGN_MASK } else { 0 };
+                let leading_zeros = int256::leading_zeros_u256(abs);
+                if leading_zeros == 256 {
+                    return $Float::from_bits(bits_sign);
+                }
+                let exponent = 255 - leading_zeros as i32 - src_frac_bits as i32;
+                if exponent > EXP_MAX {
+                    return $Float::from_bits(bits_sign | EXP_MASK);
+                }
+                // move the most significant bit to bit 127, keeping a sticky
+                // bit for the discarded bits
+                let normalized = int256::shl_u256(abs, leading_zeros);
+                let mut mantissa = normalized.hi | u128::from(normalized.lo != 0);
+                let bits_exp = if exponent < EXP_MIN {
+                    let lost_prec = (EXP_MIN - exponent) as u32;
+                    mantissa = if lost_prec >= 128 {
+                        1
+                    } else {
+                        let sticky = mantissa & !(!0 << lost_prec) != 0;
+                        (mantissa >> lost_prec) | u128::from(sticky)
+                    };
+                    0
+                } else {
+                    // the implicit one in the mantissa is added to the
+                    // exponent, so it is reduced by one here
+                    ((exponent + EXP_BIAS - 1) as $Bits) << (PREC - 1)
+                };
+                let kept = mantissa >> (128 - PREC);
+                let removed_bits = mantissa << PREC;
+                let tie = 1 << 127;
+                let odd = kept & 1 != 0;
+                let round_up =
+                    removed_bits != 0 && mode.round_up_abs(neg, odd, removed_bits.cmp(&tie));
+                // rounding up can carry into the exponent, possibly up to infinity
+                let mut bits_exp_mantissa = bits_exp + kept as $Bits;
+                if round_up {
+                    bits_exp_mantissa += 1;
+                }
+                $Float::from_bits(bits_sign | bits_exp_mantissa)
+            }
         }
     };
 }
@@ -16,12 +16,12 @@
 use crate::{
     display::Mul10,
     int256::{self, U256},
-    types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
-    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8,
+    types::extra::{LeEqU128, LeEqU16, LeEqU256, LeEqU32, LeEqU64, LeEqU8},
+    FixedI128, FixedI16, FixedI256, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU256,
+    FixedU32, FixedU64, FixedU8, RoundingMode,
 };
 use core::{
-    cmp::Ordering,
+    cmp::{self, Ordering},
     fmt::{Display, Formatter, Result as FmtResult},
     ops::{Add, Mul, Shl, Shr, Sub},
     str::FromStr,
@@ -56,25 +56,17 @@ where
         (acc, overflow)
     }
 
-    fn bin_str_frac_to_bin(bytes: &[u8], nbits: u32) -> Option<Self> {
+    fn bin_str_frac_to_bin(bytes: &[u8], nbits: u32, round: FracRound) -> Option<Self> {
         debug_assert!(!bytes.is_empty());
-        let dump_bits = Self::BITS - nbits;
         let mut rem_bits = nbits;
         let mut acc = Self::from(0);
         for (i, &byte) in bytes.iter().enumerate() {
             let val = byte - b'0';
             if rem_bits < 1 {
-                if val != 0 {
-                    // half bit is true, round up if we have more
-                    // significant bits or currently acc is odd
-                    if bytes.len() > i + 1 || Self::is_odd(acc) {
-                        acc = Self::checked_inc(acc)?;
-                    }
-                }
-                if dump_bits != 0 && acc >> nbits != Self::from(0) {
-                    return None;
-                }
-                return Some(acc);
+                // val is the half bit, and since zeros are trimmed, there are
+                // more significant bits if there are more bytes
+                let rem = discarded_cmp_half(val != 0, bytes.len() > i + 1);
+                return Self::round_frac(acc, nbits, rem, round);
             }
             acc = (acc << 1) + Self::from(val);
             rem_bits -= 1;
@@ -82,6 +74,7 @@ where
         Some(acc << rem_bits)
     }
 
+    #[allow(clippy::manual_div_ceil)]
     fn oct_str_int_to_bin(bytes: &[u8]) -> (Self, bool) {
         let max_len = (Self::BITS as usize + 2) / 3;
         let (bytes, mut overflow) = if bytes.len() > max_len {
@@ -103,9 +96,8 @@ where
         (acc, overflow)
     }
 
-    fn oct_str_frac_to_bin(bytes: &[u8], nbits: u32) -> Option<Self> {
+    fn oct_str_frac_to_bin(bytes: &[u8], nbits: u32, round: FracRound) -> Option<Self> {
         debug_assert!(!bytes.is_empty());
-        let dump_bits = Self::BITS - nbits;
         let mut rem_bits = nbits;
         let mut acc = Self::from(0);
         for (i, &byte) in bytes.iter().enumerate() {
@@ -113,17 +105,9 @@ where
             if rem_bits < 3 {
                 acc = (acc << rem_bits) + Self::from(val >> (3 - rem_bits));
                 let half = 1 << (2 - rem_bits);
-                if val & half != 0 {
-                    // half bit is true, round up if we have more
-                    // significant bits or currently acc is odd
-                    if val & (half - 1) != 0 || bytes.len() > i + 1 || Self::is_odd(acc) {
-                        acc = Self::checked_inc(acc)?;
-                    }
-                }
-                if dump_bits != 0 && acc >> nbits != Self::from(0) {
-                    return None;
-                }
-                return Some(acc);
+                let more = val & (half - 1) != 0 || bytes.len() > i + 1;
+                let rem = discarded_cmp_half(val & half != 0, more);
+                return Self::round_frac(acc, nbits, rem, round);
             }
             acc = (acc << 3) + Self::from(val);
             rem_bits -= 3;
@@ -131,6 +115,7 @@ where
         Some(acc << rem_bits)
     }
 
+    #[allow(clippy::manual_div_ceil)]
     fn hex_str_int_to_bin(bytes: &[u8]) -> (Self, bool) {
         let max_len = (Self::BITS as usize + 3) / 4;
         let (bytes, mut overflow) = if bytes.len() > max_len {
@@ -152,9 +137,8 @@ where
         (acc, overflow)
     }
 
-    fn hex_str_frac_to_bin(bytes: &[u8], nbits: u32) -> Option<Self> {
+    fn hex_str_frac_to_bin(bytes: &[u8], nbits: u32, round: FracRound) -> Option<Self> {
         debug_assert!(!bytes.is_empty());
-        let dump_bits = Self::BITS - nbits;
         let mut rem_bits = nbits;
         let mut acc = Self::from(0);
         for (i, &byte) in bytes.iter().enumerate() {
@@ -162,17 +146,9 @@ where
             if rem_bits < 4 {
                 acc = (acc << rem_bits) + Self::from(val >> (4 - rem_bits));
                 let half = 1 << (3 - rem_bits);
-                if val & half != 0 {
-                    // half bit is true, round up if we have more
-                    // significant bits or currently acc is odd
-                    if val & (half - 1) != 0 || bytes.len() > i + 1 || Self::is_odd(acc) {
-                        acc = Self::checked_inc(acc)?;
-                    }
-                }
-                if dump_bits != 0 && acc >> nbits != Self::from(0) {
-                    return None;
-                }
-                return Some(acc);
+                let more = val & (half - 1) != 0 || bytes.len() > i + 1;
+                let rem = discarded_cmp_half(val & half != 0, more);
+                return Self::round_frac(acc, nbits, rem, round);
             }
             acc = (acc << 4) + Self::from(val);
             rem_bits -= 4;
@@ -197,36 +173,67 @@ where
         (acc, overflow)
     }
 
-    fn dec_str_frac_to_bin(bytes: &[u8], nbits: u32) -> Option<Self> {
+    fn dec_str_frac_to_bin(bytes: &[u8], nbits: u32, round: FracRound) -> Option<Self> {
         let (val, is_short) = Self::parse_is_short(bytes);
+        // if is_short, dec_to_bin can round to the nearest and give correct
+        // answer immediately, except for ties when nbits is 0, since then
+        // whether to round up depends on the integer part
+        if is_short && nbits != 0 && round.mode == RoundingMode::NearestTiesEven {
+            return Self::dec_to_bin(val, nbits, Round::Nearest);
+        }
+        let floor = Self::dec_to_bin(val, nbits, Round::Floor)?;
         let one = Self::from(1);
+        let zero = Self::from(0);
         let dump_bits = Self::BITS - nbits;
-        // if is_short, dec_to_bin can round and give correct answer immediately
-        let round = if is_short {
-            Round::Nearest
-        } else {
-            Round::Floor
-        };
-        let floor = Self::dec_to_bin(val, nbits, round)?;
-        if is_short {
-            return Some(floor);
-        }
-        // since !is_short, we have a floor and we have to check whether we need to increment
 
         // add_5 is to add rounding when all bits are used
-        let (mut boundary, mut add_5) = if nbits == 0 {
-            (Self::from(1) << (Self::BITS - 1), false)
+        let (boundary, add_5) = if nbits == 0 {
+            (one << (Self::BITS - 1), false)
         } else if dump_bits == 0 {
             (floor, true)
         } else {
             ((floor << dump_bits) + (one << (dump_bits - 1)), false)
         };
-        let mut tie = true;
+        let (trunc, rem) = match Self::dec_str_cmp_bin(bytes, boundary, add_5) {
+            Ordering::Less => {
+                // only directed rounding needs to tell exact values apart,
+                // for rounding to the nearest Less rounds down just the same
+                let exact = round.mode.is_directed() && {
+                    let lower = if nbits == 0 { zero } else { floor << dump_bits };
+                    Self::dec_str_cmp_bin(bytes, lower, false) == Ordering::Equal
+                };
+                (floor, if exact { None } else { Some(Ordering::Less) })
+            }
+            Ordering::Equal => (floor, Some(Ordering::Equal)),
+            Ordering::Greater => {
+                // if !is_short, floor was found from the truncated digits and
+                // can be one less than the actual floor
+                let upper = if is_short {
+                    None
+                } else {
+                    Self::checked_inc(floor).filter(|&up| dump_bits == 0 || up >> nbits == zero)
+                };
+                match upper {
+                    Some(up) => match Self::dec_str_cmp_bin(bytes, up << dump_bits, false) {
+                        Ordering::Less => (floor, Some(Ordering::Greater)),
+                        Ordering::Equal => (up, None),
+                        // the truncated digits are too short to be a half away
+                        Ordering::Greater => (up, Some(Ordering::Less)),
+                    },
+                    None => (floor, Some(Ordering::Greater)),
+                }
+            }
+        };
+        Self::round_frac(trunc, nbits, rem, round)
+    }
+
+    // Compares the decimal fraction in bytes with the binary fraction
+    // boundary / 2^BITS, with 1 / 2^(BITS + 1) added if add_5.
+    fn dec_str_cmp_bin(bytes: &[u8], mut boundary: Self, mut add_5: bool) -> Ordering {
         for &byte in bytes {
             if !add_5 && boundary == Self::from(0) {
                 // since zeros are trimmed in bytes, there must be some byte > 0 eventually
-                tie = false;
-                break;
+                return Ordering::Greater;
             }
             let mut boundary_digit = Mul10::mul10_assign(&mut boundary);
             if add_5 {
@@ -237,24 +244,109 @@ where
                 }
                 add_5 = false;
             }
-            if byte - b'0' < boundary_digit {
-                return Some(floor);
-            }
-            if byte - b'0' > boundary_digit {
-                tie = false;
-                break;
+            match (byte - b'0').cmp(&boundary_digit) {
+                Ordering::Equal => {}
+                unequal => return unequal,
             }
         }
-        if tie && !Self::is_odd(floor) {
-            return Some(floor);
+        if !add_5 && boundary == Self::from(0) {
+            Ordering::Equal
+        } else {
+            Ordering::Less
         }
-        let next_up = Self::checked_inc(floor)?;
-        if dump_bits != 0 && next_up >> nbits != Self::from(0) {
+    }
+
+    // Rounds the truncated nbits-bit fraction trunc, where rem compares the
+    // discarded part with one half, and is None if nothing was discarded.
+    // Returns None if rounding up carries into the integer part.
+    fn round_frac(
+        trunc: Self,
+        nbits: u32,
+        rem: Option<Ordering>,
+        round: FracRound,
+    ) -> Option<Self> {
+        let rem = match rem {
+            Some(rem) => rem,
+            None => return Some(trunc),
+        };
+        // with no fractional bits, ties depend on the integer part
+        let odd = if nbits == 0 {
+            round.int_odd
+        } else {
+            Self::is_odd(trunc)
+        };
+        if !round.mode.round_up_abs(round.neg, odd, rem) {
+            return Some(trunc);
+        }
+        let next_up = Self::checked_inc(trunc)?;
+        if nbits != Self::BITS && next_up >> nbits != Self::from(0) {
             None
         } else {
             Some(next_up)
         }
     }
+
+    // Rounds the nbits-bit fraction frac to digits.len() decimal places as
+    // specified by round, and then to the nearest nbits-bit fraction. The
+    // decimal digits are written to digits. Returns None if rounding carries
+    // into the integer part.
+    fn round_frac_dec(frac: Self, nbits: u32, digits: &mut [u8], round: FracRound) -> Option<Self> {
+        debug_assert!(nbits > 0 && nbits <= Self::BITS);
+        let mut rest = frac << (Self::BITS - nbits);
+        for digit in digits.iter_mut() {
+            *digit = b'0' + Mul10::mul10_assign(&mut rest);
+        }
+        if rest != Self::from(0) {
+            let odd = match digits.last() {
+                Some(&digit) => (digit - b'0') & 1 != 0,
+                None => round.int_odd,
+            };
+            let half = Self::from(1) << (Self::BITS - 1);
+            if round.mode.round_up_abs(round.neg, odd, rest.cmp(&half)) {
+                let mut carry = true;
+                for digit in digits.iter_mut().rev() {
+                    if *digit == b'9' {
+                        *digit = b'0';
+                    } else {
+                        *digit += 1;
+                        carry = false;
+                        break;
+                    }
+                }
+                if carry {
+                    return None;
+                }
+            }
+        }
+        // dec_str_frac_to_bin expects trailing zeros to be trimmed
+        let len = digits
+            .iter()
+            .rposition(|&digit| digit != b'0')
+            .map_or(0, |i| i + 1);
+        if len == 0 {
+            return Some(Self::from(0));
+        }
+        // There can be no ties, as a decimal fraction with fewer than nbits
+        // digits cannot lie halfway between two nbits-bit fractions.
+        let nearest = FracRound {
+            neg: false,
+            mode: RoundingMode::NearestTiesEven,
+            int_odd: false,
+        };
+        Self::dec_str_frac_to_bin(&digits[..len], nbits, nearest)
+    }
+}
+
+// Compares the discarded bits with one half, given whether the most
+// significant discarded bit is set and whether any other discarded bit is set.
+// Returns None if no set bits were discarded.
+fn discarded_cmp_half(half: bool, more: bool) -> Option<Ordering> {
+    match (half, more) {
+        (false, false) => None,
+        (false, true) => Some(Ordering::Less),
+        (true, false) => Some(Ordering::Equal),
+        (true, true) => Some(Ordering::Greater),
+    }
 }
 
 fn unchecked_hex_digit(byte: u8) -> u8 {
@@ -270,6 +362,16 @@ enum Round {
     Floor,
 }
 
+// How to round the fractional part: the rounding mode is applied to the
+// absolute value, and int_odd is needed to break ties when there are no
+// fractional bits.
+#[derive(Clone, Copy, Debug)]
+struct FracRound {
+    neg: bool,
+    mode: RoundingMode,
+    int_odd: bool,
+}
+
 // Decode fractional decimal digits into nbits fractional bits.
 //
 // For an output with BIN = 8 bits, we can take DEC = 3 decimal digits.
@@ -624,18 +726,35 @@ fn parse_bounds(bytes: &[u8], radix: u32) -> Result<Parse<'_>, ParseFixedError>
     Ok(Parse { neg, int, frac })
 }
 
-fn frac_is_half(bytes: &[u8], radix: u32) -> bool {
-    // since zeros are trimmed, there must be exatly one byte
-    bytes.len() == 1 && bytes[0] - b'0' == (radix as u8) / 2
-}
-
 // Only radices 2, 8, 10 and 16 are supported.
 pub(crate) trait FromStrRadix: Sized {
     type Err;
-    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::Err>;
-    fn saturating_from_str_radix(s: &str, radix: u32) -> Result<Self, Self::Err>;
-    fn wrapping_from_str_radix(s: &str, radix: u32) -> Result<Self, Self::Err>;
-    fn overflowing_from_str_radix(s: &str, radix: u32) -> Result<(Self, bool), Self::Err>;
+    fn from_str_radix(s: &str, radix: u32, mode: RoundingMode) -> Result<Self, Self::Err>;
+    fn saturating_from_str_radix(
+        s: &str,
+        radix: u32,
+        mode: RoundingMode,
+    ) -> Result<Self, Self::Err>;
+    fn wrapping_from_str_radix(s: &str, radix: u32, mode: RoundingMode) -> Result<Self, Self::Err>;
+    fn overflowing_from_str_radix(
+        s: &str,
+        radix: u32,
+        mode: RoundingMode,
+    ) -> Result<(Self, bool), Self::Err>;
+}
+
+pub(crate) trait RoundDp: Sized {
+    // Rounds the absolute value abs with frac_nbits fractional bits to dp
+    // decimal places as specified by mode, and then to the nearest
+    // representable value. Returns the wrapped value and whether overflow
+    // occurred.
+    fn round_dp_abs(
+        abs: Self,
+        frac_nbits: u32,
+        dp: u32,
+        neg: bool,
+        mode: RoundingMode,
+    ) -> (Self, bool);
 }
 
 macro_rules! impl_from_str_traits {
@@ -647,22 +766,26 @@ macro_rules! impl_from_str_traits {
             /// Rounding is to the nearest, with ties rounded to even.
             #[inline]
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                Self::from_str_radix(s, 10)
+                Self::from_str_radix(s, 10, RoundingMode::NearestTiesEven)
             }
         }
         impl<Frac: $LeEqU> FromStrRadix for $Fixed<Frac> {
             type Err = ParseFixedError;
             #[inline]
-            fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::Err> {
-                match Self::overflowing_from_str_radix(s, radix) {
+            fn from_str_radix(s: &str, radix: u32, mode: RoundingMode) -> Result<Self, Self::Err> {
+                match Self::overflowing_from_str_radix(s, radix, mode) {
                     Ok((val, false)) => Ok(val),
                     Ok((_, true)) => Err(ParseErrorKind::Overflow.into()),
                     Err(e) => Err(e),
                 }
             }
             #[inline]
-            fn saturating_from_str_radix(s: &str, radix: u32) -> Result<Self, Self::Err> {
-                match Self::overflowing_from_str_radix(s, radix) {
+            fn saturating_from_str_radix(
+                s: &str,
+                radix: u32,
+                mode: RoundingMode,
+            ) -> Result<Self, Self::Err> {
+                match Self::overflowing_from_str_radix(s, radix, mode) {
                     Ok((val, false)) => Ok(val),
                     Ok((_, true)) => {
                         if s.starts_with('-') {
@@ -675,12 +798,20 @@ macro_rules! impl_from_str_traits {
                 }
             }
             #[inline]
-            fn wrapping_from_str_radix(s: &str, radix: u32) -> Result<Self, Self::Err> {
-                Self::overflowing_from_str_radix(s, radix).map(|(val, _)| val)
+            fn wrapping_from_str_radix(
+                s: &str,
+                radix: u32,
+                mode: RoundingMode,
+            ) -> Result<Self, Self::Err> {
+                Self::overflowing_from_str_radix(s, radix, mode).map(|(val, _)| val)
             }
             #[inline]
-            fn overflowing_from_str_radix(s: &str, radix: u32) -> Result<(Self, bool), Self::Err> {
-                $from(s.as_bytes(), radix, Self::INT_NBITS, Self::FRAC_NBITS)
+            fn overflowing_from_str_radix(
+                s: &str,
+                radix: u32,
+                mode: RoundingMode,
+            ) -> Result<(Self, bool), Self::Err> {
+                $from(s.as_bytes(), radix, Self::INT_NBITS, Self::FRAC_NBITS, mode)
                     .map(|(bits, overflow)| (Self::from_bits(bits), overflow))
             }
         }
@@ -715,13 +846,49 @@ macro_rules! impl_from_str {
             }
         }
 
+        impl RoundDp for $BitsU {
+            fn round_dp_abs(
+                abs: $BitsU,
+                frac_nbits: u32,
+                dp: u32,
+                neg: bool,
+                mode: RoundingMode,
+            ) -> ($BitsU, bool) {
+                // every value with frac_nbits fractional bits is exact with
+                // frac_nbits decimal places
+                if dp >= frac_nbits {
+                    return (abs, false);
+                }
+                let (int, frac) = if frac_nbits == $BitsU::BITS {
+                    (0, abs)
+                } else {
+                    (abs >> frac_nbits << frac_nbits, abs & !(!0 << frac_nbits))
+                };
+                let round = FracRound {
+                    neg,
+                    mode,
+                    int_odd: frac_nbits < $BitsU::BITS && (abs >> frac_nbits) & 1 != 0,
+                };
+                let mut buf = [0u8; $BitsU::BITS as usize];
+                let digits = &mut buf[..dp as usize];
+                match ParseHelper::round_frac_dec(frac, frac_nbits, digits, round) {
+                    Some(frac) => (int | frac, false),
+                    // rounded up to 1, which wraps to 0
+                    None if frac_nbits == $BitsU::BITS => (0, true),
+                    None => int.overflowing_add(1 << frac_nbits),
+                }
+            }
+        }
+
         fn $from_i(
             bytes: &[u8],
             radix: u32,
             int_nbits: u32,
             frac_nbits: u32,
+            mode: RoundingMode,
         ) -> Result<($BitsI, bool), ParseFixedError> {
-            let (neg, abs, mut overflow) = $get_int_frac(bytes, radix, int_nbits, frac_nbits)?;
+            let (neg, abs, mut overflow) =
+                $get_int_frac(bytes, radix, int_nbits, frac_nbits, mode)?;
             let bound = if !neg { $BitsI::MAX } else { $BitsI::MIN };
             if abs > bound.unsigned_abs() {
                 overflow = true;
@@ -735,8 +902,10 @@ macro_rules! impl_from_str {
             radix: u32,
             int_nbits: u32,
             frac_nbits: u32,
+            mode: RoundingMode,
         ) -> Result<($BitsU, bool), ParseFixedError> {
-            let (neg, abs, mut overflow) = $get_int_frac(bytes, radix, int_nbits, frac_nbits)?;
+            let (neg, abs, mut overflow) =
+                $get_int_frac(bytes, radix, int_nbits, frac_nbits, mode)?;
             if neg && abs > 0 {
                 overflow = true;
             }
@@ -749,23 +918,21 @@ macro_rules! impl_from_str {
             radix: u32,
             int_nbits: u32,
             frac_nbits: u32,
+            mode: RoundingMode,
         ) -> Result<(bool, $BitsU, bool), ParseFixedError> {
             let Parse { neg, int, frac } = parse_bounds(bytes, radix)?;
             let (int_val, mut overflow) = $get_int(int, radix, int_nbits);
-            let (frac_val, frac_overflow) = match $get_frac(frac, radix, frac_nbits) {
+            let round = FracRound {
+                neg,
+                mode,
+                int_odd: ParseHelper::is_odd(int_val),
+            };
+            let (frac_val, frac_overflow) = match $get_frac(frac, radix, frac_nbits, round) {
                 Some(val) => (val, false),
                 None => (0, true),
             };
             let mut val = int_val | frac_val;
-            // frac_overflow does not catch the case where:
-            //  1. int is odd
-            //  2. frac_nbits is 0
-            //  3. frac_bytes is exactly half, e.g. "5" for decimal
-            // In this case, get_frac returns 0.5 rounded to even 0.0,
-            // as it does not have a way to know that int is odd.
-            if frac_overflow
-                || (ParseHelper::is_odd(int_val) && frac_nbits == 0 && frac_is_half(frac, radix))
-            {
+            if frac_overflow {
                 let (new_val, new_overflow) = if int_nbits == 0 {
                     (val, true)
                 } else {
@@ -809,18 +976,18 @@ macro_rules! impl_from_str {
             (parsed_int, overflow)
         }
 
-        fn $get_frac(frac: &[u8], radix: u32, nbits: u32) -> Option<$BitsU> {
+        fn $get_frac(frac: &[u8], radix: u32, nbits: u32, round: FracRound) -> Option<$BitsU> {
             if $attempt_frac_half && nbits <= $BitsU::BITS / 2 {
-                return $get_frac_half(frac, radix, nbits).map($BitsU::from);
+                return $get_frac_half(frac, radix, nbits, round).map($BitsU::from);
             }
             if frac.is_empty() {
                 return Some(0);
             }
             match radix {
-                2 => ParseHelper::bin_str_frac_to_bin(frac, nbits),
-                8 => ParseHelper::oct_str_frac_to_bin(frac, nbits),
-                16 => ParseHelper::hex_str_frac_to_bin(frac, nbits),
-                10 => ParseHelper::dec_str_frac_to_bin(frac, nbits),
+                2 => ParseHelper::bin_str_frac_to_bin(frac, nbits, round),
+                8 => ParseHelper::oct_str_frac_to_bin(frac, nbits, round),
+                16 => ParseHelper::hex_str_frac_to_bin(frac, nbits, round),
+                10 => ParseHelper::dec_str_frac_to_bin(frac, nbits, round),
                 _ => unreachable!(),
             }
         }
@@ -868,6 +1035,111 @@ impl_from_str! {
     fn get_frac128, (get_frac64, true);
 }
 
+// Parses a decimal string to a 256-bit absolute value with frac_nbits
+// fractional bits, rounding to the nearest with ties rounded to even.
+//
+// The conversion of the fractional digits is exact: the digits are doubled
+// repeatedly and each carry is the next binary digit. Only the first
+// frac_nbits + 1 digits are kept, since every binary fraction with
+// frac_nbits + 1 bits has at most that many decimal digits; the discarded
+// digits are non-zero as trailing zeros are trimmed, so they can only break
+// ties.
+fn get_int_frac256(bytes: &[u8], frac_nbits: u32) -> Result<(bool, U256, bool), ParseFixedError> {
+    let Parse { neg, int, frac } = parse_bounds(bytes, 10)?;
+
+    let mut int_val = U256::ZERO;
+    let mut overflow = false;
+    for &byte in int {
+        if Mul10::mul10_assign(&mut int_val) != 0 {
+            overflow = true;
+        }
+        let (sum, carry) = int256::overflowing_add_u256(int_val, U256::from(byte - b'0'));
+        int_val = sum;
+        overflow |= carry;
+    }
+    if int_val != U256::ZERO && int256::leading_zeros_u256(int_val) < frac_nbits {
+        overflow = true;
+    }
+
+    let mut buf = [0u8; 257];
+    let kept = cmp::min(frac.len(), frac_nbits as usize + 1);
+    let digits = &mut buf[..kept];
+    for (digit, &byte) in digits.iter_mut().zip(frac) {
+        *digit = byte - b'0';
+    }
+    let mut double = || {
+        let mut carry = 0;
+        for digit in digits.iter_mut().rev() {
+            let twice = *digit * 2 + carry;
+            carry = twice / 10;
+            *digit = twice % 10;
+        }
+        carry
+    };
+    let mut frac_val = U256::ZERO;
+    for _ in 0..frac_nbits {
+        frac_val = int256::shl_u256(frac_val, 1);
+        frac_val.lo |= u128::from(double());
+    }
+    let half = double() != 0;
+    let more = frac.len() > kept || buf[..kept].iter().any(|&d| d != 0);
+
+    let mut val = int256::or_u256(int256::shl_u256(int_val, frac_nbits), frac_val);
+    if half && (more || val.lo & 1 != 0) {
+        let (sum, carry) = int256::overflowing_add_u256(val, U256::from(1u8));
+        val = sum;
+        overflow |= carry;
+    }
+    Ok((neg, val, overflow))
+}
+
+macro_rules! impl_from_str_256 {
+    ($Fixed:ident, $Signedness:tt) => {
+        impl<Frac: LeEqU256> FromStr for $Fixed<Frac> {
+            type Err = ParseFixedError;
+            /// Parses a string slice to return a fixed-point number.
+            ///
+            /// Rounding is to the nearest, with ties rounded to even.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let (neg, abs, mut overflow) = get_int_frac256(s.as_bytes(), Frac::U32)?;
+                let bits = if neg {
+                    int256::wrapping_neg_u256(abs)
+                } else {
+                    abs
+                };
+                if_signed_unsigned!(
+                    $Signedness,
+                    {
+                        let bound = U256 {
+                            lo: 0,
+                            hi: 1 << 127,
+                        };
+                        if abs > bound || (!neg && abs == bound) {
+                            overflow = true;
+                        }
+                    },
+                    {
+                        if neg && abs != U256::ZERO {
+                            overflow = true;
+                        }
+                    },
+                );
+                if overflow {
+                    return Err(ParseErrorKind::Overflow.into());
+                }
+                Ok(Self::from_bits(if_signed_unsigned!(
+                    $Signedness,
+                    int256::u256_wrapping_as_i256(bits),
+                    bits,
+                )))
+            }
+        }
+    };
+}
+
+impl_from_str_256! { FixedI256, Signed }
+impl_from_str_256! { FixedU256, Unsigned }
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -879,6 +1151,7 @@ mod tests {
         fmt::Debug,
         format,
         string::{String, ToString},
+        vec::Vec,
     };
 
     #[test]
@@ -1127,7 +1400,7 @@ mod tests {
         F: Fixed + FromStrRadix<Err = ParseFixedError>,
         F::Bits: Eq + Debug,
     {
-        match F::overflowing_from_str_radix(s, radix) {
+        match F::overflowing_from_str_radix(s, radix, RoundingMode::NearestTiesEven) {
             Ok((f, o)) => {
                 assert_eq!(f.to_bits(), bits, "{} -> ({}, {})", s, f, o);
                 assert_eq!(o, overflow, "{} -> ({}, {})", s, f, o);
@@ -1988,6 +2261,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn frac_below_tie() {
+        // 0.0937 is just below the tie 0.09375 = 1.5/16, so it rounds down
+        assert_eq!(U4F4::from_str("0.0937"), Ok(U4F4::from_bits(1)));
+        assert_eq!(I4F4::from_str("0.0937"), Ok(I4F4::from_bits(1)));
+        assert_eq!(I4F4::from_str("-0.0937"), Ok(I4F4::from_bits(-1)));
+        // the tie itself rounds to even, and anything above it rounds up
+        assert_eq!(U4F4::from_str("0.09375"), Ok(U4F4::from_bits(2)));
+        assert_eq!(U4F4::from_str("0.093751"), Ok(U4F4::from_bits(2)));
+    }
+
     fn similar<F: Fixed, G: ToFixed>(a: F, b: F, max_diff: G) -> bool {
         let abs_diff = if a > b { a - b } else { b - a };
         abs_diff <= max_diff.to_fixed::<F>()
@@ -2040,4 +2324,144 @@ mod tests {
             assert_eq!(I15F17::from_str(&fix_neg_str9).unwrap(), fix_neg);
         }
     }
+
+    // exact ±val × 2^frac_nbits / den, rounded as specified by mode
+    fn round_ref(neg: bool, val: u128, den: u128, frac_nbits: u32, mode: RoundingMode) -> i128 {
+        let num = val << frac_nbits;
+        let (quot, rem) = (num / den, num % den);
+        let up = rem != 0
+            && match mode {
+                RoundingMode::Floor => neg,
+                RoundingMode::Ceil => !neg,
+                RoundingMode::TowardZero => false,
+                RoundingMode::AwayFromZero => true,
+                RoundingMode::NearestTiesEven => 2 * rem > den || (2 * rem == den && quot % 2 == 1),
+                RoundingMode::NearestTiesAway => 2 * rem >= den,
+            };
+        let abs = (quot + u128::from(up)) as i128;
+        if neg {
+            -abs
+        } else {
+            abs
+        }
+    }
+
+    fn assert_round_8<F>(radix: u32, int: u128, frac: &str)
+    where
+        F: Fixed + FromStrRadix<Err = ParseFixedError>,
+        F::Bits: Into<i128>,
+    {
+        let den = u128::from(radix).pow(frac.len() as u32);
+        let frac_val = u128::from_str_radix(frac, radix).unwrap();
+        let val = int * den + frac_val;
+        let (min, max) = (F::MIN.to_bits().into(), F::MAX.to_bits().into());
+        for &neg in &[false, true] {
+            let s = match radix {
+                2 => format!("{}{:b}.{}", if neg { "-" } else { "" }, int, frac),
+                _ => format!("{}{}.{}", if neg { "-" } else { "" }, int, frac),
+            };
+            for &mode in &MODES {
+                let exact = round_ref(neg, val, den, F::FRAC_NBITS, mode);
+                let mut wrapped = exact.rem_euclid(256);
+                if min < 0 && wrapped > max {
+                    wrapped -= 256;
+                }
+                let overflow = exact < min || exact > max;
+                let (f, o) = F::overflowing_from_str_radix(&s, radix, mode).unwrap();
+                assert_eq!(
+                    (f.to_bits().into(), o),
+                    (wrapped, overflow),
+                    "{} {:?}",
+                    s,
+                    mode
+                );
+            }
+        }
+    }
+
+    const MODES: [RoundingMode; 6] = [
+        RoundingMode::Floor,
+        RoundingMode::Ceil,
+        RoundingMode::TowardZero,
+        RoundingMode::AwayFromZero,
+        RoundingMode::NearestTiesEven,
+        RoundingMode::NearestTiesAway,
+    ];
+
+    #[test]
+    fn check_round_modes() {
+        let mut dec_fracs = Vec::<String>::new();
+        for nbits in 0..=8u32 {
+            for m in 0..(1u128 << nbits) {
+                // m / 2^nbits and (m + 0.5) / 2^nbits are exact in decimal
+                dec_fracs.push(format!("{:01$}", m * 5u128.pow(nbits), nbits as usize));
+                let tie = format!(
+                    "{:01$}",
+                    (2 * m + 1) * 5u128.pow(nbits + 1),
+                    nbits as usize + 1
+                );
+                // prefixes of ties are just below ties
+                for len in 1..tie.len() {
+                    dec_fracs.push(String::from(&tie[..len]));
+                }
+                dec_fracs.push(tie);
+            }
+        }
+        for i in 0..1000 {
+            dec_fracs.push(format!("{:03}", i));
+        }
+        dec_fracs.retain(|f| !f.is_empty());
+        for &int in &[0, 1, 2, 127, 128, 255, 300] {
+            for frac in &dec_fracs {
+                assert_round_8::<I8F0>(10, int, frac);
+                assert_round_8::<I4F4>(10, int, frac);
+                assert_round_8::<I0F8>(10, int, frac);
+                assert_round_8::<U8F0>(10, int, frac);
+                assert_round_8::<U4F4>(10, int, frac);
+                assert_round_8::<U0F8>(10, int, frac);
+            }
+            for frac in 0..1024 {
+                let frac = format!("{:010b}", frac);
+                assert_round_8::<I8F0>(2, int, &frac);
+                assert_round_8::<I4F4>(2, int, &frac);
+                assert_round_8::<I0F8>(2, int, &frac);
+                assert_round_8::<U8F0>(2, int, &frac);
+                assert_round_8::<U4F4>(2, int, &frac);
+                assert_round_8::<U0F8>(2, int, &frac);
+            }
+        }
+
+        // 0.5058 is just below the tie 0.505859375 between 129/256 and 130/256
+        assert_eq!(U0F8::from_str("0.5058"), Ok(U0F8::from_bits(129)));
+        assert_eq!(
+            U0F8::from_str_round("0.5058", RoundingMode::Ceil),
+            Ok(U0F8::from_bits(130))
+        );
+        assert_eq!(
+            I16F16::from_str_round("-0.00001", RoundingMode::Floor),
+            Ok(-I16F16::DELTA)
+        );
+        assert_eq!(
+            I16F16::saturating_from_str_round("-32768.00001", RoundingMode::Floor),
+            Ok(I16F16::MIN)
+        );
+        assert_eq!(
+            U16F16::from_str_hex_round("0.00008", RoundingMode::Ceil),
+            Ok(U16F16::DELTA)
+        );
+        assert_eq!(
+            U16F16::from_str_octal_round("0.000001", RoundingMode::Floor),
+            Ok(U16F16::ZERO)
+        );
+        // more than 54 digits, so the long path is used for 128 bits
+        let long = "0.0000000000000000000000000000000000000000000000000000000001";
+        assert_eq!(
+            U0F128::from_str_round(long, RoundingMode::Ceil),
+            Ok(U0F128::DELTA)
+        );
+        assert_eq!(
+            I0F128::from_str_round(&format!("-{}", long), RoundingMode::TowardZero),
+            Ok(I0F128::ZERO)
+        );
+    }
 }
@@ -14,10 +14,11 @@
 // <https://opensource.org/licenses/MIT>.
 
 use crate::{
+    int256::{self, U256},
     int_helper,
     types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8,
+    FixedU8, RoundingMode,
 };
 use core::cmp::Ordering;
 
@@ -34,6 +35,117 @@ pub struct ToFixedHelper {
     pub(crate) overflow: bool,
 }
 
+impl ToFixedHelper {
+    // Rounds a result that was truncated towards −∞ with some nonzero bits
+    // discarded, in which case bits holds the exact floor. The source is
+    // negative if neg is true, and rem compares the discarded bits with one
+    // half.
+    #[inline]
+    pub(crate) fn round_floor(
+        &mut self,
+        mode: RoundingMode,
+        neg: bool,
+        rem: Ordering,
+        dst_bits: u32,
+    ) {
+        let floor_odd = match self.bits {
+            Widest::Unsigned(bits) => bits & 1 != 0,
+            Widest::Negative(bits) => bits & 1 != 0,
+        };
+        // For negative numbers, the floor has an absolute value one more than
+        // the truncated absolute value, and the discarded part of the absolute
+        // value is the complement of rem.
+        let inc = if neg {
+            !mode.round_up_abs(true, !floor_odd, rem.reverse())
+        } else {
+            mode.round_up_abs(false, floor_odd, rem)
+        };
+        if inc {
+            self.inc_floor(dst_bits);
+        }
+    }
+
+    // Increments a result that was truncated towards −∞ with some nonzero
+    // bits discarded, in which case bits holds the exact floor.
+    #[inline]
+    pub(crate) fn inc_floor(&mut self, dst_bits: u32) {
+        self.dir = Ordering::Greater;
+        self.bits = match self.bits {
+            Widest::Unsigned(bits) => Widest::Unsigned(bits + 1),
+            Widest::Negative(-1) => Widest::Unsigned(0),
+            Widest::Negative(bits) => Widest::Negative(bits + 1),
+        };
+        self.overflow = match self.bits {
+            Widest::Unsigned(bits) => 128 - bits.leading_zeros() > dst_bits,
+            Widest::Negative(bits) => 129 - (!bits).leading_zeros() > dst_bits,
+        };
+    }
+
+    // Multiplies two absolute values exactly, and truncates the product
+    // towards −∞. The product has src_frac_nbits fractional bits and is
+    // negative if neg is true.
+    pub(crate) fn from_mul_floor(
+        neg: bool,
+        lhs_abs: u128,
+        rhs_abs: u128,
+        src_frac_nbits: u32,
+        dst_frac_nbits: u32,
+        dst_int_nbits: u32,
+    ) -> ToFixedHelper {
+        let prod = int256::wide_mul_u128(lhs_abs, rhs_abs);
+        ToFixedHelper::from_u256_floor(neg, prod, src_frac_nbits, dst_frac_nbits, dst_int_nbits)
+    }
+
+    // Converts a 256-bit absolute value with src_frac_nbits fractional bits
+    // and truncates it towards −∞. The value is negative if neg is true.
+    pub(crate) fn from_u256_floor(
+        neg: bool,
+        src_abs: U256,
+        src_frac_nbits: u32,
+        dst_frac_nbits: u32,
+        dst_int_nbits: u32,
+    ) -> ToFixedHelper {
+        let (mut abs, inexact, mut overflow) = if src_frac_nbits >= dst_frac_nbits {
+            let shift = src_frac_nbits - dst_frac_nbits;
+            let abs = int256::shr_u256(src_abs, shift);
+            let back = int256::shl_u256(abs, shift);
+            (abs, back != src_abs, false)
+        } else {
+            let shift = dst_frac_nbits - src_frac_nbits;
+            let overflow = src_abs != U256::ZERO && shift > int256::leading_zeros_u256(src_abs);
+            (int256::shl_u256(src_abs, shift), false, overflow)
+        };
+        // for negative numbers, the floor has an absolute value one more
+        // than the truncated absolute value
+        if neg && inexact {
+            abs = int256::wrapping_add_u256_u128(abs, 1);
+        }
+        let wrapped = if neg { abs.lo.wrapping_neg() } else { abs.lo };
+        let bits = if neg && (wrapped as i128) < 0 {
+            Widest::Negative(wrapped as i128)
+        } else {
+            Widest::Unsigned(wrapped)
+        };
+        let req_nbits = match bits {
+            Widest::Unsigned(bits) => 128 - bits.leading_zeros(),
+            Widest::Negative(bits) => 129 - (!bits).leading_zeros(),
+        };
+        overflow = overflow
+            || abs.hi != 0
+            || (neg && abs.lo > 1 << 127)
+            || req_nbits > dst_frac_nbits + dst_int_nbits;
+        ToFixedHelper {
+            bits,
+            dir: if inexact {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            },
+            overflow,
+        }
+    }
+}
+
 pub struct ToFloatHelper {
     pub(crate) neg: bool,
     pub(crate) abs: u128,
@@ -48,11 +160,52 @@ pub enum FloatKind {
     Finite { neg: bool, conv: ToFixedHelper },
 }
 
+// The rounded value for conversion to a 256-bit fixed-point number. For
+// finite values, abs is the absolute value wrapped to 256 bits, and overflow
+// is set if the absolute value does not fit in 256 bits.
+pub enum Kind256 {
+    NaN,
+    Infinite {
+        neg: bool,
+    },
+    Finite {
+        neg: bool,
+        abs: U256,
+        overflow: bool,
+    },
+}
+
+// Conversions from and to 256-bit fixed-point numbers, which are represented
+// by their raw two’s-complement bits and their number of fractional bits.
+pub trait Sealed256: Copy {
+    fn private_to_kind256(self, dst_frac_nbits: u32) -> Kind256;
+    fn private_overflowing_from_raw256(
+        raw: U256,
+        src_frac_nbits: u32,
+        src_signed: bool,
+    ) -> (Self, bool);
+    fn private_saturating_from_raw256(raw: U256, src_frac_nbits: u32, src_signed: bool) -> Self;
+}
+
 pub trait Sealed: Copy {
     fn private_to_fixed_helper(self, dst_frac_nbits: u32, dst_int_nbits: u32) -> ToFixedHelper;
+    fn private_to_fixed_helper_round(
+        self,
+        mode: RoundingMode,
+        dst_frac_nbits: u32,
+        dst_int_nbits: u32,
+    ) -> ToFixedHelper;
+    fn private_to_fixed_helper_stochastic(
+        self,
+        random: u128,
+        dst_frac_nbits: u32,
+        dst_int_nbits: u32,
+    ) -> ToFixedHelper;
     fn private_to_float_helper(self) -> ToFloatHelper;
     fn private_saturating_from_float_helper(src: FromFloatHelper) -> Self;
     fn private_overflowing_from_float_helper(src: FromFloatHelper) -> (Self, bool);
+    fn private_saturating_from_helper(neg: bool, conv: ToFixedHelper) -> Self;
+    fn private_overflowing_from_helper(conv: ToFixedHelper) -> (Self, bool);
 }
 macro_rules! impl_sealed {
     ($Fixed:ident($LeEqU:ident, $Signedness:tt, $Inner:ident)) => {
@@ -71,6 +224,36 @@ macro_rules! impl_sealed {
                 )
             }
             #[inline]
+            fn private_to_fixed_helper_round(
+                self,
+                mode: RoundingMode,
+                dst_frac_nbits: u32,
+                dst_int_nbits: u32,
+            ) -> ToFixedHelper {
+                int_helper::$Inner::to_fixed_helper_round(
+                    self.to_bits(),
+                    Self::FRAC_NBITS as i32,
+                    dst_frac_nbits,
+                    dst_int_nbits,
+                    mode,
+                )
+            }
+            #[inline]
+            fn private_to_fixed_helper_stochastic(
+                self,
+                random: u128,
+                dst_frac_nbits: u32,
+                dst_int_nbits: u32,
+            ) -> ToFixedHelper {
+                int_helper::$Inner::to_fixed_helper_stochastic(
+                    self.to_bits(),
+                    Self::FRAC_NBITS as i32,
+                    dst_frac_nbits,
+                    dst_int_nbits,
+                    random,
+                )
+            }
+            #[inline]
             fn private_to_float_helper(self) -> ToFloatHelper {
                 let (neg, abs) = int_helper::$Inner::neg_abs(self.to_bits());
                 let abs = abs.into();
@@ -78,18 +261,33 @@ macro_rules! impl_sealed {
             }
             #[inline]
             fn private_saturating_from_float_helper(src: FromFloatHelper) -> Self {
-                let neg = match src.kind {
+                match src.kind {
                     FloatKind::NaN => panic!("NaN"),
-                    FloatKind::Infinite { neg } => neg,
-                    FloatKind::Finite { neg, .. } => neg,
-                };
-                let saturated = if neg { Self::MIN } else { Self::MAX };
-                let conv = match src.kind {
-                    FloatKind::Finite { conv, .. } => conv,
-                    _ => return saturated,
-                };
+                    FloatKind::Infinite { neg } => {
+                        if neg {
+                            Self::MIN
+                        } else {
+                            Self::MAX
+                        }
+                    }
+                    FloatKind::Finite { neg, conv } => {
+                        Self::private_saturating_from_helper(neg, conv)
+                    }
+                }
+            }
+            #[inline]
+            #[track_caller]
+            fn private_overflowing_from_float_helper(src: FromFloatHelper) -> (Self, bool) {
+                match src.kind {
+                    FloatKind::NaN => panic!("NaN"),
+                    FloatKind::Infinite { .. } => panic!("infinite"),
+                    FloatKind::Finite { conv, .. } => Self::private_overflowing_from_helper(conv),
+                }
+            }
+            #[inline]
+            fn private_saturating_from_helper(neg: bool, conv: ToFixedHelper) -> Self {
                 if conv.overflow {
-                    return saturated;
+                    return if neg { Self::MIN } else { Self::MAX };
                 }
                 let bits = if_signed_unsigned!(
                     $Signedness,
@@ -111,13 +309,7 @@ macro_rules! impl_sealed {
                 Self::from_bits(bits)
             }
             #[inline]
-            #[track_caller]
-            fn private_overflowing_from_float_helper(src: FromFloatHelper) -> (Self, bool) {
-                let conv = match src.kind {
-                    FloatKind::NaN => panic!("NaN"),
-                    FloatKind::Infinite { .. } => panic!("infinite"),
-                    FloatKind::Finite { conv, .. } => conv,
-                };
+            fn private_overflowing_from_helper(conv: ToFixedHelper) -> (Self, bool) {
                 let mut new_overflow = false;
                 let bits = if_signed_unsigned!(
                     $Signedness,
@@ -0,0 +1,178 @@
+// Copyright © 2018–2021 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    int256::{self, U256},
+    sqrt,
+};
+
+// For fixed-point numbers a = a_val / 2↑f and b = b_val / 2↑f,
+//
+//     √(a² + b²) = √(a_val² + b_val²) / 2↑f,
+//
+// so the bits of the result are the integer square root of a_val² + b_val²,
+// rounded to the nearest, and the number of fractional bits does not matter.
+// The sum of squares is computed in double width, so it never overflows. As
+// for the square root, there are no ties.
+
+macro_rules! make_hypot {
+    ($i:ident, $u:ident, $uu:ident, $isqrt_rem:path) => {
+        pub fn $u(a: $u, b: $u) -> ($u, bool) {
+            let sum = <$uu>::from(a) * <$uu>::from(a) + <$uu>::from(b) * <$uu>::from(b);
+            let (root, rem) = $isqrt_rem(sum);
+            let rounded = if rem > root { root + 1 } else { root };
+            (rounded as $u, rounded > <$uu>::from(<$u>::MAX))
+        }
+
+        pub fn $i(a: $i, b: $i) -> ($i, bool) {
+            let (uns, overflow) = $u(a.unsigned_abs(), b.unsigned_abs());
+            let ans = uns as $i;
+            (ans, overflow || ans < 0)
+        }
+    };
+}
+
+make_hypot! { i8, u8, u32, sqrt::isqrt_rem_u32 }
+make_hypot! { i16, u16, u64, sqrt::isqrt_rem_u64 }
+make_hypot! { i32, u32, u128, sqrt::isqrt_rem_u128 }
+
+pub fn u64(a: u64, b: u64) -> (u64, bool) {
+    let a2 = u128::from(a) * u128::from(a);
+    let b2 = u128::from(b) * u128::from(b);
+    let (lo, carry) = a2.overflowing_add(b2);
+    let sum = U256 {
+        lo,
+        hi: u128::from(carry),
+    };
+    let (root, rem) = sqrt::isqrt_rem_u256(sum);
+    let rounded = if rem.hi != 0 || rem.lo > root {
+        root + 1
+    } else {
+        root
+    };
+    (rounded as u64, rounded > u128::from(u64::MAX))
+}
+
+pub fn i64(a: i64, b: i64) -> (i64, bool) {
+    let (uns, overflow) = u64(a.unsigned_abs(), b.unsigned_abs());
+    let ans = uns as i64;
+    (ans, overflow || ans < 0)
+}
+
+pub fn u128(a: u128, b: u128) -> (u128, bool) {
+    let a2 = int256::wide_mul_u128(a, a);
+    let b2 = int256::wide_mul_u128(b, b);
+    let (sum, overflow) = int256::overflowing_add_u256(a2, b2);
+    if overflow {
+        // a² + b² = 2↑256 + sum, so the root is ≥ 2↑128 and only its wrapped
+        // value is returned. It is found from the root r of (a² + b²) / 4,
+        // which fits: a² + b² = 4r² + 4rem + low, where low is sum mod 4.
+        let quarter = U256 {
+            lo: (sum.lo >> 2) | (sum.hi << 126),
+            hi: (sum.hi >> 2) | (1 << 126),
+        };
+        let low = sum.lo & 3;
+        let (r, rem) = sqrt::isqrt_rem_u256(quarter);
+        let excess = int256::wrapping_add_u256_u128(int256::shl_u256(rem, 2), low);
+        // the floor of the root is 2r + 1 if 4rem + low ≥ 4r + 1, otherwise 2r
+        let odd = rem.hi != 0 || rem.lo > r || (rem.lo == r && low != 0);
+        // round up if the remainder exceeds the floor of the root
+        let limit = if odd {
+            int256::wrapping_add_u256_u128(int256::wide_mul_u128(r, 6), 2)
+        } else {
+            int256::wide_mul_u128(r, 2)
+        };
+        let up = int256::lt_u256(limit, excess);
+        let root = ((r << 1) | u128::from(odd)).wrapping_add(u128::from(up));
+        return (root, true);
+    }
+    let (root, rem) = sqrt::isqrt_rem_u256(sum);
+    if rem.hi != 0 || rem.lo > root {
+        root.overflowing_add(1)
+    } else {
+        (root, false)
+    }
+}
+
+pub fn i128(a: i128, b: i128) -> (i128, bool) {
+    let (uns, overflow) = u128(a.unsigned_abs(), b.unsigned_abs());
+    let ans = uns as i128;
+    (ans, overflow || ans < 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hypot;
+
+    #[test]
+    fn hypot_u8_exhaustive() {
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                let exact = f64::from(a).hypot(f64::from(b));
+                let (ans, overflow) = hypot::u8(a, b);
+                assert_eq!(overflow, exact.round() > 255.0, "{} {}", a, b);
+                if !overflow {
+                    assert_eq!(f64::from(ans), exact.round(), "{} {}", a, b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hypot_i8_exhaustive() {
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let exact = f64::from(a).hypot(f64::from(b));
+                let (ans, overflow) = hypot::i8(a, b);
+                assert_eq!(overflow, exact.round() > 127.0, "{} {}", a, b);
+                if !overflow {
+                    assert_eq!(f64::from(ans), exact.round(), "{} {}", a, b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hypot_wide() {
+        // 3, 4, 5 scaled so that the squares do not fit in the type
+        assert_eq!(hypot::i32(3 << 28, 4 << 28), (5 << 28, false));
+        assert_eq!(hypot::i32(3 << 28, -(4 << 28)), (5 << 28, false));
+        assert!(hypot::i32(i32::MAX, i32::MAX).1);
+        assert_eq!(hypot::u32(3 << 29, 4 << 29), (5 << 29, false));
+        assert_eq!(hypot::i64(-(3 << 60), 4 << 60), (5 << 60, false));
+        assert_eq!(hypot::u64(3 << 61, 4 << 61), (5 << 61, false));
+        assert_eq!(hypot::u64(u64::MAX, 0), (u64::MAX, false));
+        assert_eq!(hypot::u64(u64::MAX, 1), (u64::MAX, false));
+        assert!(hypot::u64(u64::MAX, u64::MAX).1);
+        assert_eq!(hypot::i128(3 << 124, 4 << 124), (5 << 124, false));
+        // 65 × 2↑122 wraps to 2↑122
+        assert_eq!(hypot::u128(39 << 122, 52 << 122), (1 << 122, true));
+        // √2 × (2↑128 − 1) = 2↑128 + 0.414… × 2↑128 − 1.414…
+        let sqrt2_frac = crate::consts::SQRT_2.to_bits() << 1;
+        let (wrapped, overflow) = hypot::u128(u128::MAX, u128::MAX);
+        assert!(overflow);
+        assert!(sqrt2_frac.wrapping_sub(wrapped) <= 2);
+        assert_eq!(hypot::i128(i128::MIN, 0), (i128::MIN, true));
+        assert_eq!(hypot::i128(i128::MAX, -1), (i128::MAX, false));
+        assert_eq!(hypot::u128(3 << 125, 4 << 125), (5 << 125, false));
+        assert_eq!(hypot::u128(u128::MAX, 0), (u128::MAX, false));
+        assert_eq!(hypot::u128(u128::MAX, 1 << 63), (u128::MAX, false));
+        // √((2↑128 − 1)² + 2↑128) > 2↑128 − ½, so it rounds up and overflows
+        assert!(hypot::u128(u128::MAX, 1 << 64).1);
+        assert!(hypot::u128(u128::MAX, u128::MAX).1);
+        // a² + b² < 2↑255 fits in 256 bits, but the root does not fit
+        assert!(hypot::i128(i128::MAX, i128::MAX).1);
+    }
+}
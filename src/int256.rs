@@ -13,22 +13,253 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
-use az_crate::WrappingAs;
-
-#[derive(Clone, Copy, Debug)]
+/*!
+256-bit integers used as the underlying representation of [`FixedI256`] and
+[`FixedU256`].
+
+These types provide only what is needed to construct and inspect the bits of
+the 256-bit fixed-point numbers: constants, conversions from primitive
+integers, comparisons, formatting, and access to the two 128-bit halves.
+
+[`FixedI256`]: crate::FixedI256
+[`FixedU256`]: crate::FixedU256
+*/
+
+use az_crate::{WrappingAs, WrappingCast};
+use core::{
+    cmp::Ordering,
+    ops::{Add, Shl, Shr},
+};
+
+/// A 256-bit unsigned integer, used as the underlying representation of
+/// [`FixedU256`].
+///
+/// The value is <code>[hi][U256::hi]() × 2<sup>128</sup> + [lo][U256::lo]()</code>.
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::int256::U256;
+/// let a = U256::from(7u32);
+/// let b = U256::from_parts(1, 0);
+/// assert!(a < b);
+/// assert_eq!(b.hi(), 1);
+/// assert_eq!(b.to_string(), "340282366920938463463374607431768211456");
+/// ```
+///
+/// [`FixedU256`]: crate::FixedU256
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub struct U256 {
-    pub lo: u128,
-    pub hi: u128,
+    pub(crate) lo: u128,
+    pub(crate) hi: u128,
 }
 
-#[derive(Clone, Copy, Debug)]
+/// A 256-bit signed integer in two’s complement, used as the underlying
+/// representation of [`FixedI256`].
+///
+/// The value is <code>[hi][I256::hi]() × 2<sup>128</sup> + [lo][I256::lo]()</code>,
+/// so the sign is the sign of [`hi`][I256::hi].
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::int256::I256;
+/// let a = I256::from(-7i32);
+/// assert_eq!(a, I256::from_parts(-1, (-7i128) as u128));
+/// assert!(a < I256::ZERO);
+/// assert_eq!(a.to_string(), "-7");
+/// ```
+///
+/// [`FixedI256`]: crate::FixedI256
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub struct I256 {
-    pub lo: u128,
-    pub hi: i128,
+    pub(crate) lo: u128,
+    pub(crate) hi: i128,
+}
+
+impl U256 {
+    /// Zero.
+    pub const ZERO: U256 = U256 { lo: 0, hi: 0 };
+    /// The smallest value that can be represented by this integer type.
+    pub const MIN: U256 = U256::ZERO;
+    /// The largest value that can be represented by this integer type.
+    pub const MAX: U256 = U256 { lo: !0, hi: !0 };
+    /// The size of this integer type in bits.
+    pub const BITS: u32 = 256;
+
+    /// Creates an integer from its most significant and least significant
+    /// 128 bits.
+    #[inline]
+    pub const fn from_parts(hi: u128, lo: u128) -> U256 {
+        U256 { lo, hi }
+    }
+
+    /// Returns the most significant 128 bits.
+    #[inline]
+    pub const fn hi(self) -> u128 {
+        self.hi
+    }
+
+    /// Returns the least significant 128 bits.
+    #[inline]
+    pub const fn lo(self) -> u128 {
+        self.lo
+    }
+}
+
+impl I256 {
+    /// Zero.
+    pub const ZERO: I256 = I256 { lo: 0, hi: 0 };
+    /// The smallest value that can be represented by this integer type.
+    pub const MIN: I256 = I256 {
+        lo: 0,
+        hi: i128::MIN,
+    };
+    /// The largest value that can be represented by this integer type.
+    pub const MAX: I256 = I256 {
+        lo: !0,
+        hi: i128::MAX,
+    };
+    /// The size of this integer type in bits.
+    pub const BITS: u32 = 256;
+
+    /// Creates an integer from its most significant 128 bits, which include
+    /// the sign bit, and its least significant 128 bits.
+    #[inline]
+    pub const fn from_parts(hi: i128, lo: u128) -> I256 {
+        I256 { lo, hi }
+    }
+
+    /// Returns the most significant 128 bits, which include the sign bit.
+    #[inline]
+    pub const fn hi(self) -> i128 {
+        self.hi
+    }
+
+    /// Returns the least significant 128 bits.
+    #[inline]
+    pub const fn lo(self) -> u128 {
+        self.lo
+    }
+}
+
+impl Ord for U256 {
+    #[inline]
+    fn cmp(&self, rhs: &U256) -> Ordering {
+        self.hi.cmp(&rhs.hi).then(self.lo.cmp(&rhs.lo))
+    }
+}
+
+impl PartialOrd for U256 {
+    #[inline]
+    fn partial_cmp(&self, rhs: &U256) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for I256 {
+    #[inline]
+    fn cmp(&self, rhs: &I256) -> Ordering {
+        self.hi.cmp(&rhs.hi).then(self.lo.cmp(&rhs.lo))
+    }
+}
+
+impl PartialOrd for I256 {
+    #[inline]
+    fn partial_cmp(&self, rhs: &I256) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+macro_rules! from_unsigned {
+    ($($Src:ident)*) => { $(
+        impl From<$Src> for U256 {
+            #[inline]
+            fn from(src: $Src) -> U256 {
+                U256 {
+                    lo: u128::from(src),
+                    hi: 0,
+                }
+            }
+        }
+
+        impl From<$Src> for I256 {
+            #[inline]
+            fn from(src: $Src) -> I256 {
+                I256 {
+                    lo: u128::from(src),
+                    hi: 0,
+                }
+            }
+        }
+    )* };
+}
+from_unsigned! { u8 u16 u32 u64 u128 }
+
+macro_rules! from_signed {
+    ($($Src:ident)*) => { $(
+        impl From<$Src> for I256 {
+            #[inline]
+            fn from(src: $Src) -> I256 {
+                let src = i128::from(src);
+                I256 {
+                    lo: src as u128,
+                    hi: src >> 127,
+                }
+            }
+        }
+    )* };
+}
+from_signed! { i8 i16 i32 i64 i128 }
+
+// Add, Shl and Shr are used for formatting, and behave like the
+// corresponding operations on primitives.
+
+impl Add for U256 {
+    type Output = U256;
+    #[inline]
+    fn add(self, rhs: U256) -> U256 {
+        let (ans, overflow) = overflowing_add_u256(self, rhs);
+        debug_assert!(!overflow, "attempt to add with overflow");
+        ans
+    }
+}
+
+impl Shl<u32> for U256 {
+    type Output = U256;
+    #[inline]
+    fn shl(self, rhs: u32) -> U256 {
+        debug_assert!(rhs < 256, "attempt to shift left with overflow");
+        shl_u256(self, rhs)
+    }
+}
+
+impl Shr<u32> for U256 {
+    type Output = U256;
+    #[inline]
+    fn shr(self, rhs: u32) -> U256 {
+        debug_assert!(rhs < 256, "attempt to shift right with overflow");
+        shr_u256(self, rhs)
+    }
+}
+
+impl WrappingCast<u8> for U256 {
+    #[inline]
+    fn wrapping_cast(self) -> u8 {
+        self.lo as u8
+    }
 }
 
 #[inline]
-pub fn u256_wrapping_as_i256(a: U256) -> I256 {
+pub(crate) fn i256_wrapping_as_u256(a: I256) -> U256 {
+    U256 {
+        lo: a.lo,
+        hi: a.hi.wrapping_as::<u128>(),
+    }
+}
+
+#[inline]
+pub(crate) fn u256_wrapping_as_i256(a: U256) -> I256 {
     I256 {
         lo: a.lo,
         hi: a.hi.wrapping_as::<i128>(),
@@ -36,33 +267,110 @@ pub fn u256_wrapping_as_i256(a: U256) -> I256 {
 }
 
 #[inline]
-pub fn wrapping_add_u256_u128(a: U256, b: u128) -> U256 {
+pub(crate) fn wrapping_add_u256_u128(a: U256, b: u128) -> U256 {
     let (lo, carry) = a.lo.overflowing_add(b);
     let hi = a.hi.wrapping_add(u128::from(carry));
     U256 { lo, hi }
 }
 
 #[inline]
-pub fn overflowing_add_u128_u256(a: u128, b: U256) -> (u128, bool) {
+pub(crate) fn overflowing_add_u128_u256(a: u128, b: U256) -> (u128, bool) {
     let (lo, carry) = a.overflowing_add(b.lo);
     (lo, carry | (b.hi != 0))
 }
 
 #[inline]
-pub fn overflowing_sub_u128_u256(a: u128, b: U256) -> (u128, bool) {
+pub(crate) fn overflowing_sub_u128_u256(a: u128, b: U256) -> (u128, bool) {
     let (lo, borrow) = a.overflowing_sub(b.lo);
     (lo, borrow | (b.hi != 0))
 }
 
 #[inline]
-pub fn wrapping_neg_u256(a: U256) -> U256 {
+pub(crate) fn overflowing_add_u256(a: U256, b: U256) -> (U256, bool) {
+    let (lo, carry) = a.lo.overflowing_add(b.lo);
+    let (hi, overflow1) = a.hi.overflowing_add(b.hi);
+    let (hi, overflow2) = hi.overflowing_add(u128::from(carry));
+    (U256 { lo, hi }, overflow1 | overflow2)
+}
+
+#[inline]
+pub(crate) fn overflowing_sub_u256(a: U256, b: U256) -> (U256, bool) {
+    let (lo, borrow) = a.lo.overflowing_sub(b.lo);
+    let (hi, overflow1) = a.hi.overflowing_sub(b.hi);
+    let (hi, overflow2) = hi.overflowing_sub(u128::from(borrow));
+    (U256 { lo, hi }, overflow1 | overflow2)
+}
+
+#[inline]
+pub(crate) fn lt_u256(a: U256, b: U256) -> bool {
+    a.hi < b.hi || (a.hi == b.hi && a.lo < b.lo)
+}
+
+#[inline]
+pub(crate) fn leading_zeros_u256(a: U256) -> u32 {
+    if a.hi == 0 {
+        128 + a.lo.leading_zeros()
+    } else {
+        a.hi.leading_zeros()
+    }
+}
+
+#[inline]
+pub(crate) fn shr_u256(a: U256, sh: u32) -> U256 {
+    if sh == 0 {
+        a
+    } else if sh < 128 {
+        U256 {
+            lo: (a.lo >> sh) | (a.hi << (128 - sh)),
+            hi: a.hi >> sh,
+        }
+    } else if sh < 256 {
+        U256 {
+            lo: a.hi >> (sh - 128),
+            hi: 0,
+        }
+    } else {
+        U256 { lo: 0, hi: 0 }
+    }
+}
+
+#[inline]
+pub(crate) fn shl_u256(a: U256, sh: u32) -> U256 {
+    if sh == 0 {
+        a
+    } else if sh < 128 {
+        U256 {
+            lo: a.lo << sh,
+            hi: (a.hi << sh) | (a.lo >> (128 - sh)),
+        }
+    } else if sh < 256 {
+        U256 {
+            lo: 0,
+            hi: a.lo << (sh - 128),
+        }
+    } else {
+        U256 { lo: 0, hi: 0 }
+    }
+}
+
+#[inline]
+pub(crate) fn trailing_zeros_u256(a: U256) -> u32 {
+    if a.lo == 0 {
+        128 + a.hi.trailing_zeros()
+    } else {
+        a.lo.trailing_zeros()
+    }
+}
+
+#[inline]
+pub(crate) fn wrapping_neg_u256(a: U256) -> U256 {
     let (lo, carry) = (!a.lo).overflowing_add(1);
     let hi = (!a.hi).wrapping_add(u128::from(carry));
     U256 { lo, hi }
 }
 
 #[inline]
-pub fn overflowing_add_i256_i128(a: I256, b: i128) -> (I256, bool) {
+pub(crate) fn overflowing_add_i256_i128(a: I256, b: i128) -> (I256, bool) {
     let b = I256 {
         lo: b.wrapping_as::<u128>(),
         hi: b >> 127,
@@ -97,7 +405,7 @@ fn i128_from_lo_hi(lo: i128, hi: i128) -> i128 {
 }
 
 #[inline]
-pub fn wide_mul_u128(lhs: u128, rhs: u128) -> U256 {
+pub(crate) fn wide_mul_u128(lhs: u128, rhs: u128) -> U256 {
     let (ll, lh) = u128_lo_hi(lhs);
     let (rl, rh) = u128_lo_hi(rhs);
     let ll_rl = ll.wrapping_mul(rl);
@@ -121,7 +429,7 @@ pub fn wide_mul_u128(lhs: u128, rhs: u128) -> U256 {
 }
 
 #[inline]
-pub fn wide_mul_i128(lhs: i128, rhs: i128) -> I256 {
+pub(crate) fn wide_mul_i128(lhs: i128, rhs: i128) -> I256 {
     let (ll, lh) = i128_lo_hi(lhs);
     let (rl, rh) = i128_lo_hi(rhs);
     let ll_rl = ll.wrapping_mul(rl);
@@ -152,8 +460,40 @@ pub fn wide_mul_i128(lhs: i128, rhs: i128) -> I256 {
     }
 }
 
+// Returns the 512-bit product as (upper 256 bits, lower 256 bits).
 #[inline]
-pub fn shl_u256_max_128(a: U256, sh: u32) -> U256 {
+pub(crate) fn wide_mul_u256(lhs: U256, rhs: U256) -> (U256, U256) {
+    let hh = wide_mul_u128(lhs.hi, rhs.hi);
+    let hl = wide_mul_u128(lhs.hi, rhs.lo);
+    let lh = wide_mul_u128(lhs.lo, rhs.hi);
+    let ll = wide_mul_u128(lhs.lo, rhs.lo);
+    // mid can have up to 257 bits, with the most significant bit in mid_carry
+    let (mid, mid_carry) = overflowing_add_u256(hl, lh);
+    let (lo, lo_carry) = overflowing_add_u256(ll, U256 { lo: 0, hi: mid.lo });
+    let mid_hi = U256 {
+        lo: mid.hi,
+        hi: u128::from(mid_carry),
+    };
+    let hi = overflowing_add_u256(hh, mid_hi).0;
+    let hi = overflowing_add_u256(
+        hi,
+        U256 {
+            lo: u128::from(lo_carry),
+            hi: 0,
+        },
+    )
+    .0;
+    (hi, lo)
+}
+
+// Returns the upper 256 bits of the 512-bit product, rounded down.
+#[inline]
+pub(crate) fn mul_hi_u256(lhs: U256, rhs: U256) -> U256 {
+    wide_mul_u256(lhs, rhs).0
+}
+
+#[inline]
+pub(crate) fn shl_u256_max_128(a: U256, sh: u32) -> U256 {
     if sh == 0 {
         a
     } else if sh == 128 {
@@ -167,7 +507,7 @@ pub fn shl_u256_max_128(a: U256, sh: u32) -> U256 {
 }
 
 #[inline]
-pub fn shl_i256_max_128(a: I256, sh: u32) -> I256 {
+pub(crate) fn shl_i256_max_128(a: I256, sh: u32) -> I256 {
     if sh == 0 {
         a
     } else if sh == 128 {
@@ -184,7 +524,7 @@ pub fn shl_i256_max_128(a: I256, sh: u32) -> I256 {
 }
 
 #[inline]
-pub fn div_half_u128(r: &mut u128, d: u128, next_half: u128) -> u128 {
+pub(crate) fn div_half_u128(r: &mut u128, d: u128, next_half: u128) -> u128 {
     let (dl, dh) = u128_lo_hi(d);
     let (mut q, rr) = (*r / dh, *r % dh);
     let m = q * dl;
@@ -204,7 +544,7 @@ pub fn div_half_u128(r: &mut u128, d: u128, next_half: u128) -> u128 {
 }
 
 #[inline]
-pub fn div_rem_u256_u128(mut n: U256, mut d: u128) -> (U256, u128) {
+pub(crate) fn div_rem_u256_u128(mut n: U256, mut d: u128) -> (U256, u128) {
     assert!(d != 0, "division by zero");
     let zeros = d.leading_zeros();
     let mut r = if zeros == 0 {
@@ -232,7 +572,7 @@ pub fn div_rem_u256_u128(mut n: U256, mut d: u128) -> (U256, u128) {
 }
 
 #[inline]
-pub fn div_rem_i256_i128(n: I256, d: i128) -> (I256, i128) {
+pub(crate) fn div_rem_i256_i128(n: I256, d: i128) -> (I256, i128) {
     let (n_neg, n_abs) = if n.hi < 0 {
         let (nl, overflow) = n.lo.overflowing_neg();
         let nh = if overflow {
@@ -276,7 +616,7 @@ pub fn div_rem_i256_i128(n: I256, d: i128) -> (I256, i128) {
 }
 
 #[inline]
-pub fn overflowing_shl_u256_into_u128(a: U256, sh: u32) -> (u128, bool) {
+pub(crate) fn overflowing_shl_u256_into_u128(a: U256, sh: u32) -> (u128, bool) {
     if sh == 128 {
         (a.hi, false)
     } else if sh == 0 {
@@ -289,7 +629,7 @@ pub fn overflowing_shl_u256_into_u128(a: U256, sh: u32) -> (u128, bool) {
 }
 
 #[inline]
-pub fn overflowing_shl_i256_into_i128(a: I256, sh: u32) -> (i128, bool) {
+pub(crate) fn overflowing_shl_i256_into_i128(a: I256, sh: u32) -> (i128, bool) {
     if sh == 128 {
         (a.hi, false)
     } else if sh == 0 {
@@ -303,6 +643,73 @@ pub fn overflowing_shl_i256_into_i128(a: I256, sh: u32) -> (i128, bool) {
     }
 }
 
+#[inline]
+pub(crate) fn and_u256(a: U256, b: U256) -> U256 {
+    U256 {
+        lo: a.lo & b.lo,
+        hi: a.hi & b.hi,
+    }
+}
+
+// 512-bit numbers are stored as (hi, lo) pairs.
+
+#[inline]
+pub(crate) fn or_u256(a: U256, b: U256) -> U256 {
+    U256 {
+        lo: a.lo | b.lo,
+        hi: a.hi | b.hi,
+    }
+}
+
+#[inline]
+pub(crate) fn wrapping_neg_u512((hi, lo): (U256, U256)) -> (U256, U256) {
+    if lo == U256::ZERO {
+        (wrapping_neg_u256(hi), lo)
+    } else {
+        let not_hi = U256 {
+            lo: !hi.lo,
+            hi: !hi.hi,
+        };
+        (not_hi, wrapping_neg_u256(lo))
+    }
+}
+
+// 0 <= sh <= 256, shifts in ones if fill_ones
+#[inline]
+pub(crate) fn shr_u512((hi, lo): (U256, U256), sh: u32, fill_ones: bool) -> (U256, U256) {
+    let fill = if fill_ones { U256::MAX } else { U256::ZERO };
+    if sh == 0 {
+        (hi, lo)
+    } else if sh < 256 {
+        let lo = or_u256(shr_u256(lo, sh), shl_u256(hi, 256 - sh));
+        let hi = or_u256(shr_u256(hi, sh), shl_u256(fill, 256 - sh));
+        (hi, lo)
+    } else {
+        debug_assert!(sh == 256);
+        (fill, hi)
+    }
+}
+
+// Long division one bit at a time; the quotient bits are shifted into n
+// as the dividend bits are shifted out.
+pub(crate) fn div_rem_u512_u256((mut hi, mut lo): (U256, U256), d: U256) -> ((U256, U256), U256) {
+    assert!(d != U256::ZERO, "division by zero");
+    let mut r = U256::ZERO;
+    for _ in 0..512 {
+        let r_carry = r.hi >> 127 != 0;
+        r = shl_u256(r, 1);
+        r.lo |= hi.hi >> 127;
+        hi = shl_u256(hi, 1);
+        hi.lo |= lo.hi >> 127;
+        lo = shl_u256(lo, 1);
+        if r_carry || r >= d {
+            r = overflowing_sub_u256(r, d).0;
+            lo.lo |= 1;
+        }
+    }
+    ((hi, lo), r)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -368,4 +775,30 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_div_rem_u512() {
+        let d = U256 { lo: 7, hi: 3 };
+        let q = U256 {
+            lo: !0,
+            hi: 1 << 100,
+        };
+        let (hi, lo) = wide_mul_u256(q, d);
+        let lo = wrapping_add_u256_u128(lo, 5);
+        let ((q_hi, q_lo), r) = div_rem_u512_u256((hi, lo), d);
+        assert_eq!(q_hi, U256::ZERO);
+        assert_eq!(q_lo, q);
+        assert_eq!(r, U256::from(5u8));
+
+        let n = (U256::MAX, U256::MAX);
+        assert_eq!(
+            div_rem_u512_u256(n, U256::MAX),
+            ((U256::from(1u8), U256::from(1u8)), U256::ZERO)
+        );
+        assert_eq!(wrapping_neg_u512(wrapping_neg_u512(n)), n);
+        assert_eq!(
+            shr_u512((U256::ZERO, U256::MAX), 256, true),
+            (U256::MAX, U256::ZERO)
+        );
+    }
 }
@@ -20,7 +20,10 @@ use crate::{
 
 macro_rules! make_helper_common {
     ($t:ident) => {
-        use crate::helpers::{ToFixedHelper, Widest};
+        use crate::{
+            helpers::{ToFixedHelper, Widest},
+            RoundingMode,
+        };
         use core::cmp::Ordering;
     };
 }
@@ -96,6 +99,71 @@ macro_rules! make_helper {
                     overflow,
                 }
             }
+
+            #[inline]
+            pub fn to_fixed_helper_round(
+                val: $i,
+                src_frac_bits: i32,
+                dst_frac_bits: u32,
+                dst_int_bits: u32,
+                mode: RoundingMode,
+            ) -> ToFixedHelper {
+                let mut conv = to_fixed_helper(val, src_frac_bits, dst_frac_bits, dst_int_bits);
+                if conv.dir == Ordering::Equal {
+                    return conv;
+                }
+                // 0 < rem < 2↑need_to_shr, where rem = val − floor × 2↑need_to_shr
+                let need_to_shr = src_frac_bits - dst_frac_bits as i32;
+                let bits_128 = i128::from(val);
+                let rem_cmp_half = if need_to_shr < 128 {
+                    let rem = bits_128 as u128 & !(!0 << need_to_shr);
+                    rem.cmp(&(1 << (need_to_shr - 1)))
+                } else if val >= 0 {
+                    // rem = val < 2↑127
+                    Ordering::Less
+                } else if need_to_shr == 128 {
+                    // rem = val + 2↑128 ≥ 2↑127
+                    if bits_128 == i128::MIN {
+                        Ordering::Equal
+                    } else {
+                        Ordering::Greater
+                    }
+                } else {
+                    // rem = val + 2↑need_to_shr ≥ 2↑(need_to_shr − 1) + 2↑127
+                    Ordering::Greater
+                };
+                conv.round_floor(mode, val < 0, rem_cmp_half, dst_frac_bits + dst_int_bits);
+                conv
+            }
+
+            #[inline]
+            pub fn to_fixed_helper_stochastic(
+                val: $i,
+                src_frac_bits: i32,
+                dst_frac_bits: u32,
+                dst_int_bits: u32,
+                random: u128,
+            ) -> ToFixedHelper {
+                let mut conv = to_fixed_helper(val, src_frac_bits, dst_frac_bits, dst_int_bits);
+                if conv.dir == Ordering::Equal {
+                    return conv;
+                }
+                // 0 < rem < 2↑need_to_shr, where rem = val − floor × 2↑need_to_shr,
+                // and need_to_shr ≤ 128 for fixed-point sources
+                let need_to_shr = src_frac_bits - dst_frac_bits as i32;
+                debug_assert!(need_to_shr <= 128);
+                let (rem, random) = if need_to_shr < 128 {
+                    let mask = !(!0 << need_to_shr);
+                    (i128::from(val) as u128 & mask, random & mask)
+                } else {
+                    (i128::from(val) as u128, random)
+                };
+                // round up with probability rem / 2↑need_to_shr
+                if random < rem {
+                    conv.inc_floor(dst_frac_bits + dst_int_bits);
+                }
+                conv
+            }
         }
 
         pub mod $u {
@@ -156,6 +224,62 @@ macro_rules! make_helper {
                     overflow,
                 }
             }
+
+            #[inline]
+            pub fn to_fixed_helper_round(
+                val: $u,
+                src_frac_bits: i32,
+                dst_frac_bits: u32,
+                dst_int_bits: u32,
+                mode: RoundingMode,
+            ) -> ToFixedHelper {
+                let mut conv = to_fixed_helper(val, src_frac_bits, dst_frac_bits, dst_int_bits);
+                if conv.dir == Ordering::Equal {
+                    return conv;
+                }
+                // 0 < rem < 2↑need_to_shr, where rem = val − floor × 2↑need_to_shr
+                let need_to_shr = src_frac_bits - dst_frac_bits as i32;
+                let bits_128 = u128::from(val);
+                let rem_cmp_half = if need_to_shr < 128 {
+                    let rem = bits_128 & !(!0 << need_to_shr);
+                    rem.cmp(&(1 << (need_to_shr - 1)))
+                } else if need_to_shr == 128 {
+                    bits_128.cmp(&(1 << 127))
+                } else {
+                    Ordering::Less
+                };
+                conv.round_floor(mode, false, rem_cmp_half, dst_frac_bits + dst_int_bits);
+                conv
+            }
+
+            #[inline]
+            pub fn to_fixed_helper_stochastic(
+                val: $u,
+                src_frac_bits: i32,
+                dst_frac_bits: u32,
+                dst_int_bits: u32,
+                random: u128,
+            ) -> ToFixedHelper {
+                let mut conv = to_fixed_helper(val, src_frac_bits, dst_frac_bits, dst_int_bits);
+                if conv.dir == Ordering::Equal {
+                    return conv;
+                }
+                // 0 < rem < 2↑need_to_shr, where rem = val − floor × 2↑need_to_shr,
+                // and need_to_shr ≤ 128 for fixed-point sources
+                let need_to_shr = src_frac_bits - dst_frac_bits as i32;
+                debug_assert!(need_to_shr <= 128);
+                let (rem, random) = if need_to_shr < 128 {
+                    let mask = !(!0 << need_to_shr);
+                    (u128::from(val) & mask, random & mask)
+                } else {
+                    (u128::from(val), random)
+                };
+                // round up with probability rem / 2↑need_to_shr
+                if random < rem {
+                    conv.inc_floor(dst_frac_bits + dst_int_bits);
+                }
+                conv
+            }
         }
     };
 }
@@ -166,6 +290,29 @@ make_helper! { i32, u32 }
 make_helper! { i64, u64 }
 make_helper! { i128, u128 }
 
+pub mod i256 {
+    use crate::int256::{self, I256, U256};
+
+    #[inline]
+    pub fn neg_abs(val: I256) -> (bool, U256) {
+        let bits = int256::i256_wrapping_as_u256(val);
+        if val.hi < 0 {
+            (true, int256::wrapping_neg_u256(bits))
+        } else {
+            (false, bits)
+        }
+    }
+}
+
+pub mod u256 {
+    use crate::int256::U256;
+
+    #[inline]
+    pub fn neg_abs(val: U256) -> (bool, U256) {
+        (false, val)
+    }
+}
+
 pub struct IntFixed<T>(pub T);
 
 macro_rules! make_int_fixed {
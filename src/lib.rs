@@ -23,6 +23,8 @@ The [*fixed* crate] provides fixed-point numbers.
   * [`FixedI32`] and [`FixedU32`] are 32-bit fixed-point numbers.
   * [`FixedI64`] and [`FixedU64`] are 64-bit fixed-point numbers.
   * [`FixedI128`] and [`FixedU128`] are 128-bit fixed-point numbers.
+  * [`FixedI256`] and [`FixedU256`] are 256-bit fixed-point numbers with a
+    subset of the API of the narrower numbers.
 
 An <i>n</i>-bit fixed-point number has <i>f</i> = `Frac` fractional bits where
 0 ≤ <i>f</i> ≤ <i>n</i>, and <i>n</i> − <i>f</i> integer bits. For example,
@@ -59,18 +61,28 @@ cannot be represented exactly, as it is 1/10<sup>3</sup>. It is binary fractions
 like 1/2<sup>4</sup> (0.0625) that can be represented exactly, provided there
 are enough fractional bits.
 
-This crate does *not* provide general analytic functions.
-
-  * No algebraic functions are provided, for example no `sqrt` or `pow`.
-  * No trigonometric functions are provided, for example no `sin` or `cos`.
-  * No other transcendental functions are provided, for example no `log` or
-    `exp`.
-
-These functions are not provided because different implementations can have
-different trade-offs, for example trading some correctness for speed.
+This crate provides some analytic functions. They are computed using integer
+arithmetic only, so that their results are the same on all targets.
+
+  * The correctly rounded square root [`sqrt`], the hypotenuse [`hypot`]
+    computed without intermediate overflow, and the integer power [`powi`] are
+    provided. The correctly rounded cube root [`cbrt`] and the general root
+    [`nth_root`] are provided too.
+  * The trigonometric functions [`sin`], [`cos`], [`tan`] and [`sin_cos`], and
+    the inverse trigonometric functions [`atan`], [`atan2`], [`asin`] and
+    [`acos`], are provided for signed fixed-point numbers.
+  * The error functions [`erf`] and [`erfc`], and the cumulative distribution
+    function [`norm_cdf`] and probability density function [`norm_pdf`] of the
+    standard normal distribution, are provided for signed fixed-point numbers.
+  * The exponential and logarithmic functions [`exp`], [`exp2`], [`ln`],
+    [`log2`] and [`log10`], the power function [`powf`] with a fractional
+    exponent, and the hyperbolic functions [`sinh`], [`cosh`] and [`tanh`] are
+    provided.
+
+Other analytic functions are not provided because different implementations can
+have different trade-offs, for example trading some correctness for speed.
 Implementations can be provided in other crates.
 
-  * The [*fixed-sqrt* crate] provides the square root operation.
   * The [*cordic* crate] provides various functions implemented using the
     [CORDIC] algorithm.
 
@@ -89,13 +101,15 @@ The conversions supported cover the following cases.
   * Checked conversions between fixed-point numbers and numeric primitives are
     provided using the [`FromFixed`] and [`ToFixed`] traits, or using the
     [`from_num`] and [`to_num`] methods and [their checked
-    versions][`checked_from_num`].
+    versions][`checked_from_num`]. The [`from_num_round`] and [`to_num_round`]
+    methods take a [`RoundingMode`] to select how the result is rounded.
   * Additionally, [`az`] casts are implemented for conversion between
     fixed-point nubmers and numeric primitives.
   * Fixed-point numbers can be parsed from decimal strings using [`FromStr`],
     and from binary, octal and hexadecimal strings using the
     [`from_str_binary`], [`from_str_octal`] and [`from_str_hex`] methods. The
-    result is rounded to the nearest, with ties rounded to even.
+    result is rounded to the nearest, with ties rounded to even, unless another
+    [`RoundingMode`] is passed to methods such as [`from_str_round`].
   * Fixed-point numbers can be converted to strings using [`Display`],
     [`Binary`], [`Octal`], [`LowerHex`] and [`UpperHex`]. The output is rounded
     to the nearest, with ties rounded to even.
@@ -161,7 +175,7 @@ The [*fixed-macro* crate] provides a convenient macro to write down fixed-point
 constants literally in the code.
 
 ```rust
-# #[cfg(feature = "skip-this-test")] {
+# #[cfg(any())] {
 use fixed::types::I16F16;
 use fixed_macro::fixed;
 
@@ -251,7 +265,6 @@ shall be dual licensed as above, without any additional terms or conditions.
 [*cordic* crate]: https://crates.io/crates/cordic
 [*fixed* crate]: https://crates.io/crates/fixed
 [*fixed-macro* crate]: https://crates.io/crates/fixed-macro
-[*fixed-sqrt* crate]: https://crates.io/crates/fixed-sqrt
 [*half* crate]: https://crates.io/crates/half
 [*num-traits* crate]: https://crates.io/crates/num-traits
 [*serde* crate]: https://crates.io/crates/serde
@@ -274,16 +287,46 @@ shall be dual licensed as above, without any additional terms or conditions.
 [`LossyInto`]: traits::LossyInto
 [`LowerHex`]: core::fmt::LowerHex
 [`Octal`]: core::fmt::Octal
+[`RoundingMode`]: RoundingMode
 [`U20F12`]: types::U20F12
 [`UpperHex`]: core::fmt::UpperHex
+[`acos`]: FixedI32::acos
+[`asin`]: FixedI32::asin
+[`atan2`]: FixedI32::atan2
+[`atan`]: FixedI32::atan
 [`az`]: az_crate
 [`bf16`]: half::bf16
+[`cbrt`]: FixedI32::cbrt
 [`checked_from_num`]: FixedI32::checked_from_num
+[`cos`]: FixedI32::cos
+[`cosh`]: FixedI32::cosh
+[`erf`]: FixedI32::erf
+[`erfc`]: FixedI32::erfc
+[`exp2`]: FixedI32::exp2
+[`exp`]: FixedI32::exp
 [`f16`]: half::f16
+[`from_num_round`]: FixedI32::from_num_round
 [`from_num`]: FixedI32::from_num
 [`from_str_binary`]: FixedI32::from_str_binary
 [`from_str_hex`]: FixedI32::from_str_hex
 [`from_str_octal`]: FixedI32::from_str_octal
+[`from_str_round`]: FixedI32::from_str_round
+[`hypot`]: FixedI32::hypot
+[`ln`]: FixedI32::ln
+[`log10`]: FixedI32::log10
+[`log2`]: FixedI32::log2
+[`norm_cdf`]: FixedI32::norm_cdf
+[`norm_pdf`]: FixedI32::norm_pdf
+[`nth_root`]: FixedI32::nth_root
+[`powf`]: FixedI32::powf
+[`powi`]: FixedI32::powi
+[`sin_cos`]: FixedI32::sin_cos
+[`sin`]: FixedI32::sin
+[`sinh`]: FixedI32::sinh
+[`sqrt`]: FixedI32::sqrt
+[`tan`]: FixedI32::tan
+[`tanh`]: FixedI32::tanh
+[`to_num_round`]: FixedI32::to_num_round
 [`to_num`]: FixedI32::to_num
 [const generics]: https://github.com/rust-lang/rust/issues/44580
 */
@@ -308,37 +351,47 @@ pub mod consts;
 mod convert;
 mod debug_hex;
 mod display;
+mod erf;
+mod exp_log;
+mod fixed256;
 mod float_helper;
 mod from_str;
 mod helpers;
+mod hypot;
 #[cfg(feature = "arbitrary")]
 mod impl_arbitrary;
 mod impl_bytemuck;
 #[cfg(feature = "num-traits")]
 mod impl_num_traits;
-mod int256;
+pub mod int256;
 mod int_helper;
 mod inv_lerp;
 mod lerp;
 mod log10;
+mod pow;
 mod prim_traits;
 #[cfg(feature = "serde")]
 mod serdeize;
+mod sqrt;
 pub mod traits;
+mod trig;
 pub mod types;
 mod unwrapped;
+mod wide_float;
 mod wrapping;
 
+pub use crate::fixed256::{FixedI256, FixedU256};
 #[cfg(feature = "num-traits")]
 pub use crate::impl_num_traits::RadixParseFixedError;
 use crate::{
     from_str::FromStrRadix,
+    helpers::Sealed,
     log10::IntFracLog10,
-    traits::{FromFixed, ToFixed},
+    traits::{Fixed, FromFixed, ToFixed},
     types::extra::{
-        IsLessOrEqual, LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8, Sum, True, Unsigned, U12, U124,
-        U125, U126, U127, U128, U13, U14, U15, U16, U28, U29, U30, U31, U32, U4, U5, U6, U60, U61,
-        U62, U63, U64, U7, U8,
+        IsLessOrEqual, LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8, Max, Maximum, Sum, True,
+        Unsigned, U12, U124, U125, U126, U127, U128, U13, U14, U15, U16, U28, U29, U30, U31, U32,
+        U4, U5, U6, U60, U61, U62, U63, U64, U7, U8,
     },
 };
 pub use crate::{from_str::ParseFixedError, unwrapped::Unwrapped, wrapping::Wrapping};
@@ -368,7 +421,7 @@ use core::{
 /// The prelude re-exports the following:
 ///
 ///  * <code>[traits]::{[FromFixed], [ToFixed]}</code>, checked conversions
-///     from/to fixed-point numbers.
+///    from/to fixed-point numbers.
 ///  * <code>[traits]::{[LossyFrom], [LossyInto]}</code>, infallible lossy
 ///    conversions.
 ///  * <code>[traits]::{[LosslessTryFrom], [LosslessTryInto]}</code>, checked
@@ -394,6 +447,8 @@ mod macros_no_frac;
 mod macros_frac;
 #[macro_use]
 mod macros_const;
+#[macro_use]
+mod macros_math;
 
 macro_rules! fixed {
     (
@@ -403,7 +458,7 @@ macro_rules! fixed {
             $s_nbits_m1:expr, $s_nbits_m2:expr, $s_nbits_m3:expr, $s_nbits_m4:expr
         ),
         $nbytes:expr, $bytes_val:expr, $rev_bytes_val:expr, $be_bytes:expr, $le_bytes:expr,
-        $UFixed:ident, $UInner:ty, $Signedness:tt,
+        $IFixed:ident, $UFixed:ident, $UInner:ty, $Signedness:tt,
         $LeEqU_C0:tt, $LeEqU_C1:tt, $LeEqU_C2:tt, $LeEqU_C3:tt,
         $Double:ident, $DoubleInner:ty, $s_nbits_2:expr, $HasDouble:tt
     ) => {
@@ -414,7 +469,7 @@ macro_rules! fixed {
                 $s_nbits_m1, $s_nbits_m2, $s_nbits_m3, $s_nbits_m4
             ),
             $nbytes, $bytes_val, $rev_bytes_val, $be_bytes, $le_bytes,
-            $UFixed[stringify!($UFixed)], $UInner, $Signedness,
+            $IFixed[stringify!($IFixed)], $UFixed[stringify!($UFixed)], $UInner, $Signedness,
             $LeEqU_C0, $LeEqU_C1, $LeEqU_C2, $LeEqU_C3,
             $Double, $DoubleInner, $s_nbits_2, $HasDouble
         }
@@ -426,7 +481,7 @@ macro_rules! fixed {
             $s_nbits_m1:expr, $s_nbits_m2:expr, $s_nbits_m3:expr, $s_nbits_m4:expr
         ),
         $nbytes:expr, $bytes_val:expr, $rev_bytes_val:expr, $be_bytes:expr, $le_bytes:expr,
-        $UFixed:ident[$s_ufixed:expr], $UInner:ty, $Signedness:tt,
+        $IFixed:ident[$s_ifixed:expr], $UFixed:ident[$s_ufixed:expr], $UInner:ty, $Signedness:tt,
         $LeEqU_C0:tt, $LeEqU_C1:tt, $LeEqU_C2:tt, $LeEqU_C3:tt,
         $Double:ident, $DoubleInner:ty, $s_nbits_2:expr, $HasDouble:tt
     ) => {
@@ -501,10 +556,7 @@ assert_eq!(two_point_75.to_string(), \"2.8\");
         impl<Frac> Clone for $Fixed<Frac> {
             #[inline]
             fn clone(&self) -> $Fixed<Frac> {
-                $Fixed {
-                    bits: self.bits,
-                    phantom: PhantomData,
-                }
+                *self
             }
         }
 
@@ -531,7 +583,7 @@ assert_eq!(two_point_75.to_string(), \"2.8\");
         fixed_no_frac! {
             $Fixed[$s_fixed]($Inner[$s_inner], $LeEqU, $s_nbits, $s_nbits_m1),
             $nbytes, $bytes_val, $rev_bytes_val, $be_bytes, $le_bytes,
-            $UFixed[$s_ufixed], $UInner, $Signedness,
+            $IFixed[$s_ifixed], $UFixed[$s_ufixed], $UInner, $Signedness,
             $Double, $DoubleInner, $s_nbits_2, $HasDouble
         }
         // inherent methods that require Frac bounds, and cannot be const
@@ -539,6 +591,11 @@ assert_eq!(two_point_75.to_string(), \"2.8\");
             $Fixed[$s_fixed]($Inner[$s_inner], $LeEqU, $s_nbits, $s_nbits_m1, $s_nbits_m4),
             $UFixed, $UInner, $Signedness
         }
+        // analytic functions, which require Frac bounds
+        fixed_math! {
+            $Fixed[$s_fixed]($Inner[$s_inner], $LeEqU, $s_nbits, $s_nbits_m1, $s_nbits_m2),
+            $Signedness
+        }
         fixed_const! {
             $Fixed[$s_fixed]($LeEqU, $s_nbits, $s_nbits_m1, $s_nbits_m2, $s_nbits_m3, $s_nbits_m4),
             $LeEqU_C0, $LeEqU_C1, $LeEqU_C2, $LeEqU_C3,
@@ -551,7 +608,7 @@ fixed! {
     "An eight",
     FixedU8(u8, LeEqU8, "8", "7", "6", "5", "4"),
     1, "0x12", "0x12", "[0x12]", "[0x12]",
-    FixedU8, u8, Unsigned,
+    FixedI8, FixedU8, u8, Unsigned,
     U8, U7, U6, U5,
     FixedU16, u16, "16", True
 }
@@ -559,7 +616,7 @@ fixed! {
     "A 16",
     FixedU16(u16, LeEqU16, "16", "15", "14", "13", "12"),
     2, "0x1234", "0x3412", "[0x12, 0x34]", "[0x34, 0x12]",
-    FixedU16, u16, Unsigned,
+    FixedI16, FixedU16, u16, Unsigned,
     U16, U15, U14, U13,
     FixedU32, u32, "32", True
 }
@@ -567,7 +624,7 @@ fixed! {
     "A 32",
     FixedU32(u32, LeEqU32, "32", "31", "30", "29", "28"),
     4, "0x1234_5678", "0x7856_3412", "[0x12, 0x34, 0x56, 0x78]", "[0x78, 0x56, 0x34, 0x12]",
-    FixedU32, u32, Unsigned,
+    FixedI32, FixedU32, u32, Unsigned,
     U32, U31, U30, U29,
     FixedU64, u64, "64", True
 }
@@ -577,7 +634,7 @@ fixed! {
     8, "0x1234_5678_9ABC_DE0F", "0x0FDE_BC9A_7856_3412",
     "[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0x0F]",
     "[0x0F, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]",
-    FixedU64, u64, Unsigned,
+    FixedI64, FixedU64, u64, Unsigned,
     U64, U63, U62, U61,
     FixedU128, u128, "128", True
 }
@@ -590,7 +647,7 @@ fixed! {
      0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]",
     "[0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, \
      0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]",
-    FixedU128, u128, Unsigned,
+    FixedI128, FixedU128, u128, Unsigned,
     U128, U127, U126, U125,
     FixedU128, u128, "128", False
 }
@@ -598,7 +655,7 @@ fixed! {
     "An eight",
     FixedI8(i8, LeEqU8, "8", "7", "6", "5", "4"),
     1, "0x12", "0x12", "[0x12]", "[0x12]",
-    FixedU8, u8, Signed,
+    FixedI8, FixedU8, u8, Signed,
     U7, U6, U5, U4,
     FixedI16, i16, "16", True
 }
@@ -606,7 +663,7 @@ fixed! {
     "A 16",
     FixedI16(i16, LeEqU16, "16", "15", "14", "13", "12"),
     2, "0x1234", "0x3412", "[0x12, 0x34]", "[0x34, 0x12]",
-    FixedU16, u16, Signed,
+    FixedI16, FixedU16, u16, Signed,
     U15, U14, U13, U12,
     FixedI32, i32, "32", True
 }
@@ -614,7 +671,7 @@ fixed! {
     "A 32",
     FixedI32(i32, LeEqU32, "32", "31", "30", "29", "28"),
     4, "0x1234_5678", "0x7856_3412", "[0x12, 0x34, 0x56, 0x78]", "[0x78, 0x56, 0x34, 0x12]",
-    FixedU32, u32, Signed,
+    FixedI32, FixedU32, u32, Signed,
     U31, U30, U29, U28,
     FixedI64, i64, "64", True
 }
@@ -624,7 +681,7 @@ fixed! {
     8, "0x1234_5678_9ABC_DE0F", "0x0FDE_BC9A_7856_3412",
     "[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0x0F]",
     "[0x0F, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]",
-    FixedU64, u64, Signed,
+    FixedI64, FixedU64, u64, Signed,
     U63, U62, U61, U60,
     FixedI128, i128, "128", True
 }
@@ -637,7 +694,7 @@ fixed! {
      0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]",
     "[0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, \
      0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]",
-    FixedU128, u128, Signed,
+    FixedI128, FixedU128, u128, Signed,
     U127, U126, U125, U124,
     FixedI128, i128, "128", False
 }
@@ -686,6 +743,71 @@ impl F128Bits {
     }
 }
 
+/// The rounding mode used by conversions such as [`from_num_round`] and
+/// [`to_num_round`].
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::{types::I16F16, RoundingMode};
+/// let x = I16F16::from_num(-2.5);
+/// assert_eq!(x.to_num_round::<i32>(RoundingMode::Floor), -3);
+/// assert_eq!(x.to_num_round::<i32>(RoundingMode::Ceil), -2);
+/// assert_eq!(x.to_num_round::<i32>(RoundingMode::TowardZero), -2);
+/// assert_eq!(x.to_num_round::<i32>(RoundingMode::AwayFromZero), -3);
+/// assert_eq!(x.to_num_round::<i32>(RoundingMode::NearestTiesEven), -2);
+/// assert_eq!(x.to_num_round::<i32>(RoundingMode::NearestTiesAway), -3);
+/// ```
+///
+/// [`from_num_round`]: FixedI32::from_num_round
+/// [`to_num_round`]: FixedI32::to_num_round
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RoundingMode {
+    /// Rounds towards −∞.
+    Floor,
+    /// Rounds towards +∞.
+    Ceil,
+    /// Rounds towards zero.
+    TowardZero,
+    /// Rounds away from zero.
+    AwayFromZero,
+    /// Rounds to the nearest, with ties rounded to even.
+    NearestTiesEven,
+    /// Rounds to the nearest, with ties rounded away from zero.
+    NearestTiesAway,
+}
+
+impl RoundingMode {
+    // Returns true if an inexact absolute value should be rounded up rather
+    // than truncated. `odd` is true if the truncated absolute value is odd, and
+    // `rem` compares the discarded part with one half.
+    #[inline]
+    pub(crate) fn round_up_abs(self, neg: bool, odd: bool, rem: Ordering) -> bool {
+        match self {
+            RoundingMode::Floor => neg,
+            RoundingMode::Ceil => !neg,
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => true,
+            RoundingMode::NearestTiesEven => match rem {
+                Ordering::Less => false,
+                Ordering::Equal => odd,
+                Ordering::Greater => true,
+            },
+            RoundingMode::NearestTiesAway => rem != Ordering::Less,
+        }
+    }
+
+    // Returns true if the rounding depends only on the sign and on whether the
+    // value is exact, not on how the discarded part compares with one half.
+    #[inline]
+    pub(crate) fn is_directed(self) -> bool {
+        !matches!(
+            self,
+            RoundingMode::NearestTiesEven | RoundingMode::NearestTiesAway
+        )
+    }
+}
+
 /// Defines constant fixed-point numbers from integer expressions.
 ///
 /// This macro is useful because [`from_num`] cannot be used in constant
@@ -1347,6 +1469,176 @@ mod tests {
         );
     }
 
+    #[test]
+    fn round_to_multiple() {
+        use crate::types::{I4F4, U4F4};
+        use crate::RoundingMode;
+        use core::convert::TryFrom;
+        const MODES: [RoundingMode; 6] = [
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+            RoundingMode::TowardZero,
+            RoundingMode::AwayFromZero,
+            RoundingMode::NearestTiesEven,
+            RoundingMode::NearestTiesAway,
+        ];
+        // reference on raw bits using i32 arithmetic
+        fn reference(bits: i32, step: i32, mode: RoundingMode) -> i32 {
+            let step = step.abs();
+            let (quot, rem) = (bits.div_euclid(step), bits.rem_euclid(step));
+            if rem == 0 {
+                return bits;
+            }
+            let up = match mode {
+                RoundingMode::Floor => false,
+                RoundingMode::Ceil => true,
+                RoundingMode::TowardZero => bits < 0,
+                RoundingMode::AwayFromZero => bits > 0,
+                RoundingMode::NearestTiesEven => {
+                    2 * rem > step || (2 * rem == step && quot % 2 != 0)
+                }
+                RoundingMode::NearestTiesAway => 2 * rem > step || (2 * rem == step && bits > 0),
+            };
+            (quot + i32::from(up)) * step
+        }
+        for &mode in &MODES {
+            for bits in i8::MIN..=i8::MAX {
+                let f = I4F4::from_bits(bits);
+                assert_eq!(f.checked_round_to_multiple(I4F4::ZERO, mode), None);
+                for step in (i8::MIN..=i8::MAX).filter(|&s| s != 0) {
+                    let expected = reference(bits.into(), step.into(), mode);
+                    let wrapped = (
+                        I4F4::from_bits(expected as i8),
+                        i8::try_from(expected).is_err(),
+                    );
+                    let expected = i8::try_from(expected).ok().map(I4F4::from_bits);
+                    let neg_step = I4F4::from_bits(step.wrapping_neg());
+                    let step = I4F4::from_bits(step);
+                    assert_eq!(f.checked_round_to_multiple(step, mode), expected);
+                    assert_eq!(f.overflowing_round_to_multiple(step, mode), wrapped);
+                    // the sign of step is ignored, even for step == MIN
+                    assert_eq!(f.overflowing_round_to_multiple(neg_step, mode), wrapped);
+                }
+            }
+            for bits in u8::MIN..=u8::MAX {
+                let f = U4F4::from_bits(bits);
+                assert_eq!(f.checked_round_to_multiple(U4F4::ZERO, mode), None);
+                for step in 1..=u8::MAX {
+                    let expected = reference(bits.into(), step.into(), mode);
+                    let wrapped = (
+                        U4F4::from_bits(expected as u8),
+                        u8::try_from(expected).is_err(),
+                    );
+                    let expected = u8::try_from(expected).ok().map(U4F4::from_bits);
+                    let step = U4F4::from_bits(step);
+                    assert_eq!(f.checked_round_to_multiple(step, mode), expected);
+                    assert_eq!(f.overflowing_round_to_multiple(step, mode), wrapped);
+                }
+            }
+        }
+
+        let step = I4F4::from_num(0.75);
+        let mode = RoundingMode::NearestTiesEven;
+        assert_eq!(I4F4::from_num(2).round_to_multiple(-step, mode), step * 3);
+        assert_eq!(I4F4::from_num(-2).round_to_multiple(-step, mode), step * -3);
+        assert_eq!(I4F4::MAX.wrapping_round_to_multiple(-step, mode), -7.75);
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn round_to_multiple_zero_step() {
+        use crate::types::I4F4;
+        use crate::RoundingMode;
+        let _ = I4F4::ONE.overflowing_round_to_multiple(I4F4::ZERO, RoundingMode::Floor);
+    }
+
+    #[test]
+    fn round_dp() {
+        use crate::types::{I0F128, I4F4, U0F128, U4F4, U64F64};
+        use crate::RoundingMode;
+        use core::convert::TryFrom;
+        const MODES: [RoundingMode; 6] = [
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+            RoundingMode::TowardZero,
+            RoundingMode::AwayFromZero,
+            RoundingMode::NearestTiesEven,
+            RoundingMode::NearestTiesAway,
+        ];
+        // reference on raw bits with four fractional bits
+        fn reference(bits: i32, dp: u32, mode: RoundingMode) -> i32 {
+            if dp >= 4 {
+                return bits;
+            }
+            let pow = 10i32.pow(dp);
+            let num = bits * pow;
+            let (quot, rem) = (num.div_euclid(16), num.rem_euclid(16));
+            let up = rem != 0
+                && match mode {
+                    RoundingMode::Floor => false,
+                    RoundingMode::Ceil => true,
+                    RoundingMode::TowardZero => bits < 0,
+                    RoundingMode::AwayFromZero => bits > 0,
+                    RoundingMode::NearestTiesEven => rem > 8 || (rem == 8 && quot % 2 != 0),
+                    RoundingMode::NearestTiesAway => rem > 8 || (rem == 8 && bits > 0),
+                };
+            let dec = quot + i32::from(up);
+            // there are no ties when converting back
+            (dec * 16 + pow / 2).div_euclid(pow)
+        }
+        for &mode in &MODES {
+            for dp in 0..6 {
+                for bits in i8::MIN..=i8::MAX {
+                    let expected = reference(bits.into(), dp, mode);
+                    let wrapped = (
+                        I4F4::from_bits(expected as i8),
+                        i8::try_from(expected).is_err(),
+                    );
+                    let expected = i8::try_from(expected).ok().map(I4F4::from_bits);
+                    let f = I4F4::from_bits(bits);
+                    assert_eq!(f.checked_round_dp(dp, mode), expected);
+                    assert_eq!(f.overflowing_round_dp(dp, mode), wrapped);
+                }
+                for bits in u8::MIN..=u8::MAX {
+                    let expected = reference(bits.into(), dp, mode);
+                    let wrapped = (
+                        U4F4::from_bits(expected as u8),
+                        u8::try_from(expected).is_err(),
+                    );
+                    let expected = u8::try_from(expected).ok().map(U4F4::from_bits);
+                    let f = U4F4::from_bits(bits);
+                    assert_eq!(f.checked_round_dp(dp, mode), expected);
+                    assert_eq!(f.overflowing_round_dp(dp, mode), wrapped);
+                }
+            }
+        }
+
+        let f = U0F128::from_bits(0x5555_5555_5555_5555_5555_5555_5555_5555);
+        let nearest = RoundingMode::NearestTiesEven;
+        assert_eq!(f.round_dp(3, nearest), "0.333".parse::<U0F128>().unwrap());
+        assert_eq!(
+            f.round_dp(3, RoundingMode::Ceil),
+            "0.334".parse::<U0F128>().unwrap()
+        );
+        assert_eq!(f.checked_round_dp(0, RoundingMode::Ceil), None);
+        assert_eq!(f.saturating_round_dp(0, RoundingMode::Ceil), U0F128::MAX);
+        assert_eq!(
+            f.overflowing_round_dp(0, RoundingMode::Ceil),
+            (U0F128::ZERO, true)
+        );
+        assert_eq!(U0F128::MAX.checked_round_dp(30, RoundingMode::Ceil), None);
+        assert_eq!(U0F128::MAX.round_dp(128, RoundingMode::Ceil), U0F128::MAX);
+        let f = -I0F128::from_bits(0x5555_5555_5555_5555_5555_5555_5555_5555);
+        let expected = "-0.333334".parse::<I0F128>().unwrap();
+        assert_eq!(f.round_dp(6, RoundingMode::Floor), expected);
+        assert_eq!(I0F128::MIN.round_dp(1, RoundingMode::Floor), I0F128::MIN);
+        let f = "1234.567891".parse::<U64F64>().unwrap();
+        let expected = "1234.568".parse::<U64F64>().unwrap();
+        assert_eq!(f.round_dp(3, nearest), expected);
+        let expected = "1235".parse::<U64F64>().unwrap();
+        assert_eq!(f.round_dp(0, nearest), expected);
+    }
+
     #[test]
     fn reciprocals() {
         // 4/3 wraps to 1/3 = 0x0.5555_5555
@@ -66,7 +66,7 @@ assert_eq!(Fix::FRAC_NBITS, 6);
                 Self::FRAC_MASK ^ ((Self::FRAC_MASK as $UInner) >> 1) as $Inner;
 
             fixed_from_to! { $Fixed[$s_fixed]($Inner[$s_inner], $s_nbits), $Signedness }
-            fixed_round! { $Fixed[$s_fixed]($s_nbits), $Signedness }
+            fixed_round! { $Fixed[$s_fixed]($Inner, $s_nbits), $Signedness }
 
             comment! {
                 "Integer base-2 logarithm, rounded down.
@@ -250,6 +250,922 @@ assert_eq!(Fix::from_num(2).recip(), Fix::from_num(0.5));
                 }
             }
 
+            comment! {
+                "Returns the square root.
+
+The result is rounded to the nearest representable value. There are no
+ties, as the square root of a fixed-point number can never lie exactly
+half-way between two successive fixed-point numbers.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+Panics if the number is negative.
+
+When debug assertions are enabled, this method also panics if the
+square root overflows. Overflow can only occur when there are no
+integer bits and the number is ≥ 0.25. When debug assertions are not
+enabled, the wrapped value can be returned, but it is not considered a
+breaking change if in the future it panics; if wrapping is required
+use [`wrapping_sqrt`] instead.
+
+",
+                },
+                "# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.25).sqrt(), Fix::from_num(1.5));
+// √2 = 1.4142…, which is 22.627…/16, so it is rounded to 23/16
+assert_eq!(Fix::from_num(2).sqrt(), Fix::from_num(1.4375));
+```
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+[`wrapping_sqrt`]: Self::wrapping_sqrt
+",
+                },
+                "";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn sqrt(self) -> $Fixed<Frac> {
+                    let (ans, overflow) = self.overflowing_sqrt();
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Returns `self` raised to the integer power `n`.
+
+The power is computed using square-and-multiply with wide intermediate
+values, so that the result is rounded only once, to the nearest with
+ties rounded to even, instead of after every multiplication. Negative
+exponents are computed using the reciprocal.
+
+# Panics
+
+Panics if `self` is zero and `n` is negative.
+
+When debug assertions are enabled, this method also panics if the
+result overflows. When debug assertions are not enabled, the wrapped
+value can be returned, but it is not considered a breaking change if
+in the future it panics; if wrapping is required use [`wrapping_powi`]
+instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1.5).powi(3), Fix::from_num(3.375));
+assert_eq!(Fix::from_num(2).powi(-2), Fix::from_num(0.25));
+// 1/3 = 0.333…, which is 5.33…/16, so it is rounded to 5/16
+assert_eq!(Fix::from_num(3).powi(-1), Fix::from_num(0.3125));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1.5).powi(3), Fix::from_num(-3.375));
+",
+                },
+                "```
+
+[`wrapping_powi`]: Self::wrapping_powi
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn powi(self, n: i32) -> $Fixed<Frac> {
+                    let (ans, overflow) = self.overflowing_powi(n);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Hypotenuse, √(`self`² + `other`²).
+
+The squares and their sum are computed in double width, so that the
+intermediate values never overflow, even when the square of `self` or
+`other` does not fit. The result is rounded to the nearest
+representable value. There are no ties, as the result can never lie
+exactly half-way between two successive fixed-point numbers.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if saturation is required use [`saturating_hypot`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// √(3² + 4²) = 5
+assert_eq!(Fix::from_num(3).hypot(Fix::from_num(4)), Fix::from_num(5));
+// √(1² + 1²) = 1.4142…, which is 22.627…/16, so it is rounded to 23/16
+assert_eq!(Fix::ONE.hypot(Fix::ONE), Fix::from_num(1.4375));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-3).hypot(Fix::from_num(-4)), Fix::from_num(5));
+",
+                },
+                "```
+
+[`saturating_hypot`]: Self::saturating_hypot
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn hypot(self, other: $Fixed<Frac>) -> $Fixed<Frac> {
+                    let (ans, overflow) = hypot::$Inner(self.to_bits(), other.to_bits());
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Multiplication with the specified [rounding mode]. Returns the
+product `self` × `rhs`.
+
+The product is computed in double width, so that only the rounded result
+can overflow.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped value can be returned,
+but it is not considered a breaking change if in the future it panics; if
+overflow is possible use [`checked_mul_round`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+// 5/16 × 1/2 = 2.5/16
+let (a, b) = (Fix::from_bits(5), Fix::ONE / 2);
+assert_eq!(a.mul_round(b, RoundingMode::Floor), Fix::from_bits(2));
+assert_eq!(a.mul_round(b, RoundingMode::Ceil), Fix::from_bits(3));
+assert_eq!(a.mul_round(b, RoundingMode::NearestTiesEven), Fix::from_bits(2));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).mul_round(b, RoundingMode::TowardZero), Fix::from_bits(-2));
+assert_eq!((-a).mul_round(b, RoundingMode::NearestTiesAway), Fix::from_bits(-3));
+",
+                },
+                "```
+
+[`checked_mul_round`]: Self::checked_mul_round
+[rounding mode]: RoundingMode
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn mul_round(self, rhs: $Fixed<Frac>, mode: RoundingMode) -> $Fixed<Frac> {
+                    let (ans, overflow) =
+                        arith::overflowing_mul_round(self.to_bits(), rhs.to_bits(), Frac::U32, mode);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Division with the specified [rounding mode]. Returns the
+quotient `self` ÷ `rhs`.
+
+The quotient is computed from a double-width dividend, so that only the
+rounded result can overflow.
+
+# Panics
+
+Panics if the divisor is zero.
+
+When debug assertions are enabled, this method also panics if the
+result does not fit. When debug assertions are not enabled, the wrapped
+value can be returned, but it is not considered a breaking change if in
+the future it panics; if overflow is possible use [`checked_div_round`]
+instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+// 5/16 ÷ 2 = 2.5/16
+let (a, b) = (Fix::from_bits(5), Fix::from_num(2));
+assert_eq!(a.div_round(b, RoundingMode::Floor), Fix::from_bits(2));
+assert_eq!(a.div_round(b, RoundingMode::Ceil), Fix::from_bits(3));
+assert_eq!(a.div_round(b, RoundingMode::NearestTiesEven), Fix::from_bits(2));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).div_round(b, RoundingMode::TowardZero), Fix::from_bits(-2));
+assert_eq!((-a).div_round(b, RoundingMode::NearestTiesAway), Fix::from_bits(-3));
+",
+                },
+                "```
+
+[`checked_div_round`]: Self::checked_div_round
+[rounding mode]: RoundingMode
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn div_round(self, rhs: $Fixed<Frac>, mode: RoundingMode) -> $Fixed<Frac> {
+                    assert!(rhs.to_bits() != 0, "division by zero");
+                    let (ans, overflow) =
+                        arith::overflowing_div_round(self.to_bits(), rhs.to_bits(), Frac::U32, mode);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Multiplication rounding down. Returns the product rounded towards −∞.
+
+This is the rounding used by the `*` operator. This is the same as
+[`mul_round`] with [`RoundingMode::Floor`].
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped value can be returned,
+but it is not considered a breaking change if in the future it panics; if
+overflow is possible use [`checked_mul_floor`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 3/16 × 1/2 = 1.5/16
+assert_eq!(Fix::from_bits(3).mul_floor(Fix::ONE / 2), Fix::from_bits(1));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// −3/16 × 1/2 = −1.5/16
+assert_eq!(Fix::from_bits(-3).mul_floor(Fix::ONE / 2), Fix::from_bits(-2));
+",
+                },
+                "```
+
+[`checked_mul_floor`]: Self::checked_mul_floor
+[`mul_round`]: Self::mul_round
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn mul_floor(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                    self.mul_round(rhs, RoundingMode::Floor)
+                }
+            }
+
+            comment! {
+                "Multiplication rounding up. Returns the product rounded towards +∞.
+
+This is the same as [`mul_round`] with [`RoundingMode::Ceil`].
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped value can be returned,
+but it is not considered a breaking change if in the future it panics; if
+overflow is possible use [`checked_mul_ceil`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 3/16 × 1/2 = 1.5/16
+assert_eq!(Fix::from_bits(3).mul_ceil(Fix::ONE / 2), Fix::from_bits(2));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// −3/16 × 1/2 = −1.5/16
+assert_eq!(Fix::from_bits(-3).mul_ceil(Fix::ONE / 2), Fix::from_bits(-1));
+",
+                },
+                "```
+
+[`checked_mul_ceil`]: Self::checked_mul_ceil
+[`mul_round`]: Self::mul_round
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn mul_ceil(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                    self.mul_round(rhs, RoundingMode::Ceil)
+                }
+            }
+
+            comment! {
+                "Division rounding down. Returns the quotient rounded towards −∞.
+
+This is the same as [`div_round`] with [`RoundingMode::Floor`]. Unlike
+the `/` operator, which truncates towards zero, the rounding direction does
+not depend on the signs of the operands.
+
+# Panics
+
+Panics if the divisor is zero.
+
+When debug assertions are enabled, this method also panics if the
+result does not fit. When debug assertions are not enabled, the wrapped
+value can be returned, but it is not considered a breaking change if in
+the future it panics; if overflow is possible use [`checked_div_floor`]
+instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1/16 ÷ 3 = 0.33…/16
+assert_eq!(Fix::from_bits(1).div_floor(Fix::from_num(3)), Fix::from_bits(0));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// −1/16 ÷ 3 = −0.33…/16
+assert_eq!(Fix::from_bits(-1).div_floor(Fix::from_num(3)), Fix::from_bits(-1));
+",
+                },
+                "```
+
+[`checked_div_floor`]: Self::checked_div_floor
+[`div_round`]: Self::div_round
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn div_floor(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                    self.div_round(rhs, RoundingMode::Floor)
+                }
+            }
+
+            comment! {
+                "Division rounding up. Returns the quotient rounded towards +∞.
+
+This is the same as [`div_round`] with [`RoundingMode::Ceil`]. Unlike
+the `/` operator, which truncates towards zero, the rounding direction does
+not depend on the signs of the operands.
+
+# Panics
+
+Panics if the divisor is zero.
+
+When debug assertions are enabled, this method also panics if the
+result does not fit. When debug assertions are not enabled, the wrapped
+value can be returned, but it is not considered a breaking change if in
+the future it panics; if overflow is possible use [`checked_div_ceil`]
+instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1/16 ÷ 3 = 0.33…/16
+assert_eq!(Fix::from_bits(1).div_ceil(Fix::from_num(3)), Fix::from_bits(1));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// −1/16 ÷ 3 = −0.33…/16
+assert_eq!(Fix::from_bits(-1).div_ceil(Fix::from_num(3)), Fix::from_bits(0));
+",
+                },
+                "```
+
+[`checked_div_ceil`]: Self::checked_div_ceil
+[`div_round`]: Self::div_round
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn div_ceil(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                    self.div_round(rhs, RoundingMode::Ceil)
+                }
+            }
+
+            comment! {
+                "Multiplication that also returns whether the product is inexact.
+
+Returns a [tuple] of the product and a [`bool`] indicating whether the
+product had to be rounded. The product is rounded towards −∞, which is the
+rounding used by the `*` operator.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped value can be returned,
+but it is not considered a breaking change if in the future it panics; if
+overflow is possible use [`checked_mul`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1.5).mul_exact(Fix::from_num(2)), (Fix::from_num(3), false));
+// 3/16 × 1/2 = 1.5/16
+assert_eq!(Fix::from_bits(3).mul_exact(Fix::ONE / 2), (Fix::from_bits(1), true));
+```
+
+[`checked_mul`]: Self::checked_mul
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn mul_exact(self, rhs: $Fixed<Frac>) -> ($Fixed<Frac>, bool) {
+                    let (ans, overflow, inexact) = arith::overflowing_mul_exact(
+                        self.to_bits(),
+                        rhs.to_bits(),
+                        Frac::U32,
+                        RoundingMode::Floor,
+                    );
+                    debug_assert!(!overflow, "overflow");
+                    (Self::from_bits(ans), inexact)
+                }
+            }
+
+            comment! {
+                "Division that also returns whether the quotient is inexact.
+
+Returns a [tuple] of the quotient and a [`bool`] indicating whether the
+quotient had to be rounded. The quotient is rounded towards zero, which is the
+rounding used by the `/` operator.
+
+# Panics
+
+Panics if the divisor is zero.
+
+When debug assertions are enabled, this method also panics if the
+result does not fit. When debug assertions are not enabled, the wrapped
+value can be returned, but it is not considered a breaking change if in
+the future it panics; if overflow is possible use [`checked_div`]
+instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(3).div_exact(Fix::from_num(2)), (Fix::from_num(1.5), false));
+// 1/3 = 5.33/16
+assert_eq!(Fix::ONE.div_exact(Fix::from_num(3)), (Fix::from_bits(5), true));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-Fix::ONE).div_exact(Fix::from_num(3)), (Fix::from_bits(-5), true));
+",
+                },
+                "```
+
+[`checked_div`]: Self::checked_div
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn div_exact(self, rhs: $Fixed<Frac>) -> ($Fixed<Frac>, bool) {
+                    assert!(rhs.to_bits() != 0, "division by zero");
+                    let (ans, overflow, inexact) = arith::overflowing_div_exact(
+                        self.to_bits(),
+                        rhs.to_bits(),
+                        Frac::U32,
+                        RoundingMode::TowardZero,
+                    );
+                    debug_assert!(!overflow, "overflow");
+                    (Self::from_bits(ans), inexact)
+                }
+            }
+
+            comment! {
+                "Multiplies two fixed-point numbers and converts the product
+to the fixed-point type `Dst`.
+
+The two operands can have different fixed-point types, including different
+widths and signedness. The product is computed exactly and then rounded once
+to `Dst`: any extra fractional bits are discarded, which rounds towards −∞
+like the `*` operator. For operands with up to 64 bits, [`MulOutput`] gives
+a destination type that holds the product exactly. For 128-bit operands that
+type is a 256-bit number, which cannot be used as `Dst`, so the product is
+rounded or overflows unless it fits in `Dst`.
+
+# Panics
+
+When debug assertions are enabled, panics if the product does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if overflow is possible use [`checked_mul_into`] instead.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U2, U3, U4},
+    ", $s_fixed, ",
+};
+let a = ", $s_fixed, "::<U4>::from_num(1.25);
+let b = ", $s_fixed, "::<U2>::from_num(1.5);
+// 1.25 × 1.5 = 1.875 is exact with three fractional bits
+assert_eq!(a.mul_into::<", $s_fixed, "<U3>, _>(b), 1.875);
+// with two fractional bits, the product is rounded down
+let c: ", $s_fixed, "<U2> = a.mul_into(b);
+assert_eq!(c, 1.75);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "let d: ", $s_fixed, "<U2> = (-a).mul_into(b);
+assert_eq!(d, -2);
+",
+                },
+                "```
+
+[`MulOutput`]: crate::traits::MulOutput
+[`checked_mul_into`]: Self::checked_mul_into
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn mul_into<Dst: Fixed, Rhs: Fixed>(self, rhs: Rhs) -> Dst {
+                    let lhs = self.private_to_float_helper();
+                    let rhs = rhs.private_to_float_helper();
+                    let conv = helpers::ToFixedHelper::from_mul_floor(
+                        lhs.neg != rhs.neg,
+                        lhs.abs,
+                        rhs.abs,
+                        Self::FRAC_NBITS + Rhs::FRAC_NBITS,
+                        Dst::FRAC_NBITS,
+                        Dst::INT_NBITS,
+                    );
+                    let (wrapped, overflow) = Dst::private_overflowing_from_helper(conv);
+                    debug_assert!(!overflow, "overflow");
+                    let _ = overflow;
+                    wrapped
+                }
+            }
+
+            comment! {
+                "Checked multiplication into the fixed-point type `Dst`.
+Returns the product rounded towards −∞, or [`None`] on overflow.
+
+See [`mul_into`] for more information.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U2, U4},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+type Dst = ", $s_fixed, "<U2>;
+let two = ", $s_fixed, "::<U2>::from_num(2);
+assert_eq!(Fix::from_num(1.25).checked_mul_into::<Dst, _>(two), Some(Dst::from_num(2.5)));
+assert_eq!(Fix::MAX.checked_mul_into::<Fix, _>(two), None);
+```
+
+[`mul_into`]: Self::mul_into
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_mul_into<Dst: Fixed, Rhs: Fixed>(self, rhs: Rhs) -> Option<Dst> {
+                    let lhs = self.private_to_float_helper();
+                    let rhs = rhs.private_to_float_helper();
+                    let conv = helpers::ToFixedHelper::from_mul_floor(
+                        lhs.neg != rhs.neg,
+                        lhs.abs,
+                        rhs.abs,
+                        Self::FRAC_NBITS + Rhs::FRAC_NBITS,
+                        Dst::FRAC_NBITS,
+                        Dst::INT_NBITS,
+                    );
+                    match Dst::private_overflowing_from_helper(conv) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating multiplication into the fixed-point type `Dst`.
+Returns the product rounded towards −∞, saturating on overflow.
+
+See [`mul_into`] for more information.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U2, U4},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+type Dst = ", $s_fixed, "<U2>;
+let two = ", $s_fixed, "::<U2>::from_num(2);
+assert_eq!(Fix::from_num(1.25).saturating_mul_into::<Dst, _>(two), 2.5);
+assert_eq!(Fix::MAX.saturating_mul_into::<Fix, _>(two), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MAX.saturating_mul_into::<Fix, _>(-two), Fix::MIN);
+",
+                },
+                "```
+
+[`mul_into`]: Self::mul_into
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_mul_into<Dst: Fixed, Rhs: Fixed>(self, rhs: Rhs) -> Dst {
+                    let lhs = self.private_to_float_helper();
+                    let rhs = rhs.private_to_float_helper();
+                    let neg = lhs.neg != rhs.neg;
+                    let conv = helpers::ToFixedHelper::from_mul_floor(
+                        neg,
+                        lhs.abs,
+                        rhs.abs,
+                        Self::FRAC_NBITS + Rhs::FRAC_NBITS,
+                        Dst::FRAC_NBITS,
+                        Dst::INT_NBITS,
+                    );
+                    Dst::private_saturating_from_helper(neg, conv)
+                }
+            }
+
+            comment! {
+                "Multiplication and division with a single rounding.
+Returns `self` × `mul` ÷ `div` rounded towards zero.
+
+The product `self` × `mul` is computed in double width, so that only the final
+result can overflow. The quotient is rounded towards zero, which is the rounding
+used by the `/` operator.
+
+# Panics
+
+Panics if `div` is zero.
+
+When debug assertions are enabled, this method also panics if the
+result does not fit. When debug assertions are not enabled, the wrapped
+value can be returned, but it is not considered a breaking change if in
+the future it panics; if overflow is possible use [`checked_mul_div`]
+instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1.5 × 3 ÷ 2.25 = 2
+let ans = Fix::from_num(1.5).mul_div(Fix::from_num(3), Fix::from_num(2.25));
+assert_eq!(ans, 2);
+// 5/16 × 1/2 ÷ 2 = 1.25/16
+assert_eq!(Fix::from_bits(5).mul_div(Fix::ONE / 2, Fix::from_num(2)), Fix::from_bits(1));
+// the product MAX × 2 would overflow, but the result fits
+assert_eq!(Fix::MAX.mul_div(Fix::from_num(2), Fix::from_num(2)), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// −5/16 × 1/2 ÷ 2 = −1.25/16
+assert_eq!(Fix::from_bits(-5).mul_div(Fix::ONE / 2, Fix::from_num(2)), Fix::from_bits(-1));
+",
+                },
+                "```
+
+[`checked_mul_div`]: Self::checked_mul_div
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn mul_div(self, mul: $Fixed<Frac>, div: $Fixed<Frac>) -> $Fixed<Frac> {
+                    self.mul_div_round(mul, div, RoundingMode::TowardZero)
+                }
+            }
+
+            comment! {
+                "Multiplication and division with a single rounding using
+the specified [rounding mode]. Returns `self` × `mul` ÷ `div`.
+
+See [`mul_div`] for more information.
+
+# Panics
+
+Panics if `div` is zero.
+
+When debug assertions are enabled, this method also panics if the
+result does not fit. When debug assertions are not enabled, the wrapped
+value can be returned, but it is not considered a breaking change if in
+the future it panics; if overflow is possible use
+[`checked_mul_div_round`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+// 5/16 × 1/2 ÷ 2 = 1.25/16
+let (a, mul, div) = (Fix::from_bits(5), Fix::ONE / 2, Fix::from_num(2));
+assert_eq!(a.mul_div_round(mul, div, RoundingMode::Floor), Fix::from_bits(1));
+assert_eq!(a.mul_div_round(mul, div, RoundingMode::Ceil), Fix::from_bits(2));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).mul_div_round(mul, div, RoundingMode::Floor), Fix::from_bits(-2));
+assert_eq!((-a).mul_div_round(mul, div, RoundingMode::Ceil), Fix::from_bits(-1));
+",
+                },
+                "```
+
+[`checked_mul_div_round`]: Self::checked_mul_div_round
+[`mul_div`]: Self::mul_div
+[rounding mode]: RoundingMode
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn mul_div_round(
+                    self,
+                    mul: $Fixed<Frac>,
+                    div: $Fixed<Frac>,
+                    mode: RoundingMode,
+                ) -> $Fixed<Frac> {
+                    assert!(div.to_bits() != 0, "division by zero");
+                    let (ans, overflow) = arith::overflowing_mul_div_round(
+                        self.to_bits(),
+                        mul.to_bits(),
+                        div.to_bits(),
+                        mode,
+                    );
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Checked multiplication and division with a single
+rounding. Returns `self` × `mul` ÷ `div` rounded towards zero, or [`None`] if
+`div` is zero or on overflow.
+
+See [`mul_div`] for more information.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let two = Fix::from_num(2);
+assert_eq!(Fix::MAX.checked_mul_div(two, two), Some(Fix::MAX));
+assert_eq!(Fix::MAX.checked_mul_div(two, Fix::ONE), None);
+assert_eq!(Fix::ONE.checked_mul_div(two, Fix::ZERO), None);
+```
+
+[`mul_div`]: Self::mul_div
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_mul_div(
+                    self,
+                    mul: $Fixed<Frac>,
+                    div: $Fixed<Frac>,
+                ) -> Option<$Fixed<Frac>> {
+                    self.checked_mul_div_round(mul, div, RoundingMode::TowardZero)
+                }
+            }
+
+            comment! {
+                "Checked multiplication and division with a single
+rounding using the specified [rounding mode]. Returns `self` × `mul` ÷ `div`,
+or [`None`] if `div` is zero or on overflow.
+
+See [`mul_div`] for more information.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+let (a, mul, div) = (Fix::from_bits(5), Fix::ONE / 2, Fix::from_num(2));
+let ans = a.checked_mul_div_round(mul, div, RoundingMode::Ceil);
+assert_eq!(ans, Some(Fix::from_bits(2)));
+let ans = Fix::MAX.checked_mul_div_round(mul, Fix::DELTA, RoundingMode::Floor);
+assert_eq!(ans, None);
+```
+
+[`mul_div`]: Self::mul_div
+[rounding mode]: RoundingMode
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_mul_div_round(
+                    self,
+                    mul: $Fixed<Frac>,
+                    div: $Fixed<Frac>,
+                    mode: RoundingMode,
+                ) -> Option<$Fixed<Frac>> {
+                    if div.to_bits() == 0 {
+                        return None;
+                    }
+                    match arith::overflowing_mul_div_round(
+                        self.to_bits(),
+                        mul.to_bits(),
+                        div.to_bits(),
+                        mode,
+                    ) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating multiplication and division with a single
+rounding. Returns `self` × `mul` ÷ `div` rounded towards zero, saturating on
+overflow.
+
+See [`mul_div`] for more information.
+
+# Panics
+
+Panics if `div` is zero.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let two = Fix::from_num(2);
+assert_eq!(Fix::MAX.saturating_mul_div(two, two), Fix::MAX);
+assert_eq!(Fix::MAX.saturating_mul_div(two, Fix::ONE), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MAX.saturating_mul_div(two, -Fix::ONE), Fix::MIN);
+",
+                },
+                "```
+
+[`mul_div`]: Self::mul_div
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_mul_div(
+                    self,
+                    mul: $Fixed<Frac>,
+                    div: $Fixed<Frac>,
+                ) -> $Fixed<Frac> {
+                    self.saturating_mul_div_round(mul, div, RoundingMode::TowardZero)
+                }
+            }
+
+            comment! {
+                "Saturating multiplication and division with a single
+rounding using the specified [rounding mode]. Returns `self` × `mul` ÷ `div`,
+saturating on overflow.
+
+See [`mul_div`] for more information.
+
+# Panics
+
+Panics if `div` is zero.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+let (a, mul, div) = (Fix::from_bits(5), Fix::ONE / 2, Fix::from_num(2));
+let ans = a.saturating_mul_div_round(mul, div, RoundingMode::Ceil);
+assert_eq!(ans, Fix::from_bits(2));
+let ans = Fix::MAX.saturating_mul_div_round(mul, Fix::DELTA, RoundingMode::Floor);
+assert_eq!(ans, Fix::MAX);
+```
+
+[`mul_div`]: Self::mul_div
+[rounding mode]: RoundingMode
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_mul_div_round(
+                    self,
+                    mul: $Fixed<Frac>,
+                    div: $Fixed<Frac>,
+                    mode: RoundingMode,
+                ) -> $Fixed<Frac> {
+                    assert!(div.to_bits() != 0, "division by zero");
+                    match arith::overflowing_mul_div_round(
+                        self.to_bits(),
+                        mul.to_bits(),
+                        div.to_bits(),
+                        mode,
+                    ) {
+                        (ans, false) => Self::from_bits(ans),
+                        (_, true) => {
+                            if ((self < 0) != (mul < 0)) != (div < 0) {
+                                Self::MIN
+                            } else {
+                                Self::MAX
+                            }
+                        }
+                    }
+                }
+            }
+
             comment! {
                 "Euclidean division.
 
@@ -383,59 +1299,157 @@ assert_eq!(acc, 5);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "
-// MAX × 1.5 − MAX = MAX / 2, which does not overflow
-acc = -Fix::MAX;
-acc.mul_acc(Fix::MAX, Fix::from_num(1.5));
-assert_eq!(acc, Fix::MAX / 2);
-"
+                    "
+// MAX × 1.5 − MAX = MAX / 2, which does not overflow
+acc = -Fix::MAX;
+acc.mul_acc(Fix::MAX, Fix::from_num(1.5));
+assert_eq!(acc, Fix::MAX / 2);
+"
+                },
+                "```
+
+[`wrapping_mul_acc`]: Self::wrapping_mul_acc
+";
+                #[inline]
+                pub fn mul_acc<AFrac: $LeEqU, BFrac: $LeEqU>(
+                    &mut self,
+                    a: $Fixed<AFrac>,
+                    b: $Fixed<BFrac>,
+                ) {
+                    let (ans, overflow) = arith::overflowing_mul_add(
+                        a.to_bits(),
+                        b.to_bits(),
+                        self.to_bits(),
+                        AFrac::I32 + BFrac::I32 - Frac::I32,
+                    );
+                    debug_assert!(!overflow, "overflow");
+                    *self = Self::from_bits(ans);
+                }
+            }
+
+            comment! {
+                "Remainder for Euclidean division by an integer.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(7.5).rem_euclid_int(2), Fix::from_num(1.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).rem_euclid_int(2), Fix::from_num(0.5));
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn rem_euclid_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                    let (ans, overflow) = self.overflowing_rem_euclid_int(rhs);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Adds an integer to the fixed-point number.
+
+The integer `rhs` is scaled by 2<sup><i>f</i></sup>, where <i>f</i> is the
+number of fractional bits, so the result is `self` + `rhs`.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, the scaled integer would overflow on
+its own, but the final result is representable; in these cases this method
+returns the correct result without overflow.
+
+",
+                },
+                "# Panics
+
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value
+can be returned, but it is not considered a breaking change if in the
+future it panics; if wrapping is required use [`wrapping_add_int`]
+instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+assert_eq!(Fix::from_num(1.5).add_int(2), Fix::from_num(3.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// −8 ≤ Fix < 8, so 8 does not fit on its own, but the sum 0.5 does
+assert_eq!(Fix::from_num(-7.5).add_int(8), Fix::from_num(0.5));
+",
                 },
                 "```
 
-[`wrapping_mul_acc`]: Self::wrapping_mul_acc
+[`wrapping_add_int`]: Self::wrapping_add_int
 ";
                 #[inline]
-                pub fn mul_acc<AFrac: $LeEqU, BFrac: $LeEqU>(
-                    &mut self,
-                    a: $Fixed<AFrac>,
-                    b: $Fixed<BFrac>,
-                ) {
-                    let (ans, overflow) = arith::overflowing_mul_add(
-                        a.to_bits(),
-                        b.to_bits(),
-                        self.to_bits(),
-                        AFrac::I32 + BFrac::I32 - Frac::I32,
-                    );
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn add_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                    let (ans, overflow) = self.overflowing_add_int(rhs);
                     debug_assert!(!overflow, "overflow");
-                    *self = Self::from_bits(ans);
+                    ans
                 }
             }
 
             comment! {
-                "Remainder for Euclidean division by an integer.
+                "Subtracts an integer from the fixed-point number.
 
-# Panics
+The integer `rhs` is scaled by 2<sup><i>f</i></sup>, where <i>f</i> is the
+number of fractional bits, so the result is `self` − `rhs`.
 
-Panics if the divisor is zero.
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, the scaled integer would overflow on
+its own, but the final result is representable; in these cases this method
+returns the correct result without overflow.
+
+",
+                },
+                "# Panics
+
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value
+can be returned, but it is not considered a breaking change if in the
+future it panics; if wrapping is required use [`wrapping_sub_int`]
+instead.
 
 # Examples
 
 ```rust
-use fixed::{types::extra::U4, ", $s_fixed, "};
-type Fix = ", $s_fixed, "<U4>;
-assert_eq!(Fix::from_num(7.5).rem_euclid_int(2), Fix::from_num(1.5));
+use fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+assert_eq!(Fix::from_num(3.5).sub_int(2), Fix::from_num(1.5));
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).rem_euclid_int(2), Fix::from_num(0.5));
+                    "// −8 ≤ Fix < 8, so 8 does not fit on its own, but the difference −0.5 does
+assert_eq!(Fix::from_num(7.5).sub_int(8), Fix::from_num(-0.5));
 ",
                 },
                 "```
+
+[`wrapping_sub_int`]: Self::wrapping_sub_int
 ";
                 #[inline]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub fn rem_euclid_int(self, rhs: $Inner) -> $Fixed<Frac> {
-                    let (ans, overflow) = self.overflowing_rem_euclid_int(rhs);
+                pub fn sub_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                    let (ans, overflow) = self.overflowing_sub_int(rhs);
                     debug_assert!(!overflow, "overflow");
                     ans
                 }
@@ -585,28 +1599,343 @@ assert_eq!(Fix::MAX.checked_div(Fix::ONE / 2), None);
             }
 
             comment! {
-                "Checked reciprocal. Returns the reciprocal, or
-[`None`] if `self` is zero or on overflow.
+                "Checked reciprocal. Returns the reciprocal, or
+[`None`] if `self` is zero or on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2).checked_recip(), Some(Fix::from_num(0.5)));
+assert_eq!(Fix::ZERO.checked_recip(), None);
+```
+";
+                #[inline]
+                pub fn checked_recip(self) -> Option<$Fixed<Frac>> {
+                    if self.to_bits() == 0 {
+                        None
+                    } else {
+                        match self.overflowing_recip() {
+                            (ans, false) => Some(ans),
+                            (_, true) => None,
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Checked square root. Returns the square root, or
+[`None`] ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "if the number is negative or on overflow",
+                    "on overflow, which can never happen for unsigned numbers",
+                ),
+                ".
+
+The result is rounded to the nearest representable value.
+
+# Examples
+
+```rust
+use fixed::{",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+"),
+                    concat!("types::extra::U4, ", $s_fixed),
+                ),
+                "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.25).checked_sqrt(), Some(Fix::from_num(1.5)));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1).checked_sqrt(), None);
+// no integer bits, so √0.25 = 0.5 overflows
+type ZeroIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+assert_eq!(ZeroIntBits::from_num(0.25).checked_sqrt(), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_sqrt(self) -> Option<$Fixed<Frac>> {
+                    if_signed! {
+                        $Signedness;
+                        if self.is_negative() {
+                            return None;
+                        }
+                    }
+                    match sqrt::$Inner(self.to_bits(), Frac::U32) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked power. Returns `self` raised to the integer
+power `n`, or [`None`] if `self` is zero and `n` is negative or on
+overflow.
+
+Like [`powi`], the power is rounded only once.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2).checked_powi(-1), Some(Fix::from_num(0.5)));
+assert_eq!(Fix::ZERO.checked_powi(-1), None);
+assert_eq!(Fix::MAX.checked_powi(2), None);
+```
+
+[`powi`]: Self::powi
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_powi(self, n: i32) -> Option<$Fixed<Frac>> {
+                    if n < 0 && self.to_bits() == 0 {
+                        return None;
+                    }
+                    match pow::powi_bits(self.to_bits(), Frac::U32, n) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked hypotenuse. Returns √(`self`² + `other`²), or
+[`None`] on overflow.
+
+The squares and their sum are computed in double width, so that the
+intermediate values never overflow; only the result itself can
+overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(
+    Fix::from_num(3).checked_hypot(Fix::from_num(4)),
+    Some(Fix::from_num(5))
+);
+assert_eq!(Fix::MAX.checked_hypot(Fix::MAX), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_hypot(self, other: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                    match hypot::$Inner(self.to_bits(), other.to_bits()) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked multiplication with the specified [rounding mode].
+Returns the product `self` × `rhs`, or [`None`] on overflow.
+
+See [`mul_round`] for more information.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+let (a, b) = (Fix::from_bits(5), Fix::ONE / 2);
+assert_eq!(a.checked_mul_round(b, RoundingMode::Ceil), Some(Fix::from_bits(3)));
+assert_eq!(Fix::MAX.checked_mul_round(Fix::from_num(2), RoundingMode::Floor), None);
+```
+
+[`mul_round`]: Self::mul_round
+[rounding mode]: RoundingMode
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_mul_round(
+                    self,
+                    rhs: $Fixed<Frac>,
+                    mode: RoundingMode,
+                ) -> Option<$Fixed<Frac>> {
+                    let bits = (self.to_bits(), rhs.to_bits());
+                    match arith::overflowing_mul_round(bits.0, bits.1, Frac::U32, mode) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked division with the specified [rounding mode].
+Returns the quotient `self` ÷ `rhs`, or [`None`] if the divisor is zero or
+on overflow.
+
+See [`div_round`] for more information.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+let (a, b) = (Fix::from_bits(5), Fix::from_num(2));
+assert_eq!(a.checked_div_round(b, RoundingMode::Ceil), Some(Fix::from_bits(3)));
+assert_eq!(Fix::MAX.checked_div_round(Fix::ONE / 2, RoundingMode::Floor), None);
+assert_eq!(a.checked_div_round(Fix::ZERO, RoundingMode::Floor), None);
+```
+
+[`div_round`]: Self::div_round
+[rounding mode]: RoundingMode
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_div_round(
+                    self,
+                    rhs: $Fixed<Frac>,
+                    mode: RoundingMode,
+                ) -> Option<$Fixed<Frac>> {
+                    if rhs.to_bits() == 0 {
+                        return None;
+                    }
+                    let bits = (self.to_bits(), rhs.to_bits());
+                    match arith::overflowing_div_round(bits.0, bits.1, Frac::U32, mode) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked multiplication rounding down. Returns the product rounded
+towards −∞, or [`None`] on overflow.
+
+This is the same as [`checked_mul_round`] with
+[`RoundingMode::Floor`].
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_bits(3).checked_mul_floor(Fix::ONE / 2), Some(Fix::from_bits(1)));
+assert_eq!(Fix::MAX.checked_mul_floor(Fix::from_num(2)), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_bits(-3).checked_mul_floor(Fix::ONE / 2), Some(Fix::from_bits(-2)));
+",
+                },
+                "```
+
+[`checked_mul_round`]: Self::checked_mul_round
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_mul_floor(self, rhs: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                    self.checked_mul_round(rhs, RoundingMode::Floor)
+                }
+            }
+
+            comment! {
+                "Checked multiplication rounding up. Returns the product rounded
+towards +∞, or [`None`] on overflow.
+
+This is the same as [`checked_mul_round`] with
+[`RoundingMode::Ceil`].
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_bits(3).checked_mul_ceil(Fix::ONE / 2), Some(Fix::from_bits(2)));
+assert_eq!(Fix::MAX.checked_mul_ceil(Fix::from_num(2)), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_bits(-3).checked_mul_ceil(Fix::ONE / 2), Some(Fix::from_bits(-1)));
+",
+                },
+                "```
+
+[`checked_mul_round`]: Self::checked_mul_round
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_mul_ceil(self, rhs: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                    self.checked_mul_round(rhs, RoundingMode::Ceil)
+                }
+            }
+
+            comment! {
+                "Checked division rounding down. Returns the quotient rounded
+towards −∞, or [`None`] if the divisor is zero or on overflow.
+
+This is the same as [`checked_div_round`] with
+[`RoundingMode::Floor`].
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_bits(1).checked_div_floor(Fix::from_num(3)), Some(Fix::from_bits(0)));
+assert_eq!(Fix::MAX.checked_div_floor(Fix::ONE / 2), None);
+assert_eq!(Fix::ONE.checked_div_floor(Fix::ZERO), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_bits(-1).checked_div_floor(Fix::from_num(3)), Some(Fix::from_bits(-1)));
+",
+                },
+                "```
+
+[`checked_div_round`]: Self::checked_div_round
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_div_floor(self, rhs: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                    self.checked_div_round(rhs, RoundingMode::Floor)
+                }
+            }
+
+            comment! {
+                "Checked division rounding up. Returns the quotient rounded
+towards +∞, or [`None`] if the divisor is zero or on overflow.
+
+This is the same as [`checked_div_round`] with
+[`RoundingMode::Ceil`].
 
 # Examples
 
 ```rust
 use fixed::{types::extra::U4, ", $s_fixed, "};
 type Fix = ", $s_fixed, "<U4>;
-assert_eq!(Fix::from_num(2).checked_recip(), Some(Fix::from_num(0.5)));
-assert_eq!(Fix::ZERO.checked_recip(), None);
-```
+assert_eq!(Fix::from_bits(1).checked_div_ceil(Fix::from_num(3)), Some(Fix::from_bits(1)));
+assert_eq!(Fix::MAX.checked_div_ceil(Fix::ONE / 2), None);
+assert_eq!(Fix::ONE.checked_div_ceil(Fix::ZERO), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_bits(-1).checked_div_ceil(Fix::from_num(3)), Some(Fix::from_bits(0)));
+",
+                },
+                "```
+
+[`checked_div_round`]: Self::checked_div_round
 ";
                 #[inline]
-                pub fn checked_recip(self) -> Option<$Fixed<Frac>> {
-                    if self.to_bits() == 0 {
-                        None
-                    } else {
-                        match self.overflowing_recip() {
-                            (ans, false) => Some(ans),
-                            (_, true) => None,
-                        }
-                    }
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_div_ceil(self, rhs: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                    self.checked_div_round(rhs, RoundingMode::Ceil)
                 }
             }
 
@@ -865,6 +2194,73 @@ assert_eq!(Fix::from_num(-7.5).checked_rem_euclid_int(20), None);
                 }
             }
 
+            comment! {
+                "Checked addition of an integer. Returns the sum, or [`None`] on overflow.
+
+See [`add_int`] for more information.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+assert_eq!(Fix::from_num(1.5).checked_add_int(2), Some(Fix::from_num(3.5)));
+assert_eq!(Fix::MAX.checked_add_int(1), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// −8 ≤ Fix < 8, so 8 does not fit on its own, but the sum 0.5 does
+assert_eq!(Fix::from_num(-7.5).checked_add_int(8), Some(Fix::from_num(0.5)));
+",
+                },
+                "```
+
+[`add_int`]: Self::add_int
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_add_int(self, rhs: $Inner) -> Option<$Fixed<Frac>> {
+                    match self.overflowing_add_int(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked subtraction of an integer. Returns the difference, or [`None`]
+on overflow.
+
+See [`sub_int`] for more information.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+assert_eq!(Fix::from_num(3.5).checked_sub_int(2), Some(Fix::from_num(1.5)));
+assert_eq!(Fix::MIN.checked_sub_int(1), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// −8 ≤ Fix < 8, so 8 does not fit on its own, but the difference −0.5 does
+assert_eq!(Fix::from_num(7.5).checked_sub_int(8), Some(Fix::from_num(-0.5)));
+",
+                },
+                "```
+
+[`sub_int`]: Self::sub_int
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_sub_int(self, rhs: $Inner) -> Option<$Fixed<Frac>> {
+                    match self.overflowing_sub_int(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
             comment! {
                 "Checked linear interpolation between `start` and `end`. Returns
 [`None`] on overflow.
@@ -927,33 +2323,267 @@ assert_eq!(ZeroIntBits::from_num(0.25).saturating_signum(), ZeroIntBits::MAX);
 assert_eq!(ZeroIntBits::from_num(-0.5).saturating_signum(), ZeroIntBits::MIN);
 ```
 ";
-                    #[inline]
-                    pub fn saturating_signum(self) -> $Fixed<Frac> {
-                        match self.to_bits().cmp(&0) {
-                            Ordering::Equal => Self::ZERO,
-                            Ordering::Greater => Self::saturating_from_num(1),
-                            Ordering::Less => Self::saturating_from_num(-1),
-                        }
+                    #[inline]
+                    pub fn saturating_signum(self) -> $Fixed<Frac> {
+                        match self.to_bits().cmp(&0) {
+                            Ordering::Equal => Self::ZERO,
+                            Ordering::Greater => Self::saturating_from_num(1),
+                            Ordering::Less => Self::saturating_from_num(-1),
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating multiplication. Returns the product, saturating on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(3).saturating_mul(Fix::from_num(2)), Fix::from_num(6));
+assert_eq!(Fix::MAX.saturating_mul(Fix::from_num(2)), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_mul(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                    match arith::overflowing_mul(self.to_bits(), rhs.to_bits(), Frac::U32) {
+                        (ans, false) => Self::from_bits(ans),
+                        (_, true) => {
+                            if (self < 0) != (rhs < 0) {
+                                Self::MIN
+                            } else {
+                                Self::MAX
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating division. Returns the quotient, saturating on overflow.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let one_half = Fix::ONE / 2;
+assert_eq!(Fix::ONE.saturating_div(Fix::from_num(2)), one_half);
+assert_eq!(Fix::MAX.saturating_div(one_half), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_div(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                    match arith::overflowing_div(self.to_bits(), rhs.to_bits(), Frac::U32) {
+                        (ans, false) => Self::from_bits(ans),
+                        (_, true) => {
+                            if (self < 0) != (rhs < 0) {
+                                Self::MIN
+                            } else {
+                                Self::MAX
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating reciprocal. Returns the reciprocal,
+saturating on overflow.
+
+# Panics
+
+Panics if the fixed-point number is zero.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U", $s_nbits_m1, ", ", $s_fixed, "};
+// only one integer bit
+type Fix = ", $s_fixed, "<U", $s_nbits_m1, ">;
+assert_eq!(Fix::from_num(0.25).saturating_recip(), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-0.25).saturating_recip(), Fix::MIN);
+",
+                },
+                "```
+";
+                #[inline]
+                pub fn saturating_recip(self) -> $Fixed<Frac> {
+                    match self.overflowing_recip() {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if self < 0 {
+                                Self::MIN
+                            } else {
+                                Self::MAX
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating square root. Returns the square root,
+saturating on overflow.
+
+The result is rounded to the nearest representable value.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+Panics if the number is negative.
+
+",
+                },
+                "# Examples
+
+```rust
+use fixed::{",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+"),
+                    concat!("types::extra::U4, ", $s_fixed),
+                ),
+                "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.25).saturating_sqrt(), Fix::from_num(1.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// no integer bits, so √0.25 = 0.5 overflows
+type ZeroIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+assert_eq!(ZeroIntBits::from_num(0.25).saturating_sqrt(), ZeroIntBits::MAX);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_sqrt(self) -> $Fixed<Frac> {
+                    match self.overflowing_sqrt() {
+                        (ans, false) => ans,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating power. Returns `self` raised to the integer
+power `n`, saturating on overflow.
+
+Like [`powi`], the power is rounded only once.
+
+# Panics
+
+Panics if `self` is zero and `n` is negative.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1.5).saturating_powi(3), Fix::from_num(3.375));
+assert_eq!(Fix::MAX.saturating_powi(2), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.saturating_powi(2), Fix::MAX);
+assert_eq!(Fix::MIN.saturating_powi(3), Fix::MIN);
+",
+                },
+                "```
+
+[`powi`]: Self::powi
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_powi(self, n: i32) -> $Fixed<Frac> {
+                    match self.overflowing_powi(n) {
+                        (ans, false) => ans,
+                        (_, true) => if_signed_unsigned!(
+                            $Signedness,
+                            if self.is_negative() && n % 2 != 0 {
+                                Self::MIN
+                            } else {
+                                Self::MAX
+                            },
+                            Self::MAX,
+                        ),
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating hypotenuse. Returns √(`self`² + `other`²),
+saturating to [`MAX`][Self::MAX] on overflow.
+
+The squares and their sum are computed in double width, so that the
+intermediate values never overflow; only the result itself can
+overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(
+    Fix::from_num(3).saturating_hypot(Fix::from_num(4)),
+    Fix::from_num(5)
+);
+assert_eq!(Fix::MAX.saturating_hypot(Fix::MAX), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_hypot(self, other: $Fixed<Frac>) -> $Fixed<Frac> {
+                    match hypot::$Inner(self.to_bits(), other.to_bits()) {
+                        (ans, false) => Self::from_bits(ans),
+                        (_, true) => Self::MAX,
                     }
                 }
             }
 
             comment! {
-                "Saturating multiplication. Returns the product, saturating on overflow.
+                "Saturating multiplication with the specified [rounding
+mode]. Returns the product `self` × `rhs`, saturating on overflow.
+
+See [`mul_round`] for more information.
 
 # Examples
 
 ```rust
-use fixed::{types::extra::U4, ", $s_fixed, "};
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
 type Fix = ", $s_fixed, "<U4>;
-assert_eq!(Fix::from_num(3).saturating_mul(Fix::from_num(2)), Fix::from_num(6));
-assert_eq!(Fix::MAX.saturating_mul(Fix::from_num(2)), Fix::MAX);
+let (a, b) = (Fix::from_bits(5), Fix::ONE / 2);
+assert_eq!(a.saturating_mul_round(b, RoundingMode::Ceil), Fix::from_bits(3));
+let two = Fix::from_num(2);
+assert_eq!(Fix::MAX.saturating_mul_round(two, RoundingMode::Floor), Fix::MAX);
 ```
+
+[`mul_round`]: Self::mul_round
+[rounding mode]: RoundingMode
 ";
                 #[inline]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub fn saturating_mul(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
-                    match arith::overflowing_mul(self.to_bits(), rhs.to_bits(), Frac::U32) {
+                pub fn saturating_mul_round(self, rhs: $Fixed<Frac>, mode: RoundingMode) -> $Fixed<Frac> {
+                    let bits = (self.to_bits(), rhs.to_bits());
+                    match arith::overflowing_mul_round(bits.0, bits.1, Frac::U32, mode) {
                         (ans, false) => Self::from_bits(ans),
                         (_, true) => {
                             if (self < 0) != (rhs < 0) {
@@ -967,7 +2597,10 @@ assert_eq!(Fix::MAX.saturating_mul(Fix::from_num(2)), Fix::MAX);
             }
 
             comment! {
-                "Saturating division. Returns the quotient, saturating on overflow.
+                "Saturating division with the specified [rounding mode].
+Returns the quotient `self` ÷ `rhs`, saturating on overflow.
+
+See [`div_round`] for more information.
 
 # Panics
 
@@ -976,17 +2609,23 @@ Panics if the divisor is zero.
 # Examples
 
 ```rust
-use fixed::{types::extra::U4, ", $s_fixed, "};
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
 type Fix = ", $s_fixed, "<U4>;
-let one_half = Fix::ONE / 2;
-assert_eq!(Fix::ONE.saturating_div(Fix::from_num(2)), one_half);
-assert_eq!(Fix::MAX.saturating_div(one_half), Fix::MAX);
+let (a, b) = (Fix::from_bits(5), Fix::from_num(2));
+assert_eq!(a.saturating_div_round(b, RoundingMode::Ceil), Fix::from_bits(3));
+let half = Fix::ONE / 2;
+assert_eq!(Fix::MAX.saturating_div_round(half, RoundingMode::Floor), Fix::MAX);
 ```
+
+[`div_round`]: Self::div_round
+[rounding mode]: RoundingMode
 ";
                 #[inline]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub fn saturating_div(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
-                    match arith::overflowing_div(self.to_bits(), rhs.to_bits(), Frac::U32) {
+                pub fn saturating_div_round(self, rhs: $Fixed<Frac>, mode: RoundingMode) -> $Fixed<Frac> {
+                    assert!(rhs.to_bits() != 0, "division by zero");
+                    let bits = (self.to_bits(), rhs.to_bits());
+                    match arith::overflowing_div_round(bits.0, bits.1, Frac::U32, mode) {
                         (ans, false) => Self::from_bits(ans),
                         (_, true) => {
                             if (self < 0) != (rhs < 0) {
@@ -1000,40 +2639,134 @@ assert_eq!(Fix::MAX.saturating_div(one_half), Fix::MAX);
             }
 
             comment! {
-                "Saturating reciprocal. Returns the reciprocal,
-saturating on overflow.
+                "Saturating multiplication rounding down. Returns the product rounded
+towards −∞, saturating on overflow.
+
+This is the same as [`saturating_mul_round`] with
+[`RoundingMode::Floor`].
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_bits(3).saturating_mul_floor(Fix::ONE / 2), Fix::from_bits(1));
+assert_eq!(Fix::MAX.saturating_mul_floor(Fix::from_num(2)), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_bits(-3).saturating_mul_floor(Fix::ONE / 2), Fix::from_bits(-2));
+",
+                },
+                "```
+
+[`saturating_mul_round`]: Self::saturating_mul_round
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_mul_floor(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                    self.saturating_mul_round(rhs, RoundingMode::Floor)
+                }
+            }
+
+            comment! {
+                "Saturating multiplication rounding up. Returns the product rounded
+towards +∞, saturating on overflow.
+
+This is the same as [`saturating_mul_round`] with
+[`RoundingMode::Ceil`].
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_bits(3).saturating_mul_ceil(Fix::ONE / 2), Fix::from_bits(2));
+assert_eq!(Fix::MAX.saturating_mul_ceil(Fix::from_num(2)), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_bits(-3).saturating_mul_ceil(Fix::ONE / 2), Fix::from_bits(-1));
+",
+                },
+                "```
+
+[`saturating_mul_round`]: Self::saturating_mul_round
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_mul_ceil(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                    self.saturating_mul_round(rhs, RoundingMode::Ceil)
+                }
+            }
+
+            comment! {
+                "Saturating division rounding down. Returns the quotient rounded
+towards −∞, saturating on overflow.
+
+This is the same as [`saturating_div_round`] with
+[`RoundingMode::Floor`].
 
 # Panics
 
-Panics if the fixed-point number is zero.
+Panics if the divisor is zero.
 
 # Examples
 
 ```rust
-use fixed::{types::extra::U", $s_nbits_m1, ", ", $s_fixed, "};
-// only one integer bit
-type Fix = ", $s_fixed, "<U", $s_nbits_m1, ">;
-assert_eq!(Fix::from_num(0.25).saturating_recip(), Fix::MAX);
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_bits(1).saturating_div_floor(Fix::from_num(3)), Fix::from_bits(0));
+assert_eq!(Fix::MAX.saturating_div_floor(Fix::ONE / 2), Fix::MAX);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::from_num(-0.25).saturating_recip(), Fix::MIN);
+                    "assert_eq!(Fix::from_bits(-1).saturating_div_floor(Fix::from_num(3)), Fix::from_bits(-1));
 ",
                 },
                 "```
+
+[`saturating_div_round`]: Self::saturating_div_round
 ";
                 #[inline]
-                pub fn saturating_recip(self) -> $Fixed<Frac> {
-                    match self.overflowing_recip() {
-                        (ans, false) => ans,
-                        (_, true) => {
-                            if self < 0 {
-                                Self::MIN
-                            } else {
-                                Self::MAX
-                            }
-                        }
-                    }
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_div_floor(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                    self.saturating_div_round(rhs, RoundingMode::Floor)
+                }
+            }
+
+            comment! {
+                "Saturating division rounding up. Returns the quotient rounded
+towards +∞, saturating on overflow.
+
+This is the same as [`saturating_div_round`] with
+[`RoundingMode::Ceil`].
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_bits(1).saturating_div_ceil(Fix::from_num(3)), Fix::from_bits(1));
+assert_eq!(Fix::MAX.saturating_div_ceil(Fix::ONE / 2), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_bits(-1).saturating_div_ceil(Fix::from_num(3)), Fix::from_bits(0));
+",
+                },
+                "```
+
+[`saturating_div_round`]: Self::saturating_div_round
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_div_ceil(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                    self.saturating_div_round(rhs, RoundingMode::Ceil)
                 }
             }
 
@@ -1227,6 +2960,79 @@ assert_eq!(Fix::from_num(-7.5).saturating_rem_euclid_int(20), Fix::MAX);
                 }
             }
 
+            comment! {
+                "Saturating addition of an integer. Returns the sum, saturating on overflow.
+
+See [`add_int`] for more information.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+assert_eq!(Fix::from_num(1.5).saturating_add_int(2), Fix::from_num(3.5));
+assert_eq!(Fix::from_num(1.5).saturating_add_int(20), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(1.5).saturating_add_int(-20), Fix::MIN);
+",
+                },
+                "```
+
+[`add_int`]: Self::add_int
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_add_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                    match self.overflowing_add_int(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => if_signed_unsigned!(
+                            $Signedness,
+                            if rhs < 0 { Self::MIN } else { Self::MAX },
+                            Self::MAX,
+                        ),
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating subtraction of an integer. Returns the difference, saturating
+on overflow.
+
+See [`sub_int`] for more information.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+assert_eq!(Fix::from_num(3.5).saturating_sub_int(2), Fix::from_num(1.5));
+assert_eq!(Fix::from_num(3.5).saturating_sub_int(20), Fix::MIN);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(3.5).saturating_sub_int(-20), Fix::MAX);
+",
+                },
+                "```
+
+[`sub_int`]: Self::sub_int
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_sub_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                    match self.overflowing_sub_int(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => if_signed_unsigned!(
+                            $Signedness,
+                            if rhs < 0 { Self::MAX } else { Self::MIN },
+                            Self::MIN,
+                        ),
+                    }
+                }
+            }
+
             comment! {
                 "Linear interpolation between `start` and `end`, saturating on
 overflow.
@@ -1395,6 +3201,85 @@ assert_eq!(Fix::from_num(0.25).wrapping_recip(), Fix::ZERO);
                 }
             }
 
+            comment! {
+                "Wrapping square root. Returns the square root,
+wrapping on overflow.
+
+The result is rounded to the nearest representable value.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+Panics if the number is negative.
+
+",
+                },
+                "# Examples
+
+```rust
+use fixed::{",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+"),
+                    concat!("types::extra::U4, ", $s_fixed),
+                ),
+                "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.25).wrapping_sqrt(), Fix::from_num(1.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// no integer bits, so √0.25 = 0.5 wraps to −0.5
+type ZeroIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+assert_eq!(ZeroIntBits::from_num(0.25).wrapping_sqrt(), ZeroIntBits::from_num(-0.5));
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn wrapping_sqrt(self) -> $Fixed<Frac> {
+                    self.overflowing_sqrt().0
+                }
+            }
+
+            comment! {
+                "Wrapping power. Returns `self` raised to the integer
+power `n`, wrapping on overflow.
+
+Like [`powi`], the power is rounded only once.
+
+Very large results are wrapped as described for [`overflowing_powi`].
+
+# Panics
+
+Panics if `self` is zero and `n` is negative.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1.5).wrapping_powi(3), Fix::from_num(3.375));
+// 2↑", $s_nbits_m4, " needs ", $s_nbits_m4, " integer bits, so it wraps to zero
+assert_eq!(Fix::from_num(2).wrapping_powi(", $s_nbits_m4, "), Fix::ZERO);
+```
+
+[`overflowing_powi`]: Self::overflowing_powi
+[`powi`]: Self::powi
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn wrapping_powi(self, n: i32) -> $Fixed<Frac> {
+                    self.overflowing_powi(n).0
+                }
+            }
+
             comment! {
                 "Wrapping Euclidean division. Returns the quotient, wrapping on overflow.
 
@@ -1532,8 +3417,59 @@ assert_eq!(Fix::from_num(-7.5).wrapping_rem_euclid_int(20), Fix::from_num(-3.5))
 ";
                 #[inline]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub fn wrapping_rem_euclid_int(self, rhs: $Inner) -> $Fixed<Frac> {
-                    self.overflowing_rem_euclid_int(rhs).0
+                pub fn wrapping_rem_euclid_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                    self.overflowing_rem_euclid_int(rhs).0
+                }
+            }
+
+            comment! {
+                "Wrapping addition of an integer. Returns the sum, wrapping on overflow.
+
+See [`add_int`] for more information.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+assert_eq!(Fix::from_num(1.5).wrapping_add_int(2), Fix::from_num(3.5));
+// Fix has 4 integer bits, so adding 16 wraps around to the same value
+assert_eq!(Fix::from_num(1.5).wrapping_add_int(16), Fix::from_num(1.5));
+```
+
+[`add_int`]: Self::add_int
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn wrapping_add_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                    let (ans, _) = self.overflowing_add_int(rhs);
+                    ans
+                }
+            }
+
+            comment! {
+                "Wrapping subtraction of an integer. Returns the difference, wrapping on
+overflow.
+
+See [`sub_int`] for more information.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+assert_eq!(Fix::from_num(3.5).wrapping_sub_int(2), Fix::from_num(1.5));
+// Fix has 4 integer bits, so subtracting 16 wraps around to the same value
+assert_eq!(Fix::from_num(3.5).wrapping_sub_int(16), Fix::from_num(3.5));
+```
+
+[`sub_int`]: Self::sub_int
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn wrapping_sub_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                    let (ans, _) = self.overflowing_sub_int(rhs);
+                    ans
                 }
             }
 
@@ -1705,6 +3641,94 @@ assert_eq!(Fix::from_num(0.25).unwrapped_recip(), Fix::from_num(4));
                 }
             }
 
+            comment! {
+                "Unwrapped square root. Returns the square root,
+panicking on overflow.
+
+The result is rounded to the nearest representable value.
+
+# Panics
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Panics if the number is negative or on overflow.",
+                    "Panics on overflow, which can never happen for unsigned numbers.",
+                ),
+                "
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.25).unwrapped_sqrt(), Fix::from_num(1.5));
+```
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+The following panics because of overflow.
+
+```should_panic
+use fixed::{types::extra::U", $s_nbits, ", ", $s_fixed, "};
+// no integer bits, so √0.25 = 0.5 overflows
+type ZeroIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+let _overflow = ZeroIntBits::from_num(0.25).unwrapped_sqrt();
+```
+",
+                },
+                "";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn unwrapped_sqrt(self) -> $Fixed<Frac> {
+                    match self.overflowing_sqrt() {
+                        (_, true) => panic!("overflow"),
+                        (ans, false) => ans,
+                    }
+                }
+            }
+
+            comment! {
+                "Unwrapped power. Returns `self` raised to the integer
+power `n`, panicking on overflow.
+
+Like [`powi`], the power is rounded only once.
+
+# Panics
+
+Panics if `self` is zero and `n` is negative, or on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1.5).unwrapped_powi(3), Fix::from_num(3.375));
+```
+
+The following panics because of overflow.
+
+```should_panic
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let _overflow = Fix::MAX.unwrapped_powi(2);
+```
+
+[`powi`]: Self::powi
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn unwrapped_powi(self, n: i32) -> $Fixed<Frac> {
+                    match self.overflowing_powi(n) {
+                        (_, true) => panic!("overflow"),
+                        (ans, false) => ans,
+                    }
+                }
+            }
+
             comment! {
                 "Unwrapped Euclidean division. Returns the quotient, panicking on overflow.
 
@@ -1950,6 +3974,83 @@ let _overflow = Fix::from_num(-7.5).unwrapped_rem_euclid_int(20);
                 }
             }
 
+            comment! {
+                "Unwrapped addition of an integer. Returns the sum, panicking on overflow.
+
+See [`add_int`] for more information.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+assert_eq!(Fix::from_num(1.5).unwrapped_add_int(2), Fix::from_num(3.5));
+```
+
+The following panics because of overflow.
+
+```should_panic
+use fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+let _overflow = Fix::MAX.unwrapped_add_int(1);
+```
+
+[`add_int`]: Self::add_int
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn unwrapped_add_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                    match self.overflowing_add_int(rhs) {
+                        (_, true) => panic!("overflow"),
+                        (ans, false) => ans,
+                    }
+                }
+            }
+
+            comment! {
+                "Unwrapped subtraction of an integer. Returns the difference, panicking
+on overflow.
+
+See [`sub_int`] for more information.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+assert_eq!(Fix::from_num(3.5).unwrapped_sub_int(2), Fix::from_num(1.5));
+```
+
+The following panics because of overflow.
+
+```should_panic
+use fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+let _overflow = Fix::MIN.unwrapped_sub_int(1);
+```
+
+[`sub_int`]: Self::sub_int
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn unwrapped_sub_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                    match self.overflowing_sub_int(rhs) {
+                        (_, true) => panic!("overflow"),
+                        (ans, false) => ans,
+                    }
+                }
+            }
+
             comment! {
                 "Linear interpolation between `start` and `end`, panicking on
 overflow.
@@ -2150,6 +4251,116 @@ assert_eq!(Small::from_num(0.25).overflowing_recip(), (Small::ZERO, true));
                 }
             }
 
+            comment! {
+                "Overflowing square root.
+
+Returns a [tuple] of the square root and a [`bool`] indicating whether
+an overflow has occurred. On overflow, the wrapped value is returned.
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when there are no integer bits and the
+number is ≥ 0.25.",
+                    "Overflow can never happen for unsigned numbers.",
+                ),
+                "
+
+The result is rounded to the nearest representable value.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+Panics if the number is negative.
+
+",
+                },
+                "# Examples
+
+```rust
+use fixed::{",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+"),
+                    concat!("types::extra::U4, ", $s_fixed),
+                ),
+                "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.25).overflowing_sqrt(), (Fix::from_num(1.5), false));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// no integer bits, so √0.25 = 0.5 wraps to −0.5
+type ZeroIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+assert_eq!(
+    ZeroIntBits::from_num(0.25).overflowing_sqrt(),
+    (ZeroIntBits::from_num(-0.5), true)
+);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn overflowing_sqrt(self) -> ($Fixed<Frac>, bool) {
+                    if_signed! {
+                        $Signedness;
+                        assert!(!self.is_negative(), "square root of negative number");
+                    }
+                    let (ans, overflow) = sqrt::$Inner(self.to_bits(), Frac::U32);
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Overflowing power.
+
+Returns a [tuple] of `self` raised to the integer power `n` and a
+[`bool`] indicating whether an overflow has occurred. On overflow, the
+wrapped value is returned.
+
+Like [`powi`], the power is rounded only once.
+
+The wrapped value is the rounded result wrapped like in the other
+overflowing methods, provided `n` is positive and the exact power needs
+no more than 128 fractional bits more than `self`, which is always the
+case when `self` is an integer. Otherwise, if the result is
+2<sup>256</sup> × [`DELTA`][Self::DELTA] or larger in magnitude, only
+its 256 most significant bits are kept before it is wrapped, so the
+lower bits of the wrapped value are zero.
+
+# Panics
+
+Panics if `self` is zero and `n` is negative.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1.5).overflowing_powi(3), (Fix::from_num(3.375), false));
+// 2↑", $s_nbits_m4, " needs ", $s_nbits_m4, " integer bits, so it wraps to zero
+assert_eq!(Fix::from_num(2).overflowing_powi(", $s_nbits_m4, "), (Fix::ZERO, true));
+// 3↑200 needs more than 256 bits, but it is an integer, so it wraps exactly
+let wrapped = Fix::from_bits(", $s_inner, "::wrapping_pow(3, 200) << 4);
+assert_eq!(Fix::from_num(3).overflowing_powi(200), (wrapped, true));
+```
+
+[`powi`]: Self::powi
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn overflowing_powi(self, n: i32) -> ($Fixed<Frac>, bool) {
+                    assert!(n >= 0 || self.to_bits() != 0, "division by zero");
+                    let (ans, overflow) = pow::powi_bits(self.to_bits(), Frac::U32, n);
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+
             comment! {
                 "Overflowing Euclidean division.
 
@@ -2386,6 +4597,96 @@ assert_eq!(Fix::from_num(-7.5).overflowing_rem_euclid_int(20), (Fix::from_num(-3
                 }
             }
 
+            comment! {
+                "Overflowing addition of an integer.
+
+Returns a [tuple] of the sum and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+See [`add_int`] for more information.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+assert_eq!(Fix::from_num(1.5).overflowing_add_int(2), (Fix::from_num(3.5), false));
+assert_eq!(Fix::from_num(1.5).overflowing_add_int(16), (Fix::from_num(1.5), true));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// −8 ≤ Fix < 8, so 8 does not fit on its own, but the sum 0.5 does
+assert_eq!(Fix::from_num(-7.5).overflowing_add_int(8), (Fix::from_num(0.5), false));
+",
+                },
+                "```
+
+[`add_int`]: Self::add_int
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn overflowing_add_int(self, rhs: $Inner) -> ($Fixed<Frac>, bool) {
+                    let bits = self.to_bits();
+                    if Self::INT_NBITS == 0 {
+                        // rhs scaled by 2^NBITS wraps to zero
+                        return (self, rhs != 0);
+                    }
+                    // only the integer part can change, so check its range
+                    let int_bits = bits >> Self::FRAC_NBITS;
+                    let (int_bits, overflow) = int_bits.overflowing_add(rhs);
+                    let min_int_bits = Self::MIN.to_bits() >> Self::FRAC_NBITS;
+                    let max_int_bits = Self::MAX.to_bits() >> Self::FRAC_NBITS;
+                    let fits = min_int_bits <= int_bits && int_bits <= max_int_bits;
+                    let ans = bits.wrapping_add(rhs << Self::FRAC_NBITS);
+                    (Self::from_bits(ans), overflow || !fits)
+                }
+            }
+
+            comment! {
+                "Overflowing subtraction of an integer.
+
+Returns a [tuple] of the difference and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+See [`sub_int`] for more information.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+assert_eq!(Fix::from_num(3.5).overflowing_sub_int(2), (Fix::from_num(1.5), false));
+assert_eq!(Fix::from_num(3.5).overflowing_sub_int(16), (Fix::from_num(3.5), true));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// −8 ≤ Fix < 8, so 8 does not fit on its own, but the difference −0.5 does
+assert_eq!(Fix::from_num(7.5).overflowing_sub_int(8), (Fix::from_num(-0.5), false));
+",
+                },
+                "```
+
+[`sub_int`]: Self::sub_int
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn overflowing_sub_int(self, rhs: $Inner) -> ($Fixed<Frac>, bool) {
+                    let bits = self.to_bits();
+                    if Self::INT_NBITS == 0 {
+                        // rhs scaled by 2^NBITS wraps to zero
+                        return (self, rhs != 0);
+                    }
+                    // only the integer part can change, so check its range
+                    let int_bits = bits >> Self::FRAC_NBITS;
+                    let (int_bits, overflow) = int_bits.overflowing_sub(rhs);
+                    let min_int_bits = Self::MIN.to_bits() >> Self::FRAC_NBITS;
+                    let max_int_bits = Self::MAX.to_bits() >> Self::FRAC_NBITS;
+                    let fits = min_int_bits <= int_bits && int_bits <= max_int_bits;
+                    let ans = bits.wrapping_sub(rhs << Self::FRAC_NBITS);
+                    (Self::from_bits(ans), overflow || !fits)
+                }
+            }
+
             comment! {
                 "Overflowing linear interpolation between `start` and `end`.
 
@@ -823,98 +823,1083 @@ assert_eq!(one_point_625.overflowing_to_num::<f32>(), (1.625f32, false));
             }
         }
 
+        comment! {
+            r#"Creates a fixed-point number from another number, rounding as
+specified by `mode`.
+
+The other number can be:
+
+  * Another fixed-point number.
+  * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
+    [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
+    [`usize`]. This conversion is always exact when the value fits.
+  * A floating-point number of type [`f16`], [`bf16`], [`f32`],
+    [`f64`] or [`F128Bits`].
+  * Any other number `src` for which [`ToFixed`] is implemented, in
+    which case this method returns
+    <code>src.[to\_fixed\_round][ToFixed::to_fixed_round]\(mode)</code>.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, "#, $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+
+// 2.53125 is 10.10001 in binary, half-way between 10.1000 and 10.1001
+let x = 2.53125f64;
+let below = Fix::from_bits(0b10_1000);
+let above = Fix::from_bits(0b10_1001);
+assert_eq!(Fix::from_num_round(x, RoundingMode::Floor), below);
+assert_eq!(Fix::from_num_round(x, RoundingMode::Ceil), above);
+assert_eq!(Fix::from_num_round(x, RoundingMode::NearestTiesEven), below);
+assert_eq!(Fix::from_num_round(x, RoundingMode::NearestTiesAway), above);
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!(Fix::from_num_round(-x, RoundingMode::TowardZero), -below);
+assert_eq!(Fix::from_num_round(-x, RoundingMode::AwayFromZero), -above);
+"
+            },
+            "```
+
+[`bf16`]: half::bf16
+[`f16`]: half::f16
+[finite]: f64::is_finite
+";
+            #[inline]
+            pub fn from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> $Fixed<Frac> {
+                src.to_fixed_round(mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, rounding as
+specified by `mode`.
+
+The other number can be:
+
+  * Another fixed-point number.
+  * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
+    [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
+    [`usize`].
+  * A floating-point number of type [`f16`], [`bf16`], [`f32`],
+    [`f64`] or [`F128Bits`]. A value that is too large is rounded to
+    infinity if `mode` rounds its absolute value up, otherwise it is
+    rounded to the largest finite value with the same sign.
+  * Any other type `Dst` for which [`FromFixed`] is implemented, in
+    which case this method returns
+    <code>Dst::[from\_fixed\_round][FromFixed::from_fixed_round]\(self, mode)</code>.
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, "#, $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+
+// 2.5 is 10.1 in binary
+let two_point_5 = Fix::from_bits(0b101 << (4 - 1));
+assert_eq!(two_point_5.to_num_round::<i32>(RoundingMode::Floor), 2);
+assert_eq!(two_point_5.to_num_round::<i32>(RoundingMode::Ceil), 3);
+assert_eq!(two_point_5.to_num_round::<i32>(RoundingMode::NearestTiesEven), 2);
+assert_eq!(two_point_5.to_num_round::<i32>(RoundingMode::NearestTiesAway), 3);
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!((-two_point_5).to_num_round::<i32>(RoundingMode::TowardZero), -2);
+assert_eq!((-two_point_5).to_num_round::<i32>(RoundingMode::AwayFromZero), -3);
+"
+            },
+            "```
+
+[`bf16`]: half::bf16
+[`f16`]: half::f16
+";
+            #[inline]
+            pub fn to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst {
+                Dst::from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number if it
+fits, otherwise returns [`None`], rounding as specified by `mode`.
+
+The other number can be any number for which [`ToFixed`] is
+implemented, as for [`from_num_round`], in which case this method
+returns
+<code>src.[checked\_to\_fixed\_round][ToFixed::checked_to_fixed_round]\(mode)</code>.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, "#, $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+
+// 0.96875 is 0.11111 in binary
+let x = 0.96875f32;
+let expected = Fix::from_bits(0b1111);
+assert_eq!(Fix::checked_from_num_round(x, RoundingMode::Floor), Some(expected));
+assert_eq!(Fix::checked_from_num_round(x, RoundingMode::Ceil), Some(Fix::ONE));
+assert!(Fix::checked_from_num_round(1e40, RoundingMode::Floor).is_none());
+",
+            if_unsigned_else_empty_str! {
+                $Signedness;
+                "// −0.03125 rounds down to −0.0625, which does not fit
+assert!(Fix::checked_from_num_round(-0.03125, RoundingMode::Floor).is_none());
+assert_eq!(Fix::checked_from_num_round(-0.03125, RoundingMode::Ceil), Some(Fix::ZERO));
+"
+            },
+            "```
+
+[`from_num_round`]: Self::from_num_round
+";
+            #[inline]
+            pub fn checked_from_num_round<Src: ToFixed>(
+                src: Src,
+                mode: RoundingMode,
+            ) -> Option<$Fixed<Frac>> {
+                src.checked_to_fixed_round(mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number if it
+fits, otherwise returns [`None`], rounding as specified by `mode`.
+
+The other number can be any number for which [`FromFixed`] is
+implemented, as for [`to_num_round`], in which case this method
+returns
+<code>Dst::[checked\_from\_fixed\_round][FromFixed::checked_from_fixed_round]\(self, mode)</code>.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U6},
+    "#, $s_fixed, ", RoundingMode,
+};
+type Fix = ", $s_fixed, "<U4>;
+
+// 2.5 is 10.1 in binary
+let two_point_5 = Fix::from_bits(0b101 << (4 - 1));
+assert_eq!(two_point_5.checked_to_num_round::<i32>(RoundingMode::Ceil), Some(3));
+type TooFewIntBits = ", $s_fixed, "<U6>;
+assert!(Fix::MAX.checked_to_num_round::<TooFewIntBits>(RoundingMode::Floor).is_none());
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "// −0.5 rounds down to −1, which does not fit in u32
+let minus_half = -Fix::from_bits(0b1000);
+assert!(minus_half.checked_to_num_round::<u32>(RoundingMode::Floor).is_none());
+assert_eq!(minus_half.checked_to_num_round::<u32>(RoundingMode::Ceil), Some(0));
+"
+            },
+            "```
+
+[`to_num_round`]: Self::to_num_round
+";
+            #[inline]
+            pub fn checked_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Option<Dst> {
+                Dst::checked_from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number,
+saturating if it does not fit, and rounding as specified by `mode`.
+
+The other number can be any number for which [`ToFixed`] is
+implemented, as for [`from_num_round`], in which case this method
+returns
+<code>src.[saturating\_to\_fixed\_round][ToFixed::saturating_to_fixed_round]\(mode)</code>.
+
+# Panics
+
+This method panics if the value is a floating-point [NaN].
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, "#, $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+
+assert_eq!(Fix::saturating_from_num_round(1e40, RoundingMode::Floor), Fix::MAX);
+assert_eq!(Fix::saturating_from_num_round(-1e40, RoundingMode::Ceil), Fix::MIN);
+",
+            if_unsigned_else_empty_str! {
+                $Signedness;
+                "// −0.03125 rounds down to −0.0625, which saturates to zero
+assert_eq!(Fix::saturating_from_num_round(-0.03125, RoundingMode::Floor), Fix::ZERO);
+"
+            },
+            "```
+
+[NaN]: f64::is_nan
+[`from_num_round`]: Self::from_num_round
+";
+            #[inline]
+            pub fn saturating_from_num_round<Src: ToFixed>(
+                src: Src,
+                mode: RoundingMode,
+            ) -> $Fixed<Frac> {
+                src.saturating_to_fixed_round(mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number,
+saturating the value if it does not fit, and rounding as specified by
+`mode`.
+
+The other number can be any number for which [`FromFixed`] is
+implemented, as for [`to_num_round`], in which case this method
+returns
+<code>Dst::[saturating\_from\_fixed\_round][FromFixed::saturating_from_fixed_round]\(self, mode)</code>.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U6},
+    "#, $s_fixed, ", RoundingMode,
+};
+type Fix = ", $s_fixed, "<U4>;
+
+// 2.5 is 10.1 in binary
+let two_point_5 = Fix::from_bits(0b101 << (4 - 1));
+assert_eq!(two_point_5.saturating_to_num_round::<i32>(RoundingMode::Ceil), 3);
+type TooFewIntBits = ", $s_fixed, "<U6>;
+let saturated = Fix::MAX.saturating_to_num_round::<TooFewIntBits>(RoundingMode::Floor);
+assert_eq!(saturated, TooFewIntBits::MAX);
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "// −0.5 rounds down to −1, which saturates to 0 in u32
+let minus_half = -Fix::from_bits(0b1000);
+assert_eq!(minus_half.saturating_to_num_round::<u32>(RoundingMode::Floor), 0);
+"
+            },
+            "```
+
+[`to_num_round`]: Self::to_num_round
+";
+            #[inline]
+            pub fn saturating_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst {
+                Dst::saturating_from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another fixed-point number,
+rounding stochastically.
+
+If `src` has more fractional bits than `Self`, the extra fractional bits are
+discarded, and the result is rounded up with a probability equal to the
+discarded fraction, so that the expected value of the result is equal to
+`src`. Only the lowest <i>n</i> bits of `random` are used, where <i>n</i> is
+the number of discarded fractional bits; the result is rounded up if those
+bits are less than the discarded bits. If `random` is uniformly distributed,
+the result is rounded up with the required probability.
+
+If `src` does not have more fractional bits than `Self`, `random` is ignored.
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U6},
+    "#, $s_fixed, ",
+};
+type Src = ", $s_fixed, "<U6>;
+type Dst = ", $s_fixed, "<U4>;
+// two bits are discarded, 0b01, so src rounds up for one in four values
+let src = Src::from_num(1.25) + Src::DELTA;
+assert_eq!(Dst::from_num_stochastic(src, 0), Dst::from_num(1.3125));
+assert_eq!(Dst::from_num_stochastic(src, 1), Dst::from_num(1.25));
+assert_eq!(Dst::from_num_stochastic(src, 0b111), Dst::from_num(1.25));
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "// for −src, the discarded bits are 0b11
+assert_eq!(Dst::from_num_stochastic(-src, 2), Dst::from_num(-1.25));
+assert_eq!(Dst::from_num_stochastic(-src, 3), Dst::from_num(-1.3125));
+"
+            },
+            "```
+";
+            #[inline]
+            pub fn from_num_stochastic<Src: Fixed>(src: Src, random: u128) -> $Fixed<Frac> {
+                let conv = src.private_to_fixed_helper_stochastic(
+                    random,
+                    Self::FRAC_NBITS,
+                    Self::INT_NBITS,
+                );
+                let (wrapped, overflow) = Self::private_overflowing_from_helper(conv);
+                debug_assert!(!overflow, "{} overflows", src);
+                let _ = overflow;
+                wrapped
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another fixed-point number,
+rounding stochastically.
+
+This method returns
+<code>Dst::[from\_num\_stochastic][Fixed::from_num_stochastic]\(self, random)</code>;
+see [`from_num_stochastic`] for details.
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U6},
+    "#, $s_fixed, ",
+};
+type Src = ", $s_fixed, "<U6>;
+type Dst = ", $s_fixed, "<U4>;
+let src = Src::from_num(1.25) + Src::DELTA;
+assert_eq!(src.to_num_stochastic::<Dst>(0), Dst::from_num(1.3125));
+assert_eq!(src.to_num_stochastic::<Dst>(1), Dst::from_num(1.25));
+```
+
+[`from_num_stochastic`]: Self::from_num_stochastic
+";
+            #[inline]
+            pub fn to_num_stochastic<Dst: Fixed>(self, random: u128) -> Dst {
+                Dst::from_num_stochastic(self, random)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, also
+returning whether the conversion was inexact.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether the value had to be rounded. The other number can be any number for
+which [`ToFixed`] is implemented, as for [`from_num`], and the returned value
+is the same as the value returned by [`from_num`]. This method returns
+<code>src.[to\_fixed\_exact][ToFixed::to_fixed_exact]\()</code>.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if overflow is possible use [`checked_from_num`] instead.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U2, U4},
+    "#, $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U2>;
+
+// 1.75 is 1.11 in binary, which fits exactly
+assert_eq!(Fix::from_num_exact(1.75f32), (Fix::from_num(1.75), false));
+// 1.625 is 1.101 in binary, which is rounded to even
+assert_eq!(Fix::from_num_exact(1.625f32), (Fix::from_num(1.5), true));
+assert_eq!(Fix::from_num_exact(3), (Fix::from_num(3), false));
+// extra fractional bits are discarded
+let src = ", $s_fixed, "::<U4>::from_num(1.3125);
+assert_eq!(Fix::from_num_exact(src), (Fix::from_num(1.25), true));
+```
+
+[`checked_from_num`]: Self::checked_from_num
+[`from_num`]: Self::from_num
+[finite]: f64::is_finite
+";
+            #[inline]
+            pub fn from_num_exact<Src: ToFixed>(src: Src) -> ($Fixed<Frac>, bool) {
+                src.to_fixed_exact()
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, also
+returning whether the conversion was inexact.
+
+Returns a [tuple] of the other number and a [`bool`] indicating whether
+the value had to be rounded. The other number can be any number for which
+[`FromFixed`] is implemented, as for [`to_num`], and the returned value is
+the same as the value returned by [`to_num`]. This method returns
+<code>Dst::[from\_fixed\_exact][FromFixed::from_fixed_exact]\(self)</code>.
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if overflow is possible use [`checked_to_num`] instead.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U2, U4},
+    "#, $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+
+let src = Fix::from_num(1.3125);
+assert_eq!(src.to_num_exact::<f32>(), (1.3125, false));
+assert_eq!(src.to_num_exact::<i32>(), (1, true));
+assert_eq!(src.to_num_exact::<", $s_fixed, "<U2>>(), (", $s_fixed, "::<U2>::from_num(1.25), true));
+assert_eq!(Fix::from_num(3).to_num_exact::<u8>(), (3, false));
+```
+
+[`checked_to_num`]: Self::checked_to_num
+[`to_num`]: Self::to_num
+";
+            #[inline]
+            pub fn to_num_exact<Dst: FromFixed>(self) -> (Dst, bool) {
+                Dst::from_fixed_exact(self)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing binary digits to return a fixed-point number.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, r#"<U4>;
+// 1.75 is 1.11 in binary
+let f = Fix::from_str_binary("1.11");
+let check = Fix::from_bits(0b111 << (4 - 2));
+assert_eq!(f, Ok(check));
+"#,
+            if_signed_else_empty_str! {
+                $Signedness;
+                r#"let neg = Fix::from_str_binary("-1.11");
+assert_eq!(neg, Ok(-check));
+"#,
+            },
+            "```
+";
+            #[inline]
+            pub fn from_str_binary(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::from_str_radix(src, 2, RoundingMode::NearestTiesEven)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing octal digits to return a fixed-point number.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, r#"<U4>;
+// 1.75 is 1.11 in binary, 1.6 in octal
+let f = Fix::from_str_octal("1.6");
+let check = Fix::from_bits(0b111 << (4 - 2));
+assert_eq!(f, Ok(check));
+"#,
+            if_signed_else_empty_str! {
+                $Signedness;
+                r#"let neg = Fix::from_str_octal("-1.6");
+assert_eq!(neg, Ok(-check));
+"#,
+            },
+            "```
+";
+            #[inline]
+            pub fn from_str_octal(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::from_str_radix(src, 8, RoundingMode::NearestTiesEven)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing hexadecimal digits to return a fixed-point number.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, r#"<U4>;
+// 1.75 is 1.11 in binary, 1.C in hexadecimal
+let f = Fix::from_str_hex("1.C");
+let check = Fix::from_bits(0b111 << (4 - 2));
+assert_eq!(f, Ok(check));
+"#,
+            if_signed_else_empty_str! {
+                $Signedness;
+                r#"let neg = Fix::from_str_hex("-1.C");
+assert_eq!(neg, Ok(-check));
+"#,
+            },
+            "```
+";
+            #[inline]
+            pub fn from_str_hex(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::from_str_radix(src, 16, RoundingMode::NearestTiesEven)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing decimal digits to return a fixed-point number,
+saturating on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+assert_eq!(I8F8::saturating_from_str("9999"), Ok(I8F8::MAX));
+assert_eq!(I8F8::saturating_from_str("-9999"), Ok(I8F8::MIN));
+"#,
+                r#"use fixed::types::U8F8;
+assert_eq!(U8F8::saturating_from_str("9999"), Ok(U8F8::MAX));
+assert_eq!(U8F8::saturating_from_str("-1"), Ok(U8F8::ZERO));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn saturating_from_str(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::saturating_from_str_radix(src, 10, RoundingMode::NearestTiesEven)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing binary digits to return a fixed-point number,
+saturating on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+assert_eq!(I8F8::saturating_from_str_binary("101100111000"), Ok(I8F8::MAX));
+assert_eq!(I8F8::saturating_from_str_binary("-101100111000"), Ok(I8F8::MIN));
+"#,
+                r#"use fixed::types::U8F8;
+assert_eq!(U8F8::saturating_from_str_binary("101100111000"), Ok(U8F8::MAX));
+assert_eq!(U8F8::saturating_from_str_binary("-1"), Ok(U8F8::ZERO));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn saturating_from_str_binary(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::saturating_from_str_radix(src, 2, RoundingMode::NearestTiesEven)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing octal digits to return a fixed-point number,
+saturating on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+assert_eq!(I8F8::saturating_from_str_octal("7777"), Ok(I8F8::MAX));
+assert_eq!(I8F8::saturating_from_str_octal("-7777"), Ok(I8F8::MIN));
+"#,
+                r#"use fixed::types::U8F8;
+assert_eq!(U8F8::saturating_from_str_octal("7777"), Ok(U8F8::MAX));
+assert_eq!(U8F8::saturating_from_str_octal("-1"), Ok(U8F8::ZERO));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn saturating_from_str_octal(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::saturating_from_str_radix(src, 8, RoundingMode::NearestTiesEven)
+            }
+        }
+
+        comment! {
+            "Prases a string slice containing hexadecimal digits to return a fixed-point number,
+saturating on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+assert_eq!(I8F8::saturating_from_str_hex("FFFF"), Ok(I8F8::MAX));
+assert_eq!(I8F8::saturating_from_str_hex("-FFFF"), Ok(I8F8::MIN));
+"#,
+                r#"use fixed::types::U8F8;
+assert_eq!(U8F8::saturating_from_str_hex("FFFF"), Ok(U8F8::MAX));
+assert_eq!(U8F8::saturating_from_str_hex("-1"), Ok(U8F8::ZERO));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn saturating_from_str_hex(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::saturating_from_str_radix(src, 16, RoundingMode::NearestTiesEven)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing decimal digits to return a fixed-point number,
+wrapping on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+// 9999.5 = 15.5 + 256 × n
+assert_eq!(I8F8::wrapping_from_str("9999.5"), Ok(I8F8::from_num(15.5)));
+assert_eq!(I8F8::wrapping_from_str("-9999.5"), Ok(I8F8::from_num(-15.5)));
+"#,
+                r#"use fixed::types::U8F8;
+// 9999.5 = 15.5 + 256 × n
+assert_eq!(U8F8::wrapping_from_str("9999.5"), Ok(U8F8::from_num(15.5)));
+assert_eq!(U8F8::wrapping_from_str("-9999.5"), Ok(U8F8::from_num(240.5)));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn wrapping_from_str(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::wrapping_from_str_radix(src, 10, RoundingMode::NearestTiesEven)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing binary digits to return a fixed-point number,
+wrapping on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+let check = I8F8::from_bits(0b1110001 << (8 - 1));
+assert_eq!(I8F8::wrapping_from_str_binary("101100111000.1"), Ok(check));
+assert_eq!(I8F8::wrapping_from_str_binary("-101100111000.1"), Ok(-check));
+"#,
+                r#"use fixed::types::U8F8;
+let check = U8F8::from_bits(0b1110001 << (8 - 1));
+assert_eq!(U8F8::wrapping_from_str_binary("101100111000.1"), Ok(check));
+assert_eq!(U8F8::wrapping_from_str_binary("-101100111000.1"), Ok(check.wrapping_neg()));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn wrapping_from_str_binary(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::wrapping_from_str_radix(src, 2, RoundingMode::NearestTiesEven)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing octal digits to return a fixed-point number,
+wrapping on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+let check = I8F8::from_bits(0o1654 << (8 - 3));
+assert_eq!(I8F8::wrapping_from_str_octal("7165.4"), Ok(check));
+assert_eq!(I8F8::wrapping_from_str_octal("-7165.4"), Ok(-check));
+"#,
+                r#"use fixed::types::U8F8;
+let check = U8F8::from_bits(0o1654 << (8 - 3));
+assert_eq!(U8F8::wrapping_from_str_octal("7165.4"), Ok(check));
+assert_eq!(U8F8::wrapping_from_str_octal("-7165.4"), Ok(check.wrapping_neg()));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn wrapping_from_str_octal(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::wrapping_from_str_radix(src, 8, RoundingMode::NearestTiesEven)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing hexadecimal digits to return a fixed-point number,
+wrapping on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+let check = I8F8::from_bits(0xFFE);
+assert_eq!(I8F8::wrapping_from_str_hex("C0F.FE"), Ok(check));
+assert_eq!(I8F8::wrapping_from_str_hex("-C0F.FE"), Ok(-check));
+"#,
+                r#"use fixed::types::U8F8;
+let check = U8F8::from_bits(0xFFE);
+assert_eq!(U8F8::wrapping_from_str_hex("C0F.FE"), Ok(check));
+assert_eq!(U8F8::wrapping_from_str_hex("-C0F.FE"), Ok(check.wrapping_neg()));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn wrapping_from_str_hex(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::wrapping_from_str_radix(src, 16, RoundingMode::NearestTiesEven)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing decimal digits to return a fixed-point number.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+assert_eq!(I8F8::overflowing_from_str("99.5"), Ok((I8F8::from_num(99.5), false)));
+// 9999.5 = 15.5 + 256 × n
+assert_eq!(I8F8::overflowing_from_str("-9999.5"), Ok((I8F8::from_num(-15.5), true)));
+"#,
+                r#"use fixed::types::U8F8;
+assert_eq!(U8F8::overflowing_from_str("99.5"), Ok((U8F8::from_num(99.5), false)));
+// 9999.5 = 15.5 + 256 × n
+assert_eq!(U8F8::overflowing_from_str("9999.5"), Ok((U8F8::from_num(15.5), true)));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn overflowing_from_str(
+                src: &str,
+            ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
+                FromStrRadix::overflowing_from_str_radix(src, 10, RoundingMode::NearestTiesEven)
+            }
+        }
+
         comment! {
             "Parses a string slice containing binary digits to return a fixed-point number.
 
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+let check = I8F8::from_bits(0b1110001 << (8 - 1));
+assert_eq!(I8F8::overflowing_from_str_binary("111000.1"), Ok((check, false)));
+assert_eq!(I8F8::overflowing_from_str_binary("-101100111000.1"), Ok((-check, true)));
+"#,
+                r#"use fixed::types::U8F8;
+let check = U8F8::from_bits(0b1110001 << (8 - 1));
+assert_eq!(U8F8::overflowing_from_str_binary("111000.1"), Ok((check, false)));
+assert_eq!(U8F8::overflowing_from_str_binary("101100111000.1"), Ok((check, true)));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn overflowing_from_str_binary(
+                src: &str,
+            ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
+                FromStrRadix::overflowing_from_str_radix(src, 2, RoundingMode::NearestTiesEven)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing octal digits to return a fixed-point number.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+let check = I8F8::from_bits(0o1654 << (8 - 3));
+assert_eq!(I8F8::overflowing_from_str_octal("165.4"), Ok((check, false)));
+assert_eq!(I8F8::overflowing_from_str_octal("-7165.4"), Ok((-check, true)));
+"#,
+                r#"use fixed::types::U8F8;
+let check = U8F8::from_bits(0o1654 << (8 - 3));
+assert_eq!(U8F8::overflowing_from_str_octal("165.4"), Ok((check, false)));
+assert_eq!(U8F8::overflowing_from_str_octal("7165.4"), Ok((check, true)));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn overflowing_from_str_octal(
+                src: &str,
+            ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
+                FromStrRadix::overflowing_from_str_radix(src, 8, RoundingMode::NearestTiesEven)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing hexadecimal digits to return a fixed-point number.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
 Rounding is to the nearest, with ties rounded to even.
 
 # Examples
 
 ```rust
-use fixed::{types::extra::U4, ", $s_fixed, "};
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+let check = I8F8::from_bits(0xFFE);
+assert_eq!(I8F8::overflowing_from_str_hex("F.FE"), Ok((check, false)));
+assert_eq!(I8F8::overflowing_from_str_hex("-C0F.FE"), Ok((-check, true)));
+"#,
+                r#"use fixed::types::U8F8;
+let check = U8F8::from_bits(0xFFE);
+assert_eq!(U8F8::overflowing_from_str_hex("F.FE"), Ok((check, false)));
+assert_eq!(U8F8::overflowing_from_str_hex("C0F.FE"), Ok((check, true)));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn overflowing_from_str_hex(
+                src: &str,
+            ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
+                FromStrRadix::overflowing_from_str_radix(src, 16, RoundingMode::NearestTiesEven)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing decimal digits to return a fixed-point number,
+rounding as specified by `mode`.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
 type Fix = ", $s_fixed, r#"<U4>;
-// 1.75 is 1.11 in binary
-let f = Fix::from_str_binary("1.11");
-let check = Fix::from_bits(0b111 << (4 - 2));
-assert_eq!(f, Ok(check));
+// 0.1 is 1.6/16
+assert_eq!(Fix::from_str_round("0.1", RoundingMode::Floor), Ok(Fix::from_bits(1)));
+assert_eq!(Fix::from_str_round("0.1", RoundingMode::Ceil), Ok(Fix::from_bits(2)));
 "#,
             if_signed_else_empty_str! {
                 $Signedness;
-                r#"let neg = Fix::from_str_binary("-1.11");
-assert_eq!(neg, Ok(-check));
+                r#"assert_eq!(
+    Fix::from_str_round("-0.1", RoundingMode::Floor),
+    Ok(Fix::from_bits(-2))
+);
+assert_eq!(Fix::from_str_round("-0.1", RoundingMode::TowardZero), Ok(Fix::from_bits(-1)));
 "#,
             },
             "```
 ";
             #[inline]
-            pub fn from_str_binary(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
-                FromStrRadix::from_str_radix(src, 2)
+            pub fn from_str_round(
+                src: &str,
+                mode: RoundingMode,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::from_str_radix(src, 10, mode)
             }
         }
 
         comment! {
-            "Parses a string slice containing octal digits to return a fixed-point number.
-
-Rounding is to the nearest, with ties rounded to even.
+            "Parses a string slice containing binary digits to return a fixed-point number,
+rounding as specified by `mode`.
 
 # Examples
 
 ```rust
-use fixed::{types::extra::U4, ", $s_fixed, "};
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
 type Fix = ", $s_fixed, r#"<U4>;
-// 1.75 is 1.11 in binary, 1.6 in octal
-let f = Fix::from_str_octal("1.6");
-let check = Fix::from_bits(0b111 << (4 - 2));
-assert_eq!(f, Ok(check));
+// 0.00011 in binary is 1.5/16
+assert_eq!(Fix::from_str_binary_round("0.00011", RoundingMode::Floor), Ok(Fix::from_bits(1)));
+assert_eq!(Fix::from_str_binary_round("0.00011", RoundingMode::Ceil), Ok(Fix::from_bits(2)));
 "#,
             if_signed_else_empty_str! {
                 $Signedness;
-                r#"let neg = Fix::from_str_octal("-1.6");
-assert_eq!(neg, Ok(-check));
+                r#"assert_eq!(
+    Fix::from_str_binary_round("-0.00011", RoundingMode::Floor),
+    Ok(Fix::from_bits(-2))
+);
+assert_eq!(
+    Fix::from_str_binary_round("-0.00011", RoundingMode::TowardZero),
+    Ok(Fix::from_bits(-1))
+);
 "#,
             },
             "```
 ";
             #[inline]
-            pub fn from_str_octal(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
-                FromStrRadix::from_str_radix(src, 8)
+            pub fn from_str_binary_round(
+                src: &str,
+                mode: RoundingMode,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::from_str_radix(src, 2, mode)
             }
         }
 
         comment! {
-            "Parses a string slice containing hexadecimal digits to return a fixed-point number.
+            "Parses a string slice containing octal digits to return a fixed-point number,
+rounding as specified by `mode`.
 
-Rounding is to the nearest, with ties rounded to even.
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, r#"<U4>;
+// 0.03 in octal is 0.75/16
+assert_eq!(Fix::from_str_octal_round("0.03", RoundingMode::Floor), Ok(Fix::from_bits(0)));
+assert_eq!(Fix::from_str_octal_round("0.03", RoundingMode::Ceil), Ok(Fix::from_bits(1)));
+"#,
+            if_signed_else_empty_str! {
+                $Signedness;
+                r#"assert_eq!(
+    Fix::from_str_octal_round("-0.03", RoundingMode::Floor),
+    Ok(Fix::from_bits(-1))
+);
+assert_eq!(Fix::from_str_octal_round("-0.03", RoundingMode::TowardZero), Ok(Fix::from_bits(-0)));
+"#,
+            },
+            "```
+";
+            #[inline]
+            pub fn from_str_octal_round(
+                src: &str,
+                mode: RoundingMode,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::from_str_radix(src, 8, mode)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing hexadecimal digits to return a fixed-point number,
+rounding as specified by `mode`.
 
 # Examples
 
 ```rust
-use fixed::{types::extra::U4, ", $s_fixed, "};
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
 type Fix = ", $s_fixed, r#"<U4>;
-// 1.75 is 1.11 in binary, 1.C in hexadecimal
-let f = Fix::from_str_hex("1.C");
-let check = Fix::from_bits(0b111 << (4 - 2));
-assert_eq!(f, Ok(check));
+// 0.18 in hexadecimal is 1.5/16
+assert_eq!(Fix::from_str_hex_round("0.18", RoundingMode::Floor), Ok(Fix::from_bits(1)));
+assert_eq!(Fix::from_str_hex_round("0.18", RoundingMode::Ceil), Ok(Fix::from_bits(2)));
 "#,
             if_signed_else_empty_str! {
                 $Signedness;
-                r#"let neg = Fix::from_str_hex("-1.C");
-assert_eq!(neg, Ok(-check));
+                r#"assert_eq!(
+    Fix::from_str_hex_round("-0.18", RoundingMode::Floor),
+    Ok(Fix::from_bits(-2))
+);
+assert_eq!(Fix::from_str_hex_round("-0.18", RoundingMode::TowardZero), Ok(Fix::from_bits(-1)));
 "#,
             },
             "```
 ";
             #[inline]
-            pub fn from_str_hex(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
-                FromStrRadix::from_str_radix(src, 16)
+            pub fn from_str_hex_round(
+                src: &str,
+                mode: RoundingMode,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::from_str_radix(src, 16, mode)
             }
         }
 
         comment! {
             "Parses a string slice containing decimal digits to return a fixed-point number,
-saturating on overflow.
-
-Rounding is to the nearest, with ties rounded to even.
+saturating on overflow and rounding as specified by `mode`.
 
 # Examples
 
@@ -922,28 +1907,30 @@ Rounding is to the nearest, with ties rounded to even.
 ",
             if_signed_unsigned!(
                 $Signedness,
-                r#"use fixed::types::I8F8;
-assert_eq!(I8F8::saturating_from_str("9999"), Ok(I8F8::MAX));
-assert_eq!(I8F8::saturating_from_str("-9999"), Ok(I8F8::MIN));
+                r#"use fixed::{types::I8F8, RoundingMode};
+assert_eq!(I8F8::saturating_from_str_round("0.001", RoundingMode::Ceil), Ok(I8F8::DELTA));
+assert_eq!(I8F8::saturating_from_str_round("-9999", RoundingMode::Floor), Ok(I8F8::MIN));
 "#,
-                r#"use fixed::types::U8F8;
-assert_eq!(U8F8::saturating_from_str("9999"), Ok(U8F8::MAX));
-assert_eq!(U8F8::saturating_from_str("-1"), Ok(U8F8::ZERO));
+                r#"use fixed::{types::U8F8, RoundingMode};
+assert_eq!(U8F8::saturating_from_str_round("0.001", RoundingMode::Ceil), Ok(U8F8::DELTA));
+// rounding down a negative number gives a number less than zero
+assert_eq!(U8F8::saturating_from_str_round("-0.001", RoundingMode::Floor), Ok(U8F8::ZERO));
 "#,
             ),
             "```
 ";
             #[inline]
-            pub fn saturating_from_str(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
-                FromStrRadix::saturating_from_str_radix(src, 10)
+            pub fn saturating_from_str_round(
+                src: &str,
+                mode: RoundingMode,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::saturating_from_str_radix(src, 10, mode)
             }
         }
 
         comment! {
             "Parses a string slice containing binary digits to return a fixed-point number,
-saturating on overflow.
-
-Rounding is to the nearest, with ties rounded to even.
+saturating on overflow and rounding as specified by `mode`.
 
 # Examples
 
@@ -951,28 +1938,42 @@ Rounding is to the nearest, with ties rounded to even.
 ",
             if_signed_unsigned!(
                 $Signedness,
-                r#"use fixed::types::I8F8;
-assert_eq!(I8F8::saturating_from_str_binary("101100111000"), Ok(I8F8::MAX));
-assert_eq!(I8F8::saturating_from_str_binary("-101100111000"), Ok(I8F8::MIN));
+                r#"use fixed::{types::I8F8, RoundingMode};
+assert_eq!(
+    I8F8::saturating_from_str_binary_round("0.000000001", RoundingMode::Ceil),
+    Ok(I8F8::DELTA)
+);
+assert_eq!(
+    I8F8::saturating_from_str_binary_round("-101100111000", RoundingMode::Floor),
+    Ok(I8F8::MIN)
+);
 "#,
-                r#"use fixed::types::U8F8;
-assert_eq!(U8F8::saturating_from_str_binary("101100111000"), Ok(U8F8::MAX));
-assert_eq!(U8F8::saturating_from_str_binary("-1"), Ok(U8F8::ZERO));
+                r#"use fixed::{types::U8F8, RoundingMode};
+assert_eq!(
+    U8F8::saturating_from_str_binary_round("0.000000001", RoundingMode::Ceil),
+    Ok(U8F8::DELTA)
+);
+// rounding down a negative number gives a number less than zero
+assert_eq!(
+    U8F8::saturating_from_str_binary_round("-0.000000001", RoundingMode::Floor),
+    Ok(U8F8::ZERO)
+);
 "#,
             ),
             "```
 ";
             #[inline]
-            pub fn saturating_from_str_binary(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
-                FromStrRadix::saturating_from_str_radix(src, 2)
+            pub fn saturating_from_str_binary_round(
+                src: &str,
+                mode: RoundingMode,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::saturating_from_str_radix(src, 2, mode)
             }
         }
 
         comment! {
             "Parses a string slice containing octal digits to return a fixed-point number,
-saturating on overflow.
-
-Rounding is to the nearest, with ties rounded to even.
+saturating on overflow and rounding as specified by `mode`.
 
 # Examples
 
@@ -980,28 +1981,30 @@ Rounding is to the nearest, with ties rounded to even.
 ",
             if_signed_unsigned!(
                 $Signedness,
-                r#"use fixed::types::I8F8;
-assert_eq!(I8F8::saturating_from_str_octal("7777"), Ok(I8F8::MAX));
-assert_eq!(I8F8::saturating_from_str_octal("-7777"), Ok(I8F8::MIN));
+                r#"use fixed::{types::I8F8, RoundingMode};
+assert_eq!(I8F8::saturating_from_str_octal_round("0.001", RoundingMode::Ceil), Ok(I8F8::DELTA));
+assert_eq!(I8F8::saturating_from_str_octal_round("-7777", RoundingMode::Floor), Ok(I8F8::MIN));
 "#,
-                r#"use fixed::types::U8F8;
-assert_eq!(U8F8::saturating_from_str_octal("7777"), Ok(U8F8::MAX));
-assert_eq!(U8F8::saturating_from_str_octal("-1"), Ok(U8F8::ZERO));
+                r#"use fixed::{types::U8F8, RoundingMode};
+assert_eq!(U8F8::saturating_from_str_octal_round("0.001", RoundingMode::Ceil), Ok(U8F8::DELTA));
+// rounding down a negative number gives a number less than zero
+assert_eq!(U8F8::saturating_from_str_octal_round("-0.001", RoundingMode::Floor), Ok(U8F8::ZERO));
 "#,
             ),
             "```
 ";
             #[inline]
-            pub fn saturating_from_str_octal(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
-                FromStrRadix::saturating_from_str_radix(src, 8)
+            pub fn saturating_from_str_octal_round(
+                src: &str,
+                mode: RoundingMode,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::saturating_from_str_radix(src, 8, mode)
             }
         }
 
         comment! {
-            "Prases a string slice containing hexadecimal digits to return a fixed-point number,
-saturating on overflow.
-
-Rounding is to the nearest, with ties rounded to even.
+            "Parses a string slice containing hexadecimal digits to return a fixed-point number,
+saturating on overflow and rounding as specified by `mode`.
 
 # Examples
 
@@ -1009,28 +2012,30 @@ Rounding is to the nearest, with ties rounded to even.
 ",
             if_signed_unsigned!(
                 $Signedness,
-                r#"use fixed::types::I8F8;
-assert_eq!(I8F8::saturating_from_str_hex("FFFF"), Ok(I8F8::MAX));
-assert_eq!(I8F8::saturating_from_str_hex("-FFFF"), Ok(I8F8::MIN));
+                r#"use fixed::{types::I8F8, RoundingMode};
+assert_eq!(I8F8::saturating_from_str_hex_round("0.008", RoundingMode::Ceil), Ok(I8F8::DELTA));
+assert_eq!(I8F8::saturating_from_str_hex_round("-FFFF", RoundingMode::Floor), Ok(I8F8::MIN));
 "#,
-                r#"use fixed::types::U8F8;
-assert_eq!(U8F8::saturating_from_str_hex("FFFF"), Ok(U8F8::MAX));
-assert_eq!(U8F8::saturating_from_str_hex("-1"), Ok(U8F8::ZERO));
+                r#"use fixed::{types::U8F8, RoundingMode};
+assert_eq!(U8F8::saturating_from_str_hex_round("0.008", RoundingMode::Ceil), Ok(U8F8::DELTA));
+// rounding down a negative number gives a number less than zero
+assert_eq!(U8F8::saturating_from_str_hex_round("-0.008", RoundingMode::Floor), Ok(U8F8::ZERO));
 "#,
             ),
             "```
 ";
             #[inline]
-            pub fn saturating_from_str_hex(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
-                FromStrRadix::saturating_from_str_radix(src, 16)
+            pub fn saturating_from_str_hex_round(
+                src: &str,
+                mode: RoundingMode,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::saturating_from_str_radix(src, 16, mode)
             }
         }
 
         comment! {
             "Parses a string slice containing decimal digits to return a fixed-point number,
-wrapping on overflow.
-
-Rounding is to the nearest, with ties rounded to even.
+wrapping on overflow and rounding as specified by `mode`.
 
 # Examples
 
@@ -1038,30 +2043,36 @@ Rounding is to the nearest, with ties rounded to even.
 ",
             if_signed_unsigned!(
                 $Signedness,
-                r#"use fixed::types::I8F8;
-// 9999.5 = 15.5 + 256 × n
-assert_eq!(I8F8::wrapping_from_str("9999.5"), Ok(I8F8::from_num(15.5)));
-assert_eq!(I8F8::wrapping_from_str("-9999.5"), Ok(I8F8::from_num(-15.5)));
+                r#"use fixed::{types::I8F8, RoundingMode};
+// 9999 = 15 + 256 × n
+let check = I8F8::from_num(15) + I8F8::DELTA;
+assert_eq!(I8F8::wrapping_from_str_round("9999.001", RoundingMode::Ceil), Ok(check));
+assert_eq!(I8F8::wrapping_from_str_round("-9999.001", RoundingMode::Floor), Ok(-check));
 "#,
-                r#"use fixed::types::U8F8;
-// 9999.5 = 15.5 + 256 × n
-assert_eq!(U8F8::wrapping_from_str("9999.5"), Ok(U8F8::from_num(15.5)));
-assert_eq!(U8F8::wrapping_from_str("-9999.5"), Ok(U8F8::from_num(240.5)));
+                r#"use fixed::{types::U8F8, RoundingMode};
+// 9999 = 15 + 256 × n
+let check = U8F8::from_num(15) + U8F8::DELTA;
+assert_eq!(U8F8::wrapping_from_str_round("9999.001", RoundingMode::Ceil), Ok(check));
+assert_eq!(
+    U8F8::wrapping_from_str_round("-9999.001", RoundingMode::Floor),
+    Ok(check.wrapping_neg())
+);
 "#,
             ),
             "```
 ";
             #[inline]
-            pub fn wrapping_from_str(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
-                FromStrRadix::wrapping_from_str_radix(src, 10)
+            pub fn wrapping_from_str_round(
+                src: &str,
+                mode: RoundingMode,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::wrapping_from_str_radix(src, 10, mode)
             }
         }
 
         comment! {
             "Parses a string slice containing binary digits to return a fixed-point number,
-wrapping on overflow.
-
-Rounding is to the nearest, with ties rounded to even.
+wrapping on overflow and rounding as specified by `mode`.
 
 # Examples
 
@@ -1069,30 +2080,45 @@ Rounding is to the nearest, with ties rounded to even.
 ",
             if_signed_unsigned!(
                 $Signedness,
-                r#"use fixed::types::I8F8;
-let check = I8F8::from_bits(0b1110001 << (8 - 1));
-assert_eq!(I8F8::wrapping_from_str_binary("101100111000.1"), Ok(check));
-assert_eq!(I8F8::wrapping_from_str_binary("-101100111000.1"), Ok(-check));
+                r#"use fixed::{types::I8F8, RoundingMode};
+// 101100111000 = 111000 + 100000000 × n
+let check = I8F8::from_num(0b111000) + I8F8::DELTA;
+assert_eq!(
+    I8F8::wrapping_from_str_binary_round("101100111000.000000001", RoundingMode::Ceil),
+    Ok(check)
+);
+assert_eq!(
+    I8F8::wrapping_from_str_binary_round("-101100111000.000000001", RoundingMode::Floor),
+    Ok(-check)
+);
 "#,
-                r#"use fixed::types::U8F8;
-let check = U8F8::from_bits(0b1110001 << (8 - 1));
-assert_eq!(U8F8::wrapping_from_str_binary("101100111000.1"), Ok(check));
-assert_eq!(U8F8::wrapping_from_str_binary("-101100111000.1"), Ok(check.wrapping_neg()));
+                r#"use fixed::{types::U8F8, RoundingMode};
+// 101100111000 = 111000 + 100000000 × n
+let check = U8F8::from_num(0b111000) + U8F8::DELTA;
+assert_eq!(
+    U8F8::wrapping_from_str_binary_round("101100111000.000000001", RoundingMode::Ceil),
+    Ok(check)
+);
+assert_eq!(
+    U8F8::wrapping_from_str_binary_round("-101100111000.000000001", RoundingMode::Floor),
+    Ok(check.wrapping_neg())
+);
 "#,
             ),
             "```
 ";
             #[inline]
-            pub fn wrapping_from_str_binary(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
-                FromStrRadix::wrapping_from_str_radix(src, 2)
+            pub fn wrapping_from_str_binary_round(
+                src: &str,
+                mode: RoundingMode,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::wrapping_from_str_radix(src, 2, mode)
             }
         }
 
         comment! {
             "Parses a string slice containing octal digits to return a fixed-point number,
-wrapping on overflow.
-
-Rounding is to the nearest, with ties rounded to even.
+wrapping on overflow and rounding as specified by `mode`.
 
 # Examples
 
@@ -1100,30 +2126,36 @@ Rounding is to the nearest, with ties rounded to even.
 ",
             if_signed_unsigned!(
                 $Signedness,
-                r#"use fixed::types::I8F8;
-let check = I8F8::from_bits(0o1654 << (8 - 3));
-assert_eq!(I8F8::wrapping_from_str_octal("7165.4"), Ok(check));
-assert_eq!(I8F8::wrapping_from_str_octal("-7165.4"), Ok(-check));
+                r#"use fixed::{types::I8F8, RoundingMode};
+// 7165 = 165 + 400 × n
+let check = I8F8::from_num(0o165) + I8F8::DELTA;
+assert_eq!(I8F8::wrapping_from_str_octal_round("7165.001", RoundingMode::Ceil), Ok(check));
+assert_eq!(I8F8::wrapping_from_str_octal_round("-7165.001", RoundingMode::Floor), Ok(-check));
 "#,
-                r#"use fixed::types::U8F8;
-let check = U8F8::from_bits(0o1654 << (8 - 3));
-assert_eq!(U8F8::wrapping_from_str_octal("7165.4"), Ok(check));
-assert_eq!(U8F8::wrapping_from_str_octal("-7165.4"), Ok(check.wrapping_neg()));
+                r#"use fixed::{types::U8F8, RoundingMode};
+// 7165 = 165 + 400 × n
+let check = U8F8::from_num(0o165) + U8F8::DELTA;
+assert_eq!(U8F8::wrapping_from_str_octal_round("7165.001", RoundingMode::Ceil), Ok(check));
+assert_eq!(
+    U8F8::wrapping_from_str_octal_round("-7165.001", RoundingMode::Floor),
+    Ok(check.wrapping_neg())
+);
 "#,
             ),
             "```
 ";
             #[inline]
-            pub fn wrapping_from_str_octal(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
-                FromStrRadix::wrapping_from_str_radix(src, 8)
+            pub fn wrapping_from_str_octal_round(
+                src: &str,
+                mode: RoundingMode,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::wrapping_from_str_radix(src, 8, mode)
             }
         }
 
         comment! {
             "Parses a string slice containing hexadecimal digits to return a fixed-point number,
-wrapping on overflow.
-
-Rounding is to the nearest, with ties rounded to even.
+wrapping on overflow and rounding as specified by `mode`.
 
 # Examples
 
@@ -1131,166 +2163,209 @@ Rounding is to the nearest, with ties rounded to even.
 ",
             if_signed_unsigned!(
                 $Signedness,
-                r#"use fixed::types::I8F8;
-let check = I8F8::from_bits(0xFFE);
-assert_eq!(I8F8::wrapping_from_str_hex("C0F.FE"), Ok(check));
-assert_eq!(I8F8::wrapping_from_str_hex("-C0F.FE"), Ok(-check));
+                r#"use fixed::{types::I8F8, RoundingMode};
+// C0F = F + 100 × n
+let check = I8F8::from_num(0xF) + I8F8::DELTA;
+assert_eq!(I8F8::wrapping_from_str_hex_round("C0F.008", RoundingMode::Ceil), Ok(check));
+assert_eq!(I8F8::wrapping_from_str_hex_round("-C0F.008", RoundingMode::Floor), Ok(-check));
 "#,
-                r#"use fixed::types::U8F8;
-let check = U8F8::from_bits(0xFFE);
-assert_eq!(U8F8::wrapping_from_str_hex("C0F.FE"), Ok(check));
-assert_eq!(U8F8::wrapping_from_str_hex("-C0F.FE"), Ok(check.wrapping_neg()));
+                r#"use fixed::{types::U8F8, RoundingMode};
+// C0F = F + 100 × n
+let check = U8F8::from_num(0xF) + U8F8::DELTA;
+assert_eq!(U8F8::wrapping_from_str_hex_round("C0F.008", RoundingMode::Ceil), Ok(check));
+assert_eq!(
+    U8F8::wrapping_from_str_hex_round("-C0F.008", RoundingMode::Floor),
+    Ok(check.wrapping_neg())
+);
 "#,
             ),
             "```
 ";
             #[inline]
-            pub fn wrapping_from_str_hex(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
-                FromStrRadix::wrapping_from_str_radix(src, 16)
+            pub fn wrapping_from_str_hex_round(
+                src: &str,
+                mode: RoundingMode,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::wrapping_from_str_radix(src, 16, mode)
             }
         }
 
         comment! {
-            "Parses a string slice containing decimal digits to return a fixed-point number.
+            "Parses a string slice containing decimal digits to return a fixed-point number,
+rounding as specified by `mode`.
 
 Returns a [tuple] of the fixed-point number and a [`bool`] indicating
 whether an overflow has occurred. On overflow, the wrapped value is
 returned.
 
-Rounding is to the nearest, with ties rounded to even.
-
 # Examples
 
 ```rust
 ",
             if_signed_unsigned!(
                 $Signedness,
-                r#"use fixed::types::I8F8;
-assert_eq!(I8F8::overflowing_from_str("99.5"), Ok((I8F8::from_num(99.5), false)));
-// 9999.5 = 15.5 + 256 × n
-assert_eq!(I8F8::overflowing_from_str("-9999.5"), Ok((I8F8::from_num(-15.5), true)));
+                r#"use fixed::{types::I8F8, RoundingMode};
+let check = I8F8::from_num(15) + I8F8::DELTA;
+assert_eq!(I8F8::overflowing_from_str_round("15.001", RoundingMode::Ceil), Ok((check, false)));
+// 9999 = 15 + 256 × n
+assert_eq!(I8F8::overflowing_from_str_round("-9999.001", RoundingMode::Floor), Ok((-check, true)));
 "#,
-                r#"use fixed::types::U8F8;
-assert_eq!(U8F8::overflowing_from_str("99.5"), Ok((U8F8::from_num(99.5), false)));
-// 9999.5 = 15.5 + 256 × n
-assert_eq!(U8F8::overflowing_from_str("9999.5"), Ok((U8F8::from_num(15.5), true)));
+                r#"use fixed::{types::U8F8, RoundingMode};
+let check = U8F8::from_num(15) + U8F8::DELTA;
+assert_eq!(U8F8::overflowing_from_str_round("15.001", RoundingMode::Ceil), Ok((check, false)));
+// 9999 = 15 + 256 × n
+assert_eq!(U8F8::overflowing_from_str_round("9999.001", RoundingMode::Ceil), Ok((check, true)));
 "#,
             ),
             "```
 ";
             #[inline]
-            pub fn overflowing_from_str(
+            pub fn overflowing_from_str_round(
                 src: &str,
+                mode: RoundingMode,
             ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
-                FromStrRadix::overflowing_from_str_radix(src, 10)
+                FromStrRadix::overflowing_from_str_radix(src, 10, mode)
             }
         }
 
         comment! {
-            "Parses a string slice containing binary digits to return a fixed-point number.
+            "Parses a string slice containing binary digits to return a fixed-point number,
+rounding as specified by `mode`.
 
 Returns a [tuple] of the fixed-point number and a [`bool`] indicating
 whether an overflow has occurred. On overflow, the wrapped value is
 returned.
 
-Rounding is to the nearest, with ties rounded to even.
-
 # Examples
 
 ```rust
 ",
             if_signed_unsigned!(
                 $Signedness,
-                r#"use fixed::types::I8F8;
-let check = I8F8::from_bits(0b1110001 << (8 - 1));
-assert_eq!(I8F8::overflowing_from_str_binary("111000.1"), Ok((check, false)));
-assert_eq!(I8F8::overflowing_from_str_binary("-101100111000.1"), Ok((-check, true)));
+                r#"use fixed::{types::I8F8, RoundingMode};
+let check = I8F8::from_num(0b111000) + I8F8::DELTA;
+assert_eq!(
+    I8F8::overflowing_from_str_binary_round("111000.000000001", RoundingMode::Ceil),
+    Ok((check, false))
+);
+// 101100111000 = 111000 + 100000000 × n
+assert_eq!(
+    I8F8::overflowing_from_str_binary_round("-101100111000.000000001", RoundingMode::Floor),
+    Ok((-check, true))
+);
 "#,
-                r#"use fixed::types::U8F8;
-let check = U8F8::from_bits(0b1110001 << (8 - 1));
-assert_eq!(U8F8::overflowing_from_str_binary("111000.1"), Ok((check, false)));
-assert_eq!(U8F8::overflowing_from_str_binary("101100111000.1"), Ok((check, true)));
+                r#"use fixed::{types::U8F8, RoundingMode};
+let check = U8F8::from_num(0b111000) + U8F8::DELTA;
+assert_eq!(
+    U8F8::overflowing_from_str_binary_round("111000.000000001", RoundingMode::Ceil),
+    Ok((check, false))
+);
+// 101100111000 = 111000 + 100000000 × n
+assert_eq!(
+    U8F8::overflowing_from_str_binary_round("101100111000.000000001", RoundingMode::Ceil),
+    Ok((check, true))
+);
 "#,
             ),
             "```
 ";
             #[inline]
-            pub fn overflowing_from_str_binary(
+            pub fn overflowing_from_str_binary_round(
                 src: &str,
+                mode: RoundingMode,
             ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
-                FromStrRadix::overflowing_from_str_radix(src, 2)
+                FromStrRadix::overflowing_from_str_radix(src, 2, mode)
             }
         }
 
         comment! {
-            "Parses a string slice containing octal digits to return a fixed-point number.
+            "Parses a string slice containing octal digits to return a fixed-point number,
+rounding as specified by `mode`.
 
 Returns a [tuple] of the fixed-point number and a [`bool`] indicating
 whether an overflow has occurred. On overflow, the wrapped value is
 returned.
 
-Rounding is to the nearest, with ties rounded to even.
-
 # Examples
 
 ```rust
 ",
             if_signed_unsigned!(
                 $Signedness,
-                r#"use fixed::types::I8F8;
-let check = I8F8::from_bits(0o1654 << (8 - 3));
-assert_eq!(I8F8::overflowing_from_str_octal("165.4"), Ok((check, false)));
-assert_eq!(I8F8::overflowing_from_str_octal("-7165.4"), Ok((-check, true)));
+                r#"use fixed::{types::I8F8, RoundingMode};
+let check = I8F8::from_num(0o165) + I8F8::DELTA;
+assert_eq!(
+    I8F8::overflowing_from_str_octal_round("165.001", RoundingMode::Ceil),
+    Ok((check, false))
+);
+// 7165 = 165 + 400 × n
+assert_eq!(
+    I8F8::overflowing_from_str_octal_round("-7165.001", RoundingMode::Floor),
+    Ok((-check, true))
+);
 "#,
-                r#"use fixed::types::U8F8;
-let check = U8F8::from_bits(0o1654 << (8 - 3));
-assert_eq!(U8F8::overflowing_from_str_octal("165.4"), Ok((check, false)));
-assert_eq!(U8F8::overflowing_from_str_octal("7165.4"), Ok((check, true)));
+                r#"use fixed::{types::U8F8, RoundingMode};
+let check = U8F8::from_num(0o165) + U8F8::DELTA;
+assert_eq!(
+    U8F8::overflowing_from_str_octal_round("165.001", RoundingMode::Ceil),
+    Ok((check, false))
+);
+// 7165 = 165 + 400 × n
+assert_eq!(
+    U8F8::overflowing_from_str_octal_round("7165.001", RoundingMode::Ceil),
+    Ok((check, true))
+);
 "#,
             ),
             "```
 ";
             #[inline]
-            pub fn overflowing_from_str_octal(
+            pub fn overflowing_from_str_octal_round(
                 src: &str,
+                mode: RoundingMode,
             ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
-                FromStrRadix::overflowing_from_str_radix(src, 8)
+                FromStrRadix::overflowing_from_str_radix(src, 8, mode)
             }
         }
 
         comment! {
-            "Parses a string slice containing hexadecimal digits to return a fixed-point number.
+            "Parses a string slice containing hexadecimal digits to return a fixed-point number,
+rounding as specified by `mode`.
 
 Returns a [tuple] of the fixed-point number and a [`bool`] indicating
 whether an overflow has occurred. On overflow, the wrapped value is
 returned.
 
-Rounding is to the nearest, with ties rounded to even.
-
 # Examples
 
 ```rust
 ",
             if_signed_unsigned!(
                 $Signedness,
-                r#"use fixed::types::I8F8;
-let check = I8F8::from_bits(0xFFE);
-assert_eq!(I8F8::overflowing_from_str_hex("F.FE"), Ok((check, false)));
-assert_eq!(I8F8::overflowing_from_str_hex("-C0F.FE"), Ok((-check, true)));
+                r#"use fixed::{types::I8F8, RoundingMode};
+let check = I8F8::from_num(0xF) + I8F8::DELTA;
+assert_eq!(I8F8::overflowing_from_str_hex_round("F.008", RoundingMode::Ceil), Ok((check, false)));
+// C0F = F + 100 × n
+assert_eq!(
+    I8F8::overflowing_from_str_hex_round("-C0F.008", RoundingMode::Floor),
+    Ok((-check, true))
+);
 "#,
-                r#"use fixed::types::U8F8;
-let check = U8F8::from_bits(0xFFE);
-assert_eq!(U8F8::overflowing_from_str_hex("F.FE"), Ok((check, false)));
-assert_eq!(U8F8::overflowing_from_str_hex("C0F.FE"), Ok((check, true)));
+                r#"use fixed::{types::U8F8, RoundingMode};
+let check = U8F8::from_num(0xF) + U8F8::DELTA;
+assert_eq!(U8F8::overflowing_from_str_hex_round("F.008", RoundingMode::Ceil), Ok((check, false)));
+// C0F = F + 100 × n
+assert_eq!(U8F8::overflowing_from_str_hex_round("C0F.008", RoundingMode::Ceil), Ok((check, true)));
 "#,
             ),
             "```
 ";
             #[inline]
-            pub fn overflowing_from_str_hex(
+            pub fn overflowing_from_str_hex_round(
                 src: &str,
+                mode: RoundingMode,
             ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
-                FromStrRadix::overflowing_from_str_radix(src, 16)
+                FromStrRadix::overflowing_from_str_radix(src, 16, mode)
             }
         }
     };
@@ -0,0 +1,2337 @@
+// Copyright © 2018–2021 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+macro_rules! fixed_math {
+    (
+        $Fixed:ident[$s_fixed:expr](
+            $Inner:ident[$s_inner:expr], $LeEqU:tt, $s_nbits:expr, $s_nbits_m1:expr,
+            $s_nbits_m2:expr
+        ),
+        $Signedness:tt
+    ) => {
+        /// This block contains exponential and logarithmic functions, powers
+        /// with fractional exponents, roots, and hyperbolic functions.
+        ///
+        /// The functions are computed using integer arithmetic only, with a
+        /// wide intermediate precision, so their results are the same on all
+        /// targets. The error is less than one [`DELTA`][Self::DELTA], and the
+        /// result is rounded to the nearest in all but exceptionally rare
+        /// cases.
+        impl<Frac: $LeEqU> $Fixed<Frac> {
+            comment! {
+                "Exponential, <i>e</i><sup>`self`</sup>.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the saturated value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if saturation is required use [`saturating_exp`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ZERO.exp(), Fix::ONE);
+// e = 2.718…, which is 43.49…/16, so it is rounded to 43/16
+assert_eq!(Fix::ONE.exp(), Fix::from_num(2.6875));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// 1/e = 0.368…, which is 5.89…/16, so it is rounded to 6/16
+assert_eq!(Fix::from_num(-1).exp(), Fix::from_num(0.375));
+",
+                },
+                "```
+
+[`saturating_exp`]: Self::saturating_exp
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn exp(self) -> $Fixed<Frac> {
+                    let (ans, overflow) = exp_log::exp_bits(self.to_bits(), Frac::U32);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Base-2 exponential, 2<sup>`self`</sup>.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the saturated value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if saturation is required use [`saturating_exp2`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2).exp2(), Fix::from_num(4));
+// √2 = 1.414…, which is 22.63…/16, so it is rounded to 23/16
+assert_eq!(Fix::from_num(0.5).exp2(), Fix::from_num(1.4375));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1).exp2(), Fix::from_num(0.5));
+",
+                },
+                "```
+
+[`saturating_exp2`]: Self::saturating_exp2
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn exp2(self) -> $Fixed<Frac> {
+                    let (ans, overflow) = exp_log::exp2_bits(self.to_bits(), Frac::U32);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Natural logarithm, ln `self`.
+
+# Panics
+
+Panics if `self` is ",
+                if_signed_unsigned!($Signedness, "≤ 0", "zero"),
+                ".
+
+When debug assertions are enabled, this method also panics if the
+result does not fit. When debug assertions are not enabled, the
+saturated value can be returned, but it is not considered a breaking
+change if in the future it panics; if saturation is required use
+[`saturating_ln`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.ln(), Fix::ZERO);
+// ln 2 = 0.693…, which is 11.09…/16, so it is rounded to 11/16
+assert_eq!(Fix::from_num(2).ln(), Fix::from_num(0.6875));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(0.5).ln(), Fix::from_num(-0.6875));
+",
+                },
+                "```
+
+[`saturating_ln`]: Self::saturating_ln
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn ln(self) -> $Fixed<Frac> {
+                    assert!(self.to_bits() > 0, "logarithm of non-positive number");
+                    let (ans, overflow) = exp_log::ln_bits(self.to_bits(), Frac::U32);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Base-2 logarithm, log<sub>2</sub> `self`.
+
+Unlike [`int_log2`], the result has a fractional part.
+
+# Panics
+
+Panics if `self` is ",
+                if_signed_unsigned!($Signedness, "≤ 0", "zero"),
+                ".
+
+When debug assertions are enabled, this method also panics if the
+result does not fit. When debug assertions are not enabled, the
+saturated value can be returned, but it is not considered a breaking
+change if in the future it panics; if saturation is required use
+[`saturating_log2`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(4).log2(), Fix::from_num(2));
+// log₂ 3 = 1.585…, which is 25.36…/16, so it is rounded to 25/16
+assert_eq!(Fix::from_num(3).log2(), Fix::from_num(1.5625));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(0.25).log2(), Fix::from_num(-2));
+",
+                },
+                "```
+
+[`int_log2`]: Self::int_log2
+[`saturating_log2`]: Self::saturating_log2
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn log2(self) -> $Fixed<Frac> {
+                    assert!(self.to_bits() > 0, "logarithm of non-positive number");
+                    let (ans, overflow) = exp_log::log2_bits(self.to_bits(), Frac::U32);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Base-10 logarithm, log<sub>10</sub> `self`.
+
+Unlike [`int_log10`], the result has a fractional part. The integer part
+of the logarithm is found exactly, so the logarithm of an exact power of
+ten is exact.
+
+# Panics
+
+Panics if `self` is ",
+                if_signed_unsigned!($Signedness, "≤ 0", "zero"),
+                ".
+
+When debug assertions are enabled, this method also panics if the
+result does not fit. When debug assertions are not enabled, the
+saturated value can be returned, but it is not considered a breaking
+change if in the future it panics; if saturation is required use
+[`saturating_log10`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.log10(), Fix::ZERO);
+// log₁₀ 5 = 0.699…, which is 11.18…/16, so it is rounded to 11/16
+assert_eq!(Fix::from_num(5).log10(), Fix::from_num(0.6875));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// log₁₀ 0.5 = −0.301…, which is −4.82…/16, so it is rounded to −5/16
+assert_eq!(Fix::from_num(0.5).log10(), Fix::from_num(-0.3125));
+",
+                },
+                "```
+
+[`int_log10`]: Self::int_log10
+[`saturating_log10`]: Self::saturating_log10
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn log10(self) -> $Fixed<Frac> {
+                    assert!(self.to_bits() > 0, "logarithm of non-positive number");
+                    let (ans, overflow) = exp_log::log10_bits(self.to_bits(), Frac::U32);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Power, `self` raised to the power `exp`.
+
+The result is computed as 2<sup>`exp` × log<sub>2</sub> `self`</sup>
+using integer arithmetic with a wide intermediate precision. The error
+is less than one [`DELTA`][Self::DELTA]. The result is rounded to the
+nearest, except that when the exact result lies half-way between two
+representable values, or extremely close to half-way, it can be
+rounded either way.
+
+The exponent can have a different number of fractional bits from
+`self`.
+
+# Panics
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Panics if `self` is negative, or if `self` is zero and `exp`
+is negative.
+
+",
+                },
+                "When debug assertions are enabled, ",
+                if_signed_else_empty_str! { $Signedness; "this method also " },
+                "panics if the result does not fit. When debug assertions are not
+enabled, the saturated value can be returned, but it is not considered a
+breaking change if in the future it panics; if saturation is required
+use [`saturating_powf`] instead.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U2, U4},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(4).powf(Fix::from_num(0.5)), Fix::from_num(2));
+// √2 = 1.414…, which is 22.63…/16, so it is rounded to 23/16
+assert_eq!(Fix::from_num(2).powf(Fix::from_num(0.5)), Fix::from_num(1.4375));
+let three_halves = ", $s_fixed, "::<U2>::from_num(1.5);
+assert_eq!(Fix::from_num(2.25).powf(three_halves), Fix::from_num(3.375));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(4).powf(Fix::from_num(-0.5)), Fix::from_num(0.5));
+",
+                },
+                "```
+
+[`saturating_powf`]: Self::saturating_powf
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn powf<ExpFrac: $LeEqU>(self, exp: $Fixed<ExpFrac>) -> $Fixed<Frac> {
+                    if_signed! {
+                        $Signedness;
+                        assert!(!self.is_negative(), "power of negative number");
+                        assert!(self.to_bits() != 0 || !exp.is_negative(), "division by zero");
+                    }
+                    let (ans, overflow) =
+                        pow::powf_bits(self.to_bits(), Frac::U32, exp.to_bits(), ExpFrac::U32);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Cube root.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "The cube root of a negative number is negative.
+
+",
+                },
+                "The result is rounded to the nearest. There are no ties, as the exact
+root can never lie half-way between two representable values. The
+rounding is checked using exact integer arithmetic, so the result is
+always correctly rounded.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit,
+which can only happen if there are ",
+                if_signed_unsigned!($Signedness, "fewer than two", "no"),
+                " integer bits. When
+debug assertions are not enabled, the saturated value can be returned,
+but it is not considered a breaking change if in the future it panics;
+if overflow is possible use [`checked_cbrt`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(3.375).cbrt(), Fix::from_num(1.5));
+// ∛2 = 1.259…, which is 20.15…/16, so it is rounded to 20/16
+assert_eq!(Fix::from_num(2).cbrt(), Fix::from_num(1.25));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-3.375).cbrt(), Fix::from_num(-1.5));
+",
+                },
+                "```
+
+[`checked_cbrt`]: Self::checked_cbrt
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn cbrt(self) -> $Fixed<Frac> {
+                    let (ans, overflow) = pow::nth_root_bits(self.to_bits(), Frac::U32, 3);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Returns the `n`th root of `self`.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For odd `n`, the root of a negative number is negative.
+
+",
+                },
+                "The result is rounded to the nearest. There are no ties, as the exact
+root can never lie half-way between two representable values. As long
+as `n` × ([`FRAC_NBITS`][Self::FRAC_NBITS] + 1) ≤ 3968, which holds
+for all `n` ≤ 30, the rounding is checked using exact integer
+arithmetic, so the result is correctly rounded. Larger roots are
+computed with a wide intermediate precision, which is enough to round
+them correctly unless they are extremely close to half-way.
+
+# Panics
+
+Panics if `n` is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    ", or if `self` is negative and `n` is even",
+                },
+                ".
+
+When debug assertions are enabled, this method also panics if the
+result does not fit, which can only happen if there are ",
+                if_signed_unsigned!($Signedness, "fewer than two", "no"),
+                "
+integer bits. When debug assertions are not enabled, the saturated value
+can be returned, but it is not considered a breaking change if in the
+future it panics; if overflow is possible use [`checked_nth_root`]
+instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.25).nth_root(2), Fix::from_num(1.5));
+assert_eq!(Fix::from_num(5.0625).nth_root(4), Fix::from_num(1.5));
+// 2^(1/5) = 1.148…, which is 18.37…/16, so it is rounded to 18/16
+assert_eq!(Fix::from_num(2).nth_root(5), Fix::from_num(1.125));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-3.375).nth_root(3), Fix::from_num(-1.5));
+",
+                },
+                "```
+
+[`checked_nth_root`]: Self::checked_nth_root
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn nth_root(self, n: u32) -> $Fixed<Frac> {
+                    assert!(n != 0, "zeroth root");
+                    if_signed! {
+                        $Signedness;
+                        assert!(
+                            !self.is_negative() || n % 2 != 0,
+                            "even root of negative number"
+                        );
+                    }
+                    let (ans, overflow) = pow::nth_root_bits(self.to_bits(), Frac::U32, n);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Hyperbolic sine.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the saturated value can be
+returned, but it is not considered a breaking change if in the future it
+panics; if saturation is required use [`saturating_sinh`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ZERO.sinh(), Fix::ZERO);
+// sinh 1 = 1.175…, which is 18.80…/16, so it is rounded to 19/16
+assert_eq!(Fix::ONE.sinh(), Fix::from_num(1.1875));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1).sinh(), Fix::from_num(-1.1875));
+",
+                },
+                "```
+
+[`saturating_sinh`]: Self::saturating_sinh
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn sinh(self) -> $Fixed<Frac> {
+                    let (ans, overflow) = exp_log::sinh_bits(self.to_bits(), Frac::U32);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Hyperbolic cosine.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the saturated value can be
+returned, but it is not considered a breaking change if in the future it
+panics; if saturation is required use [`saturating_cosh`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ZERO.cosh(), Fix::ONE);
+// cosh 1 = 1.543…, which is 24.69…/16, so it is rounded to 25/16
+assert_eq!(Fix::ONE.cosh(), Fix::from_num(1.5625));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1).cosh(), Fix::from_num(1.5625));
+",
+                },
+                "```
+
+[`saturating_cosh`]: Self::saturating_cosh
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn cosh(self) -> $Fixed<Frac> {
+                    let (ans, overflow) = exp_log::cosh_bits(self.to_bits(), Frac::U32);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Hyperbolic tangent.
+
+The result never overflows, as its magnitude is never greater than
+the magnitude of `self`. No intermediate value can overflow either, so
+the result tends to ",
+                if_signed_unsigned!($Signedness, "±1", "1"),
+                " for large arguments even if there are only a few integer
+bits, and it is exactly ",
+                if_signed_unsigned!($Signedness, "±1", "1"),
+                " once the exact result is within half a
+[`DELTA`][Self::DELTA] of it.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ZERO.tanh(), Fix::ZERO);
+// tanh 1 = 0.761…, which is 12.18…/16, so it is rounded to 12/16
+assert_eq!(Fix::ONE.tanh(), Fix::from_num(0.75));
+assert_eq!(Fix::MAX.tanh(), Fix::ONE);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.tanh(), -Fix::ONE);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn tanh(self) -> $Fixed<Frac> {
+                    Self::from_bits(exp_log::tanh_bits(self.to_bits(), Frac::U32).0)
+                }
+            }
+
+            comment! {
+                "Checked exponential. Returns <i>e</i><sup>`self`</sup>,
+or [`None`] on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ZERO.checked_exp(), Some(Fix::ONE));
+assert_eq!(Fix::MAX.checked_exp(), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_exp(self) -> Option<$Fixed<Frac>> {
+                    match exp_log::exp_bits(self.to_bits(), Frac::U32) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked base-2 exponential. Returns
+2<sup>`self`</sup>, or [`None`] on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2).checked_exp2(), Some(Fix::from_num(4)));
+assert_eq!(Fix::MAX.checked_exp2(), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_exp2(self) -> Option<$Fixed<Frac>> {
+                    match exp_log::exp2_bits(self.to_bits(), Frac::U32) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked natural logarithm. Returns ln `self`, or
+[`None`] if `self` is ",
+                if_signed_unsigned!($Signedness, "≤ 0", "zero"),
+                " or on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.checked_ln(), Some(Fix::ZERO));
+assert_eq!(Fix::ZERO.checked_ln(), None);
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-1).checked_ln(), None);
+",
+                    "// ln 0.5 is negative, so it overflows
+assert_eq!(Fix::from_num(0.5).checked_ln(), None);
+",
+                ),
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_ln(self) -> Option<$Fixed<Frac>> {
+                    if self.to_bits() > 0 {
+                        match exp_log::ln_bits(self.to_bits(), Frac::U32) {
+                            (ans, false) => Some(Self::from_bits(ans)),
+                            (_, true) => None,
+                        }
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            comment! {
+                "Checked base-2 logarithm. Returns
+log<sub>2</sub> `self`, or [`None`] if `self` is ",
+                if_signed_unsigned!($Signedness, "≤ 0", "zero"),
+                " or on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(4).checked_log2(), Some(Fix::from_num(2)));
+assert_eq!(Fix::ZERO.checked_log2(), None);
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-1).checked_log2(), None);
+",
+                    "// log₂ 0.5 is negative, so it overflows
+assert_eq!(Fix::from_num(0.5).checked_log2(), None);
+",
+                ),
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_log2(self) -> Option<$Fixed<Frac>> {
+                    if self.to_bits() > 0 {
+                        match exp_log::log2_bits(self.to_bits(), Frac::U32) {
+                            (ans, false) => Some(Self::from_bits(ans)),
+                            (_, true) => None,
+                        }
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            comment! {
+                "Checked base-10 logarithm. Returns
+log<sub>10</sub> `self`, or [`None`] if `self` is ",
+                if_signed_unsigned!($Signedness, "≤ 0", "zero"),
+                " or on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.checked_log10(), Some(Fix::ZERO));
+assert_eq!(Fix::ZERO.checked_log10(), None);
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-1).checked_log10(), None);
+",
+                    "// log₁₀ 0.5 is negative, so it overflows
+assert_eq!(Fix::from_num(0.5).checked_log10(), None);
+",
+                ),
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_log10(self) -> Option<$Fixed<Frac>> {
+                    if self.to_bits() > 0 {
+                        match exp_log::log10_bits(self.to_bits(), Frac::U32) {
+                            (ans, false) => Some(Self::from_bits(ans)),
+                            (_, true) => None,
+                        }
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            comment! {
+                "Checked power. Returns `self` raised to the power
+`exp`, or [`None`] ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "if `self` is negative, if `self` is zero and `exp` is
+negative, or on overflow",
+                    "on overflow",
+                ),
+                ".
+
+The result is computed and rounded as for [`powf`], and the exponent
+can have a different number of fractional bits from `self`.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U2, U4},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(4).checked_powf(Fix::from_num(0.5)), Some(Fix::from_num(2)));
+let two = ", $s_fixed, "::<U2>::from_num(2);
+assert_eq!(Fix::MAX.checked_powf(two), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-4).checked_powf(Fix::from_num(0.5)), None);
+assert_eq!(Fix::ZERO.checked_powf(Fix::from_num(-1)), None);
+",
+                },
+                "```
+
+[`powf`]: Self::powf
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_powf<ExpFrac: $LeEqU>(
+                    self,
+                    exp: $Fixed<ExpFrac>,
+                ) -> Option<$Fixed<Frac>> {
+                    if_signed! {
+                        $Signedness;
+                        if self.is_negative() || (self.to_bits() == 0 && exp.is_negative()) {
+                            return None;
+                        }
+                    }
+                    match pow::powf_bits(self.to_bits(), Frac::U32, exp.to_bits(), ExpFrac::U32) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked cube root. Returns the cube root, or [`None`] on
+overflow.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "The cube root of a negative number is negative.
+
+",
+                },
+                "The result is rounded to the nearest. There are no ties, as the exact
+root can never lie half-way between two representable values. The
+rounding is checked using exact integer arithmetic, so the result is
+always correctly rounded.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(3.375).checked_cbrt(), Some(Fix::from_num(1.5)));
+// no integer bits, so the cube root of MAX rounds up to one, which overflows
+type ZeroIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+assert_eq!(ZeroIntBits::MAX.checked_cbrt(), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_cbrt(self) -> Option<$Fixed<Frac>> {
+                    match pow::nth_root_bits(self.to_bits(), Frac::U32, 3) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked `n`th root. Returns the `n`th root of `self`, or
+[`None`] if `n` is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    ", if `self` is negative and `n` is even,",
+                },
+                " or on overflow.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For odd `n`, the root of a negative number is negative.
+
+",
+                },
+                "The result is rounded to the nearest. There are no ties, as the exact
+root can never lie half-way between two representable values. As long
+as `n` × ([`FRAC_NBITS`][Self::FRAC_NBITS] + 1) ≤ 3968, which holds
+for all `n` ≤ 30, the rounding is checked using exact integer
+arithmetic, so the result is correctly rounded. Larger roots are
+computed with a wide intermediate precision, which is enough to round
+them correctly unless they are extremely close to half-way.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.25).checked_nth_root(2), Some(Fix::from_num(1.5)));
+assert_eq!(Fix::from_num(2.25).checked_nth_root(0), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.25).checked_nth_root(2), None);
+",
+                },
+                "// no integer bits, so the cube root of MAX rounds up to one, which overflows
+type ZeroIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+assert_eq!(ZeroIntBits::MAX.checked_nth_root(3), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_nth_root(self, n: u32) -> Option<$Fixed<Frac>> {
+                    if n == 0 {
+                        return None;
+                    }
+                    if_signed! {
+                        $Signedness;
+                        if self.is_negative() && n % 2 == 0 {
+                            return None;
+                        }
+                    }
+                    match pow::nth_root_bits(self.to_bits(), Frac::U32, n) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked hyperbolic sine. Returns the hyperbolic sine, or
+[`None`] on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.checked_sinh(), Some(Fix::from_num(1.1875)));
+assert_eq!(Fix::MAX.checked_sinh(), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_sinh(self) -> Option<$Fixed<Frac>> {
+                    match exp_log::sinh_bits(self.to_bits(), Frac::U32) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked hyperbolic cosine. Returns the hyperbolic
+cosine, or [`None`] on overflow.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.checked_cosh(), Some(Fix::from_num(1.5625)));
+assert_eq!(Fix::MAX.checked_cosh(), None);
+// no integer bits, so cosh 0 = 1 overflows
+type ZeroIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+assert_eq!(ZeroIntBits::ZERO.checked_cosh(), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_cosh(self) -> Option<$Fixed<Frac>> {
+                    match exp_log::cosh_bits(self.to_bits(), Frac::U32) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating exponential. Returns
+<i>e</i><sup>`self`</sup>, saturating on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ZERO.saturating_exp(), Fix::ONE);
+assert_eq!(Fix::MAX.saturating_exp(), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// e↑MIN is too small to be represented, which is not an overflow
+assert_eq!(Fix::MIN.saturating_exp(), Fix::ZERO);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_exp(self) -> $Fixed<Frac> {
+                    Self::from_bits(exp_log::exp_bits(self.to_bits(), Frac::U32).0)
+                }
+            }
+
+            comment! {
+                "Saturating base-2 exponential. Returns
+2<sup>`self`</sup>, saturating on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2).saturating_exp2(), Fix::from_num(4));
+assert_eq!(Fix::MAX.saturating_exp2(), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_exp2(self) -> $Fixed<Frac> {
+                    Self::from_bits(exp_log::exp2_bits(self.to_bits(), Frac::U32).0)
+                }
+            }
+
+            comment! {
+                "Saturating natural logarithm. Returns ln `self`,
+saturating on overflow.
+
+The logarithm of zero saturates to [`MIN`][Self::MIN].
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+Panics if `self` is negative.
+
+",
+                },
+                "# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.saturating_ln(), Fix::ZERO);
+assert_eq!(Fix::ZERO.saturating_ln(), Fix::MIN);
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "// ln 0.5 is negative, so it saturates to zero
+assert_eq!(Fix::from_num(0.5).saturating_ln(), Fix::ZERO);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_ln(self) -> $Fixed<Frac> {
+                    if_signed! {
+                        $Signedness;
+                        assert!(!self.is_negative(), "logarithm of negative number");
+                    }
+                    if self.to_bits() == 0 {
+                        return Self::MIN;
+                    }
+                    Self::from_bits(exp_log::ln_bits(self.to_bits(), Frac::U32).0)
+                }
+            }
+
+            comment! {
+                "Saturating base-2 logarithm. Returns
+log<sub>2</sub> `self`, saturating on overflow.
+
+The logarithm of zero saturates to [`MIN`][Self::MIN].
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+Panics if `self` is negative.
+
+",
+                },
+                "# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(4).saturating_log2(), Fix::from_num(2));
+assert_eq!(Fix::ZERO.saturating_log2(), Fix::MIN);
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "// log₂ 0.5 is negative, so it saturates to zero
+assert_eq!(Fix::from_num(0.5).saturating_log2(), Fix::ZERO);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_log2(self) -> $Fixed<Frac> {
+                    if_signed! {
+                        $Signedness;
+                        assert!(!self.is_negative(), "logarithm of negative number");
+                    }
+                    if self.to_bits() == 0 {
+                        return Self::MIN;
+                    }
+                    Self::from_bits(exp_log::log2_bits(self.to_bits(), Frac::U32).0)
+                }
+            }
+
+            comment! {
+                "Saturating base-10 logarithm. Returns
+log<sub>10</sub> `self`, saturating on overflow.
+
+The logarithm of zero saturates to [`MIN`][Self::MIN].
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+Panics if `self` is negative.
+
+",
+                },
+                "# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.saturating_log10(), Fix::ZERO);
+assert_eq!(Fix::ZERO.saturating_log10(), Fix::MIN);
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "// log₁₀ 0.5 is negative, so it saturates to zero
+assert_eq!(Fix::from_num(0.5).saturating_log10(), Fix::ZERO);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_log10(self) -> $Fixed<Frac> {
+                    if_signed! {
+                        $Signedness;
+                        assert!(!self.is_negative(), "logarithm of negative number");
+                    }
+                    if self.to_bits() == 0 {
+                        return Self::MIN;
+                    }
+                    Self::from_bits(exp_log::log10_bits(self.to_bits(), Frac::U32).0)
+                }
+            }
+
+            comment! {
+                "Saturating power. Returns `self` raised to the power
+`exp`, saturating on overflow.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Zero raised to a negative power saturates to
+[`MAX`][Self::MAX].
+
+",
+                },
+                "The result is computed and rounded as for [`powf`], and the exponent
+can have a different number of fractional bits from `self`.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+Panics if `self` is negative.
+
+",
+                },
+                "# Examples
+
+```rust
+use fixed::{
+    types::extra::{U2, U4},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(4).saturating_powf(Fix::from_num(0.5)), Fix::from_num(2));
+let two = ", $s_fixed, "::<U2>::from_num(2);
+assert_eq!(Fix::MAX.saturating_powf(two), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::ZERO.saturating_powf(Fix::from_num(-1)), Fix::MAX);
+",
+                },
+                "```
+
+[`powf`]: Self::powf
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_powf<ExpFrac: $LeEqU>(
+                    self,
+                    exp: $Fixed<ExpFrac>,
+                ) -> $Fixed<Frac> {
+                    if_signed! {
+                        $Signedness;
+                        assert!(!self.is_negative(), "power of negative number");
+                        if self.to_bits() == 0 && exp.is_negative() {
+                            return Self::MAX;
+                        }
+                    }
+                    Self::from_bits(
+                        pow::powf_bits(self.to_bits(), Frac::U32, exp.to_bits(), ExpFrac::U32).0,
+                    )
+                }
+            }
+
+            comment! {
+                "Saturating hyperbolic sine. Returns the hyperbolic sine,
+saturating on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.saturating_sinh(), Fix::from_num(1.1875));
+assert_eq!(Fix::MAX.saturating_sinh(), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.saturating_sinh(), Fix::MIN);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_sinh(self) -> $Fixed<Frac> {
+                    Self::from_bits(exp_log::sinh_bits(self.to_bits(), Frac::U32).0)
+                }
+            }
+
+            comment! {
+                "Saturating hyperbolic cosine. Returns the hyperbolic
+cosine, saturating on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.saturating_cosh(), Fix::from_num(1.5625));
+assert_eq!(Fix::MAX.saturating_cosh(), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_cosh(self) -> $Fixed<Frac> {
+                    Self::from_bits(exp_log::cosh_bits(self.to_bits(), Frac::U32).0)
+                }
+            }
+        }
+
+        if_signed! {
+            $Signedness;
+            /// This block contains trigonometric functions and their inverses.
+            ///
+            /// The functions are computed using integer arithmetic only, so
+            /// their results are the same on all targets. The error is less
+            /// than one ULP (unit in the last place, which is
+            /// [`DELTA`][Self::DELTA]), and the result is rounded to the nearest
+            /// in all but exceptionally rare cases.
+            ///
+            /// The arguments of [`sin`][Self::sin], [`cos`][Self::cos],
+            /// [`tan`][Self::tan] and [`sin_cos`][Self::sin_cos] are reduced
+            /// modulo [τ/4][crate::consts::FRAC_TAU_4] using 4/τ with 768
+            /// fractional bits, so that the error bound holds for all
+            /// arguments, including large ones.
+            impl<Frac: $LeEqU> $Fixed<Frac> {
+                comment! {
+                    "Sine.
+
+The result always fits, as the magnitude of the sine is never greater
+than the magnitude of `self`.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ZERO.sin(), Fix::ZERO);
+// sin 1 = 0.841…, which is 13.46…/16, so it is rounded to 13/16
+assert_eq!(Fix::ONE.sin(), Fix::from_num(0.8125));
+assert_eq!(Fix::from_num(-1).sin(), Fix::from_num(-0.8125));
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn sin(self) -> $Fixed<Frac> {
+                        let (ans, overflow) = trig::sin_bits(self.to_bits(), Frac::U32);
+                        debug_assert!(!overflow, "overflow");
+                        Self::from_bits(ans)
+                    }
+                }
+
+                comment! {
+                    "Cosine.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit,
+which can only happen if there are fewer than two integer bits. When
+debug assertions are not enabled, the saturated value can be returned,
+but it is not considered a breaking change if in the future it panics;
+if saturation is required use [`saturating_cos`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ZERO.cos(), Fix::ONE);
+// cos 1 = 0.540…, which is 8.64…/16, so it is rounded to 9/16
+assert_eq!(Fix::ONE.cos(), Fix::from_num(0.5625));
+assert_eq!(Fix::from_num(-1).cos(), Fix::from_num(0.5625));
+```
+
+[`saturating_cos`]: Self::saturating_cos
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn cos(self) -> $Fixed<Frac> {
+                        let (ans, overflow) = trig::cos_bits(self.to_bits(), Frac::U32);
+                        debug_assert!(!overflow, "overflow");
+                        Self::from_bits(ans)
+                    }
+                }
+
+                comment! {
+                    "Tangent.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the saturated value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if saturation is required use [`saturating_tan`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ZERO.tan(), Fix::ZERO);
+// tan 1 = 1.557…, which is 24.92…/16, so it is rounded to 25/16
+assert_eq!(Fix::ONE.tan(), Fix::from_num(1.5625));
+```
+
+[`saturating_tan`]: Self::saturating_tan
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn tan(self) -> $Fixed<Frac> {
+                        let (ans, overflow) = trig::tan_bits(self.to_bits(), Frac::U32);
+                        debug_assert!(!overflow, "overflow");
+                        Self::from_bits(ans)
+                    }
+                }
+
+                comment! {
+                    "Simultaneously computes the sine and cosine.
+
+Returns a [tuple] of the sine and the cosine. This is faster than
+calling [`sin`][Self::sin] and [`cos`][Self::cos] separately, as the
+argument is reduced only once.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit,
+which can only happen if there are fewer than two integer bits. When
+debug assertions are not enabled, the saturated value can be returned,
+but it is not considered a breaking change if in the future it panics;
+if saturation is required use [`saturating_sin_cos`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ZERO.sin_cos(), (Fix::ZERO, Fix::ONE));
+let (sin, cos) = Fix::ONE.sin_cos();
+assert_eq!(sin, Fix::from_num(0.8125));
+assert_eq!(cos, Fix::from_num(0.5625));
+```
+
+[`saturating_sin_cos`]: Self::saturating_sin_cos
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn sin_cos(self) -> ($Fixed<Frac>, $Fixed<Frac>) {
+                        let (sin, cos, overflow) = trig::sin_cos_bits(self.to_bits(), Frac::U32);
+                        debug_assert!(!overflow, "overflow");
+                        (Self::from_bits(sin), Self::from_bits(cos))
+                    }
+                }
+
+                comment! {
+                    "Arctangent.
+
+The result is in the range −[τ/4][crate::consts::FRAC_TAU_4] < <i>r</i> <
+[τ/4][crate::consts::FRAC_TAU_4], and it always fits, as the magnitude of the
+result is never greater than the magnitude of the argument.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ZERO.atan(), Fix::ZERO);
+// atan 1 = 0.785…, which is 12.56…/16, so it is rounded to 13/16
+assert_eq!(Fix::ONE.atan(), Fix::from_num(0.8125));
+assert_eq!(Fix::from_num(-1).atan(), Fix::from_num(-0.8125));
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn atan(self) -> $Fixed<Frac> {
+                        let (ans, overflow) = trig::atan_bits(self.to_bits(), Frac::U32);
+                        debug_assert!(!overflow, "overflow");
+                        Self::from_bits(ans)
+                    }
+                }
+
+                comment! {
+                    "Four-quadrant arctangent.
+
+Returns the angle of the point (`other`, `self`), that is `self` is the
+<i>y</i> coordinate and `other` is the <i>x</i> coordinate. The result
+is in the range −[τ/2][crate::consts::FRAC_TAU_2] ≤ <i>r</i> ≤
+[τ/2][crate::consts::FRAC_TAU_2]. The angle of the origin is zero.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit,
+which can only happen if there are fewer than three integer bits. When
+debug assertions are not enabled, the saturated value can be returned,
+but it is not considered a breaking change if in the future it panics;
+if overflow is possible use [`checked_atan2`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ZERO.atan2(Fix::ZERO), Fix::ZERO);
+// the angle of (−1, 1) is 3τ/8 = 2.356…, which is 37.69…/16, so it is
+// rounded to 38/16
+assert_eq!(Fix::ONE.atan2(Fix::from_num(-1)), Fix::from_num(2.375));
+// the angle of (−1, 0) is τ/2 = 3.141…, which is 50.26…/16, so it is
+// rounded to 50/16
+assert_eq!(Fix::ZERO.atan2(Fix::from_num(-1)), Fix::from_num(3.125));
+```
+
+[`checked_atan2`]: Self::checked_atan2
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn atan2(self, other: $Fixed<Frac>) -> $Fixed<Frac> {
+                        let (ans, overflow) =
+                            trig::atan2_bits(self.to_bits(), other.to_bits(), Frac::U32);
+                        debug_assert!(!overflow, "overflow");
+                        Self::from_bits(ans)
+                    }
+                }
+
+                comment! {
+                    "Arcsine.
+
+The result is in the range −[τ/4][crate::consts::FRAC_TAU_4] ≤ <i>r</i> ≤
+[τ/4][crate::consts::FRAC_TAU_4].
+
+# Panics
+
+Panics if `self` is less than −1 or greater than 1.
+
+When debug assertions are enabled, this method also panics if the
+result does not fit, which can only happen if there are fewer than
+two integer bits. When debug assertions are not enabled, the saturated
+value can be returned, but it is not considered a breaking change if in
+the future it panics; if overflow is possible use [`checked_asin`]
+instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ZERO.asin(), Fix::ZERO);
+// asin 0.5 = 0.523…, which is 8.37…/16, so it is rounded to 8/16
+assert_eq!(Fix::from_num(0.5).asin(), Fix::from_num(0.5));
+// asin 1 = 1.570…, which is 25.13…/16, so it is rounded to 25/16
+assert_eq!(Fix::ONE.asin(), Fix::from_num(1.5625));
+```
+
+[`checked_asin`]: Self::checked_asin
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn asin(self) -> $Fixed<Frac> {
+                        assert!(self.unsigned_abs() <= 1, "argument out of domain");
+                        let (ans, overflow) = trig::asin_bits(self.to_bits(), Frac::U32);
+                        debug_assert!(!overflow, "overflow");
+                        Self::from_bits(ans)
+                    }
+                }
+
+                comment! {
+                    "Arccosine.
+
+The result is in the range 0 ≤ <i>r</i> ≤
+[τ/2][crate::consts::FRAC_TAU_2].
+
+# Panics
+
+Panics if `self` is less than −1 or greater than 1.
+
+When debug assertions are enabled, this method also panics if the
+result does not fit, which can only happen if there are fewer than
+three integer bits. When debug assertions are not enabled, the saturated
+value can be returned, but it is not considered a breaking change if in
+the future it panics; if overflow is possible use [`checked_acos`]
+instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.acos(), Fix::ZERO);
+// acos 0.5 = 1.047…, which is 16.75…/16, so it is rounded to 17/16
+assert_eq!(Fix::from_num(0.5).acos(), Fix::from_num(1.0625));
+// acos −1 = 3.141…, which is 50.26…/16, so it is rounded to 50/16
+assert_eq!(Fix::from_num(-1).acos(), Fix::from_num(3.125));
+```
+
+[`checked_acos`]: Self::checked_acos
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn acos(self) -> $Fixed<Frac> {
+                        assert!(self.unsigned_abs() <= 1, "argument out of domain");
+                        let (ans, overflow) = trig::acos_bits(self.to_bits(), Frac::U32);
+                        debug_assert!(!overflow, "overflow");
+                        Self::from_bits(ans)
+                    }
+                }
+
+                comment! {
+                    "Checked sine. Returns the sine, or [`None`] on overflow.
+
+As explained for [`sin`], the sine always fits, so this method never
+returns [`None`]; it is provided for consistency with the other
+trigonometric methods.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.checked_sin(), Some(Fix::from_num(0.8125)));
+```
+
+[`sin`]: Self::sin
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_sin(self) -> Option<$Fixed<Frac>> {
+                        match trig::sin_bits(self.to_bits(), Frac::U32) {
+                            (_, true) => None,
+                            (ans, false) => Some(Self::from_bits(ans)),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Checked cosine. Returns the cosine, or [`None`] on overflow.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits_m1, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.checked_cos(), Some(Fix::from_num(0.5625)));
+// with only one integer bit, cos 0 = 1 does not fit
+type OneIntBit = ", $s_fixed, "<U", $s_nbits_m1, ">;
+assert_eq!(OneIntBit::ZERO.checked_cos(), None);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_cos(self) -> Option<$Fixed<Frac>> {
+                        match trig::cos_bits(self.to_bits(), Frac::U32) {
+                            (_, true) => None,
+                            (ans, false) => Some(Self::from_bits(ans)),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Checked tangent. Returns the tangent, or [`None`] on overflow.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits_m1, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.checked_tan(), Some(Fix::from_num(1.5625)));
+// with only one integer bit, tan 0.875 = 1.197… does not fit
+type OneIntBit = ", $s_fixed, "<U", $s_nbits_m1, ">;
+assert_eq!(OneIntBit::from_num(0.875).checked_tan(), None);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_tan(self) -> Option<$Fixed<Frac>> {
+                        match trig::tan_bits(self.to_bits(), Frac::U32) {
+                            (_, true) => None,
+                            (ans, false) => Some(Self::from_bits(ans)),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Checked simultaneous sine and cosine. Returns a [tuple]
+of the sine and the cosine, or [`None`] if either of them overflows.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits_m1, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(
+    Fix::ONE.checked_sin_cos(),
+    Some((Fix::from_num(0.8125), Fix::from_num(0.5625)))
+);
+// with only one integer bit, cos 0 = 1 does not fit
+type OneIntBit = ", $s_fixed, "<U", $s_nbits_m1, ">;
+assert_eq!(OneIntBit::ZERO.checked_sin_cos(), None);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_sin_cos(self) -> Option<($Fixed<Frac>, $Fixed<Frac>)> {
+                        match trig::sin_cos_bits(self.to_bits(), Frac::U32) {
+                            (_, _, true) => None,
+                            (sin, cos, false) => Some((Self::from_bits(sin), Self::from_bits(cos))),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Checked arctangent. Returns the arctangent, or [`None`] on
+overflow.
+
+As explained for [`atan`], the arctangent always fits, so this method
+never returns [`None`]; it is provided for consistency with the other
+inverse trigonometric methods.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.checked_atan(), Some(Fix::from_num(0.8125)));
+assert_eq!(Fix::MIN.checked_atan(), Some(Fix::MIN.atan()));
+```
+
+[`atan`]: Self::atan
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_atan(self) -> Option<$Fixed<Frac>> {
+                        match trig::atan_bits(self.to_bits(), Frac::U32) {
+                            (_, true) => None,
+                            (ans, false) => Some(Self::from_bits(ans)),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Checked four-quadrant arctangent. Returns the angle of
+the point (`other`, `self`), or [`None`] on overflow.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits_m2, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(
+    Fix::ZERO.checked_atan2(Fix::from_num(-1)),
+    Some(Fix::from_num(3.125))
+);
+// with only two integer bits, τ/2 does not fit
+type Small = ", $s_fixed, "<U", $s_nbits_m2, ">;
+assert_eq!(Small::ZERO.checked_atan2(Small::from_num(-1)), None);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_atan2(self, other: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                        match trig::atan2_bits(self.to_bits(), other.to_bits(), Frac::U32) {
+                            (_, true) => None,
+                            (ans, false) => Some(Self::from_bits(ans)),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Checked arcsine. Returns the arcsine, or [`None`] if
+`self` is less than −1 or greater than 1, or on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.checked_asin(), Some(Fix::from_num(1.5625)));
+assert_eq!(Fix::from_num(2).checked_asin(), None);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_asin(self) -> Option<$Fixed<Frac>> {
+                        if self.unsigned_abs() > 1 {
+                            return None;
+                        }
+                        match trig::asin_bits(self.to_bits(), Frac::U32) {
+                            (_, true) => None,
+                            (ans, false) => Some(Self::from_bits(ans)),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Checked arccosine. Returns the arccosine, or [`None`]
+if `self` is less than −1 or greater than 1, or on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(-1).checked_acos(), Some(Fix::from_num(3.125)));
+assert_eq!(Fix::from_num(-2).checked_acos(), None);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_acos(self) -> Option<$Fixed<Frac>> {
+                        if self.unsigned_abs() > 1 {
+                            return None;
+                        }
+                        match trig::acos_bits(self.to_bits(), Frac::U32) {
+                            (_, true) => None,
+                            (ans, false) => Some(Self::from_bits(ans)),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Saturating sine. Returns the sine, saturating on overflow.
+
+As explained for [`sin`], the sine always fits, so this method never
+saturates; it is provided for consistency with the other trigonometric
+methods.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.saturating_sin(), Fix::from_num(0.8125));
+```
+
+[`sin`]: Self::sin
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn saturating_sin(self) -> $Fixed<Frac> {
+                        Self::from_bits(trig::sin_bits(self.to_bits(), Frac::U32).0)
+                    }
+                }
+
+                comment! {
+                    "Saturating cosine. Returns the cosine, saturating on overflow.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits_m1, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.saturating_cos(), Fix::from_num(0.5625));
+// with only one integer bit, cos 0 = 1 does not fit
+type OneIntBit = ", $s_fixed, "<U", $s_nbits_m1, ">;
+assert_eq!(OneIntBit::ZERO.saturating_cos(), OneIntBit::MAX);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn saturating_cos(self) -> $Fixed<Frac> {
+                        Self::from_bits(trig::cos_bits(self.to_bits(), Frac::U32).0)
+                    }
+                }
+
+                comment! {
+                    "Saturating tangent. Returns the tangent, saturating on overflow.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits_m1, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.saturating_tan(), Fix::from_num(1.5625));
+// with only one integer bit, tan ±0.875 = ±1.197… does not fit
+type OneIntBit = ", $s_fixed, "<U", $s_nbits_m1, ">;
+assert_eq!(OneIntBit::from_num(0.875).saturating_tan(), OneIntBit::MAX);
+assert_eq!(OneIntBit::from_num(-0.875).saturating_tan(), OneIntBit::MIN);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn saturating_tan(self) -> $Fixed<Frac> {
+                        Self::from_bits(trig::tan_bits(self.to_bits(), Frac::U32).0)
+                    }
+                }
+
+                comment! {
+                    "Saturating simultaneous sine and cosine. Returns a
+[tuple] of the sine and the cosine, each saturating on overflow.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits_m1, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(
+    Fix::ONE.saturating_sin_cos(),
+    (Fix::from_num(0.8125), Fix::from_num(0.5625))
+);
+// with only one integer bit, cos 0 = 1 does not fit
+type OneIntBit = ", $s_fixed, "<U", $s_nbits_m1, ">;
+assert_eq!(
+    OneIntBit::ZERO.saturating_sin_cos(),
+    (OneIntBit::ZERO, OneIntBit::MAX)
+);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn saturating_sin_cos(self) -> ($Fixed<Frac>, $Fixed<Frac>) {
+                        let (sin, cos, _) = trig::sin_cos_bits(self.to_bits(), Frac::U32);
+                        (Self::from_bits(sin), Self::from_bits(cos))
+                    }
+                }
+            }
+        }
+
+        if_signed! {
+            $Signedness;
+            /// This block contains the error function and the standard normal
+            /// distribution.
+            ///
+            /// The functions are computed using integer arithmetic only, so
+            /// their results are the same on all targets. The error is less
+            /// than one ULP (unit in the last place, which is
+            /// [`DELTA`][Self::DELTA]), and the result is rounded to the nearest
+            /// in all but exceptionally rare cases.
+            impl<Frac: $LeEqU> $Fixed<Frac> {
+                comment! {
+                    "Error function.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit,
+which can only happen if there are fewer than two integer bits. When
+debug assertions are not enabled, the wrapped value can be returned, but
+it is not considered a breaking change if in the future it panics; if
+wrapping is required use [`wrapping_erf`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ZERO.erf(), Fix::ZERO);
+// erf 1 = 0.842…, which is 13.48…/16, so it is rounded to 13/16
+assert_eq!(Fix::ONE.erf(), Fix::from_num(0.8125));
+assert_eq!(Fix::from_num(-1).erf(), Fix::from_num(-0.8125));
+assert_eq!(Fix::MAX.erf(), Fix::ONE);
+```
+
+[`wrapping_erf`]: Self::wrapping_erf
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn erf(self) -> $Fixed<Frac> {
+                        let (ans, overflow) = self.overflowing_erf();
+                        debug_assert!(!overflow, "overflow");
+                        ans
+                    }
+                }
+
+                comment! {
+                    "Complementary error function, 1 − erf `self`.
+
+The result is computed directly rather than by subtracting the rounded
+error function from one, so it is rounded only once.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit,
+which can only happen if there are fewer than three integer bits. When
+debug assertions are not enabled, the wrapped value can be returned, but
+it is not considered a breaking change if in the future it panics; if
+wrapping is required use [`wrapping_erfc`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ZERO.erfc(), Fix::ONE);
+// erfc 1 = 0.157…, which is 2.51…/16, so it is rounded to 3/16
+assert_eq!(Fix::ONE.erfc(), Fix::from_num(0.1875));
+// erfc −1 = 1.842…, which is 29.48…/16, so it is rounded to 29/16
+assert_eq!(Fix::from_num(-1).erfc(), Fix::from_num(1.8125));
+```
+
+[`wrapping_erfc`]: Self::wrapping_erfc
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn erfc(self) -> $Fixed<Frac> {
+                        let (ans, overflow) = self.overflowing_erfc();
+                        debug_assert!(!overflow, "overflow");
+                        ans
+                    }
+                }
+
+                comment! {
+                    "Cumulative distribution function of the standard normal
+distribution, (1 + erf (`self`/√2)) / 2.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit,
+which can only happen if there are fewer than two integer bits. When
+debug assertions are not enabled, the wrapped value can be returned, but
+it is not considered a breaking change if in the future it panics; if
+wrapping is required use [`wrapping_norm_cdf`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ZERO.norm_cdf(), Fix::from_num(0.5));
+// Φ(1) = 0.841…, which is 13.46…/16, so it is rounded to 13/16
+assert_eq!(Fix::ONE.norm_cdf(), Fix::from_num(0.8125));
+// Φ(−1) = 0.158…, which is 2.53…/16, so it is rounded to 3/16
+assert_eq!(Fix::from_num(-1).norm_cdf(), Fix::from_num(0.1875));
+```
+
+[`wrapping_norm_cdf`]: Self::wrapping_norm_cdf
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn norm_cdf(self) -> $Fixed<Frac> {
+                        let (ans, overflow) = self.overflowing_norm_cdf();
+                        debug_assert!(!overflow, "overflow");
+                        ans
+                    }
+                }
+
+                comment! {
+                    "Probability density function of the standard normal
+distribution, <i>e</i><sup>−`self`²/2</sup> / √(2π).
+
+The result is never greater than 1/√(2π) = 0.398…, so it always fits.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// φ(0) = 0.398…, which is 6.38…/16, so it is rounded to 6/16
+assert_eq!(Fix::ZERO.norm_pdf(), Fix::from_num(0.375));
+// φ(1) = 0.241…, which is 3.87…/16, so it is rounded to 4/16
+assert_eq!(Fix::ONE.norm_pdf(), Fix::from_num(0.25));
+assert_eq!(Fix::from_num(-1).norm_pdf(), Fix::from_num(0.25));
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn norm_pdf(self) -> $Fixed<Frac> {
+                        Self::from_bits(erf::norm_pdf_bits(self.to_bits(), Frac::U32).0)
+                    }
+                }
+
+                comment! {
+                    "Checked error function. Returns the error function, or [`None`] on
+overflow.
+
+Overflow can only occur if there are fewer than two integer bits.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.checked_erf(), Some(Fix::from_num(0.8125)));
+// with no integer bits, erf −0.5 = −0.520… does not fit
+type NoIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+assert_eq!(NoIntBits::MIN.checked_erf(), None);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_erf(self) -> Option<$Fixed<Frac>> {
+                        match erf::erf_bits(self.to_bits(), Frac::U32) {
+                            (_, true) => None,
+                            (ans, false) => Some(Self::from_bits(ans)),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Checked complementary error function. Returns the complementary error
+function, or [`None`] on overflow.
+
+Overflow can only occur if there are fewer than three integer bits.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits_m1, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.checked_erfc(), Some(Fix::from_num(0.1875)));
+// with only one integer bit, erfc −0.5 = 1.520… does not fit
+type OneIntBit = ", $s_fixed, "<U", $s_nbits_m1, ">;
+assert_eq!(OneIntBit::from_num(-0.5).checked_erfc(), None);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_erfc(self) -> Option<$Fixed<Frac>> {
+                        match erf::erfc_bits(self.to_bits(), Frac::U32) {
+                            (_, true) => None,
+                            (ans, false) => Some(Self::from_bits(ans)),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Checked cumulative distribution function of the standard normal
+distribution. Returns the cumulative distribution function, or [`None`]
+on overflow.
+
+Overflow can only occur if there are fewer than two integer bits.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.checked_norm_cdf(), Some(Fix::from_num(0.8125)));
+// with no integer bits, Φ(0) = 0.5 does not fit
+type NoIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+assert_eq!(NoIntBits::ZERO.checked_norm_cdf(), None);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_norm_cdf(self) -> Option<$Fixed<Frac>> {
+                        match erf::norm_cdf_bits(self.to_bits(), Frac::U32) {
+                            (_, true) => None,
+                            (ans, false) => Some(Self::from_bits(ans)),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Saturating error function. Returns the error function, saturating on
+overflow.
+
+Overflow can only occur if there are fewer than two integer bits.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.saturating_erf(), Fix::from_num(0.8125));
+// with no integer bits, erf −0.5 = −0.520… does not fit
+type NoIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+assert_eq!(NoIntBits::MIN.saturating_erf(), NoIntBits::MIN);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn saturating_erf(self) -> $Fixed<Frac> {
+                        Self::from_bits(erf::erf_bits(self.to_bits(), Frac::U32).0)
+                    }
+                }
+
+                comment! {
+                    "Saturating complementary error function. Returns the complementary error
+function, saturating on overflow.
+
+Overflow can only occur if there are fewer than three integer bits.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits_m1, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.saturating_erfc(), Fix::from_num(0.1875));
+// with only one integer bit, erfc −0.5 = 1.520… does not fit
+type OneIntBit = ", $s_fixed, "<U", $s_nbits_m1, ">;
+assert_eq!(OneIntBit::from_num(-0.5).saturating_erfc(), OneIntBit::MAX);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn saturating_erfc(self) -> $Fixed<Frac> {
+                        Self::from_bits(erf::erfc_bits(self.to_bits(), Frac::U32).0)
+                    }
+                }
+
+                comment! {
+                    "Saturating cumulative distribution function of the standard normal
+distribution. Returns the cumulative distribution function, saturating
+on overflow.
+
+Overflow can only occur if there are fewer than two integer bits.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.saturating_norm_cdf(), Fix::from_num(0.8125));
+// with no integer bits, Φ(0) = 0.5 does not fit
+type NoIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+assert_eq!(NoIntBits::ZERO.saturating_norm_cdf(), NoIntBits::MAX);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn saturating_norm_cdf(self) -> $Fixed<Frac> {
+                        Self::from_bits(erf::norm_cdf_bits(self.to_bits(), Frac::U32).0)
+                    }
+                }
+
+                comment! {
+                    "Wrapping error function. Returns the error function, wrapping on
+overflow.
+
+Overflow can only occur if there are fewer than two integer bits.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.wrapping_erf(), Fix::from_num(0.8125));
+// with no integer bits, erf −0.5 = −0.520… does not fit, and it wraps around to 0.479…
+type NoIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+let wrapped = NoIntBits::MIN.wrapping_erf();
+assert!(0.47 < wrapped && wrapped < 0.49);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn wrapping_erf(self) -> $Fixed<Frac> {
+                        self.overflowing_erf().0
+                    }
+                }
+
+                comment! {
+                    "Wrapping complementary error function. Returns the complementary error
+function, wrapping on overflow.
+
+Overflow can only occur if there are fewer than three integer bits.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits_m1, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.wrapping_erfc(), Fix::from_num(0.1875));
+// with only one integer bit, erfc −0.5 = 1.520… does not fit, and it wraps around to −0.479…
+type OneIntBit = ", $s_fixed, "<U", $s_nbits_m1, ">;
+let wrapped = OneIntBit::from_num(-0.5).wrapping_erfc();
+assert!(-0.49 < wrapped && wrapped < -0.47);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn wrapping_erfc(self) -> $Fixed<Frac> {
+                        self.overflowing_erfc().0
+                    }
+                }
+
+                comment! {
+                    "Wrapping cumulative distribution function of the standard normal
+distribution. Returns the cumulative distribution function, wrapping on
+overflow.
+
+Overflow can only occur if there are fewer than two integer bits.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.wrapping_norm_cdf(), Fix::from_num(0.8125));
+// with no integer bits, Φ(0) = 0.5 does not fit, and it wraps around to −0.5
+type NoIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+let wrapped = NoIntBits::ZERO.wrapping_norm_cdf();
+assert!(wrapped == NoIntBits::MIN);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn wrapping_norm_cdf(self) -> $Fixed<Frac> {
+                        self.overflowing_norm_cdf().0
+                    }
+                }
+
+                comment! {
+                    "Overflowing error function.
+
+Returns a [tuple] of the error function and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+Overflow can only occur if there are fewer than two integer bits.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.overflowing_erf(), (Fix::from_num(0.8125), false));
+// with no integer bits, erf −0.5 = −0.520… does not fit, and it wraps around to 0.479…
+type NoIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+let (wrapped, overflow) = NoIntBits::MIN.overflowing_erf();
+assert!(0.47 < wrapped && wrapped < 0.49 && overflow);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn overflowing_erf(self) -> ($Fixed<Frac>, bool) {
+                        let (ans, overflow) = erf::erf_bits_wrapping(self.to_bits(), Frac::U32);
+                        (Self::from_bits(ans), overflow)
+                    }
+                }
+
+                comment! {
+                    "Overflowing complementary error function.
+
+Returns a [tuple] of the complementary error function and a [`bool`]
+indicating whether an overflow has occurred. On overflow, the wrapped
+value is returned.
+
+Overflow can only occur if there are fewer than three integer bits.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits_m1, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.overflowing_erfc(), (Fix::from_num(0.1875), false));
+// with only one integer bit, erfc −0.5 = 1.520… does not fit, and it wraps around to −0.479…
+type OneIntBit = ", $s_fixed, "<U", $s_nbits_m1, ">;
+let (wrapped, overflow) = OneIntBit::from_num(-0.5).overflowing_erfc();
+assert!(-0.49 < wrapped && wrapped < -0.47 && overflow);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn overflowing_erfc(self) -> ($Fixed<Frac>, bool) {
+                        let (ans, overflow) = erf::erfc_bits_wrapping(self.to_bits(), Frac::U32);
+                        (Self::from_bits(ans), overflow)
+                    }
+                }
+
+                comment! {
+                    "Overflowing cumulative distribution function of the standard normal
+distribution.
+
+Returns a [tuple] of the cumulative distribution function and a [`bool`]
+indicating whether an overflow has occurred. On overflow, the wrapped
+value is returned.
+
+Overflow can only occur if there are fewer than two integer bits.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::ONE.overflowing_norm_cdf(), (Fix::from_num(0.8125), false));
+// with no integer bits, Φ(0) = 0.5 does not fit, and it wraps around to −0.5
+type NoIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+let (wrapped, overflow) = NoIntBits::ZERO.overflowing_norm_cdf();
+assert!(wrapped == NoIntBits::MIN && overflow);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn overflowing_norm_cdf(self) -> ($Fixed<Frac>, bool) {
+                        let (ans, overflow) = erf::norm_cdf_bits_wrapping(self.to_bits(), Frac::U32);
+                        (Self::from_bits(ans), overflow)
+                    }
+                }
+            }
+        }
+    };
+}
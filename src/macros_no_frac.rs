@@ -19,7 +19,7 @@ macro_rules! fixed_no_frac {
             $Inner:ident[$s_inner:expr], $LeEqU:tt, $s_nbits:expr, $s_nbits_m1:expr
         ),
         $nbytes:expr, $bytes_val:expr, $rev_bytes_val:expr, $be_bytes:expr, $le_bytes:expr,
-        $UFixed:ident[$s_ufixed:expr], $UInner:ty, $Signedness:tt,
+        $IFixed:ident[$s_ifixed:expr], $UFixed:ident[$s_ufixed:expr], $UInner:ty, $Signedness:tt,
         $Double:ident, $DoubleInner:ty, $s_nbits_2:expr, $HasDouble:tt
     ) => {
         /// The implementation of items in this block is independent
@@ -765,6 +765,312 @@ assert_eq!(a.wide_mul(b), 1.328_125);
                         $Double::from_bits(self_bits * rhs_bits)
                     }
                 }
+
+                comment! {
+                    "Divides two fixed-point numbers and returns a wider
+type to retain more precision.
+
+If `self` has <i>f</i> fractional bits and ", $s_nbits, " − <i>f</i>
+integer bits, and `rhs` has <i>g</i> fractional bits and ", $s_nbits,
+" − <i>g</i> integer bits, then the returned fixed-point number will
+have <i>f</i> + <i>g</i> fractional bits and ", $s_nbits_2,
+" − <i>f</i> − <i>g</i> integer bits. The quotient is rounded towards zero,
+which is the rounding used by the `/` operator.
+
+# Panics
+
+Panics if the divisor is zero or if the quotient does not fit, which can only
+happen if `rhs` has ",
+                    if_signed_unsigned!($Signedness, "at least ", "more than "),
+                    $s_nbits, " ÷ 2 fractional bits.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U2, U4},
+    ", $s_fixed, ",
+};
+// 1.25 ÷ 1.5 = 0.833…
+let a = ", $s_fixed, "::<U2>::from_num(1.25);
+let b = ", $s_fixed, "::<U4>::from_num(1.5);
+// six fractional bits: 0.828_125 = 53/64
+assert_eq!(a.wide_div(b), 0.828_125);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn wide_div<RhsFrac: $LeEqU>(
+                        self,
+                        rhs: $Fixed<RhsFrac>,
+                    ) -> $Double<Sum<Frac, RhsFrac>>
+                    where
+                        Frac: $LeEqU + Add<RhsFrac>,
+                    {
+                        assert!(rhs.to_bits() != 0, "division by zero");
+                        match self.checked_wide_div(rhs) {
+                            Some(quot) => quot,
+                            None => panic!("overflow"),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Checked widening division. Returns the quotient as a
+wider type, or [`None`] if the divisor is zero or if the quotient does not fit.
+
+See [`wide_div`] for more information.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U2, U4},
+    ", $s_fixed, ",
+};
+let a = ", $s_fixed, "::<U2>::from_num(1.25);
+let b = ", $s_fixed, "::<U4>::from_num(1.5);
+assert_eq!(a.checked_wide_div(b).unwrap(), 0.828_125);
+assert!(a.checked_wide_div(", $s_fixed, "::<U4>::ZERO).is_none());
+```
+
+[`wide_div`]: Self::wide_div
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_wide_div<RhsFrac: $LeEqU>(
+                        self,
+                        rhs: $Fixed<RhsFrac>,
+                    ) -> Option<$Double<Sum<Frac, RhsFrac>>>
+                    where
+                        Frac: $LeEqU + Add<RhsFrac>,
+                    {
+                        let rhs_bits = <$DoubleInner>::from(rhs.to_bits());
+                        if rhs_bits == 0 {
+                            return None;
+                        }
+                        // The quotient has f + g fractional bits, so the
+                        // dividend would have to be shifted left by 2g, which
+                        // does not fit. Instead divide twice, shifting by g
+                        // each time; both partial quotients are truncated
+                        // towards zero and have the same sign.
+                        let shift = RhsFrac::U32;
+                        let num = <$DoubleInner>::from(self.to_bits()) << shift;
+                        let quot = num.checked_div(rhs_bits)?;
+                        let rem = num % rhs_bits;
+                        let quot_frac = (rem << shift) / rhs_bits;
+                        let quot = quot.checked_mul(1 << shift)?.checked_add(quot_frac)?;
+                        Some($Double::from_bits(quot))
+                    }
+                }
+
+                comment! {
+                    "Adds two fixed-point numbers that can have different
+numbers of fractional bits, and returns a wider type that holds the exact sum.
+
+If `self` has <i>f</i> fractional bits and `rhs` has <i>g</i> fractional bits,
+then the returned fixed-point number will have ", $s_nbits_2, " bits,
+max(<i>f</i>, <i>g</i>) of which are fractional.
+
+",
+                    if_signed_unsigned!(
+                        $Signedness,
+                        "# Panics
+
+Panics if the sum does not fit. This can only happen if one operand has no
+fractional bits and the other has no integer bits, and both are negative.
+
+",
+                        "The sum always fits.
+
+",
+                    ),
+                    "# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U6},
+    ", $s_fixed, ",
+};
+let a = ", $s_fixed, "::<U4>::from_num(1.25);
+let b = ", $s_fixed, "::<U6>::from_num(0.015625);
+assert_eq!(a.add_wide(b), 1.265625);
+// the sum of the maximum values does not overflow
+let max = ", $s_fixed, "::<U4>::MAX.add_wide(", $s_fixed, "::<U6>::MAX);
+assert!(max > ", $s_fixed, "::<U4>::MAX);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn add_wide<RhsFrac: $LeEqU>(
+                        self,
+                        rhs: $Fixed<RhsFrac>,
+                    ) -> $Double<Maximum<Frac, RhsFrac>>
+                    where
+                        Frac: $LeEqU + Max<RhsFrac>,
+                    {
+                        match self.checked_add_wide(rhs) {
+                            Some(sum) => sum,
+                            None => panic!("overflow"),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Subtracts two fixed-point numbers that can have
+different numbers of fractional bits, and returns a wider type that holds the
+exact difference.
+
+If `self` has <i>f</i> fractional bits and `rhs` has <i>g</i> fractional bits,
+then the returned fixed-point number will have ", $s_nbits_2, " bits,
+max(<i>f</i>, <i>g</i>) of which are fractional.
+
+# Panics
+
+",
+                    if_signed_unsigned!(
+                        $Signedness,
+                        "Panics if the difference does not fit. This can only
+happen if one operand has no fractional bits and the other has no integer bits.
+",
+                        "Panics if the difference is negative.
+",
+                    ),
+                    "
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U6},
+    ", $s_fixed, ",
+};
+let a = ", $s_fixed, "::<U4>::from_num(1.25);
+let b = ", $s_fixed, "::<U6>::from_num(0.015625);
+assert_eq!(a.sub_wide(b), 1.234375);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn sub_wide<RhsFrac: $LeEqU>(
+                        self,
+                        rhs: $Fixed<RhsFrac>,
+                    ) -> $Double<Maximum<Frac, RhsFrac>>
+                    where
+                        Frac: $LeEqU + Max<RhsFrac>,
+                    {
+                        match self.checked_sub_wide(rhs) {
+                            Some(diff) => diff,
+                            None => panic!("overflow"),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Checked addition of two fixed-point numbers that can have
+different numbers of fractional bits. Returns the exact sum, or [`None`] if it
+does not fit.
+
+If `self` has <i>f</i> fractional bits and `rhs` has <i>g</i> fractional bits,
+then the returned fixed-point number will have ", $s_nbits_2, " bits,
+max(<i>f</i>, <i>g</i>) of which are fractional.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U6},
+    ", $s_fixed, ",
+};
+let a = ", $s_fixed, "::<U4>::from_num(1.25);
+let b = ", $s_fixed, "::<U6>::from_num(0.015625);
+assert_eq!(a.checked_add_wide(b).unwrap(), 1.265625);
+",
+                    if_signed_else_empty_str! {
+                        $Signedness;
+                        "use fixed::types::extra::{U0, U", $s_nbits, "};
+let int = ", $s_fixed, "::<U0>::MIN;
+let frac = ", $s_fixed, "::<U", $s_nbits, ">::MIN;
+assert!(int.checked_add_wide(frac).is_none());
+",
+                    },
+                    "```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_add_wide<RhsFrac: $LeEqU>(
+                        self,
+                        rhs: $Fixed<RhsFrac>,
+                    ) -> Option<$Double<Maximum<Frac, RhsFrac>>>
+                    where
+                        Frac: $LeEqU + Max<RhsFrac>,
+                    {
+                        let align = |bits: $DoubleInner, shift: u32| {
+                            let shifted = bits.checked_shl(shift).unwrap_or(0);
+                            if bits == 0 || shifted.checked_shr(shift) == Some(bits) {
+                                Some(shifted)
+                            } else {
+                                None
+                            }
+                        };
+                        let frac_nbits = Frac::U32.max(RhsFrac::U32);
+                        let lhs = align(self.to_bits().into(), frac_nbits - Frac::U32)?;
+                        let rhs = align(rhs.to_bits().into(), frac_nbits - RhsFrac::U32)?;
+                        lhs.checked_add(rhs).map($Double::from_bits)
+                    }
+                }
+
+                comment! {
+                    "Checked subtraction of two fixed-point numbers that can
+have different numbers of fractional bits. Returns the exact difference, or
+[`None`] if it does not fit.
+
+If `self` has <i>f</i> fractional bits and `rhs` has <i>g</i> fractional bits,
+then the returned fixed-point number will have ", $s_nbits_2, " bits,
+max(<i>f</i>, <i>g</i>) of which are fractional.
+
+# Examples
+
+```rust
+use fixed::{
+    types::extra::{U4, U6},
+    ", $s_fixed, ",
+};
+let a = ", $s_fixed, "::<U4>::from_num(1.25);
+let b = ", $s_fixed, "::<U6>::from_num(0.015625);
+assert_eq!(a.checked_sub_wide(b).unwrap(), 1.234375);
+",
+                    if_signed_unsigned!(
+                        $Signedness,
+                        "assert_eq!(b.checked_sub_wide(a).unwrap(), -1.234375);
+",
+                        "assert!(b.checked_sub_wide(a).is_none());
+",
+                    ),
+                    "```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_sub_wide<RhsFrac: $LeEqU>(
+                        self,
+                        rhs: $Fixed<RhsFrac>,
+                    ) -> Option<$Double<Maximum<Frac, RhsFrac>>>
+                    where
+                        Frac: $LeEqU + Max<RhsFrac>,
+                    {
+                        let align = |bits: $DoubleInner, shift: u32| {
+                            let shifted = bits.checked_shl(shift).unwrap_or(0);
+                            if bits == 0 || shifted.checked_shr(shift) == Some(bits) {
+                                Some(shifted)
+                            } else {
+                                None
+                            }
+                        };
+                        let frac_nbits = Frac::U32.max(RhsFrac::U32);
+                        let lhs = align(self.to_bits().into(), frac_nbits - Frac::U32)?;
+                        let rhs = align(rhs.to_bits().into(), frac_nbits - RhsFrac::U32)?;
+                        lhs.checked_sub(rhs).map($Double::from_bits)
+                    }
+                }
             }
 
             comment! {
@@ -1338,6 +1644,114 @@ assert_eq!(Fix::MIN.checked_sub(Fix::ONE), None);
                 }
             }
 
+            if_unsigned! {
+                $Signedness;
+                comment! {
+                    "Checked addition with a signed fixed-point number.
+Returns the sum, or [`None`] on overflow.
+
+The `rhs` parameter is signed and has the same number of fractional bits as
+`self`.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ifixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type IFix = ", $s_ifixed, "<U4>;
+assert_eq!(
+    Fix::from_num(5).checked_add_signed(IFix::from_num(-3)),
+    Some(Fix::from_num(2))
+);
+assert_eq!(Fix::from_num(2).checked_add_signed(IFix::from_num(-3)), None);
+assert_eq!(Fix::MAX.checked_add_signed(IFix::DELTA), None);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn checked_add_signed(
+                        self,
+                        rhs: $IFixed<Frac>,
+                    ) -> Option<$Fixed<Frac>> {
+                        match self.overflowing_add_signed(rhs) {
+                            (ans, false) => Some(ans),
+                            (_, true) => None,
+                        }
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Checked addition with an unsigned fixed-point number.
+Returns the sum, or [`None`] on overflow.
+
+The `rhs` parameter is unsigned and has the same number of fractional bits as
+`self`.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", $s_ufixed, "<U4>;
+assert_eq!(
+    Fix::from_num(-5).checked_add_unsigned(UFix::from_num(3)),
+    Some(Fix::from_num(-2))
+);
+assert_eq!(Fix::MIN.checked_add_unsigned(UFix::MAX), Some(Fix::MAX));
+assert_eq!(Fix::ZERO.checked_add_unsigned(UFix::MAX), None);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn checked_add_unsigned(
+                        self,
+                        rhs: $UFixed<Frac>,
+                    ) -> Option<$Fixed<Frac>> {
+                        match self.overflowing_add_unsigned(rhs) {
+                            (ans, false) => Some(ans),
+                            (_, true) => None,
+                        }
+                    }
+                }
+
+                comment! {
+                    "Checked subtraction of an unsigned fixed-point number.
+Returns the difference, or [`None`] on overflow.
+
+The `rhs` parameter is unsigned and has the same number of fractional bits as
+`self`.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", $s_ufixed, "<U4>;
+assert_eq!(
+    Fix::from_num(3).checked_sub_unsigned(UFix::from_num(5)),
+    Some(Fix::from_num(-2))
+);
+assert_eq!(Fix::MAX.checked_sub_unsigned(UFix::MAX), Some(Fix::MIN));
+assert_eq!(Fix::ZERO.checked_sub_unsigned(UFix::MAX), None);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn checked_sub_unsigned(
+                        self,
+                        rhs: $UFixed<Frac>,
+                    ) -> Option<$Fixed<Frac>> {
+                        match self.overflowing_sub_unsigned(rhs) {
+                            (ans, false) => Some(ans),
+                            (_, true) => None,
+                        }
+                    }
+                }
+            }
+
             comment! {
                 "Checked remainder. Returns the remainder, or [`None`] if
 the divisor is zero.
@@ -1423,34 +1837,180 @@ assert_eq!(Fix::MAX.checked_mul_add(Fix::from_num(1.5), -Fix::MAX), Some(Fix::MA
                 }
             }
 
-            comment! {
-                "Checked multiplication by an integer. Returns the
-product, or [`None`] on overflow.
+            if_unsigned! {
+                $Signedness;
+                comment! {
+                    "Checked multiplication by a signed fixed-point number.
+Returns the product, or [`None`] on overflow.
+
+The `rhs` parameter is signed and can have a different number of
+fractional bits from `self`. The product is rounded down.
 
 # Examples
 
 ```rust
-use fixed::{types::extra::U4, ", $s_fixed, "};
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ifixed, "};
 type Fix = ", $s_fixed, "<U4>;
-assert_eq!(Fix::MAX.checked_mul_int(1), Some(Fix::MAX));
-assert_eq!(Fix::MAX.checked_mul_int(2), None);
+type IFix = ", $s_ifixed, "<U4>;
+assert_eq!(
+    Fix::from_num(6).checked_mul_signed(IFix::from_num(0.5)),
+    Some(Fix::from_num(3))
+);
+assert_eq!(Fix::from_num(6).checked_mul_signed(IFix::from_num(-0.5)), None);
+assert_eq!(Fix::MAX.checked_mul_signed(IFix::from_num(2)), None);
 ```
 ";
-                #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_mul_int(self, rhs: $Inner) -> Option<$Fixed<Frac>> {
-                    match self.to_bits().checked_mul(rhs) {
-                        None => None,
-                        Some(bits) => Some(Self::from_bits(bits)),
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_mul_signed<RhsFrac: $LeEqU>(
+                        self,
+                        rhs: $IFixed<RhsFrac>,
+                    ) -> Option<$Fixed<Frac>> {
+                        self.checked_mul_add_signed(rhs, Self::ZERO)
                     }
                 }
-            }
 
-            comment! {
-                "Checked division by an integer. Returns the quotient, or
-[`None`] if the divisor is zero",
-                if_signed_unsigned!(
-                    $Signedness,
+                comment! {
+                    "Checked multiply by a signed fixed-point number and add.
+Returns `self` × `mul` + `add`, or [`None`] on overflow.
+
+The `mul` parameter is signed and can have a different number of
+fractional bits from `self`. The product `self` × `mul` is rounded down
+before `add` is added.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ifixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type IFix = ", $s_ifixed, "<U4>;
+assert_eq!(
+    Fix::from_num(4).checked_mul_add_signed(IFix::from_num(-0.5), Fix::from_num(3)),
+    Some(Fix::from_num(1))
+);
+assert_eq!(Fix::from_num(4).checked_mul_add_signed(IFix::from_num(-1), Fix::from_num(3)), None);
+assert_eq!(Fix::MAX.checked_mul_add_signed(IFix::ONE, Fix::DELTA), None);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_mul_add_signed<MulFrac: $LeEqU>(
+                        self,
+                        mul: $IFixed<MulFrac>,
+                        add: $Fixed<Frac>,
+                    ) -> Option<$Fixed<Frac>> {
+                        match self.overflowing_mul_add_signed(mul, add) {
+                            (ans, false) => Some(ans),
+                            (_, true) => None,
+                        }
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Checked multiplication by an unsigned fixed-point number.
+Returns the product, or [`None`] on overflow.
+
+The `rhs` parameter is unsigned and can have a different number of
+fractional bits from `self`. The product is rounded down.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", $s_ufixed, "<U4>;
+assert_eq!(
+    Fix::from_num(-3).checked_mul_unsigned(UFix::from_num(2)),
+    Some(Fix::from_num(-6))
+);
+assert_eq!(Fix::MIN.checked_mul_unsigned(UFix::from_num(0.5)), Some(Fix::MIN / 2));
+assert_eq!((-Fix::ONE).checked_mul_unsigned(UFix::MAX), None);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_mul_unsigned<RhsFrac: $LeEqU>(
+                        self,
+                        rhs: $UFixed<RhsFrac>,
+                    ) -> Option<$Fixed<Frac>> {
+                        self.checked_mul_add_unsigned(rhs, Self::ZERO)
+                    }
+                }
+
+                comment! {
+                    "Checked multiply by an unsigned fixed-point number and add.
+Returns `self` × `mul` + `add`, or [`None`] on overflow.
+
+The `mul` parameter is unsigned and can have a different number of
+fractional bits from `self`. The product `self` × `mul` is rounded down
+before `add` is added. For some cases, the product would overflow on its
+own, but the final result `self` × `mul` + `add` is representable; in
+these cases this method returns the correct result without overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", $s_ufixed, "<U4>;
+assert_eq!(
+    Fix::from_num(-2).checked_mul_add_unsigned(UFix::from_num(1.5), Fix::from_num(1)),
+    Some(Fix::from_num(-2))
+);
+// MIN × 1.5 + MAX = MIN / 2 − DELTA, which does not overflow
+assert_eq!(
+    Fix::MIN.checked_mul_add_unsigned(UFix::from_num(1.5), Fix::MAX),
+    Some(Fix::MIN / 2 - Fix::DELTA)
+);
+assert_eq!(Fix::MAX.checked_mul_add_unsigned(UFix::ONE, Fix::DELTA), None);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn checked_mul_add_unsigned<MulFrac: $LeEqU>(
+                        self,
+                        mul: $UFixed<MulFrac>,
+                        add: $Fixed<Frac>,
+                    ) -> Option<$Fixed<Frac>> {
+                        match self.overflowing_mul_add_unsigned(mul, add) {
+                            (ans, false) => Some(ans),
+                            (_, true) => None,
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Checked multiplication by an integer. Returns the
+product, or [`None`] on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::MAX.checked_mul_int(1), Some(Fix::MAX));
+assert_eq!(Fix::MAX.checked_mul_int(2), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_mul_int(self, rhs: $Inner) -> Option<$Fixed<Frac>> {
+                    match self.to_bits().checked_mul(rhs) {
+                        None => None,
+                        Some(bits) => Some(Self::from_bits(bits)),
+                    }
+                }
+            }
+
+            comment! {
+                "Checked division by an integer. Returns the quotient, or
+[`None`] if the divisor is zero",
+                if_signed_unsigned!(
+                    $Signedness,
                     " or if the division results in overflow.",
                     ".",
                 ),
@@ -1811,6 +2371,111 @@ assert_eq!(Fix::ZERO.saturating_sub(Fix::ONE), Fix::ZERO);",
                 }
             }
 
+            if_unsigned! {
+                $Signedness;
+                comment! {
+                    "Saturating addition with a signed fixed-point number.
+Returns the sum, saturating on overflow.
+
+The `rhs` parameter is signed and has the same number of fractional bits as
+`self`.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ifixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type IFix = ", $s_ifixed, "<U4>;
+assert_eq!(
+    Fix::from_num(5).saturating_add_signed(IFix::from_num(-3)),
+    Fix::from_num(2)
+);
+assert_eq!(Fix::from_num(2).saturating_add_signed(IFix::from_num(-3)), Fix::ZERO);
+assert_eq!(Fix::MAX.saturating_add_signed(IFix::ONE), Fix::MAX);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn saturating_add_signed(self, rhs: $IFixed<Frac>) -> $Fixed<Frac> {
+                        match self.overflowing_add_signed(rhs) {
+                            (ans, false) => ans,
+                            (_, true) => {
+                                if rhs.to_bits() < 0 {
+                                    Self::ZERO
+                                } else {
+                                    Self::MAX
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Saturating addition with an unsigned fixed-point number.
+Returns the sum, saturating on overflow.
+
+The `rhs` parameter is unsigned and has the same number of fractional bits as
+`self`.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", $s_ufixed, "<U4>;
+assert_eq!(
+    Fix::from_num(-5).saturating_add_unsigned(UFix::from_num(3)),
+    Fix::from_num(-2)
+);
+assert_eq!(Fix::MIN.saturating_add_unsigned(UFix::MAX), Fix::MAX);
+assert_eq!(Fix::ZERO.saturating_add_unsigned(UFix::MAX), Fix::MAX);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn saturating_add_unsigned(self, rhs: $UFixed<Frac>) -> $Fixed<Frac> {
+                        match self.overflowing_add_unsigned(rhs) {
+                            (ans, false) => ans,
+                            (_, true) => Self::MAX,
+                        }
+                    }
+                }
+
+                comment! {
+                    "Saturating subtraction of an unsigned fixed-point number.
+Returns the difference, saturating on overflow.
+
+The `rhs` parameter is unsigned and has the same number of fractional bits as
+`self`.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", $s_ufixed, "<U4>;
+assert_eq!(
+    Fix::from_num(3).saturating_sub_unsigned(UFix::from_num(5)),
+    Fix::from_num(-2)
+);
+assert_eq!(Fix::MAX.saturating_sub_unsigned(UFix::MAX), Fix::MIN);
+assert_eq!(Fix::ZERO.saturating_sub_unsigned(UFix::MAX), Fix::MIN);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn saturating_sub_unsigned(self, rhs: $UFixed<Frac>) -> $Fixed<Frac> {
+                        match self.overflowing_sub_unsigned(rhs) {
+                            (ans, false) => ans,
+                            (_, true) => Self::MIN,
+                        }
+                    }
+                }
+            }
+
             comment! {
                 "Saturating multiply and add.
 Returns `self` × `mul` + `add`, saturating on overflow.
@@ -1878,6 +2543,165 @@ assert_eq!(Fix::MAX.saturating_mul_add(Fix::from_num(1.5), -Fix::MAX), half_max)
                 }
             }
 
+            if_unsigned! {
+                $Signedness;
+                comment! {
+                    "Saturating multiplication by a signed fixed-point number.
+Returns the product, saturating on overflow.
+
+The `rhs` parameter is signed and can have a different number of
+fractional bits from `self`. The product is rounded down.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ifixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type IFix = ", $s_ifixed, "<U4>;
+assert_eq!(
+    Fix::from_num(6).saturating_mul_signed(IFix::from_num(0.5)),
+    Fix::from_num(3)
+);
+assert_eq!(Fix::from_num(6).saturating_mul_signed(IFix::from_num(-0.5)), Fix::ZERO);
+assert_eq!(Fix::MAX.saturating_mul_signed(IFix::from_num(2)), Fix::MAX);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn saturating_mul_signed<RhsFrac: $LeEqU>(
+                        self,
+                        rhs: $IFixed<RhsFrac>,
+                    ) -> $Fixed<Frac> {
+                        self.saturating_mul_add_signed(rhs, Self::ZERO)
+                    }
+                }
+
+                comment! {
+                    "Saturating multiply by a signed fixed-point number and add.
+Returns `self` × `mul` + `add`, saturating on overflow.
+
+The `mul` parameter is signed and can have a different number of
+fractional bits from `self`. The product `self` × `mul` is rounded down
+before `add` is added.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ifixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type IFix = ", $s_ifixed, "<U4>;
+assert_eq!(
+    Fix::from_num(4).saturating_mul_add_signed(IFix::from_num(-0.5), Fix::from_num(3)),
+    Fix::from_num(1)
+);
+let four = Fix::from_num(4);
+assert_eq!(four.saturating_mul_add_signed(IFix::from_num(-1), Fix::from_num(3)), Fix::ZERO);
+assert_eq!(Fix::MAX.saturating_mul_add_signed(IFix::ONE, Fix::DELTA), Fix::MAX);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn saturating_mul_add_signed<MulFrac: $LeEqU>(
+                        self,
+                        mul: $IFixed<MulFrac>,
+                        add: $Fixed<Frac>,
+                    ) -> $Fixed<Frac> {
+                        match self.overflowing_mul_add_signed(mul, add) {
+                            (ans, false) => ans,
+                            (_, true) => {
+                                if mul.is_negative() {
+                                    Self::ZERO
+                                } else {
+                                    Self::MAX
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Saturating multiplication by an unsigned fixed-point number.
+Returns the product, saturating on overflow.
+
+The `rhs` parameter is unsigned and can have a different number of
+fractional bits from `self`. The product is rounded down.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", $s_ufixed, "<U4>;
+assert_eq!(
+    Fix::from_num(-3).saturating_mul_unsigned(UFix::from_num(2)),
+    Fix::from_num(-6)
+);
+assert_eq!((-Fix::ONE).saturating_mul_unsigned(UFix::MAX), Fix::MIN);
+assert_eq!(Fix::ONE.saturating_mul_unsigned(UFix::MAX), Fix::MAX);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn saturating_mul_unsigned<RhsFrac: $LeEqU>(
+                        self,
+                        rhs: $UFixed<RhsFrac>,
+                    ) -> $Fixed<Frac> {
+                        self.saturating_mul_add_unsigned(rhs, Self::ZERO)
+                    }
+                }
+
+                comment! {
+                    "Saturating multiply by an unsigned fixed-point number and add.
+Returns `self` × `mul` + `add`, saturating on overflow.
+
+The `mul` parameter is unsigned and can have a different number of
+fractional bits from `self`. The product `self` × `mul` is rounded down
+before `add` is added. For some cases, the product would overflow on its
+own, but the final result `self` × `mul` + `add` is representable; in
+these cases this method returns the correct result without overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", $s_ufixed, "<U4>;
+assert_eq!(
+    Fix::from_num(-2).saturating_mul_add_unsigned(UFix::from_num(1.5), Fix::from_num(1)),
+    Fix::from_num(-2)
+);
+// MIN × 1.5 + MAX = MIN / 2 − DELTA, which does not overflow
+assert_eq!(
+    Fix::MIN.saturating_mul_add_unsigned(UFix::from_num(1.5), Fix::MAX),
+    Fix::MIN / 2 - Fix::DELTA
+);
+assert_eq!(Fix::MIN.saturating_mul_add_unsigned(UFix::ONE, -Fix::DELTA), Fix::MIN);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn saturating_mul_add_unsigned<MulFrac: $LeEqU>(
+                        self,
+                        mul: $UFixed<MulFrac>,
+                        add: $Fixed<Frac>,
+                    ) -> $Fixed<Frac> {
+                        match self.overflowing_mul_add_unsigned(mul, add) {
+                            (ans, false) => ans,
+                            (_, true) => {
+                                if self.is_negative() {
+                                    Self::MIN
+                                } else {
+                                    Self::MAX
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
             comment! {
                 "Saturating multiplication by an integer. Returns the product, saturating on overflow.
 
@@ -2076,85 +2900,315 @@ assert_eq!(Fix::from_num(5).wrapping_neg(), Fix::from_bits(neg_five_bits));",
 # Examples
 
 ```rust
-use fixed::{types::extra::U4, ", $s_fixed, "};
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let one_minus_delta = Fix::ONE - Fix::DELTA;
+assert_eq!(Fix::from_num(3).wrapping_add(Fix::from_num(2)), Fix::from_num(5));
+assert_eq!(Fix::MAX.wrapping_add(Fix::ONE), ",
+                if_signed_else_empty_str! { $Signedness; "Fix::MIN + " },
+                "one_minus_delta);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn wrapping_add(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                    Self::from_bits(self.to_bits().wrapping_add(rhs.to_bits()))
+                }
+            }
+
+            comment! {
+                "Wrapping subtraction. Returns the difference, wrapping on overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let one_minus_delta = Fix::ONE - Fix::DELTA;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(3).wrapping_sub(Fix::from_num(5)), Fix::from_num(-2));
+assert_eq!(Fix::MIN",
+                    "assert_eq!(Fix::from_num(5).wrapping_sub(Fix::from_num(3)), Fix::from_num(2));
+assert_eq!(Fix::ZERO",
+                ),
+                ".wrapping_sub(Fix::ONE), Fix::MAX - one_minus_delta);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn wrapping_sub(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                    Self::from_bits(self.to_bits().wrapping_sub(rhs.to_bits()))
+                }
+            }
+
+            if_unsigned! {
+                $Signedness;
+                comment! {
+                    "Wrapping addition with a signed fixed-point number.
+Returns the sum, wrapping on overflow.
+
+The `rhs` parameter is signed and has the same number of fractional bits as
+`self`.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ifixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type IFix = ", $s_ifixed, "<U4>;
+assert_eq!(
+    Fix::from_num(5).wrapping_add_signed(IFix::from_num(-3)),
+    Fix::from_num(2)
+);
+assert_eq!(Fix::ZERO.wrapping_add_signed(-IFix::DELTA), Fix::MAX);
+assert_eq!(Fix::MAX.wrapping_add_signed(IFix::DELTA), Fix::ZERO);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn wrapping_add_signed(self, rhs: $IFixed<Frac>) -> $Fixed<Frac> {
+                        let rhs_bits = rhs.to_bits() as $Inner;
+                        Self::from_bits(self.to_bits().wrapping_add(rhs_bits))
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Wrapping addition with an unsigned fixed-point number.
+Returns the sum, wrapping on overflow.
+
+The `rhs` parameter is unsigned and has the same number of fractional bits as
+`self`.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", $s_ufixed, "<U4>;
+assert_eq!(
+    Fix::from_num(-5).wrapping_add_unsigned(UFix::from_num(3)),
+    Fix::from_num(-2)
+);
+assert_eq!(Fix::MAX.wrapping_add_unsigned(UFix::DELTA), Fix::MIN);
+assert_eq!(Fix::ZERO.wrapping_add_unsigned(UFix::MAX), -Fix::DELTA);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn wrapping_add_unsigned(self, rhs: $UFixed<Frac>) -> $Fixed<Frac> {
+                        let rhs_bits = rhs.to_bits() as $Inner;
+                        Self::from_bits(self.to_bits().wrapping_add(rhs_bits))
+                    }
+                }
+
+                comment! {
+                    "Wrapping subtraction of an unsigned fixed-point number.
+Returns the difference, wrapping on overflow.
+
+The `rhs` parameter is unsigned and has the same number of fractional bits as
+`self`.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", $s_ufixed, "<U4>;
+assert_eq!(
+    Fix::from_num(3).wrapping_sub_unsigned(UFix::from_num(5)),
+    Fix::from_num(-2)
+);
+assert_eq!(Fix::MIN.wrapping_sub_unsigned(UFix::DELTA), Fix::MAX);
+assert_eq!(Fix::ZERO.wrapping_sub_unsigned(UFix::MAX), Fix::DELTA);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn wrapping_sub_unsigned(self, rhs: $UFixed<Frac>) -> $Fixed<Frac> {
+                        let rhs_bits = rhs.to_bits() as $Inner;
+                        Self::from_bits(self.to_bits().wrapping_sub(rhs_bits))
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping multiply and add.
+Returns `self` × `mul` + `add`, wrapping on overflow.
+
+The `mul` parameter can have a fixed-point type like
+`self` but with a different number of fractional bits.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(
+    Fix::from_num(4).wrapping_mul_add(Fix::from_num(0.5), Fix::from_num(3)),
+    Fix::from_num(5)
+);
+assert_eq!(Fix::MAX.wrapping_mul_add(Fix::ONE, Fix::from_num(0)), Fix::MAX);
+assert_eq!(Fix::MAX.wrapping_mul_add(Fix::ONE, Fix::from_bits(1)), Fix::MIN);
+let wrapped = Fix::MAX.wrapping_mul_int(4);
+assert_eq!(Fix::MAX.wrapping_mul_add(Fix::from_num(3), Fix::MAX), wrapped);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn wrapping_mul_add<MulFrac: $LeEqU>(
+                    self,
+                    mul: $Fixed<MulFrac>,
+                    add: $Fixed<Frac>,
+                ) -> $Fixed<Frac> {
+                    let (ans, _) = arith::overflowing_mul_add(
+                        self.to_bits(),
+                        mul.to_bits(),
+                        add.to_bits(),
+                        MulFrac::I32,
+                    );
+                    Self::from_bits(ans)
+                }
+            }
+
+            if_unsigned! {
+                $Signedness;
+                comment! {
+                    "Wrapping multiplication by a signed fixed-point number.
+Returns the product, wrapping on overflow.
+
+The `rhs` parameter is signed and can have a different number of
+fractional bits from `self`. The product is rounded down.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ifixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type IFix = ", $s_ifixed, "<U4>;
+assert_eq!(
+    Fix::from_num(6).wrapping_mul_signed(IFix::from_num(0.5)),
+    Fix::from_num(3)
+);
+// −1 wraps to MAX − 1 + DELTA
+let wrapped = Fix::MAX - Fix::ONE + Fix::DELTA;
+assert_eq!(Fix::ONE.wrapping_mul_signed(-IFix::ONE), wrapped);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn wrapping_mul_signed<RhsFrac: $LeEqU>(
+                        self,
+                        rhs: $IFixed<RhsFrac>,
+                    ) -> $Fixed<Frac> {
+                        self.wrapping_mul_add_signed(rhs, Self::ZERO)
+                    }
+                }
+
+                comment! {
+                    "Wrapping multiply by a signed fixed-point number and add.
+Returns `self` × `mul` + `add`, wrapping on overflow.
+
+The `mul` parameter is signed and can have a different number of
+fractional bits from `self`. The product `self` × `mul` is rounded down
+before `add` is added.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ifixed, "};
 type Fix = ", $s_fixed, "<U4>;
-let one_minus_delta = Fix::ONE - Fix::DELTA;
-assert_eq!(Fix::from_num(3).wrapping_add(Fix::from_num(2)), Fix::from_num(5));
-assert_eq!(Fix::MAX.wrapping_add(Fix::ONE), ",
-                if_signed_else_empty_str! { $Signedness; "Fix::MIN + " },
-                "one_minus_delta);
+type IFix = ", $s_ifixed, "<U4>;
+assert_eq!(
+    Fix::from_num(4).wrapping_mul_add_signed(IFix::from_num(-0.5), Fix::from_num(3)),
+    Fix::from_num(1)
+);
+assert_eq!(Fix::MAX.wrapping_mul_add_signed(IFix::ONE, Fix::DELTA), Fix::ZERO);
 ```
 ";
-                #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn wrapping_add(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
-                    Self::from_bits(self.to_bits().wrapping_add(rhs.to_bits()))
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn wrapping_mul_add_signed<MulFrac: $LeEqU>(
+                        self,
+                        mul: $IFixed<MulFrac>,
+                        add: $Fixed<Frac>,
+                    ) -> $Fixed<Frac> {
+                        let (ans, _) = self.overflowing_mul_add_signed(mul, add);
+                        ans
+                    }
                 }
             }
 
-            comment! {
-                "Wrapping subtraction. Returns the difference, wrapping on overflow.
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Wrapping multiplication by an unsigned fixed-point number.
+Returns the product, wrapping on overflow.
+
+The `rhs` parameter is unsigned and can have a different number of
+fractional bits from `self`. The product is rounded down.
 
 # Examples
 
 ```rust
-use fixed::{types::extra::U4, ", $s_fixed, "};
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
 type Fix = ", $s_fixed, "<U4>;
-let one_minus_delta = Fix::ONE - Fix::DELTA;
-",
-                if_signed_unsigned!(
-                    $Signedness,
-                    "assert_eq!(Fix::from_num(3).wrapping_sub(Fix::from_num(5)), Fix::from_num(-2));
-assert_eq!(Fix::MIN",
-                    "assert_eq!(Fix::from_num(5).wrapping_sub(Fix::from_num(3)), Fix::from_num(2));
-assert_eq!(Fix::ZERO",
-                ),
-                ".wrapping_sub(Fix::ONE), Fix::MAX - one_minus_delta);
+type UFix = ", $s_ufixed, "<U4>;
+assert_eq!(
+    Fix::from_num(-3).wrapping_mul_unsigned(UFix::from_num(2)),
+    Fix::from_num(-6)
+);
+let wrapped = Fix::MAX.wrapping_mul_int(4);
+assert_eq!(Fix::MAX.wrapping_mul_unsigned(UFix::from_num(4)), wrapped);
 ```
 ";
-                #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn wrapping_sub(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
-                    Self::from_bits(self.to_bits().wrapping_sub(rhs.to_bits()))
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn wrapping_mul_unsigned<RhsFrac: $LeEqU>(
+                        self,
+                        rhs: $UFixed<RhsFrac>,
+                    ) -> $Fixed<Frac> {
+                        self.wrapping_mul_add_unsigned(rhs, Self::ZERO)
+                    }
                 }
-            }
 
-            comment! {
-                "Wrapping multiply and add.
+                comment! {
+                    "Wrapping multiply by an unsigned fixed-point number and add.
 Returns `self` × `mul` + `add`, wrapping on overflow.
 
-The `mul` parameter can have a fixed-point type like
-`self` but with a different number of fractional bits.
+The `mul` parameter is unsigned and can have a different number of
+fractional bits from `self`. The product `self` × `mul` is rounded down
+before `add` is added. For some cases, the product would overflow on its
+own, but the final result `self` × `mul` + `add` is representable; in
+these cases this method returns the correct result without overflow.
 
 # Examples
 
 ```rust
-use fixed::{types::extra::U4, ", $s_fixed, "};
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
 type Fix = ", $s_fixed, "<U4>;
+type UFix = ", $s_ufixed, "<U4>;
 assert_eq!(
-    Fix::from_num(4).wrapping_mul_add(Fix::from_num(0.5), Fix::from_num(3)),
-    Fix::from_num(5)
+    Fix::from_num(-2).wrapping_mul_add_unsigned(UFix::from_num(1.5), Fix::from_num(1)),
+    Fix::from_num(-2)
 );
-assert_eq!(Fix::MAX.wrapping_mul_add(Fix::ONE, Fix::from_num(0)), Fix::MAX);
-assert_eq!(Fix::MAX.wrapping_mul_add(Fix::ONE, Fix::from_bits(1)), Fix::MIN);
-let wrapped = Fix::MAX.wrapping_mul_int(4);
-assert_eq!(Fix::MAX.wrapping_mul_add(Fix::from_num(3), Fix::MAX), wrapped);
+assert_eq!(Fix::MAX.wrapping_mul_add_unsigned(UFix::ONE, Fix::DELTA), Fix::MIN);
 ```
 ";
-                #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub fn wrapping_mul_add<MulFrac: $LeEqU>(
-                    self,
-                    mul: $Fixed<MulFrac>,
-                    add: $Fixed<Frac>,
-                ) -> $Fixed<Frac> {
-                    let (ans, _) = arith::overflowing_mul_add(
-                        self.to_bits(),
-                        mul.to_bits(),
-                        add.to_bits(),
-                        MulFrac::I32,
-                    );
-                    Self::from_bits(ans)
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn wrapping_mul_add_unsigned<MulFrac: $LeEqU>(
+                        self,
+                        mul: $UFixed<MulFrac>,
+                        add: $Fixed<Frac>,
+                    ) -> $Fixed<Frac> {
+                        let (ans, _) = self.overflowing_mul_add_unsigned(mul, add);
+                        ans
+                    }
                 }
             }
 
@@ -3079,6 +4133,120 @@ assert_eq!(Fix::ZERO",
                 }
             }
 
+            if_unsigned! {
+                $Signedness;
+                comment! {
+                    "Overflowing addition with a signed fixed-point number.
+
+Returns a [tuple] of the sum and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+The `rhs` parameter is signed and has the same number of fractional bits as
+`self`.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ifixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type IFix = ", $s_ifixed, "<U4>;
+assert_eq!(
+    Fix::from_num(5).overflowing_add_signed(IFix::from_num(-3)),
+    (Fix::from_num(2), false)
+);
+assert_eq!(Fix::ZERO.overflowing_add_signed(-IFix::DELTA), (Fix::MAX, true));
+assert_eq!(Fix::MAX.overflowing_add_signed(IFix::DELTA), (Fix::ZERO, true));
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn overflowing_add_signed(
+                        self,
+                        rhs: $IFixed<Frac>,
+                    ) -> ($Fixed<Frac>, bool) {
+                        let rhs_bits = rhs.to_bits();
+                        let (ans, o) = self.to_bits().overflowing_add(rhs_bits as $Inner);
+                        // adding a negative rhs is a wrapping addition that must carry
+                        (Self::from_bits(ans), o != (rhs_bits < 0))
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Overflowing addition with an unsigned fixed-point number.
+
+Returns a [tuple] of the sum and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+The `rhs` parameter is unsigned and has the same number of fractional bits as
+`self`.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", $s_ufixed, "<U4>;
+assert_eq!(
+    Fix::from_num(-5).overflowing_add_unsigned(UFix::from_num(3)),
+    (Fix::from_num(-2), false)
+);
+assert_eq!(Fix::MIN.overflowing_add_unsigned(UFix::MAX), (Fix::MAX, false));
+assert_eq!(Fix::MAX.overflowing_add_unsigned(UFix::DELTA), (Fix::MIN, true));
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn overflowing_add_unsigned(
+                        self,
+                        rhs: $UFixed<Frac>,
+                    ) -> ($Fixed<Frac>, bool) {
+                        let rhs_bits = rhs.to_bits() as $Inner;
+                        let (ans, o) = self.to_bits().overflowing_add(rhs_bits);
+                        // rhs_bits is negative when rhs does not fit in $Inner
+                        (Self::from_bits(ans), o != (rhs_bits < 0))
+                    }
+                }
+
+                comment! {
+                    "Overflowing subtraction of an unsigned fixed-point number.
+
+Returns a [tuple] of the difference and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+The `rhs` parameter is unsigned and has the same number of fractional bits as
+`self`.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", $s_ufixed, "<U4>;
+assert_eq!(
+    Fix::from_num(3).overflowing_sub_unsigned(UFix::from_num(5)),
+    (Fix::from_num(-2), false)
+);
+assert_eq!(Fix::MAX.overflowing_sub_unsigned(UFix::MAX), (Fix::MIN, false));
+assert_eq!(Fix::MIN.overflowing_sub_unsigned(UFix::DELTA), (Fix::MAX, true));
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn overflowing_sub_unsigned(
+                        self,
+                        rhs: $UFixed<Frac>,
+                    ) -> ($Fixed<Frac>, bool) {
+                        let rhs_bits = rhs.to_bits() as $Inner;
+                        let (ans, o) = self.to_bits().overflowing_sub(rhs_bits);
+                        // rhs_bits is negative when rhs does not fit in $Inner
+                        (Self::from_bits(ans), o != (rhs_bits < 0))
+                    }
+                }
+            }
+
             comment! {
                 "Overflowing multiply and add.
 
@@ -3144,6 +4312,195 @@ assert_eq!(
                 }
             }
 
+            if_unsigned! {
+                $Signedness;
+                comment! {
+                    "Overflowing multiplication by a signed fixed-point number.
+
+Returns a [tuple] of the product and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+The `rhs` parameter is signed and can have a different number of
+fractional bits from `self`. The product is rounded down.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ifixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type IFix = ", $s_ifixed, "<U4>;
+assert_eq!(
+    Fix::from_num(6).overflowing_mul_signed(IFix::from_num(0.5)),
+    (Fix::from_num(3), false)
+);
+// −1 wraps to MAX − 1 + DELTA
+let wrapped = Fix::MAX - Fix::ONE + Fix::DELTA;
+assert_eq!(Fix::ONE.overflowing_mul_signed(-IFix::ONE), (wrapped, true));
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn overflowing_mul_signed<RhsFrac: $LeEqU>(
+                        self,
+                        rhs: $IFixed<RhsFrac>,
+                    ) -> ($Fixed<Frac>, bool) {
+                        self.overflowing_mul_add_signed(rhs, Self::ZERO)
+                    }
+                }
+
+                comment! {
+                    "Overflowing multiply by a signed fixed-point number and add.
+
+Returns a [tuple] of `self` × `mul` + `add` and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+The `mul` parameter is signed and can have a different number of
+fractional bits from `self`. The product `self` × `mul` is rounded down
+before `add` is added.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ifixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type IFix = ", $s_ifixed, "<U4>;
+assert_eq!(
+    Fix::from_num(4).overflowing_mul_add_signed(IFix::from_num(-0.5), Fix::from_num(3)),
+    (Fix::from_num(1), false)
+);
+assert_eq!(
+    Fix::MAX.overflowing_mul_add_signed(IFix::ONE, Fix::DELTA),
+    (Fix::ZERO, true)
+);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn overflowing_mul_add_signed<MulFrac: $LeEqU>(
+                        self,
+                        mul: $IFixed<MulFrac>,
+                        add: $Fixed<Frac>,
+                    ) -> ($Fixed<Frac>, bool) {
+                        let mul_bits = mul.to_bits();
+                        let neg = mul_bits < 0;
+                        // round negative products down by rounding their absolute value up
+                        let (prod_abs, overflow1) = arith::overflowing_mul_round(
+                            self.to_bits(),
+                            mul_bits.unsigned_abs(),
+                            MulFrac::U32,
+                            if neg {
+                                RoundingMode::Ceil
+                            } else {
+                                RoundingMode::Floor
+                            },
+                        );
+                        let add_bits = add.to_bits();
+                        let (ans, overflow2) = if neg {
+                            add_bits.overflowing_sub(prod_abs)
+                        } else {
+                            add_bits.overflowing_add(prod_abs)
+                        };
+                        (Self::from_bits(ans), overflow1 || overflow2)
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Overflowing multiplication by an unsigned fixed-point number.
+
+Returns a [tuple] of the product and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+The `rhs` parameter is unsigned and can have a different number of
+fractional bits from `self`. The product is rounded down.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", $s_ufixed, "<U4>;
+assert_eq!(
+    Fix::from_num(-3).overflowing_mul_unsigned(UFix::from_num(2)),
+    (Fix::from_num(-6), false)
+);
+let wrapped = Fix::MAX.wrapping_mul_int(4);
+assert_eq!(Fix::MAX.overflowing_mul_unsigned(UFix::from_num(4)), (wrapped, true));
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn overflowing_mul_unsigned<RhsFrac: $LeEqU>(
+                        self,
+                        rhs: $UFixed<RhsFrac>,
+                    ) -> ($Fixed<Frac>, bool) {
+                        self.overflowing_mul_add_unsigned(rhs, Self::ZERO)
+                    }
+                }
+
+                comment! {
+                    "Overflowing multiply by an unsigned fixed-point number and add.
+
+Returns a [tuple] of `self` × `mul` + `add` and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+The `mul` parameter is unsigned and can have a different number of
+fractional bits from `self`. The product `self` × `mul` is rounded down
+before `add` is added. For some cases, the product would overflow on its
+own, but the final result `self` × `mul` + `add` is representable; in
+these cases this method returns the correct result without overflow.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", $s_ufixed, "<U4>;
+assert_eq!(
+    Fix::from_num(-2).overflowing_mul_add_unsigned(UFix::from_num(1.5), Fix::from_num(1)),
+    (Fix::from_num(-2), false)
+);
+assert_eq!(
+    Fix::MAX.overflowing_mul_add_unsigned(UFix::ONE, Fix::DELTA),
+    (Fix::MIN, true)
+);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub fn overflowing_mul_add_unsigned<MulFrac: $LeEqU>(
+                        self,
+                        mul: $UFixed<MulFrac>,
+                        add: $Fixed<Frac>,
+                    ) -> ($Fixed<Frac>, bool) {
+                        let self_bits = self.to_bits();
+                        let neg = self_bits < 0;
+                        // round negative products down by rounding their absolute value up
+                        let (prod_abs, overflow1) = arith::overflowing_mul_round(
+                            self_bits.unsigned_abs(),
+                            mul.to_bits(),
+                            MulFrac::U32,
+                            if neg {
+                                RoundingMode::Ceil
+                            } else {
+                                RoundingMode::Floor
+                            },
+                        );
+                        let prod_abs = $UFixed::from_bits(prod_abs);
+                        let (ans, overflow2) = if neg {
+                            add.overflowing_sub_unsigned(prod_abs)
+                        } else {
+                            add.overflowing_add_unsigned(prod_abs)
+                        };
+                        (ans, overflow1 || overflow2)
+                    }
+                }
+            }
+
             comment! {
                 "Overflowing multiplication by an integer.
 
@@ -14,7 +14,7 @@
 // <https://opensource.org/licenses/MIT>.
 
 macro_rules! fixed_round {
-    ($Fixed:ident[$s_fixed:expr]($s_nbits:expr), $Signedness:tt) => {
+    ($Fixed:ident[$s_fixed:expr]($Inner:ident, $s_nbits:expr), $Signedness:tt) => {
         comment! {
             "Returns the integer part.
 
@@ -304,6 +304,107 @@ assert_eq!(Fix::from_num(3.5).round_ties_to_even(), Fix::from_num(4));
             }
         }
 
+        comment! {
+            "Rounds to a multiple of `step`, rounding as specified by `mode`.
+
+The quotient `self`&nbsp;/&nbsp;`step` is rounded to an integer as specified
+by `mode`, and the result is that integer multiplied by `step`, computed
+exactly. Unlike [`round`], [`floor`] and [`ceil`], which round to integers,
+`step` can be any non-zero value, including values that are not powers of two.
+The sign of `step` is ignored.
+
+# Panics
+
+Panics if `step` is zero.
+
+When debug assertions are enabled, this method also panics if the result does
+not fit. When debug assertions are not enabled, the wrapped result can be
+returned, but it is not considered a breaking change if in the future it
+panics; if wrapping is required use [`wrapping_round_to_multiple`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+let step = Fix::from_num(0.75);
+// 2 / 0.75 = 2.67
+assert_eq!(Fix::from_num(2).round_to_multiple(step, RoundingMode::Floor), step * 2);
+assert_eq!(Fix::from_num(2).round_to_multiple(step, RoundingMode::Ceil), step * 3);
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!(Fix::from_num(-2).round_to_multiple(step, RoundingMode::Floor), -step * 3);
+assert_eq!(Fix::from_num(-2).round_to_multiple(step, RoundingMode::TowardZero), -step * 2);
+// the sign of step is ignored
+assert_eq!(Fix::from_num(2).round_to_multiple(-step, RoundingMode::Floor), step * 2);
+assert_eq!(Fix::from_num(-2).round_to_multiple(-step, RoundingMode::Floor), -step * 3);
+",
+            },
+            "```
+
+[`ceil`]: Self::ceil
+[`floor`]: Self::floor
+[`round`]: Self::round
+[`wrapping_round_to_multiple`]: Self::wrapping_round_to_multiple
+";
+            #[inline]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub fn round_to_multiple(self, step: $Fixed<Frac>, mode: RoundingMode) -> $Fixed<Frac> {
+                let (ans, overflow) = self.overflowing_round_to_multiple(step, mode);
+                debug_assert!(!overflow, "overflow");
+                let _ = overflow;
+                ans
+            }
+        }
+
+        comment! {
+            "Rounds to `dp` decimal places, rounding as specified by `mode`.
+
+The value is first rounded to a decimal number with `dp` digits after the
+decimal point as specified by `mode`, and then that decimal number is
+converted to the nearest representable value. If `dp` is at least the number
+of fractional bits, the value is returned unchanged, as it can be represented
+exactly in `dp` decimal places.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped result can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`wrapping_round_dp`] instead.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+// 2.6875 is exactly representable
+let x = Fix::from_num(2.6875);
+assert_eq!(x.round_dp(1, RoundingMode::Floor), Fix::from_num(2.6));
+assert_eq!(x.round_dp(1, RoundingMode::Ceil), Fix::from_num(2.7));
+assert_eq!(x.round_dp(0, RoundingMode::NearestTiesEven), Fix::from_num(3));
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!((-x).round_dp(1, RoundingMode::Floor), Fix::from_num(-2.7));
+assert_eq!((-x).round_dp(1, RoundingMode::TowardZero), Fix::from_num(-2.6));
+",
+            },
+            "```
+
+[`wrapping_round_dp`]: Self::wrapping_round_dp
+";
+            #[inline]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub fn round_dp(self, dp: u32, mode: RoundingMode) -> $Fixed<Frac> {
+                let (ans, overflow) = self.overflowing_round_dp(dp, mode);
+                debug_assert!(!overflow, "overflow");
+                let _ = overflow;
+                ans
+            }
+        }
+
         comment! {
             "Checked ceil. Rounds to the next integer towards +∞,
 returning [`None`] on overflow.
@@ -422,6 +523,67 @@ assert!(Fix::MAX.checked_round_ties_to_even().is_none());
             }
         }
 
+        comment! {
+            "Checked rounding to a multiple of `step`. Rounds to a multiple of
+`step` as specified by `mode`, returning [`None`] if `step` is zero or on
+overflow.
+
+See [`round_to_multiple`] for details.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+let step = Fix::from_num(0.75);
+assert_eq!(Fix::from_num(2).checked_round_to_multiple(step, RoundingMode::Ceil), Some(step * 3));
+assert_eq!(Fix::MAX.checked_round_to_multiple(step, RoundingMode::Ceil), None);
+assert_eq!(Fix::ONE.checked_round_to_multiple(Fix::ZERO, RoundingMode::Floor), None);
+```
+
+[`round_to_multiple`]: Self::round_to_multiple
+";
+            #[inline]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub fn checked_round_to_multiple(
+                self,
+                step: $Fixed<Frac>,
+                mode: RoundingMode,
+            ) -> Option<$Fixed<Frac>> {
+                if step.to_bits() == 0 {
+                    return None;
+                }
+                let (ans, overflow) = self.overflowing_round_to_multiple(step, mode);
+                if overflow { None } else { Some(ans) }
+            }
+        }
+
+        comment! {
+            "Checked rounding to `dp` decimal places. Rounds to `dp` decimal
+places as specified by `mode`, returning [`None`] on overflow.
+
+See [`round_dp`] for details.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+let x = Fix::from_num(2.6875);
+assert_eq!(x.checked_round_dp(1, RoundingMode::Floor), Some(Fix::from_num(2.6)));
+assert_eq!(Fix::MAX.checked_round_dp(0, RoundingMode::Ceil), None);
+```
+
+[`round_dp`]: Self::round_dp
+";
+            #[inline]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub fn checked_round_dp(self, dp: u32, mode: RoundingMode) -> Option<$Fixed<Frac>> {
+                let (ans, overflow) = self.overflowing_round_dp(dp, mode);
+                if overflow { None } else { Some(ans) }
+            }
+        }
+
         comment! {
             "Saturating ceil. Rounds to the next integer towards +∞,
 saturating on overflow.
@@ -550,6 +712,35 @@ assert_eq!(Fix::MAX.saturating_round_ties_to_even(), Fix::MAX);
             }
         }
 
+        comment! {
+            "Saturating rounding to `dp` decimal places. Rounds to `dp`
+decimal places as specified by `mode`, saturating on overflow.
+
+See [`round_dp`] for details.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+let x = Fix::from_num(2.6875);
+assert_eq!(x.saturating_round_dp(1, RoundingMode::Ceil), Fix::from_num(2.7));
+assert_eq!(Fix::MAX.saturating_round_dp(0, RoundingMode::Ceil), Fix::MAX);
+```
+
+[`round_dp`]: Self::round_dp
+";
+            #[inline]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub fn saturating_round_dp(self, dp: u32, mode: RoundingMode) -> $Fixed<Frac> {
+                match self.checked_round_dp(dp, mode) {
+                    Some(ans) => ans,
+                    None if int_helper::$Inner::is_negative(self.to_bits()) => $Fixed::MIN,
+                    None => $Fixed::MAX,
+                }
+            }
+        }
+
         comment! {
             "Wrapping ceil. Rounds to the next integer towards +∞,
 wrapping on overflow.
@@ -664,6 +855,64 @@ assert_eq!(Fix::MAX.wrapping_round_ties_to_even(), Fix::MIN);
             }
         }
 
+        comment! {
+            "Wrapping rounding to a multiple of `step`. Rounds to a multiple of
+`step` as specified by `mode`, wrapping on overflow.
+
+See [`round_to_multiple`] for details.
+
+# Panics
+
+Panics if `step` is zero.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+let step = Fix::from_num(0.75);
+assert_eq!(Fix::from_num(2).wrapping_round_to_multiple(step, RoundingMode::Ceil), step * 3);
+assert_eq!(Fix::MAX.wrapping_round_to_multiple(Fix::ONE, RoundingMode::Ceil), Fix::MIN);
+```
+
+[`round_to_multiple`]: Self::round_to_multiple
+";
+            #[inline]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub fn wrapping_round_to_multiple(
+                self,
+                step: $Fixed<Frac>,
+                mode: RoundingMode,
+            ) -> $Fixed<Frac> {
+                self.overflowing_round_to_multiple(step, mode).0
+            }
+        }
+
+        comment! {
+            "Wrapping rounding to `dp` decimal places. Rounds to `dp`
+decimal places as specified by `mode`, wrapping on overflow.
+
+See [`round_dp`] for details.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+let x = Fix::from_num(2.6875);
+assert_eq!(x.wrapping_round_dp(1, RoundingMode::Ceil), Fix::from_num(2.7));
+assert_eq!(Fix::MAX.wrapping_round_dp(0, RoundingMode::Ceil), Fix::MIN);
+```
+
+[`round_dp`]: Self::round_dp
+";
+            #[inline]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub fn wrapping_round_dp(self, dp: u32, mode: RoundingMode) -> $Fixed<Frac> {
+                self.overflowing_round_dp(dp, mode).0
+            }
+        }
+
         comment! {
             "Unwrapped ceil. Rounds to the next integer towards +∞,
 panicking on overflow.
@@ -1020,5 +1269,107 @@ assert_eq!(Fix::MAX.overflowing_round_ties_to_even(), (Fix::MIN, true));
                 }
             }
         }
+
+        comment! {
+            "Overflowing rounding to a multiple of `step`. Rounds to a multiple
+of `step` as specified by `mode`.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+See [`round_to_multiple`] for details.
+
+# Panics
+
+Panics if `step` is zero.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+let step = Fix::from_num(0.75);
+let two = Fix::from_num(2);
+assert_eq!(two.overflowing_round_to_multiple(step, RoundingMode::Ceil), (step * 3, false));
+let max = Fix::MAX;
+assert_eq!(max.overflowing_round_to_multiple(Fix::ONE, RoundingMode::Ceil), (Fix::MIN, true));
+```
+
+[`round_to_multiple`]: Self::round_to_multiple
+";
+            #[inline]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub fn overflowing_round_to_multiple(
+                self,
+                step: $Fixed<Frac>,
+                mode: RoundingMode,
+            ) -> ($Fixed<Frac>, bool) {
+                let bits = self.to_bits();
+                let (neg, abs, step_abs) = if_signed_unsigned!(
+                    $Signedness,
+                    (bits < 0, bits.unsigned_abs(), step.to_bits().unsigned_abs()),
+                    (false, bits, step.to_bits()),
+                );
+                assert!(step_abs != 0, "division by zero");
+                let (quot, rem) = (abs / step_abs, abs % step_abs);
+                // quot + 1 cannot overflow, as rem != 0 implies step_abs > 1
+                let up = rem != 0
+                    && mode.round_up_abs(neg, quot & 1 != 0, rem.cmp(&(step_abs - rem)));
+                let quot = if up { quot + 1 } else { quot };
+                let (ans_abs, overflow) = quot.overflowing_mul(step_abs);
+                if_signed_unsigned!(
+                    $Signedness,
+                    {
+                        let bound = if neg { $Inner::MIN } else { $Inner::MAX };
+                        let overflow = overflow || ans_abs > bound.unsigned_abs();
+                        let ans = ans_abs as $Inner;
+                        (Self::from_bits(if neg { ans.wrapping_neg() } else { ans }), overflow)
+                    },
+                    (Self::from_bits(ans_abs), overflow),
+                )
+            }
+        }
+
+        comment! {
+            "Overflowing rounding to `dp` decimal places. Rounds to `dp`
+decimal places as specified by `mode`.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+See [`round_dp`] for details.
+
+# Examples
+
+```rust
+use fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+let x = Fix::from_num(2.6875);
+assert_eq!(x.overflowing_round_dp(1, RoundingMode::Ceil), (Fix::from_num(2.7), false));
+assert_eq!(Fix::MAX.overflowing_round_dp(0, RoundingMode::Ceil), (Fix::MIN, true));
+```
+
+[`round_dp`]: Self::round_dp
+";
+            #[inline]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub fn overflowing_round_dp(self, dp: u32, mode: RoundingMode) -> ($Fixed<Frac>, bool) {
+                let (neg, abs) = int_helper::$Inner::neg_abs(self.to_bits());
+                let (ans_abs, overflow) =
+                    from_str::RoundDp::round_dp_abs(abs, Self::FRAC_NBITS, dp, neg, mode);
+                if_signed_unsigned!(
+                    $Signedness,
+                    {
+                        let bound = if neg { $Inner::MIN } else { $Inner::MAX };
+                        let overflow = overflow || ans_abs > bound.unsigned_abs();
+                        let ans = ans_abs as $Inner;
+                        (Self::from_bits(if neg { ans.wrapping_neg() } else { ans }), overflow)
+                    },
+                    (Self::from_bits(ans_abs), overflow),
+                )
+            }
+        }
     };
 }
@@ -0,0 +1,628 @@
+// Copyright © 2018–2021 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    exp_log,
+    int256::{self, U256},
+    wide_float::{WideBits, WideFloat},
+};
+use core::cmp::Ordering;
+
+// Once the exponent of an intermediate value is beyond this limit, the result
+// is too large or too small for any fixed-point number, and squaring further
+// could overflow the exponent.
+const EXP_LIMIT: i32 = 1 << 20;
+
+// Returns x↑n using square-and-multiply. Every step is computed with the full
+// precision of WideFloat, so that only the final result needs rounding.
+//
+// If the result is out of range, its exponent is clamped to ±2↑30, which
+// overflows any fixed-point number or rounds to zero.
+pub fn powi(x: WideFloat, n: i32) -> WideFloat {
+    let mut acc = WideFloat::ONE;
+    let mut base = x;
+    let mut rem = n.unsigned_abs();
+    while rem != 0 {
+        if rem & 1 != 0 {
+            acc = acc.mul(base);
+        }
+        rem >>= 1;
+        if rem == 0 {
+            break;
+        }
+        base = base.mul(base);
+        if base.exp > EXP_LIMIT || base.exp < -EXP_LIMIT {
+            // |base| will be multiplied into acc at least once more, and acc
+            // is on the same side of one as base, so the result is out of
+            // range too.
+            let exp = if base.exp > 0 { 1 << 30 } else { -(1 << 30) };
+            acc = WideFloat {
+                neg: x.neg && n & 1 != 0,
+                exp,
+                mant: WideFloat::ONE.mant,
+            };
+            break;
+        }
+    }
+    if n < 0 {
+        WideFloat::ONE.div(acc)
+    } else {
+        acc
+    }
+}
+
+// Returns m↑n wrapped to 256 bits.
+fn wrapping_pow_u256(m: u128, n: u32) -> U256 {
+    let mut acc = U256 { lo: 1, hi: 0 };
+    let mut base = U256 { lo: m, hi: 0 };
+    let mut rem = n;
+    while rem != 0 {
+        if rem & 1 != 0 {
+            acc = int256::wide_mul_u256(acc, base).1;
+        }
+        rem >>= 1;
+        if rem != 0 {
+            base = int256::wide_mul_u256(base, base).1;
+        }
+    }
+    acc
+}
+
+// Returns the bits of (abs / 2↑frac_nbits)↑n rounded to the nearest, with ties
+// rounded to even, and wrapped to 128 bits. abs and n must not be zero.
+//
+// With abs = m × 2↑t for odd m, the bits are m↑n / 2↑s with s = f(n − 1) − tn.
+// If s ≤ 0 they are exactly m↑n × 2↑−s, and if 0 < s ≤ 128 they only depend on
+// the lowest 256 bits of m↑n. Returns None if s > 128.
+fn wrapped_pow_abs(abs: u128, frac_nbits: u32, n: u32) -> Option<u128> {
+    debug_assert!(abs != 0 && n != 0);
+    let t = abs.trailing_zeros();
+    let m = abs >> t;
+    let s = i64::from(frac_nbits) * (i64::from(n) - 1) - i64::from(t) * i64::from(n);
+    if s <= 0 {
+        return Some(if -s >= 128 {
+            0
+        } else {
+            m.wrapping_pow(n) << -s
+        });
+    }
+    if s > 128 {
+        return None;
+    }
+    let s = s as u32;
+    let pow = wrapping_pow_u256(m, n);
+    let int = int256::shr_u256(pow, s).lo;
+    let rem = int256::shl_u256(pow, 256 - s);
+    // rem holds the discarded bits, with the rounding bit as its msb
+    let msb = 1 << 127;
+    let round_up = rem.hi & msb != 0 && (rem.hi != msb || rem.lo != 0 || int & 1 != 0);
+    Some(int.wrapping_add(u128::from(round_up)))
+}
+
+// Returns the wrapped result and whether it overflowed. val must not be zero if
+// n is negative.
+//
+// WideFloat keeps only the 256 most significant bits of the power, so for a
+// positive n, the wrapped value of an overflowing result is recomputed exactly
+// when that is possible.
+pub fn powi_bits<T: WideBits>(val: T, frac_nbits: u32, n: i32) -> (T, bool) {
+    let x = val.to_wide(frac_nbits);
+    let (ans, overflow) = T::from_wide_wrapping(powi(x, n), frac_nbits);
+    if !overflow || n <= 0 {
+        return (ans, overflow);
+    }
+    let (abs, _) = x.round_abs_u128(frac_nbits);
+    match wrapped_pow_abs(abs, frac_nbits, n as u32) {
+        Some(wrapped) => {
+            let neg = x.neg && n & 1 != 0;
+            let w = WideFloat::from_u128(neg, wrapped, frac_nbits as i32);
+            (T::from_wide_wrapping(w, frac_nbits).0, true)
+        }
+        None => (ans, true),
+    }
+}
+
+// Returns x↑y = 2↑(y log₂ x). x must not be negative, and if x is zero, y must
+// not be negative.
+//
+// The logarithm has a small relative error, so the product has a small absolute
+// error as long as it is small enough for the result to be representable.
+pub fn powf(x: WideFloat, y: WideFloat) -> WideFloat {
+    debug_assert!(!x.neg);
+    if x.is_zero() {
+        debug_assert!(!y.neg);
+        return if y.is_zero() {
+            WideFloat::ONE
+        } else {
+            WideFloat::ZERO
+        };
+    }
+    exp_log::exp2(y.mul(exp_log::log2_rel(x)))
+}
+
+// val must not be negative, and if val is zero, exp must not be negative
+#[inline]
+pub fn powf_bits<T: WideBits, E: WideBits>(
+    val: T,
+    frac_nbits: u32,
+    exp: E,
+    exp_frac_nbits: u32,
+) -> (T, bool) {
+    let x = val.to_wide(frac_nbits);
+    let y = exp.to_wide(exp_frac_nbits);
+    T::from_wide(powf(x, y), frac_nbits)
+}
+
+// Returns the nth root of x, 2↑((log₂ |x|) / n) with the sign of x. n must not
+// be zero, and if x is negative, n must be odd.
+pub fn nth_root(x: WideFloat, n: u32) -> WideFloat {
+    debug_assert!(n != 0);
+    debug_assert!(!x.neg || n & 1 != 0);
+    if x.is_zero() || n == 1 {
+        return x;
+    }
+    let abs = WideFloat { neg: false, ..x };
+    let root = exp_log::exp2(exp_log::log2_rel(abs).div_small(n));
+    if x.neg {
+        root.neg()
+    } else {
+        root
+    }
+}
+
+// Number of 64-bit limbs in the integers used to check the rounding of roots.
+const LIMBS: usize = 64;
+
+type Limbs = [u64; LIMBS];
+
+// Returns a × m, or None if the product does not fit.
+fn mul_limbs(a: &Limbs, m: &[u64; 3]) -> Option<Limbs> {
+    let mut prod = [0; LIMBS + 3];
+    for (i, &x) in a.iter().enumerate() {
+        if x == 0 {
+            continue;
+        }
+        let mut carry = 0;
+        for (j, &y) in m.iter().enumerate() {
+            let t = u128::from(x) * u128::from(y) + u128::from(prod[i + j]) + u128::from(carry);
+            prod[i + j] = t as u64;
+            carry = (t >> 64) as u64;
+        }
+        prod[i + 3] = carry;
+    }
+    if prod[LIMBS..].iter().any(|&limb| limb != 0) {
+        return None;
+    }
+    let mut ans = [0; LIMBS];
+    ans.copy_from_slice(&prod[..LIMBS]);
+    Some(ans)
+}
+
+// Returns a × 2↑shift, or None if it does not fit.
+fn shl_limbs(a: u128, shift: u64) -> Option<Limbs> {
+    let mut ans = [0; LIMBS];
+    if shift >= (LIMBS as u64) * 64 {
+        return if a == 0 { Some(ans) } else { None };
+    }
+    let (index, rem) = ((shift / 64) as usize, (shift % 64) as u32);
+    let lo = a << rem;
+    let hi = if rem == 0 { 0 } else { a >> (128 - rem) };
+    let parts = [lo as u64, (lo >> 64) as u64, hi as u64];
+    for (j, &part) in parts.iter().enumerate() {
+        if part != 0 {
+            *ans.get_mut(index + j)? = part;
+        }
+    }
+    Some(ans)
+}
+
+// Compares m↑n with a × 2↑shift exactly, where m is odd and m < 2↑192.
+// Returns None if both are too large to be compared.
+fn cmp_pow(m: U256, n: u32, a: u128, shift: u64) -> Option<Ordering> {
+    let rhs = shl_limbs(a, shift);
+    let factor = [m.lo as u64, (m.lo >> 64) as u64, m.hi as u64];
+    let mut lhs = [0; LIMBS];
+    lhs[0] = 1;
+    // unless m is one, m↑n stops fitting after a bounded number of steps
+    if m.hi != 0 || m.lo != 1 {
+        for _ in 0..n {
+            match mul_limbs(&lhs, &factor) {
+                Some(prod) => lhs = prod,
+                None => return rhs.map(|_| Ordering::Greater),
+            }
+        }
+    }
+    match rhs {
+        Some(rhs) => Some(lhs.iter().rev().cmp(rhs.iter().rev())),
+        None => Some(Ordering::Less),
+    }
+}
+
+// n must not be zero, and if val is negative, n must be odd
+pub fn nth_root_bits<T: WideBits>(val: T, frac_nbits: u32, n: u32) -> (T, bool) {
+    let x = val.to_wide(frac_nbits);
+    let root = nth_root(x, n);
+    let (low, overflow) = root.round_abs_u128(frac_nbits);
+    // Only a root just below one can round up to 2↑128 when there are 128
+    // fractional bits, as larger values have roots smaller than themselves.
+    if x.is_zero() || n == 1 || (overflow && low != 0) {
+        return T::from_wide(root, frac_nbits);
+    }
+    // The bits r of the rounded root are correct if r − ½ < |x|↑(1/n) × 2↑f <
+    // r + ½, that is if (2r − 1)↑n < |val| × 2↑(f(n − 1) + n) < (2r + 1)↑n.
+    // There are no ties, as (2r ± 1)↑n is odd while the other side is even.
+    let (abs_val, _) = x.round_abs_u128(frac_nbits);
+    let shift = u64::from(frac_nbits) * u64::from(n - 1) + u64::from(n);
+    let mut r = U256 {
+        lo: low,
+        hi: u128::from(overflow),
+    };
+    let one = U256 { lo: 1, hi: 0 };
+    loop {
+        let up = int256::or_u256(int256::shl_u256(r, 1), one);
+        if cmp_pow(up, n, abs_val, shift) != Some(Ordering::Less) {
+            break;
+        }
+        r = int256::overflowing_add_u256(r, one).0;
+    }
+    while r.lo != 0 || r.hi != 0 {
+        let down = int256::overflowing_sub_u256(int256::shl_u256(r, 1), one).0;
+        if cmp_pow(down, n, abs_val, shift) != Some(Ordering::Greater) {
+            break;
+        }
+        r = int256::overflowing_sub_u256(r, one).0;
+    }
+    T::from_wide(
+        WideFloat::from_u256(x.neg, r, frac_nbits as i32),
+        frac_nbits,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        int256::{self, U256},
+        pow,
+    };
+
+    // Rounds val / 2↑shift to the nearest, with ties rounded to even.
+    fn round_shr(val: u128, shift: u32) -> u128 {
+        if shift == 0 {
+            return val;
+        }
+        let int = val >> shift;
+        let rem = val & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+        if rem > half || (rem == half && int & 1 != 0) {
+            int + 1
+        } else {
+            int
+        }
+    }
+
+    #[test]
+    fn integers() {
+        // the wrapped values are exact even when the results need more than 256 bits
+        for base in -20i32..=20 {
+            for n in 0..=300 {
+                let expected = base.wrapping_pow(n as u32);
+                let overflow = base.checked_pow(n as u32).is_none();
+                assert_eq!(pow::powi_bits(base, 0, n), (expected, overflow));
+            }
+        }
+        for base in 0u8..=255 {
+            for n in 0..=32 {
+                let expected = base.wrapping_pow(n as u32);
+                let overflow = base.checked_pow(n as u32).is_none();
+                assert_eq!(pow::powi_bits(base, 0, n), (expected, overflow));
+            }
+        }
+        // 2↑−1 = 0.5 rounds to even 0, 3↑−1 rounds to 0, 1↑−3 = 1
+        assert_eq!(pow::powi_bits(2i8, 0, -1), (0, false));
+        assert_eq!(pow::powi_bits(3i8, 0, -1), (0, false));
+        assert_eq!(pow::powi_bits(-1i8, 0, -3), (-1, false));
+        assert_eq!(pow::powi_bits(-1i8, 0, i32::MIN), (1, false));
+    }
+
+    #[test]
+    fn rounded_once() {
+        // x = k / 2↑frac_nbits, so x↑n × 2↑frac_nbits = k↑n / 2↑(frac_nbits × (n − 1))
+        for &frac_nbits in &[1, 4, 7] {
+            for k in 1u128..=255 {
+                let mut pow = k;
+                for n in 2..=7 {
+                    pow *= k;
+                    let expected = round_shr(pow, frac_nbits * (n - 1));
+                    let ans = pow::powi_bits(k as u64, frac_nbits, n as i32);
+                    assert_eq!(ans, (expected as u64, false), "{} {} {}", k, frac_nbits, n);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wrapped_fraction() {
+        // x = k / 2↑frac_nbits, so the bits of x↑n are k↑n / 2↑(frac_nbits × (n − 1)),
+        // and wrapping to 8 bits only needs the low bits of k↑n
+        for frac_nbits in 0..=3 {
+            for n in 1..=40 {
+                let shift = frac_nbits * (n - 1);
+                for k in 1u8..=255 {
+                    let pow = u128::from(k).wrapping_pow(n);
+                    let expected = round_shr(pow, shift) as u8;
+                    let (ans, _) = pow::powi_bits(k, frac_nbits, n as i32);
+                    assert_eq!(ans, expected, "{} {} {}", k, frac_nbits, n);
+                }
+                for k in -128i8..=127 {
+                    if k == 0 {
+                        continue;
+                    }
+                    let pow = u128::from(k.unsigned_abs()).wrapping_pow(n);
+                    let abs = round_shr(pow, shift) as u8;
+                    let expected = if k < 0 && n % 2 != 0 {
+                        abs.wrapping_neg() as i8
+                    } else {
+                        abs as i8
+                    };
+                    let (ans, _) = pow::powi_bits(k, frac_nbits, n as i32);
+                    assert_eq!(ans, expected, "{} {} {}", k, frac_nbits, n);
+                }
+            }
+        }
+        // (2↑63 + 1)↑5 needs 316 bits; its lowest terms are 5 × 2↑63 + 1, and
+        // with 64 fractional bits only the lowest 64 integer bits are kept
+        let val = ((1u128 << 63) + 1) << 64;
+        let expected = (1 << 127) + (1 << 64);
+        assert_eq!(pow::powi_bits(val, 64, 5), (expected, true));
+    }
+
+    #[test]
+    fn reciprocal() {
+        for &frac_nbits in &[8, 16, 24] {
+            let scale = f64::from(1u32 << frac_nbits);
+            for k in 1..1000 {
+                let val = k * 37;
+                for n in -4..0 {
+                    let expected = (f64::from(val) / scale).powi(n) * scale;
+                    let (ans, overflow) = pow::powi_bits(val, frac_nbits, n);
+                    if expected < 2e9 {
+                        assert!(!overflow);
+                        assert!((f64::from(ans) - expected).abs() <= 0.5 + 1e-6);
+                    } else if expected > 2.2e9 {
+                        assert!(overflow);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn huge_exponents() {
+        let one = 1 << 120;
+        let half = 1 << 119;
+        assert_eq!(pow::powi_bits::<i128>(one, 120, i32::MAX), (one, false));
+        assert_eq!(pow::powi_bits::<i128>(-one, 120, i32::MAX), (-one, false));
+        assert_eq!(pow::powi_bits::<i128>(half, 120, i32::MAX), (0, false));
+        assert_eq!(pow::powi_bits::<i128>(half, 120, i32::MIN), (0, true));
+        assert_eq!(pow::powi_bits::<i128>(2 * one, 120, i32::MAX), (0, true));
+        assert_eq!(pow::powi_bits::<i128>(2 * one, 120, i32::MIN), (0, false));
+        // (1 + ε)↑n ≈ 1 + nε + n(n − 1)ε²/2, and here the ε² term is tiny
+        let n = i32::MAX;
+        let expected = one + i128::from(n);
+        assert_eq!(pow::powi_bits::<i128>(one + 1, 120, n), (expected, false));
+        let expected = one - i128::from(n);
+        assert_eq!(pow::powi_bits::<i128>(one + 1, 120, -n), (expected, false));
+        // 3↑80 needs 127 bits, so it is exact and overflows an i128 only by
+        // one bit of magnitude
+        let exact = 3u128.pow(80);
+        assert_eq!(pow::powi_bits::<u128>(3, 0, 80), (exact, false));
+        assert_eq!(pow::powi_bits::<i128>(3, 0, 80), (exact as i128, false));
+        assert_eq!(
+            pow::powi_bits::<u128>(3, 0, 81),
+            (exact.wrapping_mul(3), true)
+        );
+    }
+
+    #[test]
+    fn powf_i32() {
+        for &frac_nbits in &[4, 12, 20] {
+            let scale = f64::from(1u32 << frac_nbits);
+            for &exp in &[-2.5f64, -1.25, -0.5, 0.0, 0.25, 0.75, 1.5, 3.125] {
+                let exp_bits = (exp * 256.0) as i32;
+                let mut val = 1i32;
+                while val < i32::MAX / 3 {
+                    let expected = (f64::from(val) / scale).powf(exp) * scale;
+                    let (ans, overflow) = pow::powf_bits(val, frac_nbits, exp_bits, 8);
+                    if expected < 2e9 {
+                        assert!(!overflow, "{} {} {}", val, frac_nbits, exp);
+                        let err = (f64::from(ans) - expected).abs();
+                        assert!(err <= 0.5 + 1e-6, "{} {} {}", val, frac_nbits, exp);
+                    } else if expected > 2.2e9 {
+                        assert!(overflow, "{} {} {}", val, frac_nbits, exp);
+                    }
+                    val = val * 3 + 1;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn powf_exact() {
+        // 4↑0.5 = 2, 0.25↑1.5 = 0.125, 2↑k = 2↑k
+        assert_eq!(pow::powf_bits(4u8, 0, 1u8, 1), (2, false));
+        assert_eq!(pow::powf_bits(1i32 << 28, 30, 3i32, 1), (1 << 27, false));
+        for k in 0..128 {
+            assert_eq!(pow::powf_bits(2u128, 0, k, 0), (1 << k, false));
+        }
+        assert_eq!(pow::powf_bits(2u128, 0, 128u8, 0), (u128::MAX, true));
+        // 0↑0 = 1, 0↑y = 0
+        assert_eq!(pow::powf_bits(0u16, 8, 0i8, 4), (1 << 8, false));
+        assert_eq!(pow::powf_bits(0u16, 8, 1i8, 4), (0, false));
+    }
+
+    #[test]
+    fn powf_near_one() {
+        // integer exponents give the same result as powi unless there is
+        // overflow, for bases both close to one and not so close to one
+        let one = 1i128 << 64;
+        for &base in &[one + (one >> 20) + 12345, one + (one >> 12) + 12345] {
+            for &tick in &[1, 2, 7, 100, 10_000, 100_000, -100_000, 887_272] {
+                let exp = i128::from(tick) << 64;
+                let (powi, powi_overflow) = pow::powi_bits(base, 64, tick);
+                let (powf, powf_overflow) = pow::powf_bits(base, 64, exp, 64);
+                assert_eq!(powf_overflow, powi_overflow, "{}", tick);
+                if !powf_overflow {
+                    assert_eq!(powf, powi, "{}", tick);
+                }
+            }
+        }
+    }
+
+    // Returns the nth root of a rounded to the nearest. a × 2↑n must fit.
+    fn round_root(a: u128, n: u32) -> u128 {
+        let mut r = (a as f64).powf(1.0 / f64::from(n)) as u128;
+        while r > 0 && r.saturating_pow(n) > a {
+            r -= 1;
+        }
+        while (r + 1).saturating_pow(n) <= a {
+            r += 1;
+        }
+        // there are no ties, as (2r + 1)↑n is odd and a × 2↑n is even
+        if (2 * r + 1).pow(n) < a << n {
+            r + 1
+        } else {
+            r
+        }
+    }
+
+    #[test]
+    fn nth_root_exact() {
+        for base in 0u128..=40 {
+            for n in 1..=24 {
+                let pow = match base.checked_pow(n) {
+                    Some(pow) => pow,
+                    None => break,
+                };
+                assert_eq!(pow::nth_root_bits(pow, 0, n), (base, false));
+                if n % 2 != 0 && pow <= i128::MAX as u128 {
+                    let neg = -(pow as i128);
+                    assert_eq!(pow::nth_root_bits(neg, 0, n), (-(base as i128), false));
+                }
+            }
+        }
+        // 1.5↑3 = 3.375 with 120 fractional bits
+        let frac = 120;
+        assert_eq!(
+            pow::nth_root_bits::<i128>(27 << (frac - 3), frac, 3),
+            (3 << (frac - 1), false)
+        );
+        assert_eq!(pow::nth_root_bits::<u8>(0, 8, 5), (0, false));
+    }
+
+    #[test]
+    fn nth_root_rounded() {
+        let mut val = 1u64;
+        while val < u64::MAX / 3 {
+            for n in 2..=7 {
+                let expected = round_root(u128::from(val), n);
+                assert_eq!(pow::nth_root_bits(val, 0, n), (expected as u64, false));
+            }
+            // with 16 fractional bits, the root of val / 2↑16 has bits equal to
+            // the root of val × 2↑(16(n − 1))
+            let small = val as u32;
+            for n in 2..=3 {
+                let expected = round_root(u128::from(small) << (16 * (n - 1)), n);
+                let root = pow::nth_root_bits(small, 16, n);
+                assert_eq!(root, (expected as u32, false), "{} {}", small, n);
+            }
+            val = val * 3 + 1;
+        }
+    }
+
+    #[test]
+    fn nth_root_exhaustive_8_bits() {
+        // with f fractional bits, the root of val / 2↑f has bits equal to the
+        // root of val × 2↑(f(n − 1))
+        for frac_nbits in 0..=8 {
+            for n in 1..=12 {
+                for val in 0..=u8::MAX {
+                    let expected = round_root(u128::from(val) << (frac_nbits * (n - 1)), n);
+                    let (ans, overflow) = pow::nth_root_bits(val, frac_nbits, n);
+                    assert_eq!(overflow, expected > u128::from(u8::MAX), "{} {}", val, n);
+                    if !overflow {
+                        assert_eq!(u128::from(ans), expected, "{} {} {}", val, frac_nbits, n);
+                    }
+                }
+                for val in i8::MIN..=i8::MAX {
+                    if val < 0 && n % 2 == 0 {
+                        continue;
+                    }
+                    let abs = u128::from(val.unsigned_abs()) << (frac_nbits * (n - 1));
+                    let expected = round_root(abs, n) as i128 * i128::from(val.signum());
+                    let (ans, overflow) = pow::nth_root_bits(val, frac_nbits, n);
+                    let fits = i128::from(i8::MIN) <= expected && expected <= i128::from(i8::MAX);
+                    assert_eq!(overflow, !fits, "{} {}", val, n);
+                    if !overflow {
+                        assert_eq!(i128::from(ans), expected, "{} {} {}", val, frac_nbits, n);
+                    }
+                }
+            }
+        }
+    }
+
+    // Returns (2r + 1)↑3 / 2↑shift rounded down, which must fit in 128 bits.
+    fn half_way_cube(r: u128, shift: u32) -> u128 {
+        let m = 2 * r + 1;
+        let cube = int256::wide_mul_u256(int256::wide_mul_u128(m, m), U256 { lo: m, hi: 0 });
+        let (hi, lo) = int256::shr_u512(cube, shift, false);
+        assert!(hi == U256::default() && lo.hi == 0);
+        lo.lo
+    }
+
+    #[test]
+    fn nth_root_near_half_way() {
+        // With f fractional bits, the cube root of val / 2↑f has bits r + ½
+        // when val = (2r + 1)↑3 / 2↑(2f + 3), which is never an integer, so
+        // rounding val down or up puts the root just below or above r + ½.
+        for &r in &[1 << 126, (1 << 126) + 0x1234_5678_9ABC_DEF1, (3 << 125) - 1] {
+            let below = half_way_cube(r, 255);
+            assert_eq!(pow::nth_root_bits(below, 126, 3), (r, false));
+            assert_eq!(pow::nth_root_bits(below + 1, 126, 3), (r + 1, false));
+        }
+        for &r in &[1 << 41, (1 << 41) + 0x1_2345, (1 << 42) - 1] {
+            let below = half_way_cube(r, 3);
+            assert_eq!(pow::nth_root_bits(below, 0, 3), (r, false));
+            assert_eq!(pow::nth_root_bits(below + 1, 0, 3), (r + 1, false));
+            let (neg_below, neg_r) = (-(below as i128), -(r as i128));
+            assert_eq!(pow::nth_root_bits(neg_below, 0, 3), (neg_r, false));
+            assert_eq!(pow::nth_root_bits(neg_below - 1, 0, 3), (neg_r - 1, false));
+        }
+    }
+
+    #[test]
+    fn nth_root_overflow() {
+        // with no integer bits, roots close to one round up to one
+        assert_eq!(pow::nth_root_bits::<u8>(u8::MAX, 8, 3), (u8::MAX, true));
+        assert_eq!(pow::nth_root_bits::<u8>(u8::MAX, 8, 1), (u8::MAX, false));
+        assert_eq!(pow::nth_root_bits::<i8>(i8::MAX, 7, 3), (i8::MAX, true));
+        assert_eq!(pow::nth_root_bits::<i8>(i8::MIN, 7, 3), (i8::MIN, false));
+        assert_eq!(
+            pow::nth_root_bits::<u128>(u128::MAX, 128, 1000),
+            (u128::MAX, true)
+        );
+        // 2↑(−128 / 1000) = 0.915…, which is 234.2…/256
+        assert_eq!(pow::nth_root_bits::<u128>(1, 128, 1000).0 >> 120, 234);
+    }
+}
@@ -20,9 +20,10 @@ use crate::{
     traits::{Fixed, FixedEquiv, FromFixed, ToFixed},
     types::extra::U0,
     F128Bits, FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32,
-    FixedU64, FixedU8,
+    FixedU64, FixedU8, RoundingMode,
 };
 use bytemuck::TransparentWrapper;
+use core::cmp::Ordering;
 use half::{bf16, f16};
 
 impl ToFixed for bool {
@@ -82,6 +83,59 @@ impl ToFixed for bool {
     fn unwrapped_to_fixed<F: Fixed>(self) -> F {
         ToFixed::unwrapped_to_fixed(self as u8)
     }
+
+    /// Converts a [`bool`] to a fixed-point number.
+    ///
+    /// The conversion is exact when the value fits, so `mode` does not
+    /// affect the result.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the value does
+    /// not fit. When debug assertions are not enabled, the wrapped
+    /// value can be returned, but it is not considered a breaking
+    /// change if in the future it panics.
+    #[inline]
+    fn to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> F {
+        ToFixed::to_fixed_round(self as u8, mode)
+    }
+
+    /// Converts a [`bool`] to a fixed-point number if it fits, otherwise
+    /// returns [`None`].
+    ///
+    /// The conversion is exact when the value fits, so `mode` does not
+    /// affect the result.
+    #[inline]
+    fn checked_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> Option<F> {
+        ToFixed::checked_to_fixed_round(self as u8, mode)
+    }
+
+    /// Converts a [`bool`] to a fixed-point number, saturating if it does
+    /// not fit.
+    ///
+    /// The conversion is exact when the value fits, so `mode` does not
+    /// affect the result.
+    #[inline]
+    fn saturating_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> F {
+        ToFixed::saturating_to_fixed_round(self as u8, mode)
+    }
+
+    /// Converts a [`bool`] to a fixed-point number, also returning whether
+    /// the conversion was inexact.
+    ///
+    /// The conversion is exact when the value fits, so the returned
+    /// [`bool`] is always [`false`].
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the value does
+    /// not fit. When debug assertions are not enabled, the wrapped
+    /// value can be returned, but it is not considered a breaking
+    /// change if in the future it panics.
+    #[inline]
+    fn to_fixed_exact<F: Fixed>(self) -> (F, bool) {
+        ToFixed::to_fixed_exact(self as u8)
+    }
 }
 
 macro_rules! impl_int {
@@ -155,6 +209,54 @@ macro_rules! impl_int {
             fn unwrapped_from_fixed<F: Fixed>(src: F) -> Self {
                 IntFixed::<$Int>::int(FromFixed::unwrapped_from_fixed(src))
             }
+
+            /// Converts a fixed-point number to an integer, rounding as
+            /// specified by `mode`.
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, panics if the value
+            /// does not fit. When debug assertions are not enabled,
+            /// the wrapped value can be returned, but it is not
+            /// considered a breaking change if in the future it
+            /// panics.
+            #[inline]
+            fn from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self {
+                IntFixed::<$Int>::int(FromFixed::from_fixed_round(src, mode))
+            }
+
+            /// Converts a fixed-point number to an integer if it fits,
+            /// otherwise returns [`None`], rounding as specified by `mode`.
+            #[inline]
+            fn checked_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Option<Self> {
+                FromFixed::checked_from_fixed_round(src, mode).map(IntFixed::<$Int>::int)
+            }
+
+            /// Converts a fixed-point number to an integer, saturating if it
+            /// does not fit, rounding as specified by `mode`.
+            #[inline]
+            fn saturating_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self {
+                IntFixed::<$Int>::int(FromFixed::saturating_from_fixed_round(src, mode))
+            }
+
+            /// Converts a fixed-point number to an integer, also returning
+            /// whether the conversion was inexact.
+            ///
+            /// Any fractional bits are discarded, which rounds towards −∞,
+            /// and the conversion is inexact if any of them are non-zero.
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, panics if the value
+            /// does not fit. When debug assertions are not enabled,
+            /// the wrapped value can be returned, but it is not
+            /// considered a breaking change if in the future it
+            /// panics.
+            #[inline]
+            fn from_fixed_exact<F: Fixed>(src: F) -> (Self, bool) {
+                let (fixed, inexact) = FromFixed::from_fixed_exact(src);
+                (IntFixed::<$Int>::int(fixed), inexact)
+            }
         }
 
         impl ToFixed for $Int {
@@ -213,6 +315,61 @@ macro_rules! impl_int {
             fn unwrapped_to_fixed<F: Fixed>(self) -> F {
                 ToFixed::unwrapped_to_fixed(IntFixed(self).fixed())
             }
+
+            /// Converts an integer to a fixed-point number.
+            ///
+            /// The conversion is exact when the value fits, so `mode` does
+            /// not affect the result.
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, panics if the value
+            /// does not fit. When debug assertions are not enabled,
+            /// the wrapped value can be returned, but it is not
+            /// considered a breaking change if in the future it
+            /// panics.
+            #[inline]
+            fn to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> F {
+                ToFixed::to_fixed_round(IntFixed(self).fixed(), mode)
+            }
+
+            /// Converts an integer to a fixed-point number if it fits,
+            /// otherwise returns [`None`].
+            ///
+            /// The conversion is exact when the value fits, so `mode` does
+            /// not affect the result.
+            #[inline]
+            fn checked_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> Option<F> {
+                ToFixed::checked_to_fixed_round(IntFixed(self).fixed(), mode)
+            }
+
+            /// Converts an integer to a fixed-point number, saturating if it
+            /// does not fit.
+            ///
+            /// The conversion is exact when the value fits, so `mode` does
+            /// not affect the result.
+            #[inline]
+            fn saturating_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> F {
+                ToFixed::saturating_to_fixed_round(IntFixed(self).fixed(), mode)
+            }
+
+            /// Converts an integer to a fixed-point number, also returning
+            /// whether the conversion was inexact.
+            ///
+            /// The conversion is exact when the value fits, so the returned
+            /// [`bool`] is always [`false`].
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, panics if the value
+            /// does not fit. When debug assertions are not enabled,
+            /// the wrapped value can be returned, but it is not
+            /// considered a breaking change if in the future it
+            /// panics.
+            #[inline]
+            fn to_fixed_exact<F: Fixed>(self) -> (F, bool) {
+                ToFixed::to_fixed_exact(IntFixed(self).fixed())
+            }
         }
 
         $(
@@ -285,8 +442,7 @@ macro_rules! impl_float {
             /// [`wrapping_from_fixed`]: FromFixed::wrapping_from_fixed
             #[inline]
             fn from_fixed<F: Fixed>(src: F) -> Self {
-                let helper = src.private_to_float_helper();
-                float_helper::$Float::from_to_float_helper(helper, F::FRAC_NBITS, F::INT_NBITS)
+                FromFixed::from_fixed_round(src, RoundingMode::NearestTiesEven)
             }
 
             /// Converts a fixed-point number to a floating-point
@@ -341,6 +497,54 @@ macro_rules! impl_float {
             fn unwrapped_from_fixed<F: Fixed>(src: F) -> Self {
                 FromFixed::from_fixed(src)
             }
+
+            /// Converts a fixed-point number to a floating-point number,
+            /// rounding as specified by `mode`.
+            ///
+            /// A value that is too large is rounded to infinity if `mode`
+            /// rounds its absolute value up, otherwise it is rounded to the
+            /// largest finite value with the same sign.
+            #[inline]
+            fn from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self {
+                let helper = src.private_to_float_helper();
+                float_helper::$Float::from_to_float_helper(
+                    helper,
+                    F::FRAC_NBITS,
+                    F::INT_NBITS,
+                    mode,
+                )
+            }
+
+            /// Converts a fixed-point number to a floating-point number if it
+            /// fits, otherwise returns [`None`], rounding as specified by
+            /// `mode`.
+            ///
+            /// This method always returns [`Some`].
+            #[inline]
+            fn checked_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Option<Self> {
+                Some(FromFixed::from_fixed_round(src, mode))
+            }
+
+            /// Converts a fixed-point number to a floating-point number,
+            /// saturating if it does not fit, rounding as specified by `mode`.
+            ///
+            /// This method returns the same value as
+            /// [`from_fixed_round`][FromFixed::from_fixed_round].
+            #[inline]
+            fn saturating_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self {
+                FromFixed::from_fixed_round(src, mode)
+            }
+
+            /// Converts a fixed-point number to a floating-point number,
+            /// also returning whether the conversion was inexact.
+            ///
+            /// Rounding is to the nearest, with ties rounded to even.
+            #[inline]
+            fn from_fixed_exact<F: Fixed>(src: F) -> (Self, bool) {
+                let floor: Self = FromFixed::from_fixed_round(src, RoundingMode::Floor);
+                let ceil: Self = FromFixed::from_fixed_round(src, RoundingMode::Ceil);
+                (FromFixed::from_fixed(src), floor != ceil)
+            }
         }
 
         impl ToFixed for $Float {
@@ -475,6 +679,118 @@ when debug assertions are not enabled.
                     }
                 }
             }
+
+            comment! {
+                "Converts a floating-point number to a fixed-point number,
+rounding as specified by `mode`.
+
+# Panics
+
+Panics if `self` is not [finite].
+
+When debug assertions are enabled, also panics if the value does not
+fit. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics.
+
+[finite]: ", $link, "::is_finite
+";
+                #[inline]
+                #[track_caller]
+                fn to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> F {
+                    let kind = float_helper::$Float::to_float_kind_round(
+                        self,
+                        mode,
+                        F::FRAC_NBITS,
+                        F::INT_NBITS,
+                    );
+                    let helper = FromFloatHelper { kind };
+                    let (wrapped, overflow) = F::private_overflowing_from_float_helper(helper);
+                    debug_assert!(!overflow, $overflows_fmt, $overflows_filt(self));
+                    let _ = overflow;
+                    wrapped
+                }
+            }
+
+            /// Converts a floating-point number to a fixed-point
+            /// number if it fits, otherwise returns [`None`], rounding as
+            /// specified by `mode`.
+            #[inline]
+            fn checked_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> Option<F> {
+                let kind = float_helper::$Float::to_float_kind_round(
+                    self,
+                    mode,
+                    F::FRAC_NBITS,
+                    F::INT_NBITS,
+                );
+                match kind {
+                    FloatKind::Finite { .. } => {
+                        let helper = FromFloatHelper { kind };
+                        match F::private_overflowing_from_float_helper(helper) {
+                            (_, true) => None,
+                            (wrapped, false) => Some(wrapped),
+                        }
+                    }
+                    _ => None,
+                }
+            }
+
+            comment! {
+                "Converts a floating-point number to a fixed-point
+number, saturating if it does not fit, rounding as specified by `mode`.
+
+# Panics
+
+Panics if `self` is [NaN].
+
+[NaN]: ", $link, "::is_nan
+";
+                #[inline]
+                fn saturating_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> F {
+                    let kind = float_helper::$Float::to_float_kind_round(
+                        self,
+                        mode,
+                        F::FRAC_NBITS,
+                        F::INT_NBITS,
+                    );
+                    let helper = FromFloatHelper { kind };
+                    F::private_saturating_from_float_helper(helper)
+                }
+            }
+
+            comment! {
+                "Converts a floating-point number to a fixed-point number,
+also returning whether the conversion was inexact.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if `self` is not [finite].
+
+When debug assertions are enabled, also panics if the value does not
+fit. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics.
+
+[finite]: ", $link, "::is_finite
+";
+                #[inline]
+                #[track_caller]
+                fn to_fixed_exact<F: Fixed>(self) -> (F, bool) {
+                    let kind =
+                        float_helper::$Float::to_float_kind(self, F::FRAC_NBITS, F::INT_NBITS);
+                    let inexact = match kind {
+                        FloatKind::Finite { ref conv, .. } => conv.dir != Ordering::Equal,
+                        _ => false,
+                    };
+                    let helper = FromFloatHelper { kind };
+                    let (wrapped, overflow) = F::private_overflowing_from_float_helper(helper);
+                    debug_assert!(!overflow, $overflows_fmt, $overflows_filt(self));
+                    let _ = overflow;
+                    (wrapped, inexact)
+                }
+            }
         }
     };
 }
@@ -14,9 +14,10 @@
 // <https://opensource.org/licenses/MIT>.
 
 use crate::{
-    types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
-    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8, Unwrapped, Wrapping,
+    int256::{I256, U256},
+    types::extra::{LeEqU128, LeEqU16, LeEqU256, LeEqU32, LeEqU64, LeEqU8},
+    FixedI128, FixedI16, FixedI256, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU256,
+    FixedU32, FixedU64, FixedU8, Unwrapped, Wrapping,
 };
 use serde::{
     de::{Deserialize, Deserializer, Error as DeError},
@@ -141,6 +142,29 @@ serde_fixed! { FixedU16(LeEqU16) is u16 name "FixedU16" }
 serde_fixed! { FixedU32(LeEqU32) is u32 name "FixedU32" }
 serde_fixed! { FixedU64(LeEqU64) is u64 name "FixedU64" }
 serde_fixed! { FixedU128(LeEqU128) is u128 name "FixedU128" }
+serde_fixed! { FixedI256(LeEqU256) is I256 name "FixedI256" }
+serde_fixed! { FixedU256(LeEqU256) is U256 name "FixedU256" }
+
+// 256-bit integers are serialized as a (lo, hi) tuple.
+macro_rules! serde_int256 {
+    ($Int:ident($Hi:ident)) => {
+        impl Serialize for $Int {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                (self.lo, self.hi).serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $Int {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let (lo, hi) = <(u128, $Hi)>::deserialize(deserializer)?;
+                Ok($Int { lo, hi })
+            }
+        }
+    };
+}
+
+serde_int256! { I256(i128) }
+serde_int256! { U256(u128) }
 
 #[cfg(not(feature = "serde-str"))]
 const FIELDS: &[&str] = &["bits"];
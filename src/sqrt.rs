@@ -0,0 +1,231 @@
+// Copyright © 2018–2021 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::int256::{self, U256};
+
+// The square root of a fixed-point number x = val / 2↑f is
+//
+//     √x = √(val × 2↑f) / 2↑f,
+//
+// so the bits of the result are the integer square root of the widened
+// radicand val × 2↑f, rounded to the nearest. Since val × 2↑f is an integer,
+// the exact root cannot lie half-way between two integers, so there are no
+// ties. The integer square root r with remainder n − r² rounds up if and only
+// if n − r² > r, as (r + ½)² = r² + r + ¼.
+
+macro_rules! make_isqrt_rem {
+    ($name:ident, $uu:ident) => {
+        // Returns (r, n − r²) where r = ⌊√n⌋.
+        pub fn $name(n: $uu) -> ($uu, $uu) {
+            if n == 0 {
+                return (0, 0);
+            }
+            // highest power of four ≤ n
+            let mut d: $uu = 1 << ((<$uu>::BITS - 1 - n.leading_zeros()) & !1);
+            let mut rem = n;
+            let mut root: $uu = 0;
+            while d != 0 {
+                if rem >= root + d {
+                    rem -= root + d;
+                    root = (root >> 1) + d;
+                } else {
+                    root >>= 1;
+                }
+                d >>= 2;
+            }
+            (root, rem)
+        }
+    };
+}
+
+make_isqrt_rem! { isqrt_rem_u16, u16 }
+make_isqrt_rem! { isqrt_rem_u32, u32 }
+make_isqrt_rem! { isqrt_rem_u64, u64 }
+make_isqrt_rem! { isqrt_rem_u128, u128 }
+
+// Returns (r, n − r²) where r = ⌊√n⌋.
+//
+// This is the same algorithm as for the primitives, but the partial root can
+// temporarily need up to 129 bits, so it is kept in a U256 too.
+pub fn isqrt_rem_u256(n: U256) -> (u128, U256) {
+    if n.hi == 0 {
+        let (root, rem) = isqrt_rem_u128(n.lo);
+        return (root, U256 { lo: rem, hi: 0 });
+    }
+    let mut d = U256 {
+        lo: 0,
+        hi: 1 << ((127 - n.hi.leading_zeros()) & !1),
+    };
+    let mut rem = n;
+    let mut root = U256 { lo: 0, hi: 0 };
+    while d.lo != 0 || d.hi != 0 {
+        let (trial, _) = int256::overflowing_add_u256(root, d);
+        if !int256::lt_u256(rem, trial) {
+            rem = int256::overflowing_sub_u256(rem, trial).0;
+            root = int256::overflowing_add_u256(int256::shr_u256(root, 1), d).0;
+        } else {
+            root = int256::shr_u256(root, 1);
+        }
+        d = int256::shr_u256(d, 2);
+    }
+    debug_assert!(root.hi == 0);
+    (root.lo, rem)
+}
+
+macro_rules! make_sqrt {
+    ($i:ident, $u:ident, $uu:ident, $isqrt_rem:ident) => {
+        // Returns the rounded bits of √(val / 2↑frac_nbits) with frac_nbits
+        // fractional bits, and whether the result overflows.
+        pub fn $u(val: $u, frac_nbits: u32) -> ($u, bool) {
+            let radicand = <$uu>::from(val) << frac_nbits;
+            let (root, rem) = $isqrt_rem(radicand);
+            let rounded = if rem > root { root + 1 } else { root };
+            (rounded as $u, rounded > <$uu>::from(<$u>::MAX))
+        }
+
+        // val must not be negative
+        pub fn $i(val: $i, frac_nbits: u32) -> ($i, bool) {
+            debug_assert!(val >= 0);
+            let (uns, overflow) = $u(val as $u, frac_nbits);
+            let ans = uns as $i;
+            (ans, overflow || ans < 0)
+        }
+    };
+}
+
+make_sqrt! { i8, u8, u16, isqrt_rem_u16 }
+make_sqrt! { i16, u16, u32, isqrt_rem_u32 }
+make_sqrt! { i32, u32, u64, isqrt_rem_u64 }
+make_sqrt! { i64, u64, u128, isqrt_rem_u128 }
+
+pub fn u128(val: u128, frac_nbits: u32) -> (u128, bool) {
+    let radicand = match frac_nbits {
+        0 => U256 { lo: val, hi: 0 },
+        128 => U256 { lo: 0, hi: val },
+        _ => U256 {
+            lo: val << frac_nbits,
+            hi: val >> (128 - frac_nbits),
+        },
+    };
+    let (root, rem) = isqrt_rem_u256(radicand);
+    if rem.hi != 0 || rem.lo > root {
+        root.overflowing_add(1)
+    } else {
+        (root, false)
+    }
+}
+
+// val must not be negative
+pub fn i128(val: i128, frac_nbits: u32) -> (i128, bool) {
+    debug_assert!(val >= 0);
+    let (uns, overflow) = u128(val as u128, frac_nbits);
+    let ans = uns as i128;
+    (ans, overflow || ans < 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int256::{self, U256};
+
+    // Checks that (2r − 1)² ≤ 4n < (2r + 1)², that is r − ½ ≤ √n < r + ½.
+    fn check_rounded_u128(n: U256, r: u128) {
+        // 4n must fit, so only use n < 2↑254
+        assert!(n.hi >> 126 == 0);
+        let four_n = U256 {
+            lo: n.lo << 2,
+            hi: n.hi << 2 | n.lo >> 126,
+        };
+        let (two_r_lo, two_r_hi) = (r << 1, r >> 127);
+        // (2r − 1)² = 4r² − 4r + 1 and (2r + 1)² = 4r² + 4r + 1
+        let sq = int256::wide_mul_u128(r, r);
+        let four_sq = U256 {
+            lo: sq.lo << 2,
+            hi: sq.hi << 2 | sq.lo >> 126,
+        };
+        let four_r = U256 {
+            lo: two_r_lo << 1,
+            hi: two_r_hi << 1 | two_r_lo >> 127,
+        };
+        let one = U256 { lo: 1, hi: 0 };
+        let upper =
+            int256::overflowing_add_u256(int256::overflowing_add_u256(four_sq, four_r).0, one).0;
+        assert!(int256::lt_u256(four_n, upper));
+        if r != 0 {
+            let lower =
+                int256::overflowing_add_u256(int256::overflowing_sub_u256(four_sq, four_r).0, one)
+                    .0;
+            assert!(!int256::lt_u256(four_n, lower));
+        }
+    }
+
+    #[test]
+    fn sqrt_u8_exhaustive() {
+        for frac_nbits in 0..=8 {
+            for val in 0..=u8::MAX {
+                let exact = (f64::from(val) * f64::from(1u32 << frac_nbits)).sqrt();
+                let expected = exact.round();
+                let (ans, overflow) = super::u8(val, frac_nbits);
+                assert!(!overflow);
+                assert_eq!(f64::from(ans), expected, "{} {}", val, frac_nbits);
+            }
+        }
+    }
+
+    #[test]
+    fn sqrt_i8_exhaustive() {
+        for frac_nbits in 0..=8 {
+            for val in 0..=i8::MAX {
+                let exact = (f64::from(val) * f64::from(1u32 << frac_nbits)).sqrt();
+                let expected = exact.round();
+                let (ans, overflow) = super::i8(val, frac_nbits);
+                assert_eq!(overflow, expected > 127.0);
+                assert_eq!(ans, expected as i32 as i8, "{} {}", val, frac_nbits);
+            }
+        }
+    }
+
+    #[test]
+    fn sqrt_u128() {
+        let vals = [
+            0,
+            1,
+            2,
+            3,
+            0x1234_5678_9ABC_DEF0,
+            u128::from(u64::MAX),
+            u128::MAX >> 2,
+            u128::MAX >> 1,
+        ];
+        for &val in &vals {
+            for &frac_nbits in &[0, 1, 2, 63, 64, 65, 125, 126] {
+                let (ans, overflow) = super::u128(val, frac_nbits);
+                assert!(!overflow);
+                let n = match frac_nbits {
+                    0 => U256 { lo: val, hi: 0 },
+                    _ => U256 {
+                        lo: val << frac_nbits,
+                        hi: val >> (128 - frac_nbits),
+                    },
+                };
+                check_rounded_u128(n, ans);
+            }
+        }
+        assert_eq!(super::u128(u128::MAX, 0), (1 << 64, false));
+        assert_eq!(super::u128(u128::MAX, 128), (u128::MAX, false));
+        assert_eq!(super::u128(1 << 126, 128), (1 << 127, false));
+        assert_eq!(super::i128(1 << 126, 128), (i128::MIN, true));
+        assert_eq!(super::i128(i128::MAX, 127), (i128::MAX, false));
+    }
+}
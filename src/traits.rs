@@ -18,15 +18,16 @@ Traits for conversions and for generic use of fixed-point numbers.
 */
 
 use crate::{
-    helpers::{Sealed, Widest},
-    types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8, Unsigned},
-    F128Bits, FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32,
-    FixedU64, FixedU8, ParseFixedError,
+    helpers::{Sealed, Sealed256, Widest},
+    types::extra::{LeEqU128, LeEqU16, LeEqU256, LeEqU32, LeEqU64, LeEqU8, Unsigned},
+    F128Bits, FixedI128, FixedI16, FixedI256, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16,
+    FixedU256, FixedU32, FixedU64, FixedU8, ParseFixedError, RoundingMode,
 };
 #[cfg(feature = "arbitrary")]
 use arbitrary::Arbitrary;
 use bytemuck::{self, Pod, TransparentWrapper};
 use core::{
+    cmp::Ordering,
     fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex},
     hash::Hash,
     iter::{Product, Sum},
@@ -891,6 +892,122 @@ where
     /// <code>FixedU32::[overflowing\_to\_num][FixedU32::overflowing_to_num]</code>.
     fn overflowing_to_num<Dst: FromFixed>(self) -> (Dst, bool);
 
+    /// Creates a fixed-point number from another number, rounding as
+    /// specified by `mode`.
+    ///
+    /// Returns the same value as
+    /// <code>src.[to\_fixed\_round][ToFixed::to_fixed_round]\(mode)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_num\_round][FixedI32::from_num_round]</code>
+    /// and
+    /// <code>FixedU32::[from\_num\_round][FixedU32::from_num_round]</code>.
+    fn from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Self;
+
+    /// Converts a fixed-point number to another number, rounding as
+    /// specified by `mode`.
+    ///
+    /// Returns the same value as
+    /// <code>Dst::[from\_fixed\_round][FromFixed::from_fixed_round]\(self, mode)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[to\_num\_round][FixedI32::to_num_round]</code> and
+    /// <code>FixedU32::[to\_num\_round][FixedU32::to_num_round]</code>.
+    fn to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst;
+
+    /// Creates a fixed-point number from another number if it fits,
+    /// otherwise returns [`None`], rounding as specified by `mode`.
+    ///
+    /// Returns the same value as
+    /// <code>src.[checked\_to\_fixed\_round][ToFixed::checked_to_fixed_round]\(mode)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_from\_num\_round][FixedI32::checked_from_num_round]</code>
+    /// and
+    /// <code>FixedU32::[checked\_from\_num\_round][FixedU32::checked_from_num_round]</code>.
+    fn checked_from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Option<Self>;
+
+    /// Converts a fixed-point number to another number if it fits,
+    /// otherwise returns [`None`], rounding as specified by `mode`.
+    ///
+    /// Returns the same value as
+    /// <code>Dst::[checked\_from\_fixed\_round][FromFixed::checked_from_fixed_round]\(self, mode)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_to\_num\_round][FixedI32::checked_to_num_round]</code>
+    /// and
+    /// <code>FixedU32::[checked\_to\_num\_round][FixedU32::checked_to_num_round]</code>.
+    fn checked_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Option<Dst>;
+
+    /// Creates a fixed-point number from another number, saturating the
+    /// value if it does not fit, and rounding as specified by `mode`.
+    ///
+    /// Returns the same value as
+    /// <code>src.[saturating\_to\_fixed\_round][ToFixed::saturating_to_fixed_round]\(mode)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_from\_num\_round][FixedI32::saturating_from_num_round]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_from\_num\_round][FixedU32::saturating_from_num_round]</code>.
+    fn saturating_from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Self;
+
+    /// Converts a fixed-point number to another number, saturating the
+    /// value if it does not fit, and rounding as specified by `mode`.
+    ///
+    /// Returns the same value as
+    /// <code>Dst::[saturating\_from\_fixed\_round][FromFixed::saturating_from_fixed_round]\(self, mode)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_to\_num\_round][FixedI32::saturating_to_num_round]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_to\_num\_round][FixedU32::saturating_to_num_round]</code>.
+    fn saturating_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst;
+
+    /// Creates a fixed-point number from another fixed-point number,
+    /// rounding stochastically using the random bits in `random`.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_num\_stochastic][FixedI32::from_num_stochastic]</code>
+    /// and
+    /// <code>FixedU32::[from\_num\_stochastic][FixedU32::from_num_stochastic]</code>.
+    fn from_num_stochastic<Src: Fixed>(src: Src, random: u128) -> Self;
+
+    /// Converts a fixed-point number to another fixed-point number,
+    /// rounding stochastically using the random bits in `random`.
+    ///
+    /// Returns the same value as
+    /// <code>Dst::[from\_num\_stochastic][Fixed::from_num_stochastic]\(self, random)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[to\_num\_stochastic][FixedI32::to_num_stochastic]</code>
+    /// and
+    /// <code>FixedU32::[to\_num\_stochastic][FixedU32::to_num_stochastic]</code>.
+    fn to_num_stochastic<Dst: Fixed>(self, random: u128) -> Dst;
+
+    /// Creates a fixed-point number from another number, also returning
+    /// whether the conversion was inexact.
+    ///
+    /// Returns the same value as
+    /// <code>src.[to\_fixed\_exact][ToFixed::to_fixed_exact]\()</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_num\_exact][FixedI32::from_num_exact]</code>
+    /// and
+    /// <code>FixedU32::[from\_num\_exact][FixedU32::from_num_exact]</code>.
+    fn from_num_exact<Src: ToFixed>(src: Src) -> (Self, bool);
+
+    /// Converts a fixed-point number to another number, also returning
+    /// whether the conversion was inexact.
+    ///
+    /// Returns the same value as
+    /// <code>Dst::[from\_fixed\_exact][FromFixed::from_fixed_exact]\(self)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[to\_num\_exact][FixedI32::to_num_exact]</code>
+    /// and
+    /// <code>FixedU32::[to\_num\_exact][FixedU32::to_num_exact]</code>.
+    fn to_num_exact<Dst: FromFixed>(self) -> (Dst, bool);
+
     /// Parses a string slice containing binary digits to return a fixed-point number.
     ///
     /// Rounding is to the nearest, with ties rounded to even.
@@ -1066,6 +1183,201 @@ where
     /// <code>FixedU32::[overflowing\_from\_str\_hex][FixedU32::overflowing_from_str_hex]</code>.
     fn overflowing_from_str_hex(src: &str) -> Result<(Self, bool), ParseFixedError>;
 
+    /// Parses a string slice containing decimal digits to return a
+    /// fixed-point number, rounding as specified by `mode`.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_str\_round][FixedI32::from_str_round]</code>
+    /// and
+    /// <code>FixedU32::[from\_str\_round][FixedU32::from_str_round]</code>.
+    fn from_str_round(src: &str, mode: RoundingMode) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing binary digits to return a
+    /// fixed-point number, rounding as specified by `mode`.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_str\_binary\_round][FixedI32::from_str_binary_round]</code>
+    /// and
+    /// <code>FixedU32::[from\_str\_binary\_round][FixedU32::from_str_binary_round]</code>.
+    fn from_str_binary_round(src: &str, mode: RoundingMode) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing octal digits to return a
+    /// fixed-point number, rounding as specified by `mode`.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_str\_octal\_round][FixedI32::from_str_octal_round]</code>
+    /// and
+    /// <code>FixedU32::[from\_str\_octal\_round][FixedU32::from_str_octal_round]</code>.
+    fn from_str_octal_round(src: &str, mode: RoundingMode) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing hexadecimal digits to return a
+    /// fixed-point number, rounding as specified by `mode`.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_str\_hex\_round][FixedI32::from_str_hex_round]</code>
+    /// and
+    /// <code>FixedU32::[from\_str\_hex\_round][FixedU32::from_str_hex_round]</code>.
+    fn from_str_hex_round(src: &str, mode: RoundingMode) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing decimal digits to return a
+    /// fixed-point number, saturating on overflow and rounding as specified by
+    /// `mode`.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_from\_str\_round][FixedI32::saturating_from_str_round]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_from\_str\_round][FixedU32::saturating_from_str_round]</code>.
+    fn saturating_from_str_round(src: &str, mode: RoundingMode) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing binary digits to return a
+    /// fixed-point number, saturating on overflow and rounding as specified by
+    /// `mode`.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_from\_str\_binary\_round][FixedI32::saturating_from_str_binary_round]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_from\_str\_binary\_round][FixedU32::saturating_from_str_binary_round]</code>.
+    fn saturating_from_str_binary_round(
+        src: &str,
+        mode: RoundingMode,
+    ) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing octal digits to return a
+    /// fixed-point number, saturating on overflow and rounding as specified by
+    /// `mode`.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_from\_str\_octal\_round][FixedI32::saturating_from_str_octal_round]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_from\_str\_octal\_round][FixedU32::saturating_from_str_octal_round]</code>.
+    fn saturating_from_str_octal_round(
+        src: &str,
+        mode: RoundingMode,
+    ) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing hexadecimal digits to return a
+    /// fixed-point number, saturating on overflow and rounding as specified by
+    /// `mode`.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_from\_str\_hex\_round][FixedI32::saturating_from_str_hex_round]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_from\_str\_hex\_round][FixedU32::saturating_from_str_hex_round]</code>.
+    fn saturating_from_str_hex_round(
+        src: &str,
+        mode: RoundingMode,
+    ) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing decimal digits to return a
+    /// fixed-point number, wrapping on overflow and rounding as specified by
+    /// `mode`.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_from\_str\_round][FixedI32::wrapping_from_str_round]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_from\_str\_round][FixedU32::wrapping_from_str_round]</code>.
+    fn wrapping_from_str_round(src: &str, mode: RoundingMode) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing binary digits to return a
+    /// fixed-point number, wrapping on overflow and rounding as specified by
+    /// `mode`.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_from\_str\_binary\_round][FixedI32::wrapping_from_str_binary_round]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_from\_str\_binary\_round][FixedU32::wrapping_from_str_binary_round]</code>.
+    fn wrapping_from_str_binary_round(
+        src: &str,
+        mode: RoundingMode,
+    ) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing octal digits to return a
+    /// fixed-point number, wrapping on overflow and rounding as specified by
+    /// `mode`.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_from\_str\_octal\_round][FixedI32::wrapping_from_str_octal_round]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_from\_str\_octal\_round][FixedU32::wrapping_from_str_octal_round]</code>.
+    fn wrapping_from_str_octal_round(
+        src: &str,
+        mode: RoundingMode,
+    ) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing hexadecimal digits to return a
+    /// fixed-point number, wrapping on overflow and rounding as specified by
+    /// `mode`.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_from\_str\_hex\_round][FixedI32::wrapping_from_str_hex_round]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_from\_str\_hex\_round][FixedU32::wrapping_from_str_hex_round]</code>.
+    fn wrapping_from_str_hex_round(src: &str, mode: RoundingMode) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing decimal digits to return a
+    /// fixed-point number, rounding as specified by `mode`.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_str\_round][FixedI32::overflowing_from_str_round]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_str\_round][FixedU32::overflowing_from_str_round]</code>.
+    fn overflowing_from_str_round(
+        src: &str,
+        mode: RoundingMode,
+    ) -> Result<(Self, bool), ParseFixedError>;
+
+    /// Parses a string slice containing binary digits to return a
+    /// fixed-point number, rounding as specified by `mode`.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_str\_binary\_round][FixedI32::overflowing_from_str_binary_round]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_str\_binary\_round][FixedU32::overflowing_from_str_binary_round]</code>.
+    fn overflowing_from_str_binary_round(
+        src: &str,
+        mode: RoundingMode,
+    ) -> Result<(Self, bool), ParseFixedError>;
+
+    /// Parses a string slice containing octal digits to return a
+    /// fixed-point number, rounding as specified by `mode`.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_str\_octal\_round][FixedI32::overflowing_from_str_octal_round]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_str\_octal\_round][FixedU32::overflowing_from_str_octal_round]</code>.
+    fn overflowing_from_str_octal_round(
+        src: &str,
+        mode: RoundingMode,
+    ) -> Result<(Self, bool), ParseFixedError>;
+
+    /// Parses a string slice containing hexadecimal digits to return a
+    /// fixed-point number, rounding as specified by `mode`.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_str\_hex\_round][FixedI32::overflowing_from_str_hex_round]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_str\_hex\_round][FixedU32::overflowing_from_str_hex_round]</code>.
+    fn overflowing_from_str_hex_round(
+        src: &str,
+        mode: RoundingMode,
+    ) -> Result<(Self, bool), ParseFixedError>;
+
     /// Returns the integer part.
     ///
     /// See also <code>FixedI32::[int][FixedI32::int]</code> and
@@ -1111,6 +1423,36 @@ where
     /// <code>FixedU32::[round\_ties\_to\_even][FixedU32::round_ties_to_even]</code>.
     fn round_ties_to_even(self) -> Self;
 
+    /// Rounds to a multiple of `step`, rounding as specified by `mode`.
+    ///
+    /// See also
+    /// <code>FixedI32::[round\_to\_multiple][FixedI32::round_to_multiple]</code>
+    /// and
+    /// <code>FixedU32::[round\_to\_multiple][FixedU32::round_to_multiple]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result does not fit. When debug assertions are not enabled, the
+    /// wrapped result can be returned, but it is not considered a
+    /// breaking change if in the future it panics.
+    fn round_to_multiple(self, step: Self, mode: RoundingMode) -> Self;
+
+    /// Rounds to `dp` decimal places, rounding as specified by `mode`.
+    ///
+    /// See also <code>FixedI32::[round\_dp][FixedI32::round_dp]</code> and
+    /// <code>FixedU32::[round\_dp][FixedU32::round_dp]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the result does not
+    /// fit. When debug assertions are not enabled, the wrapped result can
+    /// be returned, but it is not considered a breaking change if in the
+    /// future it panics.
+    fn round_dp(self, dp: u32, mode: RoundingMode) -> Self;
+
     /// Checked ceil. Rounds to the next integer towards +∞, returning
     /// [`None`] on overflow.
     ///
@@ -1143,6 +1485,24 @@ where
     /// <code>FixedU32::[checked\_round\_ties\_to\_even][FixedU32::checked_round_ties_to_even]</code>.
     fn checked_round_ties_to_even(self) -> Option<Self>;
 
+    /// Checked rounding to a multiple of `step`, rounding as specified
+    /// by `mode`, returning [`None`] if `step` is zero or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_round\_to\_multiple][FixedI32::checked_round_to_multiple]</code>
+    /// and
+    /// <code>FixedU32::[checked\_round\_to\_multiple][FixedU32::checked_round_to_multiple]</code>.
+    fn checked_round_to_multiple(self, step: Self, mode: RoundingMode) -> Option<Self>;
+
+    /// Checked rounding to `dp` decimal places, rounding as specified by
+    /// `mode`, returning [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_round\_dp][FixedI32::checked_round_dp]</code>
+    /// and
+    /// <code>FixedU32::[checked\_round\_dp][FixedU32::checked_round_dp]</code>.
+    fn checked_round_dp(self, dp: u32, mode: RoundingMode) -> Option<Self>;
+
     /// Saturating ceil. Rounds to the next integer towards +∞,
     /// saturating on overflow.
     ///
@@ -1178,6 +1538,15 @@ where
     /// <code>FixedU32::[saturating\_round\_ties\_to\_even][FixedU32::saturating_round_ties_to_even]</code>.
     fn saturating_round_ties_to_even(self) -> Self;
 
+    /// Saturating rounding to `dp` decimal places, rounding as specified
+    /// by `mode`, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_round\_dp][FixedI32::saturating_round_dp]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_round\_dp][FixedU32::saturating_round_dp]</code>.
+    fn saturating_round_dp(self, dp: u32, mode: RoundingMode) -> Self;
+
     /// Wrapping ceil. Rounds to the next integer towards +∞, wrapping
     /// on overflow.
     ///
@@ -1211,6 +1580,28 @@ where
     /// <code>FixedU32::[wrapping\_round\_ties\_to\_even][FixedU32::wrapping_round_ties_to_even]</code>.
     fn wrapping_round_ties_to_even(self) -> Self;
 
+    /// Wrapping rounding to a multiple of `step`, rounding as specified
+    /// by `mode`, and wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_round\_to\_multiple][FixedI32::wrapping_round_to_multiple]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_round\_to\_multiple][FixedU32::wrapping_round_to_multiple]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    fn wrapping_round_to_multiple(self, step: Self, mode: RoundingMode) -> Self;
+
+    /// Wrapping rounding to `dp` decimal places, rounding as specified
+    /// by `mode`, and wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_round\_dp][FixedI32::wrapping_round_dp]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_round\_dp][FixedU32::wrapping_round_dp]</code>.
+    fn wrapping_round_dp(self, dp: u32, mode: RoundingMode) -> Self;
+
     /// Unwrapped ceil. Rounds to the next integer towards +∞,
     /// panicking on overflow.
     ///
@@ -1314,6 +1705,36 @@ where
     /// <code>FixedU32::[overflowing\_round\_ties\_to\_even][FixedU32::overflowing_round_ties_to_even]</code>.
     fn overflowing_round_ties_to_even(self) -> (Self, bool);
 
+    /// Overflowing rounding to a multiple of `step`, rounding as
+    /// specified by `mode`.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_round\_to\_multiple][FixedI32::overflowing_round_to_multiple]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_round\_to\_multiple][FixedU32::overflowing_round_to_multiple]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    fn overflowing_round_to_multiple(self, step: Self, mode: RoundingMode) -> (Self, bool);
+
+    /// Overflowing rounding to `dp` decimal places, rounding as
+    /// specified by `mode`.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_round\_dp][FixedI32::overflowing_round_dp]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_round\_dp][FixedU32::overflowing_round_dp]</code>.
+    fn overflowing_round_dp(self, dp: u32, mode: RoundingMode) -> (Self, bool);
+
     /// Returns the number of ones in the binary representation.
     ///
     /// See also <code>FixedI32::[count\_ones][FixedI32::count_ones]</code> and
@@ -1442,79 +1863,474 @@ where
     /// Panics if `self` is zero.
     fn recip(self) -> Self;
 
-    /// Multiply and add. Returns `self` × `mul` + `add`.
+    /// Returns the square root.
     ///
-    /// Note that the inherent [`mul_add`] method is more flexible
-    /// than this method and allows the `mul` parameter to have a
-    /// fixed-point type like `self` but with a different number of
-    /// fractional bits.
+    /// See also <code>FixedI32::[sqrt][FixedI32::sqrt]</code> and
+    /// <code>FixedU32::[sqrt][FixedU32::sqrt]</code>.
     ///
-    /// [`mul_add`]: FixedI32::mul_add
+    /// # Panics
     ///
-    /// See also <code>FixedI32::[mul\_add][FixedI32::mul_add]</code> and
-    /// <code>FixedU32::[mul\_add][FixedU32::mul_add]</code>.
+    /// Panics if `self` is negative.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn mul_add(self, mul: Self, add: Self) -> Self;
+    fn sqrt(self) -> Self;
 
-    /// Multiply and accumulate. Adds (`a` × `b`) to `self`.
+    /// Returns `self` raised to the integer power `n`.
     ///
-    /// Note that the inherent [`mul_acc`] method is more flexible than this
-    /// method and allows the `a` and `b` parameters to have a fixed-point type
-    /// like `self` but with a different number of fractional bits.
+    /// See also <code>FixedI32::[powi][FixedI32::powi]</code> and
+    /// <code>FixedU32::[powi][FixedU32::powi]</code>.
     ///
-    /// [`mul_acc`]: FixedI32::mul_acc
+    /// # Panics
     ///
-    /// See also <code>FixedI32::[mul\_acc][FixedI32::mul_acc]</code> and
-    /// <code>FixedU32::[mul\_acc][FixedU32::mul_acc]</code>.
-    fn mul_acc(&mut self, a: Self, b: Self);
+    /// Panics if `self` is zero and `n` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn powi(self, n: i32) -> Self;
 
-    /// Euclidean division by an integer.
+    /// Hypotenuse, √(`self`² + `other`²), computed without overflow
+    /// of the intermediate values.
     ///
-    /// See also <code>FixedI32::[div\_euclid][FixedI32::div_euclid]</code> and
-    /// <code>FixedU32::[div\_euclid][FixedU32::div_euclid]</code>.
+    /// See also <code>FixedI32::[hypot][FixedI32::hypot]</code> and
+    /// <code>FixedU32::[hypot][FixedU32::hypot]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero or if the division results in overflow.
+    /// When debug assertions are enabled, panics if the result does not fit.
+    /// When debug assertions are not enabled, the wrapped value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn div_euclid(self, rhs: Self) -> Self;
+    fn hypot(self, other: Self) -> Self;
 
-    /// Remainder for Euclidean division.
+    /// Exponential, <i>e</i><sup>`self`</sup>.
     ///
-    /// See also <code>FixedI32::[rem\_euclid][FixedI32::rem_euclid]</code> and
-    /// <code>FixedU32::[rem\_euclid][FixedU32::rem_euclid]</code>.
+    /// See also <code>FixedI32::[exp][FixedI32::exp]</code> and
+    /// <code>FixedU32::[exp][FixedU32::exp]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero.
+    /// When debug assertions are enabled, panics if the result does not fit.
+    /// When debug assertions are not enabled, the saturated value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn rem_euclid(self, rhs: Self) -> Self;
+    fn exp(self) -> Self;
 
-    /// Euclidean division by an integer.
+    /// Base-2 exponential, 2<sup>`self`</sup>.
     ///
-    /// See also
-    /// <code>FixedI32::[div\_euclid\_int][FixedI32::div_euclid_int]</code> and
-    /// <code>FixedU32::[div\_euclid\_int][FixedU32::div_euclid_int]</code>.
+    /// See also <code>FixedI32::[exp2][FixedI32::exp2]</code> and
+    /// <code>FixedU32::[exp2][FixedU32::exp2]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero or if the division results in overflow.
+    /// When debug assertions are enabled, panics if the result does not fit.
+    /// When debug assertions are not enabled, the saturated value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn div_euclid_int(self, rhs: Self::Bits) -> Self;
+    fn exp2(self) -> Self;
 
-    /// Remainder for Euclidean division by an integer.
+    /// Natural logarithm, ln `self`.
     ///
-    /// See also
-    /// <code>FixedI32::[rem\_euclid\_int][FixedI32::rem_euclid_int]</code> and
-    /// <code>FixedU32::[rem\_euclid\_int][FixedU32::rem_euclid_int]</code>.
+    /// See also <code>FixedI32::[ln][FixedI32::ln]</code> and
+    /// <code>FixedU32::[ln][FixedU32::ln]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero or if the division results in overflow.
+    /// Panics if `self` ≤ 0.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result does not fit. When debug assertions are not enabled, the
+    /// saturated value can be returned, but it is not considered a breaking
+    /// change if in the future it panics.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn rem_euclid_int(self, rhs: Self::Bits) -> Self;
+    fn ln(self) -> Self;
 
-    /// Linear interpolation between `start` and `end`.
+    /// Base-2 logarithm, log<sub>2</sub> `self`.
+    ///
+    /// See also <code>FixedI32::[log2][FixedI32::log2]</code> and
+    /// <code>FixedU32::[log2][FixedU32::log2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` ≤ 0.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result does not fit. When debug assertions are not enabled, the
+    /// saturated value can be returned, but it is not considered a breaking
+    /// change if in the future it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn log2(self) -> Self;
+
+    /// Base-10 logarithm, log<sub>10</sub> `self`.
+    ///
+    /// See also <code>FixedI32::[log10][FixedI32::log10]</code> and
+    /// <code>FixedU32::[log10][FixedU32::log10]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` ≤ 0.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result does not fit. When debug assertions are not enabled, the
+    /// saturated value can be returned, but it is not considered a breaking
+    /// change if in the future it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn log10(self) -> Self;
+
+    /// Power, `self` raised to the power `exp`.
+    ///
+    /// See also <code>FixedI32::[powf][FixedI32::powf]</code> and
+    /// <code>FixedU32::[powf][FixedU32::powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative, or if `self` is zero and `exp` is
+    /// negative.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result does not fit. When debug assertions are not enabled, the
+    /// saturated value can be returned, but it is not considered a breaking
+    /// change if in the future it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn powf(self, exp: Self) -> Self;
+
+    /// Cube root.
+    ///
+    /// See also <code>FixedI32::[cbrt][FixedI32::cbrt]</code> and
+    /// <code>FixedU32::[cbrt][FixedU32::cbrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the result does not fit.
+    /// When debug assertions are not enabled, the saturated value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn cbrt(self) -> Self;
+
+    /// Returns the `n`th root of `self`.
+    ///
+    /// See also <code>FixedI32::[nth\_root][FixedI32::nth_root]</code> and
+    /// <code>FixedU32::[nth\_root][FixedU32::nth_root]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if `self` is negative and `n` is even.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result does not fit. When debug assertions are not enabled, the
+    /// saturated value can be returned, but it is not considered a breaking
+    /// change if in the future it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn nth_root(self, n: u32) -> Self;
+
+    /// Hyperbolic sine.
+    ///
+    /// See also <code>FixedI32::[sinh][FixedI32::sinh]</code> and
+    /// <code>FixedU32::[sinh][FixedU32::sinh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the result does not fit.
+    /// When debug assertions are not enabled, the saturated value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn sinh(self) -> Self;
+
+    /// Hyperbolic cosine.
+    ///
+    /// See also <code>FixedI32::[cosh][FixedI32::cosh]</code> and
+    /// <code>FixedU32::[cosh][FixedU32::cosh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the result does not fit.
+    /// When debug assertions are not enabled, the saturated value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn cosh(self) -> Self;
+
+    /// Hyperbolic tangent.
+    ///
+    /// See also <code>FixedI32::[tanh][FixedI32::tanh]</code> and
+    /// <code>FixedU32::[tanh][FixedU32::tanh]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn tanh(self) -> Self;
+
+    /// Multiply and add. Returns `self` × `mul` + `add`.
+    ///
+    /// Note that the inherent [`mul_add`] method is more flexible
+    /// than this method and allows the `mul` parameter to have a
+    /// fixed-point type like `self` but with a different number of
+    /// fractional bits.
+    ///
+    /// [`mul_add`]: FixedI32::mul_add
+    ///
+    /// See also <code>FixedI32::[mul\_add][FixedI32::mul_add]</code> and
+    /// <code>FixedU32::[mul\_add][FixedU32::mul_add]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn mul_add(self, mul: Self, add: Self) -> Self;
+
+    /// Multiply and accumulate. Adds (`a` × `b`) to `self`.
+    ///
+    /// Note that the inherent [`mul_acc`] method is more flexible than this
+    /// method and allows the `a` and `b` parameters to have a fixed-point type
+    /// like `self` but with a different number of fractional bits.
+    ///
+    /// [`mul_acc`]: FixedI32::mul_acc
+    ///
+    /// See also <code>FixedI32::[mul\_acc][FixedI32::mul_acc]</code> and
+    /// <code>FixedU32::[mul\_acc][FixedU32::mul_acc]</code>.
+    fn mul_acc(&mut self, a: Self, b: Self);
+
+    /// Multiplication with the specified rounding mode.
+    ///
+    /// See also <code>FixedI32::[mul\_round][FixedI32::mul_round]</code> and
+    /// <code>FixedU32::[mul\_round][FixedU32::mul_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the result does not fit.
+    /// When debug assertions are not enabled, the wrapped value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn mul_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Division with the specified rounding mode.
+    ///
+    /// See also <code>FixedI32::[div\_round][FixedI32::div_round]</code> and
+    /// <code>FixedU32::[div\_round][FixedU32::div_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result does not fit. When debug assertions are not enabled, the wrapped
+    /// value can be returned, but it is not considered a breaking change if in
+    /// the future it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn div_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Multiplication rounding down, returning the product rounded towards −∞.
+    ///
+    /// See also <code>FixedI32::[mul\_floor][FixedI32::mul_floor]</code> and
+    /// <code>FixedU32::[mul\_floor][FixedU32::mul_floor]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the result does not fit.
+    /// When debug assertions are not enabled, the wrapped value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn mul_floor(self, rhs: Self) -> Self;
+
+    /// Multiplication rounding up, returning the product rounded towards +∞.
+    ///
+    /// See also <code>FixedI32::[mul\_ceil][FixedI32::mul_ceil]</code> and
+    /// <code>FixedU32::[mul\_ceil][FixedU32::mul_ceil]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the result does not fit.
+    /// When debug assertions are not enabled, the wrapped value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn mul_ceil(self, rhs: Self) -> Self;
+
+    /// Division rounding down, returning the quotient rounded towards −∞.
+    ///
+    /// See also <code>FixedI32::[div\_floor][FixedI32::div_floor]</code> and
+    /// <code>FixedU32::[div\_floor][FixedU32::div_floor]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result does not fit. When debug assertions are not enabled, the wrapped
+    /// value can be returned, but it is not considered a breaking change if in
+    /// the future it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn div_floor(self, rhs: Self) -> Self;
+
+    /// Division rounding up, returning the quotient rounded towards +∞.
+    ///
+    /// See also <code>FixedI32::[div\_ceil][FixedI32::div_ceil]</code> and
+    /// <code>FixedU32::[div\_ceil][FixedU32::div_ceil]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result does not fit. When debug assertions are not enabled, the wrapped
+    /// value can be returned, but it is not considered a breaking change if in
+    /// the future it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn div_ceil(self, rhs: Self) -> Self;
+
+    /// Multiplication that also returns whether the product is inexact.
+    ///
+    /// See also <code>FixedI32::[mul\_exact][FixedI32::mul_exact]</code> and
+    /// <code>FixedU32::[mul\_exact][FixedU32::mul_exact]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the result does not fit.
+    /// When debug assertions are not enabled, the wrapped value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn mul_exact(self, rhs: Self) -> (Self, bool);
+
+    /// Division that also returns whether the quotient is inexact.
+    ///
+    /// See also <code>FixedI32::[div\_exact][FixedI32::div_exact]</code> and
+    /// <code>FixedU32::[div\_exact][FixedU32::div_exact]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result does not fit. When debug assertions are not enabled, the wrapped
+    /// value can be returned, but it is not considered a breaking change if in
+    /// the future it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn div_exact(self, rhs: Self) -> (Self, bool);
+
+    /// Multiplies two fixed-point numbers and converts the product to the
+    /// fixed-point type `Dst`, rounding towards −∞.
+    ///
+    /// See also <code>FixedI32::[mul\_into][FixedI32::mul_into]</code> and
+    /// <code>FixedU32::[mul\_into][FixedU32::mul_into]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the product does not fit.
+    /// When debug assertions are not enabled, the wrapped value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn mul_into<Dst: Fixed, Rhs: Fixed>(self, rhs: Rhs) -> Dst;
+
+    /// Multiplication and division with a single rounding. Returns `self` ×
+    /// `mul` ÷ `div` rounded towards zero.
+    ///
+    /// See also <code>FixedI32::[mul\_div][FixedI32::mul_div]</code> and
+    /// <code>FixedU32::[mul\_div][FixedU32::mul_div]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `div` is zero.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result does not fit. When debug assertions are not enabled, the wrapped
+    /// value can be returned, but it is not considered a breaking change if in
+    /// the future it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn mul_div(self, mul: Self, div: Self) -> Self;
+
+    /// Multiplication and division with a single rounding using the specified
+    /// rounding mode. Returns `self` × `mul` ÷ `div`.
+    ///
+    /// See also
+    /// <code>FixedI32::[mul\_div\_round][FixedI32::mul_div_round]</code> and
+    /// <code>FixedU32::[mul\_div\_round][FixedU32::mul_div_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `div` is zero.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result does not fit. When debug assertions are not enabled, the wrapped
+    /// value can be returned, but it is not considered a breaking change if in
+    /// the future it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn mul_div_round(self, mul: Self, div: Self, mode: RoundingMode) -> Self;
+
+    /// Euclidean division by an integer.
+    ///
+    /// See also <code>FixedI32::[div\_euclid][FixedI32::div_euclid]</code> and
+    /// <code>FixedU32::[div\_euclid][FixedU32::div_euclid]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the division results in overflow.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn div_euclid(self, rhs: Self) -> Self;
+
+    /// Remainder for Euclidean division.
+    ///
+    /// See also <code>FixedI32::[rem\_euclid][FixedI32::rem_euclid]</code> and
+    /// <code>FixedU32::[rem\_euclid][FixedU32::rem_euclid]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    /// Euclidean division by an integer.
+    ///
+    /// See also
+    /// <code>FixedI32::[div\_euclid\_int][FixedI32::div_euclid_int]</code> and
+    /// <code>FixedU32::[div\_euclid\_int][FixedU32::div_euclid_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the division results in overflow.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn div_euclid_int(self, rhs: Self::Bits) -> Self;
+
+    /// Remainder for Euclidean division by an integer.
+    ///
+    /// See also
+    /// <code>FixedI32::[rem\_euclid\_int][FixedI32::rem_euclid_int]</code> and
+    /// <code>FixedU32::[rem\_euclid\_int][FixedU32::rem_euclid_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the division results in overflow.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn rem_euclid_int(self, rhs: Self::Bits) -> Self;
+
+    /// Addition of an integer. Returns the sum.
+    ///
+    /// See also
+    /// <code>FixedI32::[add\_int][FixedI32::add_int]</code> and
+    /// <code>FixedU32::[add\_int][FixedU32::add_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value
+    /// can be returned, but it is not considered a breaking change if in
+    /// the future it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn add_int(self, rhs: Self::Bits) -> Self;
+
+    /// Subtraction of an integer. Returns the difference.
+    ///
+    /// See also
+    /// <code>FixedI32::[sub\_int][FixedI32::sub_int]</code> and
+    /// <code>FixedU32::[sub\_int][FixedU32::sub_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value
+    /// can be returned, but it is not considered a breaking change if in
+    /// the future it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn sub_int(self, rhs: Self::Bits) -> Self;
+
+    /// Linear interpolation between `start` and `end`.
     ///
     /// See also <code>FixedI32::[lerp][FixedI32::lerp]</code> and
     /// <code>FixedU32::[lerp][FixedU32::lerp]</code>.
@@ -1553,6 +2369,38 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn checked_mul(self, rhs: Self) -> Option<Self>;
 
+    /// Checked multiplication into the fixed-point type `Dst`. Returns the
+    /// product rounded towards −∞, or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_mul\_into][FixedI32::checked_mul_into]</code>
+    /// and
+    /// <code>FixedU32::[checked\_mul\_into][FixedU32::checked_mul_into]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_mul_into<Dst: Fixed, Rhs: Fixed>(self, rhs: Rhs) -> Option<Dst>;
+
+    /// Checked multiplication and division with a single rounding. Returns
+    /// `self` × `mul` ÷ `div` rounded towards zero, or [`None`] if `div` is
+    /// zero or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_mul\_div][FixedI32::checked_mul_div]</code>
+    /// and
+    /// <code>FixedU32::[checked\_mul\_div][FixedU32::checked_mul_div]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_mul_div(self, mul: Self, div: Self) -> Option<Self>;
+
+    /// Checked multiplication and division with a single rounding using the
+    /// specified rounding mode. Returns `self` × `mul` ÷ `div`, or [`None`] if
+    /// `div` is zero or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_mul\_div\_round][FixedI32::checked_mul_div_round]</code>
+    /// and
+    /// <code>FixedU32::[checked\_mul\_div\_round][FixedU32::checked_mul_div_round]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_mul_div_round(self, mul: Self, div: Self, mode: RoundingMode) -> Option<Self>;
+
     /// Checked division. Returns the quotient, or [`None`] if the
     /// divisor is zero or on overflow.
     ///
@@ -1577,45 +2425,221 @@ where
     /// <code>FixedU32::[checked\_recip][FixedU32::checked_recip]</code>.
     fn checked_recip(self) -> Option<Self>;
 
-    /// Checked multiply and add. Returns `self` × `mul` + `add`, or [`None`] on overflow.
+    /// Checked square root. Returns the square root, or [`None`] if
+    /// `self` is negative or on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[checked\_mul\_add][FixedI32::checked_mul_add]</code>
-    /// and
-    /// <code>FixedU32::[checked\_mul\_add][FixedU32::checked_mul_add]</code>.
+    /// <code>FixedI32::[checked\_sqrt][FixedI32::checked_sqrt]</code> and
+    /// <code>FixedU32::[checked\_sqrt][FixedU32::checked_sqrt]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn checked_mul_add(self, mul: Self, add: Self) -> Option<Self>;
+    fn checked_sqrt(self) -> Option<Self>;
 
-    /// Checked multiply and accumulate. Adds (`a` × `b`) to `self`, or returns
-    /// [`None`] on overflow.
+    /// Checked power. Returns `self` raised to the integer power `n`,
+    /// or [`None`] if `self` is zero and `n` is negative or on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[checked\_mul\_acc][FixedI32::checked_mul_acc]</code>
-    /// and
-    /// <code>FixedU32::[checked\_mul\_acc][FixedU32::checked_mul_acc]</code>.
-    #[must_use = "this `Option` may be a `None` variant indicating overflow, which should be handled"]
-    fn checked_mul_acc(&mut self, a: Self, b: Self) -> Option<()>;
+    /// <code>FixedI32::[checked\_powi][FixedI32::checked_powi]</code> and
+    /// <code>FixedU32::[checked\_powi][FixedU32::checked_powi]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_powi(self, n: i32) -> Option<Self>;
 
-    /// Checked remainder for Euclidean division. Returns the
-    /// remainder, or [`None`] if the divisor is zero or the division
-    /// results in overflow.
+    /// Checked hypotenuse. Returns √(`self`² + `other`²), or [`None`]
+    /// on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[checked\_div\_euclid][FixedI32::checked_div_euclid]</code>
-    /// and
-    /// <code>FixedU32::[checked\_div\_euclid][FixedU32::checked_div_euclid]</code>.
+    /// <code>FixedI32::[checked\_hypot][FixedI32::checked_hypot]</code> and
+    /// <code>FixedU32::[checked\_hypot][FixedU32::checked_hypot]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn checked_div_euclid(self, rhs: Self) -> Option<Self>;
+    fn checked_hypot(self, other: Self) -> Option<Self>;
 
-    /// Checked remainder for Euclidean division. Returns the
-    /// remainder, or [`None`] if the divisor is zero.
+    /// Checked exponential. Returns <i>e</i><sup>`self`</sup>, or [`None`] on
+    /// overflow.
     ///
     /// See also
-    /// <code>FixedI32::[checked\_rem\_euclid][FixedI32::checked_rem_euclid]</code>
-    /// and
-    /// <code>FixedU32::[checked\_rem\_euclid][FixedU32::checked_rem_euclid]</code>.
+    /// <code>FixedI32::[checked\_exp][FixedI32::checked_exp]</code> and
+    /// <code>FixedU32::[checked\_exp][FixedU32::checked_exp]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn checked_rem_euclid(self, rhs: Self) -> Option<Self>;
+    fn checked_exp(self) -> Option<Self>;
+
+    /// Checked base-2 exponential. Returns 2<sup>`self`</sup>, or [`None`] on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_exp2][FixedI32::checked_exp2]</code> and
+    /// <code>FixedU32::[checked\_exp2][FixedU32::checked_exp2]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_exp2(self) -> Option<Self>;
+
+    /// Checked natural logarithm. Returns ln `self`, or [`None`] if `self` ≤ 0
+    /// or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_ln][FixedI32::checked_ln]</code> and
+    /// <code>FixedU32::[checked\_ln][FixedU32::checked_ln]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_ln(self) -> Option<Self>;
+
+    /// Checked base-2 logarithm. Returns log<sub>2</sub> `self`, or [`None`] if
+    /// `self` ≤ 0 or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_log2][FixedI32::checked_log2]</code> and
+    /// <code>FixedU32::[checked\_log2][FixedU32::checked_log2]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_log2(self) -> Option<Self>;
+
+    /// Checked base-10 logarithm. Returns log<sub>10</sub> `self`, or [`None`]
+    /// if `self` ≤ 0 or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_log10][FixedI32::checked_log10]</code> and
+    /// <code>FixedU32::[checked\_log10][FixedU32::checked_log10]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_log10(self) -> Option<Self>;
+
+    /// Checked power. Returns `self` raised to the power `exp`, or
+    /// [`None`] if `self` is negative, if `self` is zero and `exp` is
+    /// negative, or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_powf][FixedI32::checked_powf]</code> and
+    /// <code>FixedU32::[checked\_powf][FixedU32::checked_powf]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_powf(self, exp: Self) -> Option<Self>;
+
+    /// Checked cube root. Returns the cube root, or [`None`] on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_cbrt][FixedI32::checked_cbrt]</code> and
+    /// <code>FixedU32::[checked\_cbrt][FixedU32::checked_cbrt]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_cbrt(self) -> Option<Self>;
+
+    /// Checked `n`th root. Returns the `n`th root of `self`, or
+    /// [`None`] if `n` is zero, if `self` is negative and `n` is even,
+    /// or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_nth\_root][FixedI32::checked_nth_root]</code>
+    /// and
+    /// <code>FixedU32::[checked\_nth\_root][FixedU32::checked_nth_root]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_nth_root(self, n: u32) -> Option<Self>;
+
+    /// Checked hyperbolic sine. Returns the hyperbolic sine, or
+    /// [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_sinh][FixedI32::checked_sinh]</code> and
+    /// <code>FixedU32::[checked\_sinh][FixedU32::checked_sinh]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_sinh(self) -> Option<Self>;
+
+    /// Checked hyperbolic cosine. Returns the hyperbolic cosine, or
+    /// [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_cosh][FixedI32::checked_cosh]</code> and
+    /// <code>FixedU32::[checked\_cosh][FixedU32::checked_cosh]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_cosh(self) -> Option<Self>;
+
+    /// Checked multiply and add. Returns `self` × `mul` + `add`, or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_mul\_add][FixedI32::checked_mul_add]</code>
+    /// and
+    /// <code>FixedU32::[checked\_mul\_add][FixedU32::checked_mul_add]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_mul_add(self, mul: Self, add: Self) -> Option<Self>;
+
+    /// Checked multiply and accumulate. Adds (`a` × `b`) to `self`, or returns
+    /// [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_mul\_acc][FixedI32::checked_mul_acc]</code>
+    /// and
+    /// <code>FixedU32::[checked\_mul\_acc][FixedU32::checked_mul_acc]</code>.
+    #[must_use = "this `Option` may be a `None` variant indicating overflow, which should be handled"]
+    fn checked_mul_acc(&mut self, a: Self, b: Self) -> Option<()>;
+
+    /// Checked multiplication with the specified rounding mode. Returns the
+    /// product, or [`None`] on overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_mul\_round][FixedI32::checked_mul_round]</code> and
+    /// <code>FixedU32::[checked\_mul\_round][FixedU32::checked_mul_round]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_mul_round(self, rhs: Self, mode: RoundingMode) -> Option<Self>;
+
+    /// Checked division with the specified rounding mode. Returns the quotient,
+    /// or [`None`] if the divisor is zero or on overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_div\_round][FixedI32::checked_div_round]</code> and
+    /// <code>FixedU32::[checked\_div\_round][FixedU32::checked_div_round]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_div_round(self, rhs: Self, mode: RoundingMode) -> Option<Self>;
+
+    /// Checked multiplication rounding down. Returns the product rounded towards
+    /// −∞, or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_mul\_floor][FixedI32::checked_mul_floor]</code>
+    /// and
+    /// <code>FixedU32::[checked\_mul\_floor][FixedU32::checked_mul_floor]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_mul_floor(self, rhs: Self) -> Option<Self>;
+
+    /// Checked multiplication rounding up. Returns the product rounded towards +∞,
+    /// or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_mul\_ceil][FixedI32::checked_mul_ceil]</code>
+    /// and
+    /// <code>FixedU32::[checked\_mul\_ceil][FixedU32::checked_mul_ceil]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_mul_ceil(self, rhs: Self) -> Option<Self>;
+
+    /// Checked division rounding down. Returns the quotient rounded towards −∞, or
+    /// [`None`] if the divisor is zero or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_div\_floor][FixedI32::checked_div_floor]</code>
+    /// and
+    /// <code>FixedU32::[checked\_div\_floor][FixedU32::checked_div_floor]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_div_floor(self, rhs: Self) -> Option<Self>;
+
+    /// Checked division rounding up. Returns the quotient rounded towards +∞, or
+    /// [`None`] if the divisor is zero or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_div\_ceil][FixedI32::checked_div_ceil]</code>
+    /// and
+    /// <code>FixedU32::[checked\_div\_ceil][FixedU32::checked_div_ceil]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_div_ceil(self, rhs: Self) -> Option<Self>;
+
+    /// Checked remainder for Euclidean division. Returns the
+    /// remainder, or [`None`] if the divisor is zero or the division
+    /// results in overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_div\_euclid][FixedI32::checked_div_euclid]</code>
+    /// and
+    /// <code>FixedU32::[checked\_div\_euclid][FixedU32::checked_div_euclid]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_div_euclid(self, rhs: Self) -> Option<Self>;
+
+    /// Checked remainder for Euclidean division. Returns the
+    /// remainder, or [`None`] if the divisor is zero.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_rem\_euclid][FixedI32::checked_rem_euclid]</code>
+    /// and
+    /// <code>FixedU32::[checked\_rem\_euclid][FixedU32::checked_rem_euclid]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_rem_euclid(self, rhs: Self) -> Option<Self>;
 
     /// Checked multiplication by an integer. Returns the product, or
     /// [`None`] on overflow.
@@ -1671,6 +2695,26 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn checked_rem_euclid_int(self, rhs: Self::Bits) -> Option<Self>;
 
+    /// Checked addition of an integer. Returns the sum, or
+    /// [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_add\_int][FixedI32::checked_add_int]</code>
+    /// and
+    /// <code>FixedU32::[checked\_add\_int][FixedU32::checked_add_int]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_add_int(self, rhs: Self::Bits) -> Option<Self>;
+
+    /// Checked subtraction of an integer. Returns the difference, or
+    /// [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_sub\_int][FixedI32::checked_sub_int]</code>
+    /// and
+    /// <code>FixedU32::[checked\_sub\_int][FixedU32::checked_sub_int]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_sub_int(self, rhs: Self::Bits) -> Option<Self>;
+
     /// Checked shift left. Returns the shifted number, or [`None`] if
     /// `rhs` ≥ the number of bits.
     ///
@@ -1734,895 +2778,1666 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn saturating_sub(self, rhs: Self) -> Self;
 
-    /// Saturating multiplication. Returns the product, saturating on overflow.
+    /// Saturating multiplication. Returns the product, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_mul][FixedI32::saturating_mul]</code> and
+    /// <code>FixedU32::[saturating\_mul][FixedU32::saturating_mul]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_mul(self, rhs: Self) -> Self;
+
+    /// Saturating multiplication into the fixed-point type `Dst`. Returns the
+    /// product rounded towards −∞, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_mul\_into][FixedI32::saturating_mul_into]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_mul\_into][FixedU32::saturating_mul_into]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_mul_into<Dst: Fixed, Rhs: Fixed>(self, rhs: Rhs) -> Dst;
+
+    /// Saturating multiplication and division with a single rounding. Returns
+    /// `self` × `mul` ÷ `div` rounded towards zero, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_mul\_div][FixedI32::saturating_mul_div]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_mul\_div][FixedU32::saturating_mul_div]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `div` is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_mul_div(self, mul: Self, div: Self) -> Self;
+
+    /// Saturating multiplication and division with a single rounding using
+    /// the specified rounding mode. Returns `self` × `mul` ÷ `div`, saturating
+    /// on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_mul\_div\_round][FixedI32::saturating_mul_div_round]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_mul\_div\_round][FixedU32::saturating_mul_div_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `div` is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_mul_div_round(self, mul: Self, div: Self, mode: RoundingMode) -> Self;
+
+    /// Saturating division. Returns the quotient, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_div][FixedI32::saturating_div]</code> and
+    /// <code>FixedU32::[saturating\_div][FixedU32::saturating_div]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_div(self, rhs: Self) -> Self;
+
+    /// Saturating reciprocal.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_recip][FixedI32::saturating_recip]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_recip][FixedU32::saturating_recip]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    fn saturating_recip(self) -> Self;
+
+    /// Saturating square root.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_sqrt][FixedI32::saturating_sqrt]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_sqrt][FixedU32::saturating_sqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_sqrt(self) -> Self;
+
+    /// Saturating power. Returns `self` raised to the integer power
+    /// `n`, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_powi][FixedI32::saturating_powi]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_powi][FixedU32::saturating_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `n` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_powi(self, n: i32) -> Self;
+
+    /// Saturating hypotenuse. Returns √(`self`² + `other`²),
+    /// saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_hypot][FixedI32::saturating_hypot]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_hypot][FixedU32::saturating_hypot]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_hypot(self, other: Self) -> Self;
+
+    /// Saturating exponential. Returns <i>e</i><sup>`self`</sup>, saturating on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_exp][FixedI32::saturating_exp]</code> and
+    /// <code>FixedU32::[saturating\_exp][FixedU32::saturating_exp]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_exp(self) -> Self;
+
+    /// Saturating base-2 exponential. Returns 2<sup>`self`</sup>, saturating on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_exp2][FixedI32::saturating_exp2]</code> and
+    /// <code>FixedU32::[saturating\_exp2][FixedU32::saturating_exp2]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_exp2(self) -> Self;
+
+    /// Saturating natural logarithm. Returns ln `self`, saturating on overflow.
+    /// The logarithm of zero saturates to [`MIN`][Fixed::MIN].
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_ln][FixedI32::saturating_ln]</code> and
+    /// <code>FixedU32::[saturating\_ln][FixedU32::saturating_ln]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_ln(self) -> Self;
+
+    /// Saturating base-2 logarithm. Returns log<sub>2</sub> `self`, saturating
+    /// on overflow. The logarithm of zero saturates to [`MIN`][Fixed::MIN].
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_log2][FixedI32::saturating_log2]</code> and
+    /// <code>FixedU32::[saturating\_log2][FixedU32::saturating_log2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_log2(self) -> Self;
+
+    /// Saturating base-10 logarithm. Returns log<sub>10</sub> `self`, saturating
+    /// on overflow. The logarithm of zero saturates to [`MIN`][Fixed::MIN].
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_log10][FixedI32::saturating_log10]</code> and
+    /// <code>FixedU32::[saturating\_log10][FixedU32::saturating_log10]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_log10(self) -> Self;
+
+    /// Saturating power. Returns `self` raised to the power `exp`,
+    /// saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_powf][FixedI32::saturating_powf]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_powf][FixedU32::saturating_powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_powf(self, exp: Self) -> Self;
+
+    /// Saturating hyperbolic sine. Returns the hyperbolic sine,
+    /// saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_sinh][FixedI32::saturating_sinh]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_sinh][FixedU32::saturating_sinh]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_sinh(self) -> Self;
+
+    /// Saturating hyperbolic cosine. Returns the hyperbolic cosine,
+    /// saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_cosh][FixedI32::saturating_cosh]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_cosh][FixedU32::saturating_cosh]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_cosh(self) -> Self;
+
+    /// Saturating multiply and add. Returns `self` × `mul` + `add`, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_mul\_add][FixedI32::saturating_mul_add]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_mul\_add][FixedU32::saturating_mul_add]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_mul_add(self, mul: Self, add: Self) -> Self;
+
+    /// Saturating multiply and add. Adds (`a` × `b`) to `self`, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_mul\_acc][FixedI32::saturating_mul_acc]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_mul\_acc][FixedU32::saturating_mul_acc]</code>.
+    fn saturating_mul_acc(&mut self, a: Self, b: Self);
+
+    /// Saturating multiplication with the specified rounding mode. Returns the
+    /// product, saturating on overflow.
+    ///
+    /// See also <code>FixedI32::[saturating\_mul\_round][FixedI32::saturating_mul_round]</code> and
+    /// <code>FixedU32::[saturating\_mul\_round][FixedU32::saturating_mul_round]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_mul_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Saturating division with the specified rounding mode. Returns the
+    /// quotient, saturating on overflow.
+    ///
+    /// See also <code>FixedI32::[saturating\_div\_round][FixedI32::saturating_div_round]</code> and
+    /// <code>FixedU32::[saturating\_div\_round][FixedU32::saturating_div_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_div_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Saturating multiplication rounding down. Returns the product rounded towards
+    /// −∞, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_mul\_floor][FixedI32::saturating_mul_floor]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_mul\_floor][FixedU32::saturating_mul_floor]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_mul_floor(self, rhs: Self) -> Self;
+
+    /// Saturating multiplication rounding up. Returns the product rounded towards
+    /// +∞, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_mul\_ceil][FixedI32::saturating_mul_ceil]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_mul\_ceil][FixedU32::saturating_mul_ceil]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_mul_ceil(self, rhs: Self) -> Self;
+
+    /// Saturating division rounding down. Returns the quotient rounded towards −∞,
+    /// saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_div\_floor][FixedI32::saturating_div_floor]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_div\_floor][FixedU32::saturating_div_floor]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_div_floor(self, rhs: Self) -> Self;
+
+    /// Saturating division rounding up. Returns the quotient rounded towards +∞,
+    /// saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_div\_ceil][FixedI32::saturating_div_ceil]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_div\_ceil][FixedU32::saturating_div_ceil]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_div_ceil(self, rhs: Self) -> Self;
+
+    /// Saturating Euclidean division. Returns the quotient, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_div\_euclid][FixedI32::saturating_div_euclid]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_div\_euclid][FixedU32::saturating_div_euclid]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_div_euclid(self, rhs: Self) -> Self;
+
+    /// Saturating multiplication by an integer. Returns the product, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_mul\_int][FixedI32::saturating_mul_int]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_mul\_int][FixedU32::saturating_mul_int]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_mul_int(self, rhs: Self::Bits) -> Self;
+
+    /// Saturating Euclidean division by an integer. Returns the
+    /// quotient, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_div\_euclid\_int][FixedI32::saturating_div_euclid_int]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_div\_euclid\_int][FixedU32::saturating_div_euclid_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_div_euclid_int(self, rhs: Self::Bits) -> Self;
+
+    /// Saturating remainder for Euclidean division by an integer.
+    /// Returns the remainder, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_rem\_euclid\_int][FixedI32::saturating_rem_euclid_int]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_rem\_euclid\_int][FixedU32::saturating_rem_euclid_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_rem_euclid_int(self, rhs: Self::Bits) -> Self;
+
+    /// Saturating addition of an integer. Returns the sum,
+    /// saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_add\_int][FixedI32::saturating_add_int]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_add\_int][FixedU32::saturating_add_int]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_add_int(self, rhs: Self::Bits) -> Self;
+
+    /// Saturating subtraction of an integer. Returns the difference,
+    /// saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_sub\_int][FixedI32::saturating_sub_int]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_sub\_int][FixedU32::saturating_sub_int]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_sub_int(self, rhs: Self::Bits) -> Self;
+
+    /// Saturating distance. Returns the distance from `self` to `other`,
+    /// saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_dist][FixedI32::saturating_dist]</code> and
+    /// <code>FixedU32::[saturating\_dist][FixedU32::saturating_dist]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_dist(self, other: Self) -> Self;
+
+    /// Linear interpolation between `start` and `end`, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_lerp][FixedI32::saturating_lerp]</code> and
+    /// <code>FixedU32::[saturating\_lerp][FixedU32::saturating_lerp]</code>.
+    fn saturating_lerp(self, start: Self, end: Self) -> Self;
+
+    /// Inverse linear interpolation between `start` and `end`, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_inv\_lerp][FixedI32::saturating_inv_lerp]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_inv\_lerp][FixedU32::saturating_inv_lerp]</code>.
+    fn saturating_inv_lerp(self, start: Self, end: Self) -> Self;
+
+    /// Wrapping negation. Returns the negated value, wrapping on overflow.
+    ///
+    /// See also <code>FixedI32::[wrapping\_neg][FixedI32::wrapping_neg]</code>
+    /// and <code>FixedU32::[wrapping\_neg][FixedU32::wrapping_neg]</code>.
+    fn wrapping_neg(self) -> Self;
+
+    /// Wrapping addition. Returns the sum, wrapping on overflow.
+    ///
+    /// See also <code>FixedI32::[wrapping\_add][FixedI32::wrapping_add]</code>
+    /// and <code>FixedU32::[wrapping\_add][FixedU32::wrapping_add]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    /// Wrapping subtraction. Returns the difference, wrapping on overflow.
+    ///
+    /// See also <code>FixedI32::[wrapping\_sub][FixedI32::wrapping_sub]</code>
+    /// and <code>FixedU32::[wrapping\_sub][FixedU32::wrapping_sub]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    /// Wrapping multiplication. Returns the product, wrapping on overflow.
+    ///
+    /// See also <code>FixedI32::[wrapping\_mul][FixedI32::wrapping_mul]</code>
+    /// and <code>FixedU32::[wrapping\_mul][FixedU32::wrapping_mul]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    /// Wrapping division. Returns the quotient, wrapping on overflow.
+    ///
+    /// See also <code>FixedI32::[wrapping\_div][FixedI32::wrapping_div]</code>
+    /// and <code>FixedU32::[wrapping\_div][FixedU32::wrapping_div]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_div(self, rhs: Self) -> Self;
+
+    /// Wrapping reciprocal.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_recip][FixedI32::wrapping_recip]</code> and
+    /// <code>FixedU32::[wrapping\_recip][FixedU32::wrapping_recip]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    fn wrapping_recip(self) -> Self;
+
+    /// Wrapping square root.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sqrt][FixedI32::wrapping_sqrt]</code> and
+    /// <code>FixedU32::[wrapping\_sqrt][FixedU32::wrapping_sqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_sqrt(self) -> Self;
+
+    /// Wrapping power. Returns `self` raised to the integer power `n`,
+    /// wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_powi][FixedI32::wrapping_powi]</code> and
+    /// <code>FixedU32::[wrapping\_powi][FixedU32::wrapping_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `n` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_powi(self, n: i32) -> Self;
+
+    /// Wrapping multiply and add. Returns `self` × `mul` + `add`, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_mul\_add][FixedI32::wrapping_mul_add]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_mul\_add][FixedU32::wrapping_mul_add]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_mul_add(self, mul: Self, add: Self) -> Self;
+
+    /// Wrapping multiply and accumulate. Adds (`a` × `b`) to `self`, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_mul\_acc][FixedI32::wrapping_mul_acc]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_mul\_acc][FixedU32::wrapping_mul_acc]</code>.
+    fn wrapping_mul_acc(&mut self, a: Self, b: Self);
+
+    /// Wrapping Euclidean division. Returns the quotient, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_div\_euclid][FixedI32::wrapping_div_euclid]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_div\_euclid][FixedU32::wrapping_div_euclid]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_div_euclid(self, rhs: Self) -> Self;
+
+    /// Wrapping multiplication by an integer. Returns the product, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_mul\_int][FixedI32::wrapping_mul_int]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_mul\_int][FixedU32::wrapping_mul_int]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_mul_int(self, rhs: Self::Bits) -> Self;
+
+    /// Wrapping division by an integer. Returns the quotient, wrapping on overflow.
+    ///
+    /// Overflow can only occur when dividing the minimum value by −1.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_div\_int][FixedI32::wrapping_div_int]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_div\_int][FixedU32::wrapping_div_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_div_int(self, rhs: Self::Bits) -> Self;
+
+    /// Wrapping Euclidean division by an integer. Returns the
+    /// quotient, wrapping on overflow.
+    ///
+    /// Overflow can only occur when dividing the minimum value by −1.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_div\_euclid\_int][FixedI32::wrapping_div_euclid_int]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_div\_euclid\_int][FixedU32::wrapping_div_euclid_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_div_euclid_int(self, rhs: Self::Bits) -> Self;
+
+    /// Wrapping remainder for Euclidean division by an integer.
+    /// Returns the remainder, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_rem\_euclid\_int][FixedI32::wrapping_rem_euclid_int]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_rem\_euclid\_int][FixedU32::wrapping_rem_euclid_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_rem_euclid_int(self, rhs: Self::Bits) -> Self;
+
+    /// Wrapping addition of an integer. Returns the sum,
+    /// wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_add\_int][FixedI32::wrapping_add_int]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_add\_int][FixedU32::wrapping_add_int]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_add_int(self, rhs: Self::Bits) -> Self;
+
+    /// Wrapping subtraction of an integer. Returns the difference,
+    /// wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sub\_int][FixedI32::wrapping_sub_int]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_sub\_int][FixedU32::wrapping_sub_int]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_sub_int(self, rhs: Self::Bits) -> Self;
+
+    /// Wrapping shift left. Wraps `rhs` if `rhs` ≥ the number of
+    /// bits, then shifts and returns the number.
+    ///
+    /// See also <code>FixedI32::[wrapping\_shl][FixedI32::wrapping_shl]</code>
+    /// and <code>FixedU32::[wrapping\_shl][FixedU32::wrapping_shl]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_shl(self, rhs: u32) -> Self;
+
+    /// Wrapping shift right. Wraps `rhs` if `rhs` ≥ the number of
+    /// bits, then shifts and returns the number.
+    ///
+    /// See also <code>FixedI32::[wrapping\_shr][FixedI32::wrapping_shr]</code>
+    /// and <code>FixedU32::[wrapping\_shr][FixedU32::wrapping_shr]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_shr(self, rhs: u32) -> Self;
+
+    /// Wrapping distance. Returns the distance from `self` to `other`, wrapping
+    /// on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_dist][FixedI32::wrapping_dist]</code> and
+    /// <code>FixedU32::[wrapping\_dist][FixedU32::wrapping_dist]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_dist(self, other: Self) -> Self;
+
+    /// Linear interpolation between `start` and `end`, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_lerp][FixedI32::wrapping_lerp]</code> and
+    /// <code>FixedU32::[wrapping\_lerp][FixedU32::wrapping_lerp]</code>.
+    fn wrapping_lerp(self, start: Self, end: Self) -> Self;
+
+    /// Inverse linear interpolation between `start` and `end`, wrapping on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_inv\_lerp][FixedI32::wrapping_inv_lerp]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_inv\_lerp][FixedU32::wrapping_inv_lerp]</code>.
+    fn wrapping_inv_lerp(self, start: Self, end: Self) -> Self;
+
+    /// Unwrapped negation. Returns the negated value, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_neg][FixedI32::unwrapped_neg]</code> and
+    /// <code>FixedU32::[unwrapped\_neg][FixedU32::unwrapped_neg]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    fn unwrapped_neg(self) -> Self;
+
+    /// Unwrapped addition. Returns the sum, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_add][FixedI32::unwrapped_add]</code> and
+    /// <code>FixedU32::[unwrapped\_add][FixedU32::unwrapped_add]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_add(self, rhs: Self) -> Self;
+
+    /// Unwrapped subtraction. Returns the difference, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sub][FixedI32::unwrapped_sub]</code> and
+    /// <code>FixedU32::[unwrapped\_sub][FixedU32::unwrapped_sub]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_sub(self, rhs: Self) -> Self;
+
+    /// Unwrapped multiplication. Returns the product, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_mul][FixedI32::unwrapped_mul]</code> and
+    /// <code>FixedU32::[unwrapped\_mul][FixedU32::unwrapped_mul]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_mul(self, rhs: Self) -> Self;
+
+    /// Unwrapped division. Returns the quotient, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_div][FixedI32::unwrapped_div]</code> and
+    /// <code>FixedU32::[unwrapped\_div][FixedU32::unwrapped_div]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the result does not fit.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_div(self, rhs: Self) -> Self;
+
+    /// Unwrapped remainder. Returns the quotient, panicking if the divisor is zero.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_rem][FixedI32::unwrapped_rem]</code> and
+    /// <code>FixedU32::[unwrapped\_rem][FixedU32::unwrapped_rem]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_rem(self, rhs: Self) -> Self;
+
+    /// Unwrapped reciprocal. Returns reciprocal, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_recip][FixedI32::unwrapped_recip]</code> and
+    /// <code>FixedU32::[unwrapped\_recip][FixedU32::unwrapped_recip]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or on overflow.
+    #[track_caller]
+    fn unwrapped_recip(self) -> Self;
+
+    /// Unwrapped square root. Returns the square root, panicking on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sqrt][FixedI32::unwrapped_sqrt]</code> and
+    /// <code>FixedU32::[unwrapped\_sqrt][FixedU32::unwrapped_sqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative or on overflow.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_sqrt(self) -> Self;
+
+    /// Unwrapped power. Returns `self` raised to the integer power `n`,
+    /// panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_powi][FixedI32::unwrapped_powi]</code> and
+    /// <code>FixedU32::[unwrapped\_powi][FixedU32::unwrapped_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `n` is negative, or on overflow.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_powi(self, n: i32) -> Self;
+
+    /// Unwrapped multiply and add. Returns `self` × `mul` + `add`, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_mul\_add][FixedI32::unwrapped_mul_add]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_mul\_add][FixedU32::unwrapped_mul_add]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_mul_add(self, mul: Self, add: Self) -> Self;
+
+    /// Unwrapped multiply and accumulate. Adds (`a` × `b`) to `self`, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_mul\_acc][FixedI32::unwrapped_mul_acc]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_mul\_acc][FixedU32::unwrapped_mul_acc]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    fn unwrapped_mul_acc(&mut self, a: Self, b: Self);
+
+    /// Unwrapped Euclidean division. Returns the quotient, panicking on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[saturating\_mul][FixedI32::saturating_mul]</code> and
-    /// <code>FixedU32::[saturating\_mul][FixedU32::saturating_mul]</code>.
+    /// <code>FixedI32::[unwrapped\_div\_euclid][FixedI32::unwrapped_div_euclid]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_div\_euclid][FixedU32::unwrapped_div_euclid]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the result does not fit.
+    #[track_caller]
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn saturating_mul(self, rhs: Self) -> Self;
+    fn unwrapped_div_euclid(self, rhs: Self) -> Self;
 
-    /// Saturating division. Returns the quotient, saturating on overflow.
+    /// Unwrapped remainder for Euclidean division. Returns the
+    /// remainder, panicking if the divisor is zero.
     ///
     /// See also
-    /// <code>FixedI32::[saturating\_div][FixedI32::saturating_div]</code> and
-    /// <code>FixedU32::[saturating\_div][FixedU32::saturating_div]</code>.
+    /// <code>FixedI32::[unwrapped\_rem\_euclid][FixedI32::unwrapped_rem_euclid]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_rem\_euclid][FixedU32::unwrapped_rem_euclid]</code>.
     ///
     /// # Panics
     ///
     /// Panics if the divisor is zero.
+    #[track_caller]
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn saturating_div(self, rhs: Self) -> Self;
+    fn unwrapped_rem_euclid(self, rhs: Self) -> Self;
 
-    /// Saturating reciprocal.
+    /// Unwrapped multiplication by an integer. Returns the product, panicking on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[saturating\_recip][FixedI32::saturating_recip]</code>
+    /// <code>FixedI32::[unwrapped\_mul\_int][FixedI32::unwrapped_mul_int]</code>
     /// and
-    /// <code>FixedU32::[saturating\_recip][FixedU32::saturating_recip]</code>.
+    /// <code>FixedU32::[unwrapped\_mul\_int][FixedU32::unwrapped_mul_int]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if `self` is zero.
-    fn saturating_recip(self) -> Self;
-
-    /// Saturating multiply and add. Returns `self` × `mul` + `add`, saturating on overflow.
-    ///
-    /// See also
-    /// <code>FixedI32::[saturating\_mul\_add][FixedI32::saturating_mul_add]</code>
-    /// and
-    /// <code>FixedU32::[saturating\_mul\_add][FixedU32::saturating_mul_add]</code>.
+    /// Panics if the result does not fit.
+    #[track_caller]
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn saturating_mul_add(self, mul: Self, add: Self) -> Self;
+    fn unwrapped_mul_int(self, rhs: Self::Bits) -> Self;
 
-    /// Saturating multiply and add. Adds (`a` × `b`) to `self`, saturating on overflow.
+    /// Unwrapped division by an integer. Returns the quotient, panicking on overflow.
+    ///
+    /// Overflow can only occur when dividing the minimum value by −1.
     ///
     /// See also
-    /// <code>FixedI32::[saturating\_mul\_acc][FixedI32::saturating_mul_acc]</code>
+    /// <code>FixedI32::[unwrapped\_div\_int][FixedI32::unwrapped_div_int]</code>
     /// and
-    /// <code>FixedU32::[saturating\_mul\_acc][FixedU32::saturating_mul_acc]</code>.
-    fn saturating_mul_acc(&mut self, a: Self, b: Self);
+    /// <code>FixedU32::[unwrapped\_div\_int][FixedU32::unwrapped_div_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the result does not fit.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_div_int(self, rhs: Self::Bits) -> Self;
 
-    /// Saturating Euclidean division. Returns the quotient, saturating on overflow.
+    /// Unwrapped remainder for division by an integer. Returns the
+    /// remainder, panicking if the divisor is zero.
     ///
     /// See also
-    /// <code>FixedI32::[saturating\_div\_euclid][FixedI32::saturating_div_euclid]</code>
+    /// <code>FixedI32::[unwrapped\_rem\_int][FixedI32::unwrapped_rem_int]</code>
     /// and
-    /// <code>FixedU32::[saturating\_div\_euclid][FixedU32::saturating_div_euclid]</code>.
+    /// <code>FixedU32::[unwrapped\_rem\_int][FixedU32::unwrapped_rem_int]</code>.
     ///
     /// # Panics
     ///
     /// Panics if the divisor is zero.
+    #[track_caller]
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn saturating_div_euclid(self, rhs: Self) -> Self;
+    fn unwrapped_rem_int(self, rhs: Self::Bits) -> Self;
 
-    /// Saturating multiplication by an integer. Returns the product, saturating on overflow.
+    /// Unwrapped Euclidean division by an integer. Returns the
+    /// quotient, panicking on overflow.
+    ///
+    /// Overflow can only occur when dividing the minimum value by −1.
     ///
     /// See also
-    /// <code>FixedI32::[saturating\_mul\_int][FixedI32::saturating_mul_int]</code>
+    /// <code>FixedI32::[unwrapped\_div\_euclid\_int][FixedI32::unwrapped_div_euclid_int]</code>
     /// and
-    /// <code>FixedU32::[saturating\_mul\_int][FixedU32::saturating_mul_int]</code>.
+    /// <code>FixedU32::[unwrapped\_div\_euclid\_int][FixedU32::unwrapped_div_euclid_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the result does not fit.
+    #[track_caller]
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn saturating_mul_int(self, rhs: Self::Bits) -> Self;
+    fn unwrapped_div_euclid_int(self, rhs: Self::Bits) -> Self;
 
-    /// Saturating Euclidean division by an integer. Returns the
-    /// quotient, saturating on overflow.
+    /// Unwrapped remainder for Euclidean division by an integer.
+    /// Returns the remainder, panicking on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[saturating\_div\_euclid\_int][FixedI32::saturating_div_euclid_int]</code>
+    /// <code>FixedI32::[unwrapped\_rem\_euclid\_int][FixedI32::unwrapped_rem_euclid_int]</code>
     /// and
-    /// <code>FixedU32::[saturating\_div\_euclid\_int][FixedU32::saturating_div_euclid_int]</code>.
+    /// <code>FixedU32::[unwrapped\_rem\_euclid\_int][FixedU32::unwrapped_rem_euclid_int]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero.
+    /// Panics if the divisor is zero or if the result does not fit.
+    #[track_caller]
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn saturating_div_euclid_int(self, rhs: Self::Bits) -> Self;
+    fn unwrapped_rem_euclid_int(self, rhs: Self::Bits) -> Self;
 
-    /// Saturating remainder for Euclidean division by an integer.
-    /// Returns the remainder, saturating on overflow.
+    /// Unwrapped addition of an integer. Returns the sum,
+    /// panicking on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[saturating\_rem\_euclid\_int][FixedI32::saturating_rem_euclid_int]</code>
+    /// <code>FixedI32::[unwrapped\_add\_int][FixedI32::unwrapped_add_int]</code>
     /// and
-    /// <code>FixedU32::[saturating\_rem\_euclid\_int][FixedU32::saturating_rem_euclid_int]</code>.
+    /// <code>FixedU32::[unwrapped\_add\_int][FixedU32::unwrapped_add_int]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero.
+    /// Panics if the result does not fit.
+    #[track_caller]
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn saturating_rem_euclid_int(self, rhs: Self::Bits) -> Self;
+    fn unwrapped_add_int(self, rhs: Self::Bits) -> Self;
 
-    /// Saturating distance. Returns the distance from `self` to `other`,
-    /// saturating on overflow.
+    /// Unwrapped subtraction of an integer. Returns the difference,
+    /// panicking on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[saturating\_dist][FixedI32::saturating_dist]</code> and
-    /// <code>FixedU32::[saturating\_dist][FixedU32::saturating_dist]</code>.
+    /// <code>FixedI32::[unwrapped\_sub\_int][FixedI32::unwrapped_sub_int]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_sub\_int][FixedU32::unwrapped_sub_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn saturating_dist(self, other: Self) -> Self;
+    fn unwrapped_sub_int(self, rhs: Self::Bits) -> Self;
 
-    /// Linear interpolation between `start` and `end`, saturating on overflow.
+    /// Unwrapped shift left. Panics if `rhs` ≥ the number of bits.
     ///
     /// See also
-    /// <code>FixedI32::[saturating\_lerp][FixedI32::saturating_lerp]</code> and
-    /// <code>FixedU32::[saturating\_lerp][FixedU32::saturating_lerp]</code>.
-    fn saturating_lerp(self, start: Self, end: Self) -> Self;
+    /// <code>FixedI32::[unwrapped\_shl][FixedI32::unwrapped_shl]</code> and
+    /// <code>FixedU32::[unwrapped\_shl][FixedU32::unwrapped_shl]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` ≥ the number of bits.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_shl(self, rhs: u32) -> Self;
 
-    /// Inverse linear interpolation between `start` and `end`, saturating on overflow.
+    /// Unwrapped shift right. Panics if `rhs` ≥ the number of bits.
     ///
     /// See also
-    /// <code>FixedI32::[saturating\_inv\_lerp][FixedI32::saturating_inv_lerp]</code>
-    /// and
-    /// <code>FixedU32::[saturating\_inv\_lerp][FixedU32::saturating_inv_lerp]</code>.
-    fn saturating_inv_lerp(self, start: Self, end: Self) -> Self;
-
-    /// Wrapping negation. Returns the negated value, wrapping on overflow.
+    /// <code>FixedI32::[unwrapped\_shr][FixedI32::unwrapped_shr]</code> and
+    /// <code>FixedU32::[unwrapped\_shr][FixedU32::unwrapped_shr]</code>.
     ///
-    /// See also <code>FixedI32::[wrapping\_neg][FixedI32::wrapping_neg]</code>
-    /// and <code>FixedU32::[wrapping\_neg][FixedU32::wrapping_neg]</code>.
-    fn wrapping_neg(self) -> Self;
-
-    /// Wrapping addition. Returns the sum, wrapping on overflow.
+    /// # Panics
     ///
-    /// See also <code>FixedI32::[wrapping\_add][FixedI32::wrapping_add]</code>
-    /// and <code>FixedU32::[wrapping\_add][FixedU32::wrapping_add]</code>.
+    /// Panics if `rhs` ≥ the number of bits.
+    #[track_caller]
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn wrapping_add(self, rhs: Self) -> Self;
+    fn unwrapped_shr(self, rhs: u32) -> Self;
 
-    /// Wrapping subtraction. Returns the difference, wrapping on overflow.
+    /// Unwrapped distance. Returns the distance from `self` to `other`,
+    /// panicking on overflow.
     ///
-    /// See also <code>FixedI32::[wrapping\_sub][FixedI32::wrapping_sub]</code>
-    /// and <code>FixedU32::[wrapping\_sub][FixedU32::wrapping_sub]</code>.
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn wrapping_sub(self, rhs: Self) -> Self;
-
-    /// Wrapping multiplication. Returns the product, wrapping on overflow.
+    /// # Panics
     ///
-    /// See also <code>FixedI32::[wrapping\_mul][FixedI32::wrapping_mul]</code>
-    /// and <code>FixedU32::[wrapping\_mul][FixedU32::wrapping_mul]</code>.
+    /// Panics if the result does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_dist][FixedI32::unwrapped_dist]</code> and
+    /// <code>FixedU32::[unwrapped\_dist][FixedU32::unwrapped_dist]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn wrapping_mul(self, rhs: Self) -> Self;
+    fn unwrapped_dist(self, other: Self) -> Self;
 
-    /// Wrapping division. Returns the quotient, wrapping on overflow.
+    /// Linear interpolation between `start` and `end`, panicking on overflow.
     ///
-    /// See also <code>FixedI32::[wrapping\_div][FixedI32::wrapping_div]</code>
-    /// and <code>FixedU32::[wrapping\_div][FixedU32::wrapping_div]</code>.
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_lerp][FixedI32::unwrapped_lerp]</code> and
+    /// <code>FixedU32::[unwrapped\_lerp][FixedU32::unwrapped_lerp]</code>.
+    fn unwrapped_lerp(self, start: Self, end: Self) -> Self;
+
+    /// Inverse linear interpolation between `start` and `end`, panicking on overflow.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero.
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn wrapping_div(self, rhs: Self) -> Self;
+    /// Panics when `start` = `end` or when the results overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_inv\_lerp][FixedI32::unwrapped_inv_lerp]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_inv\_lerp][FixedU32::unwrapped_inv_lerp]</code>.
+    fn unwrapped_inv_lerp(self, start: Self, end: Self) -> Self;
 
-    /// Wrapping reciprocal.
+    /// Overflowing negation.
+    ///
+    /// Returns a [tuple] of the negated value and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[wrapping\_recip][FixedI32::wrapping_recip]</code> and
-    /// <code>FixedU32::[wrapping\_recip][FixedU32::wrapping_recip]</code>.
+    /// <code>FixedI32::[overflowing\_neg][FixedI32::overflowing_neg]</code> and
+    /// <code>FixedU32::[overflowing\_neg][FixedU32::overflowing_neg]</code>.
+    fn overflowing_neg(self) -> (Self, bool);
+
+    /// Overflowing addition.
     ///
-    /// # Panics
+    /// Returns a [tuple] of the sum and a [`bool`], indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
     ///
-    /// Panics if `self` is zero.
-    fn wrapping_recip(self) -> Self;
+    /// See also
+    /// <code>FixedI32::[overflowing\_add][FixedI32::overflowing_add]</code> and
+    /// <code>FixedU32::[overflowing\_add][FixedU32::overflowing_add]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
 
-    /// Wrapping multiply and add. Returns `self` × `mul` + `add`, wrapping on overflow.
+    /// Overflowing subtraction.
+    ///
+    /// Returns a [tuple] of the difference and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[wrapping\_mul\_add][FixedI32::wrapping_mul_add]</code>
-    /// and
-    /// <code>FixedU32::[wrapping\_mul\_add][FixedU32::wrapping_mul_add]</code>.
+    /// <code>FixedI32::[overflowing\_sub][FixedI32::overflowing_sub]</code> and
+    /// <code>FixedU32::[overflowing\_sub][FixedU32::overflowing_sub]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn wrapping_mul_add(self, mul: Self, add: Self) -> Self;
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool);
 
-    /// Wrapping multiply and accumulate. Adds (`a` × `b`) to `self`, wrapping on overflow.
+    /// Overflowing multiplication.
+    ///
+    /// Returns a [tuple] of the product and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[wrapping\_mul\_acc][FixedI32::wrapping_mul_acc]</code>
-    /// and
-    /// <code>FixedU32::[wrapping\_mul\_acc][FixedU32::wrapping_mul_acc]</code>.
-    fn wrapping_mul_acc(&mut self, a: Self, b: Self);
+    /// <code>FixedI32::[overflowing\_mul][FixedI32::overflowing_mul]</code> and
+    /// <code>FixedU32::[overflowing\_mul][FixedU32::overflowing_mul]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_mul(self, rhs: Self) -> (Self, bool);
 
-    /// Wrapping Euclidean division. Returns the quotient, wrapping on overflow.
+    /// Overflowing division.
+    ///
+    /// Returns a [tuple] of the quotient and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[wrapping\_div\_euclid][FixedI32::wrapping_div_euclid]</code>
-    /// and
-    /// <code>FixedU32::[wrapping\_div\_euclid][FixedU32::wrapping_div_euclid]</code>.
+    /// <code>FixedI32::[overflowing\_div][FixedI32::overflowing_div]</code> and
+    /// <code>FixedU32::[overflowing\_div][FixedU32::overflowing_div]</code>.
     ///
     /// # Panics
     ///
     /// Panics if the divisor is zero.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn wrapping_div_euclid(self, rhs: Self) -> Self;
-
-    /// Wrapping multiplication by an integer. Returns the product, wrapping on overflow.
-    ///
-    /// See also
-    /// <code>FixedI32::[wrapping\_mul\_int][FixedI32::wrapping_mul_int]</code>
-    /// and
-    /// <code>FixedU32::[wrapping\_mul\_int][FixedU32::wrapping_mul_int]</code>.
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn wrapping_mul_int(self, rhs: Self::Bits) -> Self;
+    fn overflowing_div(self, rhs: Self) -> (Self, bool);
 
-    /// Wrapping division by an integer. Returns the quotient, wrapping on overflow.
+    /// Overflowing reciprocal.
     ///
-    /// Overflow can only occur when dividing the minimum value by −1.
+    /// Returns a [tuple] of the reciprocal of `self` and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[wrapping\_div\_int][FixedI32::wrapping_div_int]</code>
+    /// <code>FixedI32::[overflowing\_recip][FixedI32::overflowing_recip]</code>
     /// and
-    /// <code>FixedU32::[wrapping\_div\_int][FixedU32::wrapping_div_int]</code>.
+    /// <code>FixedU32::[overflowing\_recip][FixedU32::overflowing_recip]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero.
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn wrapping_div_int(self, rhs: Self::Bits) -> Self;
+    /// Panics if `self` is zero.
+    fn overflowing_recip(self) -> (Self, bool);
 
-    /// Wrapping Euclidean division by an integer. Returns the
-    /// quotient, wrapping on overflow.
+    /// Overflowing square root.
     ///
-    /// Overflow can only occur when dividing the minimum value by −1.
+    /// Returns a [tuple] of the square root of `self` and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[wrapping\_div\_euclid\_int][FixedI32::wrapping_div_euclid_int]</code>
+    /// <code>FixedI32::[overflowing\_sqrt][FixedI32::overflowing_sqrt]</code>
     /// and
-    /// <code>FixedU32::[wrapping\_div\_euclid\_int][FixedU32::wrapping_div_euclid_int]</code>.
+    /// <code>FixedU32::[overflowing\_sqrt][FixedU32::overflowing_sqrt]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero.
+    /// Panics if `self` is negative.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn wrapping_div_euclid_int(self, rhs: Self::Bits) -> Self;
+    fn overflowing_sqrt(self) -> (Self, bool);
 
-    /// Wrapping remainder for Euclidean division by an integer.
-    /// Returns the remainder, wrapping on overflow.
+    /// Overflowing power.
+    ///
+    /// Returns a [tuple] of `self` raised to the integer power `n` and
+    /// a [`bool`], indicating whether an overflow has occurred. On
+    /// overflow, the wrapped value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[wrapping\_rem\_euclid\_int][FixedI32::wrapping_rem_euclid_int]</code>
+    /// <code>FixedI32::[overflowing\_powi][FixedI32::overflowing_powi]</code>
     /// and
-    /// <code>FixedU32::[wrapping\_rem\_euclid\_int][FixedU32::wrapping_rem_euclid_int]</code>.
+    /// <code>FixedU32::[overflowing\_powi][FixedU32::overflowing_powi]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero.
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn wrapping_rem_euclid_int(self, rhs: Self::Bits) -> Self;
-
-    /// Wrapping shift left. Wraps `rhs` if `rhs` ≥ the number of
-    /// bits, then shifts and returns the number.
-    ///
-    /// See also <code>FixedI32::[wrapping\_shl][FixedI32::wrapping_shl]</code>
-    /// and <code>FixedU32::[wrapping\_shl][FixedU32::wrapping_shl]</code>.
+    /// Panics if `self` is zero and `n` is negative.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn wrapping_shl(self, rhs: u32) -> Self;
+    fn overflowing_powi(self, n: i32) -> (Self, bool);
 
-    /// Wrapping shift right. Wraps `rhs` if `rhs` ≥ the number of
-    /// bits, then shifts and returns the number.
+    /// Overflowing multiply  and add.
     ///
-    /// See also <code>FixedI32::[wrapping\_shr][FixedI32::wrapping_shr]</code>
-    /// and <code>FixedU32::[wrapping\_shr][FixedU32::wrapping_shr]</code>.
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn wrapping_shr(self, rhs: u32) -> Self;
-
-    /// Wrapping distance. Returns the distance from `self` to `other`, wrapping
-    /// on overflow.
+    /// Returns a [tuple] of `self` × `mul` + `add` and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[wrapping\_dist][FixedI32::wrapping_dist]</code> and
-    /// <code>FixedU32::[wrapping\_dist][FixedU32::wrapping_dist]</code>.
+    /// <code>FixedI32::[overflowing\_mul\_add][FixedI32::overflowing_mul_add]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_mul\_add][FixedU32::overflowing_mul_add]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn wrapping_dist(self, other: Self) -> Self;
-
-    /// Linear interpolation between `start` and `end`, wrapping on overflow.
-    ///
-    /// See also
-    /// <code>FixedI32::[wrapping\_lerp][FixedI32::wrapping_lerp]</code> and
-    /// <code>FixedU32::[wrapping\_lerp][FixedU32::wrapping_lerp]</code>.
-    fn wrapping_lerp(self, start: Self, end: Self) -> Self;
+    fn overflowing_mul_add(self, mul: Self, add: Self) -> (Self, bool);
 
-    /// Inverse linear interpolation between `start` and `end`, wrapping on
-    /// overflow.
+    /// Overflowing multiply and accumulate. Adds (`a` × `b`) to `self`,
+    /// wrapping and returning [`true`] if overflow occurs.
     ///
     /// See also
-    /// <code>FixedI32::[wrapping\_inv\_lerp][FixedI32::wrapping_inv_lerp]</code>
+    /// <code>FixedI32::[overflowing\_mul\_acc][FixedI32::overflowing_mul_acc]</code>
     /// and
-    /// <code>FixedU32::[wrapping\_inv\_lerp][FixedU32::wrapping_inv_lerp]</code>.
-    fn wrapping_inv_lerp(self, start: Self, end: Self) -> Self;
+    /// <code>FixedU32::[overflowing\_mul\_acc][FixedU32::overflowing_mul_acc]</code>.
+    #[must_use = "this returns whether overflow occurs; use `wrapping_mul_acc` if the flag is not needed"]
+    fn overflowing_mul_acc(&mut self, a: Self, b: Self) -> bool;
 
-    /// Unwrapped negation. Returns the negated value, panicking on overflow.
-    ///
-    /// See also
-    /// <code>FixedI32::[unwrapped\_neg][FixedI32::unwrapped_neg]</code> and
-    /// <code>FixedU32::[unwrapped\_neg][FixedU32::unwrapped_neg]</code>.
-    ///
-    /// # Panics
+    /// Overflowing Euclidean division.
     ///
-    /// Panics if the result does not fit.
-    #[track_caller]
-    fn unwrapped_neg(self) -> Self;
-
-    /// Unwrapped addition. Returns the sum, panicking on overflow.
+    /// Returns a [tuple] of the quotient and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_add][FixedI32::unwrapped_add]</code> and
-    /// <code>FixedU32::[unwrapped\_add][FixedU32::unwrapped_add]</code>.
+    /// <code>FixedI32::[overflowing\_div\_euclid][FixedI32::overflowing_div_euclid]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_div\_euclid][FixedU32::overflowing_div_euclid]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the result does not fit.
-    #[track_caller]
+    /// Panics if the divisor is zero.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_add(self, rhs: Self) -> Self;
+    fn overflowing_div_euclid(self, rhs: Self) -> (Self, bool);
 
-    /// Unwrapped subtraction. Returns the difference, panicking on overflow.
-    ///
-    /// See also
-    /// <code>FixedI32::[unwrapped\_sub][FixedI32::unwrapped_sub]</code> and
-    /// <code>FixedU32::[unwrapped\_sub][FixedU32::unwrapped_sub]</code>.
+    /// Overflowing multiplication by an integer.
     ///
-    /// # Panics
+    /// Returns a [tuple] of the product and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
     ///
-    /// Panics if the result does not fit.
-    #[track_caller]
+    /// See also
+    /// <code>FixedI32::[overflowing\_mul\_int][FixedI32::overflowing_mul_int]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_mul\_int][FixedU32::overflowing_mul_int]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_sub(self, rhs: Self) -> Self;
+    fn overflowing_mul_int(self, rhs: Self::Bits) -> (Self, bool);
 
-    /// Unwrapped multiplication. Returns the product, panicking on overflow.
+    /// Overflowing division by an integer.
+    ///
+    /// Returns a [tuple] of the quotient and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_mul][FixedI32::unwrapped_mul]</code> and
-    /// <code>FixedU32::[unwrapped\_mul][FixedU32::unwrapped_mul]</code>.
+    /// <code>FixedI32::[overflowing\_div\_int][FixedI32::overflowing_div_int]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_div\_int][FixedU32::overflowing_div_int]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the result does not fit.
-    #[track_caller]
+    /// Panics if the divisor is zero.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_mul(self, rhs: Self) -> Self;
+    fn overflowing_div_int(self, rhs: Self::Bits) -> (Self, bool);
 
-    /// Unwrapped division. Returns the quotient, panicking on overflow.
+    /// Overflowing Euclidean division by an integer.
+    ///
+    /// Returns a [tuple] of the quotient and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_div][FixedI32::unwrapped_div]</code> and
-    /// <code>FixedU32::[unwrapped\_div][FixedU32::unwrapped_div]</code>.
+    /// <code>FixedI32::[overflowing\_div\_euclid\_int][FixedI32::overflowing_div_euclid_int]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_div\_euclid\_int][FixedU32::overflowing_div_euclid_int]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero or if the result does not fit.
-    #[track_caller]
+    /// Panics if the divisor is zero.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_div(self, rhs: Self) -> Self;
+    fn overflowing_div_euclid_int(self, rhs: Self::Bits) -> (Self, bool);
 
-    /// Unwrapped remainder. Returns the quotient, panicking if the divisor is zero.
+    /// Overflowing remainder for Euclidean division by an integer.
+    ///
+    /// Returns a [tuple] of the remainder and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_rem][FixedI32::unwrapped_rem]</code> and
-    /// <code>FixedU32::[unwrapped\_rem][FixedU32::unwrapped_rem]</code>.
+    /// <code>FixedI32::[overflowing\_rem\_euclid\_int][FixedI32::overflowing_rem_euclid_int]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_rem\_euclid\_int][FixedU32::overflowing_rem_euclid_int]</code>.
     ///
     /// # Panics
     ///
     /// Panics if the divisor is zero.
-    #[track_caller]
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_rem(self, rhs: Self) -> Self;
+    fn overflowing_rem_euclid_int(self, rhs: Self::Bits) -> (Self, bool);
 
-    /// Unwrapped reciprocal. Returns reciprocal, panicking on overflow.
+    /// Overflowing addition of an integer.
+    ///
+    /// Returns a [tuple] of the sum and a [`bool`], indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_recip][FixedI32::unwrapped_recip]</code> and
-    /// <code>FixedU32::[unwrapped\_recip][FixedU32::unwrapped_recip]</code>.
+    /// <code>FixedI32::[overflowing\_add\_int][FixedI32::overflowing_add_int]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_add\_int][FixedU32::overflowing_add_int]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_add_int(self, rhs: Self::Bits) -> (Self, bool);
+
+    /// Overflowing subtraction of an integer.
     ///
-    /// # Panics
+    /// Returns a [tuple] of the difference and a [`bool`], indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is returned.
     ///
-    /// Panics if `self` is zero or on overflow.
-    #[track_caller]
-    fn unwrapped_recip(self) -> Self;
+    /// See also
+    /// <code>FixedI32::[overflowing\_sub\_int][FixedI32::overflowing_sub_int]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_sub\_int][FixedU32::overflowing_sub_int]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_sub_int(self, rhs: Self::Bits) -> (Self, bool);
 
-    /// Unwrapped multiply and add. Returns `self` × `mul` + `add`, panicking on overflow.
+    /// Overflowing shift left.
+    ///
+    /// Returns a [tuple] of the shifted value and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_mul\_add][FixedI32::unwrapped_mul_add]</code>
-    /// and
-    /// <code>FixedU32::[unwrapped\_mul\_add][FixedU32::unwrapped_mul_add]</code>.
+    /// <code>FixedI32::[overflowing\_shl][FixedI32::overflowing_shl]</code> and
+    /// <code>FixedU32::[overflowing\_shl][FixedU32::overflowing_shl]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_shl(self, rhs: u32) -> (Self, bool);
+
+    /// Overflowing shift right.
     ///
-    /// # Panics
+    /// Returns a [tuple] of the shifted value and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
     ///
-    /// Panics if the result does not fit.
-    #[track_caller]
+    /// See also
+    /// <code>FixedI32::[overflowing\_shr][FixedI32::overflowing_shr]</code> and
+    /// <code>FixedU32::[overflowing\_shr][FixedU32::overflowing_shr]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_mul_add(self, mul: Self, add: Self) -> Self;
+    fn overflowing_shr(self, rhs: u32) -> (Self, bool);
 
-    /// Unwrapped multiply and accumulate. Adds (`a` × `b`) to `self`, panicking on overflow.
+    /// Overflowing distance.
+    ///
+    /// Returns a [tuple] of the distance from `self` to `other` and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_mul\_acc][FixedI32::unwrapped_mul_acc]</code>
+    /// <code>FixedI32::[overflowing\_dist][FixedI32::overflowing_dist]</code>
     /// and
-    /// <code>FixedU32::[unwrapped\_mul\_acc][FixedU32::unwrapped_mul_acc]</code>.
+    /// <code>FixedU32::[overflowing\_dist][FixedU32::overflowing_dist]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_dist(self, other: Self) -> (Self, bool);
+
+    /// Overflowing linear interpolation between `start` and `end`.
     ///
-    /// # Panics
+    /// Returns a [tuple] of the interpolated value and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
     ///
-    /// Panics if the result does not fit.
-    #[track_caller]
-    fn unwrapped_mul_acc(&mut self, a: Self, b: Self);
+    /// See also
+    /// <code>FixedI32::[overflowing\_lerp][FixedI32::overflowing_lerp]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_lerp][FixedU32::overflowing_lerp]</code>.
+    fn overflowing_lerp(self, start: Self, end: Self) -> (Self, bool);
 
-    /// Unwrapped Euclidean division. Returns the quotient, panicking on overflow.
+    /// Overflowing inverse linear interpolation between `start` and `end`.
+    ///
+    /// Returns a [tuple] of the computed value and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_div\_euclid][FixedI32::unwrapped_div_euclid]</code>
+    /// <code>FixedI32::[overflowing\_inv\_lerp][FixedI32::overflowing_inv_lerp]</code>
     /// and
-    /// <code>FixedU32::[unwrapped\_div\_euclid][FixedU32::unwrapped_div_euclid]</code>.
+    /// <code>FixedU32::[overflowing\_inv\_lerp][FixedU32::overflowing_inv_lerp]</code>.
+    fn overflowing_inv_lerp(self, start: Self, end: Self) -> (Self, bool);
+}
+
+/// This trait provides methods common to all signed fixed-point numbers.
+///
+/// Methods common to all fixed-point numbers including unsigned
+/// fixed-point numbers are provided by the [`Fixed`] supertrait.
+///
+/// This trait is sealed and cannot be implemented for more types; it
+/// is implemented for [`FixedI8`], [`FixedI16`], [`FixedI32`],
+/// [`FixedI64`], and [`FixedI128`].
+pub trait FixedSigned: Fixed
+where
+    Self: Neg<Output = Self>,
+{
+    /// Returns the number of bits required to represent the value.
     ///
-    /// # Panics
+    /// See also <code>FixedI32::[signed\_bits][FixedI32::signed_bits]</code>.
+    fn signed_bits(self) -> u32;
+
+    /// Returns [`true`] if the number is > 0.
     ///
-    /// Panics if the divisor is zero or if the result does not fit.
-    #[track_caller]
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_div_euclid(self, rhs: Self) -> Self;
+    /// See also <code>FixedI32::[is\_positive][FixedI32::is_positive]</code>.
+    fn is_positive(self) -> bool;
 
-    /// Unwrapped remainder for Euclidean division. Returns the
-    /// remainder, panicking if the divisor is zero.
+    /// Returns [`true`] if the number is < 0.
     ///
-    /// See also
-    /// <code>FixedI32::[unwrapped\_rem\_euclid][FixedI32::unwrapped_rem_euclid]</code>
-    /// and
-    /// <code>FixedU32::[unwrapped\_rem\_euclid][FixedU32::unwrapped_rem_euclid]</code>.
+    /// See also <code>FixedI32::[is\_negative][FixedI32::is_negative]</code>.
+    fn is_negative(self) -> bool;
+
+    /// Returns the absolute value.
     ///
-    /// # Panics
+    /// See also <code>FixedI32::[abs][FixedI32::abs]</code>.
+    fn abs(self) -> Self;
+
+    /// Returns the absolute value using an unsigned type without any
+    /// wrapping or panicking.
     ///
-    /// Panics if the divisor is zero.
-    #[track_caller]
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_rem_euclid(self, rhs: Self) -> Self;
+    /// See also <code>FixedI32::[unsigned\_abs][FixedI32::unsigned_abs]</code>.
+    fn unsigned_abs(self) -> Self::Unsigned;
 
-    /// Unwrapped multiplication by an integer. Returns the product, panicking on overflow.
+    /// Returns the distance from `self` to `other` using an unsigned type
+    /// without any wrapping or panicking.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_mul\_int][FixedI32::unwrapped_mul_int]</code>
-    /// and
-    /// <code>FixedU32::[unwrapped\_mul\_int][FixedU32::unwrapped_mul_int]</code>.
+    /// <code>FixedI32::[unsigned\_dist][FixedI32::unsigned_dist]</code>.
+    fn unsigned_dist(self, other: Self) -> Self::Unsigned;
+
+    /// Returns a number representing the sign of `self`.
     ///
-    /// # Panics
+    /// See also <code>FixedI32::[signum][FixedI32::signum]</code>.
     ///
-    /// Panics if the result does not fit.
-    #[track_caller]
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_mul_int(self, rhs: Self::Bits) -> Self;
-
-    /// Unwrapped division by an integer. Returns the quotient, panicking on overflow.
+    /// # Panics
     ///
-    /// Overflow can only occur when dividing the minimum value by −1.
+    /// When debug assertions are enabled, this method panics
+    ///   * if the value is positive and the fixed-point number has
+    ///     zero or one integer bits such that it cannot hold the
+    ///     value 1.
+    ///   * if the value is negative and the fixed-point number has
+    ///     zero integer bits, such that it cannot hold the value −1.
     ///
-    /// See also
-    /// <code>FixedI32::[unwrapped\_div\_int][FixedI32::unwrapped_div_int]</code>
-    /// and
-    /// <code>FixedU32::[unwrapped\_div\_int][FixedU32::unwrapped_div_int]</code>.
+    /// When debug assertions are not enabled, the wrapped value can
+    /// be returned in those cases, but it is not considered a
+    /// breaking change if in the future it panics; using this method
+    /// when 1 and −1 cannot be represented is almost certainly a bug.
+    fn signum(self) -> Self;
+
+    /// Sine.
     ///
-    /// # Panics
+    /// The result always fits, as the magnitude of the sine is never greater
+    /// than the magnitude of `self`.
     ///
-    /// Panics if the divisor is zero or if the result does not fit.
-    #[track_caller]
+    /// See also <code>FixedI32::[sin][FixedI32::sin]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_div_int(self, rhs: Self::Bits) -> Self;
+    fn sin(self) -> Self;
 
-    /// Unwrapped remainder for division by an integer. Returns the
-    /// remainder, panicking if the divisor is zero.
+    /// Cosine.
     ///
-    /// See also
-    /// <code>FixedI32::[unwrapped\_rem\_int][FixedI32::unwrapped_rem_int]</code>
-    /// and
-    /// <code>FixedU32::[unwrapped\_rem\_int][FixedU32::unwrapped_rem_int]</code>.
+    /// See also <code>FixedI32::[cos][FixedI32::cos]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero.
-    #[track_caller]
+    /// When debug assertions are enabled, panics if the result does not fit,
+    /// which can only happen if there are fewer than two integer bits. When
+    /// debug assertions are not enabled, the saturated value can be returned,
+    /// but it is not considered a breaking change if in the future it
+    /// panics.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_rem_int(self, rhs: Self::Bits) -> Self;
+    fn cos(self) -> Self;
 
-    /// Unwrapped Euclidean division by an integer. Returns the
-    /// quotient, panicking on overflow.
-    ///
-    /// Overflow can only occur when dividing the minimum value by −1.
+    /// Tangent.
     ///
-    /// See also
-    /// <code>FixedI32::[unwrapped\_div\_euclid\_int][FixedI32::unwrapped_div_euclid_int]</code>
-    /// and
-    /// <code>FixedU32::[unwrapped\_div\_euclid\_int][FixedU32::unwrapped_div_euclid_int]</code>.
+    /// See also <code>FixedI32::[tan][FixedI32::tan]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero or if the result does not fit.
-    #[track_caller]
+    /// When debug assertions are enabled, panics if the result does not fit.
+    /// When debug assertions are not enabled, the saturated value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_div_euclid_int(self, rhs: Self::Bits) -> Self;
+    fn tan(self) -> Self;
 
-    /// Unwrapped remainder for Euclidean division by an integer.
-    /// Returns the remainder, panicking on overflow.
+    /// Simultaneously computes the sine and cosine.
     ///
-    /// See also
-    /// <code>FixedI32::[unwrapped\_rem\_euclid\_int][FixedI32::unwrapped_rem_euclid_int]</code>
-    /// and
-    /// <code>FixedU32::[unwrapped\_rem\_euclid\_int][FixedU32::unwrapped_rem_euclid_int]</code>.
+    /// See also <code>FixedI32::[sin\_cos][FixedI32::sin_cos]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero or if the result does not fit.
-    #[track_caller]
+    /// When debug assertions are enabled, panics if the result does not fit,
+    /// which can only happen if there are fewer than two integer bits. When
+    /// debug assertions are not enabled, the saturated value can be returned,
+    /// but it is not considered a breaking change if in the future it
+    /// panics.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_rem_euclid_int(self, rhs: Self::Bits) -> Self;
+    fn sin_cos(self) -> (Self, Self);
 
-    /// Unwrapped shift left. Panics if `rhs` ≥ the number of bits.
-    ///
-    /// See also
-    /// <code>FixedI32::[unwrapped\_shl][FixedI32::unwrapped_shl]</code> and
-    /// <code>FixedU32::[unwrapped\_shl][FixedU32::unwrapped_shl]</code>.
+    /// Arctangent.
     ///
-    /// # Panics
+    /// The result always fits, as the magnitude of the arctangent is never
+    /// greater than the magnitude of `self`.
     ///
-    /// Panics if `rhs` ≥ the number of bits.
-    #[track_caller]
+    /// See also <code>FixedI32::[atan][FixedI32::atan]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_shl(self, rhs: u32) -> Self;
+    fn atan(self) -> Self;
 
-    /// Unwrapped shift right. Panics if `rhs` ≥ the number of bits.
+    /// Four-quadrant arctangent.
     ///
-    /// See also
-    /// <code>FixedI32::[unwrapped\_shr][FixedI32::unwrapped_shr]</code> and
-    /// <code>FixedU32::[unwrapped\_shr][FixedU32::unwrapped_shr]</code>.
+    /// Returns the angle of the point (`other`, `self`).
+    ///
+    /// See also <code>FixedI32::[atan2][FixedI32::atan2]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if `rhs` ≥ the number of bits.
-    #[track_caller]
+    /// When debug assertions are enabled, panics if the result does not fit,
+    /// which can only happen if there are fewer than three integer bits. When
+    /// debug assertions are not enabled, the saturated value can be returned,
+    /// but it is not considered a breaking change if in the future it
+    /// panics.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_shr(self, rhs: u32) -> Self;
+    fn atan2(self, other: Self) -> Self;
 
-    /// Unwrapped distance. Returns the distance from `self` to `other`,
-    /// panicking on overflow.
+    /// Arcsine.
+    ///
+    /// See also <code>FixedI32::[asin][FixedI32::asin]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the result does not fit.
+    /// Panics if `self` is less than −1 or greater than 1.
     ///
-    /// See also
-    /// <code>FixedI32::[unwrapped\_dist][FixedI32::unwrapped_dist]</code> and
-    /// <code>FixedU32::[unwrapped\_dist][FixedU32::unwrapped_dist]</code>.
+    /// When debug assertions are enabled, this method also panics if the
+    /// result does not fit, which can only happen if there are fewer than
+    /// two integer bits. When debug assertions are not enabled, the
+    /// saturated value can be returned, but it is not considered a breaking
+    /// change if in the future it panics.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_dist(self, other: Self) -> Self;
+    fn asin(self) -> Self;
 
-    /// Linear interpolation between `start` and `end`, panicking on overflow.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the result does not fit.
+    /// Arccosine.
     ///
-    /// See also
-    /// <code>FixedI32::[unwrapped\_lerp][FixedI32::unwrapped_lerp]</code> and
-    /// <code>FixedU32::[unwrapped\_lerp][FixedU32::unwrapped_lerp]</code>.
-    fn unwrapped_lerp(self, start: Self, end: Self) -> Self;
-
-    /// Inverse linear interpolation between `start` and `end`, panicking on overflow.
+    /// See also <code>FixedI32::[acos][FixedI32::acos]</code>.
     ///
     /// # Panics
     ///
-    /// Panics when `start` = `end` or when the results overflows.
-    ///
-    /// See also
-    /// <code>FixedI32::[unwrapped\_inv\_lerp][FixedI32::unwrapped_inv_lerp]</code>
-    /// and
-    /// <code>FixedU32::[unwrapped\_inv\_lerp][FixedU32::unwrapped_inv_lerp]</code>.
-    fn unwrapped_inv_lerp(self, start: Self, end: Self) -> Self;
-
-    /// Overflowing negation.
-    ///
-    /// Returns a [tuple] of the negated value and a [`bool`],
-    /// indicating whether an overflow has occurred. On overflow, the
-    /// wrapped value is returned.
+    /// Panics if `self` is less than −1 or greater than 1.
     ///
-    /// See also
-    /// <code>FixedI32::[overflowing\_neg][FixedI32::overflowing_neg]</code> and
-    /// <code>FixedU32::[overflowing\_neg][FixedU32::overflowing_neg]</code>.
-    fn overflowing_neg(self) -> (Self, bool);
+    /// When debug assertions are enabled, this method also panics if the
+    /// result does not fit, which can only happen if there are fewer than
+    /// three integer bits. When debug assertions are not enabled, the
+    /// saturated value can be returned, but it is not considered a breaking
+    /// change if in the future it panics.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn acos(self) -> Self;
 
-    /// Overflowing addition.
-    ///
-    /// Returns a [tuple] of the sum and a [`bool`], indicating whether
-    /// an overflow has occurred. On overflow, the wrapped value is
-    /// returned.
+    /// Checked sine. Returns the sine, or [`None`] on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_add][FixedI32::overflowing_add]</code> and
-    /// <code>FixedU32::[overflowing\_add][FixedU32::overflowing_add]</code>.
+    /// <code>FixedI32::[checked\_sin][FixedI32::checked_sin]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+    fn checked_sin(self) -> Option<Self>;
 
-    /// Overflowing subtraction.
-    ///
-    /// Returns a [tuple] of the difference and a [`bool`], indicating
-    /// whether an overflow has occurred. On overflow, the wrapped
-    /// value is returned.
+    /// Checked cosine. Returns the cosine, or [`None`] on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_sub][FixedI32::overflowing_sub]</code> and
-    /// <code>FixedU32::[overflowing\_sub][FixedU32::overflowing_sub]</code>.
+    /// <code>FixedI32::[checked\_cos][FixedI32::checked_cos]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn overflowing_sub(self, rhs: Self) -> (Self, bool);
+    fn checked_cos(self) -> Option<Self>;
 
-    /// Overflowing multiplication.
-    ///
-    /// Returns a [tuple] of the product and a [`bool`], indicating
-    /// whether an overflow has occurred. On overflow, the wrapped
-    /// value is returned.
+    /// Checked tangent. Returns the tangent, or [`None`] on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_mul][FixedI32::overflowing_mul]</code> and
-    /// <code>FixedU32::[overflowing\_mul][FixedU32::overflowing_mul]</code>.
+    /// <code>FixedI32::[checked\_tan][FixedI32::checked_tan]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn overflowing_mul(self, rhs: Self) -> (Self, bool);
+    fn checked_tan(self) -> Option<Self>;
 
-    /// Overflowing division.
-    ///
-    /// Returns a [tuple] of the quotient and a [`bool`], indicating
-    /// whether an overflow has occurred. On overflow, the wrapped
-    /// value is returned.
+    /// Checked simultaneous sine and cosine. Returns a [tuple] of the sine
+    /// and the cosine, or [`None`] if either of them overflows.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_div][FixedI32::overflowing_div]</code> and
-    /// <code>FixedU32::[overflowing\_div][FixedU32::overflowing_div]</code>.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the divisor is zero.
+    /// <code>FixedI32::[checked\_sin\_cos][FixedI32::checked_sin_cos]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn overflowing_div(self, rhs: Self) -> (Self, bool);
+    fn checked_sin_cos(self) -> Option<(Self, Self)>;
 
-    /// Overflowing reciprocal.
-    ///
-    /// Returns a [tuple] of the reciprocal of `self` and a [`bool`],
-    /// indicating whether an overflow has occurred. On overflow, the
-    /// wrapped value is returned.
+    /// Checked arctangent. Returns the arctangent, or [`None`] on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_recip][FixedI32::overflowing_recip]</code>
-    /// and
-    /// <code>FixedU32::[overflowing\_recip][FixedU32::overflowing_recip]</code>.
-    ///
-    /// # Panics
-    ///
-    /// Panics if `self` is zero.
-    fn overflowing_recip(self) -> (Self, bool);
+    /// <code>FixedI32::[checked\_atan][FixedI32::checked_atan]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_atan(self) -> Option<Self>;
 
-    /// Overflowing multiply  and add.
-    ///
-    /// Returns a [tuple] of `self` × `mul` + `add` and a [`bool`],
-    /// indicating whether an overflow has occurred. On overflow, the
-    /// wrapped value is returned.
+    /// Checked four-quadrant arctangent. Returns the angle of the
+    /// point (`other`, `self`), or [`None`] on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_mul\_add][FixedI32::overflowing_mul_add]</code>
-    /// and
-    /// <code>FixedU32::[overflowing\_mul\_add][FixedU32::overflowing_mul_add]</code>.
+    /// <code>FixedI32::[checked\_atan2][FixedI32::checked_atan2]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn overflowing_mul_add(self, mul: Self, add: Self) -> (Self, bool);
+    fn checked_atan2(self, other: Self) -> Option<Self>;
 
-    /// Overflowing multiply and accumulate. Adds (`a` × `b`) to `self`,
-    /// wrapping and returning [`true`] if overflow occurs.
+    /// Checked arcsine. Returns the arcsine, or [`None`] if `self` is
+    /// less than −1 or greater than 1, or on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_mul\_acc][FixedI32::overflowing_mul_acc]</code>
-    /// and
-    /// <code>FixedU32::[overflowing\_mul\_acc][FixedU32::overflowing_mul_acc]</code>.
-    #[must_use = "this returns whether overflow occurs; use `wrapping_mul_acc` if the flag is not needed"]
-    fn overflowing_mul_acc(&mut self, a: Self, b: Self) -> bool;
+    /// <code>FixedI32::[checked\_asin][FixedI32::checked_asin]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_asin(self) -> Option<Self>;
 
-    /// Overflowing Euclidean division.
-    ///
-    /// Returns a [tuple] of the quotient and a [`bool`], indicating
-    /// whether an overflow has occurred. On overflow, the wrapped
-    /// value is returned.
-    ///
-    /// See also
-    /// <code>FixedI32::[overflowing\_div\_euclid][FixedI32::overflowing_div_euclid]</code>
-    /// and
-    /// <code>FixedU32::[overflowing\_div\_euclid][FixedU32::overflowing_div_euclid]</code>.
-    ///
-    /// # Panics
+    /// Checked arccosine. Returns the arccosine, or [`None`] if `self`
+    /// is less than −1 or greater than 1, or on overflow.
     ///
-    /// Panics if the divisor is zero.
+    /// See also
+    /// <code>FixedI32::[checked\_acos][FixedI32::checked_acos]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn overflowing_div_euclid(self, rhs: Self) -> (Self, bool);
+    fn checked_acos(self) -> Option<Self>;
 
-    /// Overflowing multiplication by an integer.
+    /// Saturating sine. Returns the sine, saturating on overflow.
     ///
-    /// Returns a [tuple] of the product and a [`bool`], indicating
-    /// whether an overflow has occurred. On overflow, the wrapped
-    /// value is returned.
+    /// See also
+    /// <code>FixedI32::[saturating\_sin][FixedI32::saturating_sin]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_sin(self) -> Self;
+
+    /// Saturating cosine. Returns the cosine, saturating on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_mul\_int][FixedI32::overflowing_mul_int]</code>
-    /// and
-    /// <code>FixedU32::[overflowing\_mul\_int][FixedU32::overflowing_mul_int]</code>.
+    /// <code>FixedI32::[saturating\_cos][FixedI32::saturating_cos]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn overflowing_mul_int(self, rhs: Self::Bits) -> (Self, bool);
+    fn saturating_cos(self) -> Self;
 
-    /// Overflowing division by an integer.
+    /// Saturating tangent. Returns the tangent, saturating on overflow.
     ///
-    /// Returns a [tuple] of the quotient and a [`bool`], indicating
-    /// whether an overflow has occurred. On overflow, the wrapped
-    /// value is returned.
+    /// See also
+    /// <code>FixedI32::[saturating\_tan][FixedI32::saturating_tan]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_tan(self) -> Self;
+
+    /// Saturating simultaneous sine and cosine. Returns a [tuple] of the
+    /// sine and the cosine, each saturating on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_div\_int][FixedI32::overflowing_div_int]</code>
-    /// and
-    /// <code>FixedU32::[overflowing\_div\_int][FixedU32::overflowing_div_int]</code>.
+    /// <code>FixedI32::[saturating\_sin\_cos][FixedI32::saturating_sin_cos]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_sin_cos(self) -> (Self, Self);
+
+    /// Error function.
+    ///
+    /// See also <code>FixedI32::[erf][FixedI32::erf]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero.
+    /// When debug assertions are enabled, panics if the result does not
+    /// fit, which can only happen if there are fewer than two integer
+    /// bits. When debug assertions are not enabled, the wrapped value can
+    /// be returned, but it is not considered a breaking change if in the
+    /// future it panics.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn overflowing_div_int(self, rhs: Self::Bits) -> (Self, bool);
+    fn erf(self) -> Self;
 
-    /// Overflowing Euclidean division by an integer.
-    ///
-    /// Returns a [tuple] of the quotient and a [`bool`], indicating
-    /// whether an overflow has occurred. On overflow, the wrapped
-    /// value is returned.
+    /// Complementary error function, 1 − erf `self`.
     ///
-    /// See also
-    /// <code>FixedI32::[overflowing\_div\_euclid\_int][FixedI32::overflowing_div_euclid_int]</code>
-    /// and
-    /// <code>FixedU32::[overflowing\_div\_euclid\_int][FixedU32::overflowing_div_euclid_int]</code>.
+    /// See also <code>FixedI32::[erfc][FixedI32::erfc]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero.
+    /// When debug assertions are enabled, panics if the result does not
+    /// fit, which can only happen if there are fewer than three integer
+    /// bits. When debug assertions are not enabled, the wrapped value can
+    /// be returned, but it is not considered a breaking change if in the
+    /// future it panics.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn overflowing_div_euclid_int(self, rhs: Self::Bits) -> (Self, bool);
+    fn erfc(self) -> Self;
 
-    /// Overflowing remainder for Euclidean division by an integer.
-    ///
-    /// Returns a [tuple] of the remainder and a [`bool`], indicating
-    /// whether an overflow has occurred. On overflow, the wrapped
-    /// value is returned.
+    /// Cumulative distribution function of the standard normal
+    /// distribution.
     ///
-    /// See also
-    /// <code>FixedI32::[overflowing\_rem\_euclid\_int][FixedI32::overflowing_rem_euclid_int]</code>
-    /// and
-    /// <code>FixedU32::[overflowing\_rem\_euclid\_int][FixedU32::overflowing_rem_euclid_int]</code>.
+    /// See also <code>FixedI32::[norm\_cdf][FixedI32::norm_cdf]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero.
+    /// When debug assertions are enabled, panics if the result does not
+    /// fit, which can only happen if there are fewer than two integer
+    /// bits. When debug assertions are not enabled, the wrapped value can
+    /// be returned, but it is not considered a breaking change if in the
+    /// future it panics.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn overflowing_rem_euclid_int(self, rhs: Self::Bits) -> (Self, bool);
+    fn norm_cdf(self) -> Self;
 
-    /// Overflowing shift left.
+    /// Probability density function of the standard normal
+    /// distribution.
     ///
-    /// Returns a [tuple] of the shifted value and a [`bool`],
-    /// indicating whether an overflow has occurred. On overflow, the
-    /// wrapped value is returned.
+    /// See also <code>FixedI32::[norm\_pdf][FixedI32::norm_pdf]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn norm_pdf(self) -> Self;
+
+    /// Checked error function. Returns the error function, or [`None`] on
+    /// overflow.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_shl][FixedI32::overflowing_shl]</code> and
-    /// <code>FixedU32::[overflowing\_shl][FixedU32::overflowing_shl]</code>.
+    /// <code>FixedI32::[checked\_erf][FixedI32::checked_erf]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn overflowing_shl(self, rhs: u32) -> (Self, bool);
+    fn checked_erf(self) -> Option<Self>;
 
-    /// Overflowing shift right.
-    ///
-    /// Returns a [tuple] of the shifted value and a [`bool`],
-    /// indicating whether an overflow has occurred. On overflow, the
-    /// wrapped value is returned.
+    /// Checked complementary error function. Returns the complementary error
+    /// function, or [`None`] on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_shr][FixedI32::overflowing_shr]</code> and
-    /// <code>FixedU32::[overflowing\_shr][FixedU32::overflowing_shr]</code>.
+    /// <code>FixedI32::[checked\_erfc][FixedI32::checked_erfc]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn overflowing_shr(self, rhs: u32) -> (Self, bool);
+    fn checked_erfc(self) -> Option<Self>;
 
-    /// Overflowing distance.
-    ///
-    /// Returns a [tuple] of the distance from `self` to `other` and a [`bool`],
-    /// indicating whether an overflow has occurred. On overflow, the wrapped
-    /// value is returned.
+    /// Checked cumulative distribution function of the standard normal
+    /// distribution. Returns the cumulative distribution function, or [`None`]
+    /// on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_dist][FixedI32::overflowing_dist]</code>
-    /// and
-    /// <code>FixedU32::[overflowing\_dist][FixedU32::overflowing_dist]</code>.
+    /// <code>FixedI32::[checked\_norm\_cdf][FixedI32::checked_norm_cdf]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn overflowing_dist(self, other: Self) -> (Self, bool);
+    fn checked_norm_cdf(self) -> Option<Self>;
 
-    /// Overflowing linear interpolation between `start` and `end`.
-    ///
-    /// Returns a [tuple] of the interpolated value and a [`bool`], indicating
-    /// whether an overflow has occurred. On overflow, the wrapped value is
-    /// returned.
+    /// Saturating error function. Returns the error function, saturating on
+    /// overflow.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_lerp][FixedI32::overflowing_lerp]</code>
-    /// and
-    /// <code>FixedU32::[overflowing\_lerp][FixedU32::overflowing_lerp]</code>.
-    fn overflowing_lerp(self, start: Self, end: Self) -> (Self, bool);
+    /// <code>FixedI32::[saturating\_erf][FixedI32::saturating_erf]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_erf(self) -> Self;
 
-    /// Overflowing inverse linear interpolation between `start` and `end`.
-    ///
-    /// Returns a [tuple] of the computed value and a [`bool`], indicating
-    /// whether an overflow has occurred. On overflow, the wrapped value is
-    /// returned.
+    /// Saturating complementary error function. Returns the complementary error
+    /// function, saturating on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_inv\_lerp][FixedI32::overflowing_inv_lerp]</code>
-    /// and
-    /// <code>FixedU32::[overflowing\_inv\_lerp][FixedU32::overflowing_inv_lerp]</code>.
-    fn overflowing_inv_lerp(self, start: Self, end: Self) -> (Self, bool);
-}
+    /// <code>FixedI32::[saturating\_erfc][FixedI32::saturating_erfc]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_erfc(self) -> Self;
 
-/// This trait provides methods common to all signed fixed-point numbers.
-///
-/// Methods common to all fixed-point numbers including unsigned
-/// fixed-point numbers are provided by the [`Fixed`] supertrait.
-///
-/// This trait is sealed and cannot be implemented for more types; it
-/// is implemented for [`FixedI8`], [`FixedI16`], [`FixedI32`],
-/// [`FixedI64`], and [`FixedI128`].
-pub trait FixedSigned: Fixed
-where
-    Self: Neg<Output = Self>,
-{
-    /// Returns the number of bits required to represent the value.
+    /// Saturating cumulative distribution function of the standard normal
+    /// distribution. Returns the cumulative distribution function, saturating
+    /// on overflow.
     ///
-    /// See also <code>FixedI32::[signed\_bits][FixedI32::signed_bits]</code>.
-    fn signed_bits(self) -> u32;
+    /// See also
+    /// <code>FixedI32::[saturating\_norm\_cdf][FixedI32::saturating_norm_cdf]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_norm_cdf(self) -> Self;
 
-    /// Returns [`true`] if the number is > 0.
+    /// Wrapping error function. Returns the error function, wrapping on
+    /// overflow.
     ///
-    /// See also <code>FixedI32::[is\_positive][FixedI32::is_positive]</code>.
-    fn is_positive(self) -> bool;
+    /// See also
+    /// <code>FixedI32::[wrapping\_erf][FixedI32::wrapping_erf]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_erf(self) -> Self;
 
-    /// Returns [`true`] if the number is < 0.
+    /// Wrapping complementary error function. Returns the complementary error
+    /// function, wrapping on overflow.
     ///
-    /// See also <code>FixedI32::[is\_negative][FixedI32::is_negative]</code>.
-    fn is_negative(self) -> bool;
+    /// See also
+    /// <code>FixedI32::[wrapping\_erfc][FixedI32::wrapping_erfc]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_erfc(self) -> Self;
 
-    /// Returns the absolute value.
+    /// Wrapping cumulative distribution function of the standard normal
+    /// distribution. Returns the cumulative distribution function, wrapping on
+    /// overflow.
     ///
-    /// See also <code>FixedI32::[abs][FixedI32::abs]</code>.
-    fn abs(self) -> Self;
+    /// See also
+    /// <code>FixedI32::[wrapping\_norm\_cdf][FixedI32::wrapping_norm_cdf]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_norm_cdf(self) -> Self;
 
-    /// Returns the absolute value using an unsigned type without any
-    /// wrapping or panicking.
+    /// Overflowing error function.
     ///
-    /// See also <code>FixedI32::[unsigned\_abs][FixedI32::unsigned_abs]</code>.
-    fn unsigned_abs(self) -> Self::Unsigned;
-
-    /// Returns the distance from `self` to `other` using an unsigned type
-    /// without any wrapping or panicking.
+    /// Returns a [tuple] of the error function and a [`bool`] indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
     ///
     /// See also
-    /// <code>FixedI32::[unsigned\_dist][FixedI32::unsigned_dist]</code>.
-    fn unsigned_dist(self, other: Self) -> Self::Unsigned;
+    /// <code>FixedI32::[overflowing\_erf][FixedI32::overflowing_erf]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_erf(self) -> (Self, bool);
 
-    /// Returns a number representing the sign of `self`.
+    /// Overflowing complementary error function.
     ///
-    /// See also <code>FixedI32::[signum][FixedI32::signum]</code>.
+    /// Returns a [tuple] of the complementary error function and a [`bool`]
+    /// indicating whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
     ///
-    /// # Panics
+    /// See also
+    /// <code>FixedI32::[overflowing\_erfc][FixedI32::overflowing_erfc]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_erfc(self) -> (Self, bool);
+
+    /// Overflowing cumulative distribution function of the standard normal
+    /// distribution.
     ///
-    /// When debug assertions are enabled, this method panics
-    ///   * if the value is positive and the fixed-point number has
-    ///     zero or one integer bits such that it cannot hold the
-    ///     value 1.
-    ///   * if the value is negative and the fixed-point number has
-    ///     zero integer bits, such that it cannot hold the value −1.
+    /// Returns a [tuple] of the cumulative distribution function and a [`bool`]
+    /// indicating whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
     ///
-    /// When debug assertions are not enabled, the wrapped value can
-    /// be returned in those cases, but it is not considered a
-    /// breaking change if in the future it panics; using this method
-    /// when 1 and −1 cannot be represented is almost certainly a bug.
-    fn signum(self) -> Self;
+    /// See also
+    /// <code>FixedI32::[overflowing\_norm\_cdf][FixedI32::overflowing_norm_cdf]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_norm_cdf(self) -> (Self, bool);
 
     /// Checked absolute value. Returns the absolute value, or [`None`] on overflow.
     ///
@@ -2638,7 +4453,7 @@ where
     ///   * if the value is positive and the fixed-point number has zero
     ///     or one integer bits such that it cannot hold the value 1.
     ///   * if the value is negative and the fixed-point number has zero
-    ///         integer bits, such that it cannot hold the value −1.
+    ///     integer bits, such that it cannot hold the value −1.
     ///
     /// See also
     /// <code>FixedI32::[checked\_signum][FixedI32::checked_signum]</code>.
@@ -2659,7 +4474,7 @@ where
     ///   * if the value is positive and the fixed-point number has zero
     ///     or one integer bits such that it cannot hold the value 1.
     ///   * if the value is negative and the fixed-point number has zero
-    ///         integer bits, such that it cannot hold the value −1.
+    ///     integer bits, such that it cannot hold the value −1.
     ///
     /// See also
     /// <code>FixedI32::[saturating\_signum][FixedI32::saturating_signum]</code>.
@@ -2679,7 +4494,7 @@ where
     ///   * if the value is positive and the fixed-point number has zero
     ///     or one integer bits such that it cannot hold the value 1.
     ///   * if the value is negative and the fixed-point number has zero
-    ///         integer bits, such that it cannot hold the value −1.
+    ///     integer bits, such that it cannot hold the value −1.
     ///
     /// See also
     /// <code>FixedI32::[wrapping\_signum][FixedI32::wrapping_signum]</code>.
@@ -2705,7 +4520,7 @@ where
     ///   * if the value is positive and the fixed-point number has zero
     ///     or one integer bits such that it cannot hold the value 1.
     ///   * if the value is negative and the fixed-point number has zero
-    ///         integer bits, such that it cannot hold the value −1.
+    ///     integer bits, such that it cannot hold the value −1.
     ///
     /// See also
     /// <code>FixedI32::[unwrapped\_signum][FixedI32::unwrapped_signum]</code>.
@@ -2736,11 +4551,71 @@ where
     ///   * if the value is positive and the fixed-point number has zero
     ///     or one integer bits such that it cannot hold the value 1.
     ///   * if the value is negative and the fixed-point number has zero
-    ///         integer bits, such that it cannot hold the value −1.
+    ///     integer bits, such that it cannot hold the value −1.
     ///
     /// See also
     /// <code>FixedI32::[overflowing\_signum][FixedI32::overflowing_signum]</code>.
     fn overflowing_signum(self) -> (Self, bool);
+
+    /// Checked addition with an unsigned fixed-point number.
+    /// Returns the sum, or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_add\_unsigned][FixedI32::checked_add_unsigned]</code>.
+    fn checked_add_unsigned(self, rhs: Self::Unsigned) -> Option<Self>;
+
+    /// Checked subtraction of an unsigned fixed-point number.
+    /// Returns the difference, or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_sub\_unsigned][FixedI32::checked_sub_unsigned]</code>.
+    fn checked_sub_unsigned(self, rhs: Self::Unsigned) -> Option<Self>;
+
+    /// Saturating addition with an unsigned fixed-point number.
+    /// Returns the sum, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_add\_unsigned][FixedI32::saturating_add_unsigned]</code>.
+    fn saturating_add_unsigned(self, rhs: Self::Unsigned) -> Self;
+
+    /// Saturating subtraction of an unsigned fixed-point number.
+    /// Returns the difference, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_sub\_unsigned][FixedI32::saturating_sub_unsigned]</code>.
+    fn saturating_sub_unsigned(self, rhs: Self::Unsigned) -> Self;
+
+    /// Wrapping addition with an unsigned fixed-point number.
+    /// Returns the sum, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_add\_unsigned][FixedI32::wrapping_add_unsigned]</code>.
+    fn wrapping_add_unsigned(self, rhs: Self::Unsigned) -> Self;
+
+    /// Wrapping subtraction of an unsigned fixed-point number.
+    /// Returns the difference, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sub\_unsigned][FixedI32::wrapping_sub_unsigned]</code>.
+    fn wrapping_sub_unsigned(self, rhs: Self::Unsigned) -> Self;
+
+    /// Overflowing addition with an unsigned fixed-point number.
+    ///
+    /// Returns a [tuple] of the sum and a [`bool`], indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_add\_unsigned][FixedI32::overflowing_add_unsigned]</code>.
+    fn overflowing_add_unsigned(self, rhs: Self::Unsigned) -> (Self, bool);
+
+    /// Overflowing subtraction of an unsigned fixed-point number.
+    ///
+    /// Returns a [tuple] of the difference and a [`bool`], indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_sub\_unsigned][FixedI32::overflowing_sub_unsigned]</code>.
+    fn overflowing_sub_unsigned(self, rhs: Self::Unsigned) -> (Self, bool);
 }
 
 /// This trait provides methods common to all unsigned fixed-point numbers.
@@ -2806,6 +4681,36 @@ where
     /// Panics if the result does not fit.
     #[track_caller]
     fn unwrapped_next_power_of_two(self) -> Self;
+
+    /// Checked addition with a signed fixed-point number.
+    /// Returns the sum, or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedU32::[checked\_add\_signed][FixedU32::checked_add_signed]</code>.
+    fn checked_add_signed(self, rhs: Self::Signed) -> Option<Self>;
+
+    /// Saturating addition with a signed fixed-point number.
+    /// Returns the sum, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedU32::[saturating\_add\_signed][FixedU32::saturating_add_signed]</code>.
+    fn saturating_add_signed(self, rhs: Self::Signed) -> Self;
+
+    /// Wrapping addition with a signed fixed-point number.
+    /// Returns the sum, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedU32::[wrapping\_add\_signed][FixedU32::wrapping_add_signed]</code>.
+    fn wrapping_add_signed(self, rhs: Self::Signed) -> Self;
+
+    /// Overflowing addition with a signed fixed-point number.
+    ///
+    /// Returns a [tuple] of the sum and a [`bool`], indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedU32::[overflowing\_add\_signed][FixedU32::overflowing_add_signed]</code>.
+    fn overflowing_add_signed(self, rhs: Self::Signed) -> (Self, bool);
 }
 
 /// This trait provides lossless conversions that might be fallible.
@@ -2973,37 +4878,99 @@ pub trait FromFixed {
     /// Any extra fractional bits are discarded, which rounds towards −∞.
     fn wrapping_from_fixed<F: Fixed>(src: F) -> Self;
 
-    /// Converts from a fixed-point number.
+    /// Converts from a fixed-point number.
+    ///
+    /// Returns a [tuple] of the value and a [`bool`] indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// Any extra fractional bits are discarded, which rounds towards −∞.
+    fn overflowing_from_fixed<F: Fixed>(src: F) -> (Self, bool)
+    where
+        Self: Sized;
+
+    /// Converts from a fixed-point number, panicking if the value
+    /// does not fit.
+    ///
+    /// Any extra fractional bits are discarded, which rounds towards −∞.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit, even when debug assertions
+    /// are not enabled.
+    #[inline]
+    #[track_caller]
+    fn unwrapped_from_fixed<F: Fixed>(src: F) -> Self
+    where
+        Self: Sized,
+    {
+        match Self::overflowing_from_fixed(src) {
+            (val, false) => val,
+            (_, true) => panic!("overflow"),
+        }
+    }
+
+    /// Converts from a fixed-point number, rounding as specified by `mode`.
+    ///
+    /// Any extra fractional bits are rounded as specified by `mode`
+    /// instead of being discarded.
+    ///
+    /// This method has no default implementation, as it cannot be
+    /// implemented correctly in terms of [`from_fixed`], which always
+    /// rounds towards −∞.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the value does
+    /// not fit. When debug assertions are not enabled, the wrapped
+    /// value can be returned, but it is not considered a breaking
+    /// change if in the future it panics.
+    ///
+    /// [`from_fixed`]: FromFixed::from_fixed
+    fn from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self
+    where
+        Self: Sized;
+
+    /// Converts from a fixed-point number if it fits, otherwise returns
+    /// [`None`], rounding as specified by `mode`.
     ///
-    /// Returns a [tuple] of the value and a [`bool`] indicating whether
-    /// an overflow has occurred. On overflow, the wrapped value is
-    /// returned.
+    /// Any extra fractional bits are rounded as specified by `mode`
+    /// instead of being discarded. Overflow is checked after rounding.
+    fn checked_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Converts from a fixed-point number, saturating if it does not fit,
+    /// rounding as specified by `mode`.
     ///
-    /// Any extra fractional bits are discarded, which rounds towards −∞.
-    fn overflowing_from_fixed<F: Fixed>(src: F) -> (Self, bool)
+    /// Any extra fractional bits are rounded as specified by `mode`
+    /// instead of being discarded. Saturation is applied after rounding.
+    fn saturating_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self
     where
         Self: Sized;
 
-    /// Converts from a fixed-point number, panicking if the value
-    /// does not fit.
+    /// Converts from a fixed-point number, also returning whether the
+    /// conversion was inexact.
     ///
-    /// Any extra fractional bits are discarded, which rounds towards −∞.
+    /// Returns a [tuple] of the value, which is the same as the value
+    /// returned by [`from_fixed`][FromFixed::from_fixed], and a [`bool`]
+    /// which is [`true`] if and only if the value had to be rounded, that
+    /// is, if the returned value is not exactly equal to `src`. Overflow
+    /// is not reported through the [`bool`].
+    ///
+    /// This method has no default implementation, as whether a value is
+    /// represented exactly depends on the precision of the implementing
+    /// type.
     ///
     /// # Panics
     ///
-    /// Panics if the value does not fit, even when debug assertions
-    /// are not enabled.
-    #[inline]
-    #[track_caller]
-    fn unwrapped_from_fixed<F: Fixed>(src: F) -> Self
+    /// When debug assertions are enabled, panics if the value does
+    /// not fit. When debug assertions are not enabled, the wrapped
+    /// value can be returned, but it is not considered a breaking
+    /// change if in the future it panics.
+    fn from_fixed_exact<F: Fixed>(src: F) -> (Self, bool)
     where
-        Self: Sized,
-    {
-        match Self::overflowing_from_fixed(src) {
-            (val, false) => val,
-            (_, true) => panic!("overflow"),
-        }
-    }
+        Self: Sized;
 }
 
 /// This trait provides checked conversions to fixed-point numbers.
@@ -3112,6 +5079,84 @@ pub trait ToFixed {
             (_, true) => panic!("overflow"),
         }
     }
+
+    /// Converts to a fixed-point number, rounding as specified by `mode`.
+    ///
+    /// Any bits that do not fit in the fractional part of the
+    /// fixed-point number are rounded as specified by `mode`.
+    ///
+    /// This method has no default implementation, as it cannot be
+    /// implemented correctly in terms of [`to_fixed`], which rounds
+    /// towards −∞ for some types and to the nearest for others.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is a floating-point number that is not [finite].
+    ///
+    /// When debug assertions are enabled, also panics if the value
+    /// does not fit. When debug assertions are not enabled, the
+    /// wrapped value can be returned, but it is not considered a
+    /// breaking change if in the future it panics.
+    ///
+    /// [finite]: f64::is_finite
+    /// [`to_fixed`]: ToFixed::to_fixed
+    fn to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> F
+    where
+        Self: Sized;
+
+    /// Converts to a fixed-point number if it fits, otherwise returns
+    /// [`None`], rounding as specified by `mode`.
+    ///
+    /// Any bits that do not fit in the fractional part of the
+    /// fixed-point number are rounded as specified by `mode`. Overflow
+    /// is checked after rounding.
+    fn checked_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> Option<F>
+    where
+        Self: Sized;
+
+    /// Converts to a fixed-point number, saturating if it does not fit,
+    /// rounding as specified by `mode`.
+    ///
+    /// Any bits that do not fit in the fractional part of the
+    /// fixed-point number are rounded as specified by `mode`. Saturation
+    /// is applied after rounding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is a floating-point number that is [NaN].
+    ///
+    /// [NaN]: f64::is_nan
+    fn saturating_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> F
+    where
+        Self: Sized;
+
+    /// Converts to a fixed-point number, also returning whether the
+    /// conversion was inexact.
+    ///
+    /// Returns a [tuple] of the fixed-point number, which is the same as
+    /// the value returned by [`to_fixed`][ToFixed::to_fixed], and a
+    /// [`bool`] which is [`true`] if and only if the value had to be
+    /// rounded, that is, if the returned fixed-point number is not
+    /// exactly equal to `self`. Overflow is not reported through the
+    /// [`bool`].
+    ///
+    /// This method has no default implementation, as whether a value is
+    /// represented exactly depends on the precision of the implementing
+    /// type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is a floating-point number that is not [finite].
+    ///
+    /// When debug assertions are enabled, also panics if the value
+    /// does not fit. When debug assertions are not enabled, the
+    /// wrapped value can be returned, but it is not considered a
+    /// breaking change if in the future it panics.
+    ///
+    /// [finite]: f64::is_finite
+    fn to_fixed_exact<F: Fixed>(self) -> (F, bool)
+    where
+        Self: Sized;
 }
 
 /// This trait provides a way to convert a number to/from an equivalent
@@ -3187,6 +5232,103 @@ pub trait FixedEquiv {
     fn mut_from_fixed_equiv(f: &mut Self::Equiv) -> &mut Self;
 }
 
+/// This trait provides the type of the exact product of two fixed-point
+/// numbers.
+///
+/// If `Self` has <i>f</i> fractional bits and `Rhs` has <i>g</i> fractional
+/// bits, and the wider of the two has <i>n</i> bits, then the product is
+/// represented exactly by a fixed-point number with 2<i>n</i> bits,
+/// <i>f</i> + <i>g</i> of which are fractional. The product is signed if
+/// either operand is signed. When both operands have the same type, this is
+/// the type returned by <code>FixedI32::[wide\_mul][FixedI32::wide_mul]</code>.
+/// The type can be used as the destination type of
+/// [`mul_into`][Fixed::mul_into] so that the product is not rounded.
+///
+/// This trait is implemented for all pairs of fixed-point numbers. If either
+/// operand has 128 bits, the product is a 256-bit [`FixedI256`] or
+/// [`FixedU256`]. The 256-bit types do not implement [`Fixed`], so they
+/// cannot be used as the destination of [`mul_into`][Fixed::mul_into]; for
+/// two 128-bit operands of the same type the product is returned by
+/// <code>FixedI128::[wide\_mul][FixedI128::wide_mul]</code> or
+/// <code>FixedU128::[wide\_mul][FixedU128::wide_mul]</code>.
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::{
+///     traits::MulOutput,
+///     types::{I16F16, I32F0, I48F16, I64F64, U8F8},
+/// };
+/// type Price = I16F16;
+/// type Qty = I32F0;
+/// type Amount = <Price as MulOutput<Qty>>::Output;
+///
+/// let price = Price::from_num(12.25);
+/// let qty = Qty::from_num(3);
+/// let amount: Amount = price.mul_into(qty);
+/// assert_eq!(amount, I48F16::from_num(36.75));
+/// assert_eq!(price.wide_mul(qty), amount);
+///
+/// // the operands can have different widths and signedness
+/// type Total = <I32F0 as MulOutput<U8F8>>::Output;
+/// let total: Total = qty.mul_into(U8F8::from_num(2.5));
+/// assert_eq!(total, I48F16::from_num(7.5));
+///
+/// // the product of two 128-bit numbers has 256 bits
+/// type Wide = <I64F64 as MulOutput<I64F64>>::Output;
+/// let x = I64F64::from_num(1.5);
+/// let wide: Wide = x.wide_mul(x);
+/// assert_eq!(wide.to_string(), "2.25");
+/// ```
+pub trait MulOutput<Rhs> {
+    /// The type of the exact product.
+    type Output;
+}
+
+/// This trait provides conversions to 256-bit fixed-point numbers.
+///
+/// This trait is implemented for integer primitives, floating-point
+/// primitives and fixed-point numbers, including [`FixedI256`] and
+/// [`FixedU256`]. It is the bound of the source type of
+/// <code>FixedI256::[from\_num][FixedI256::from_num]</code> and of its
+/// checked, saturating, wrapping and overflowing variants. It is sealed and
+/// cannot be implemented for other types.
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::{traits::ToFixed256, types::extra::U100, FixedI256};
+/// fn double<Src: ToFixed256>(src: Src) -> FixedI256<U100> {
+///     let f = FixedI256::from_num(src);
+///     f + f
+/// }
+/// assert_eq!(double(3i32).to_num::<i32>(), 6);
+/// assert_eq!(double(-1.25f64).to_num::<f64>(), -2.5);
+/// ```
+pub trait ToFixed256: Sealed256 {}
+
+/// This trait provides conversions from 256-bit fixed-point numbers.
+///
+/// This trait is implemented for integer primitives, floating-point
+/// primitives and fixed-point numbers, including [`FixedI256`] and
+/// [`FixedU256`]. It is the bound of the destination type of
+/// <code>FixedI256::[to\_num][FixedI256::to_num]</code> and of its
+/// checked, saturating, wrapping and overflowing variants. It is sealed and
+/// cannot be implemented for other types.
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::{traits::FromFixed256, types::extra::U100, FixedU256};
+/// fn halve<Dst: FromFixed256>(f: FixedU256<U100>) -> Dst {
+///     (f / FixedU256::from_num(2)).to_num()
+/// }
+/// let f = FixedU256::<U100>::from_num(5);
+/// assert_eq!(halve::<u32>(f), 2);
+/// assert_eq!(halve::<f32>(f), 2.5);
+/// ```
+pub trait FromFixed256: Sealed256 {}
+
 macro_rules! trait_delegate {
     (fn $method:ident($($param:ident: $Param:ty),*) -> $Ret:ty) => {
         #[inline]
@@ -3218,6 +5360,16 @@ macro_rules! trait_delegate {
             self.$method($($param),*)
         }
     };
+    (
+        fn $method:ident<$Gen:ident: $Trait:ident, $Gen2:ident: $Trait2:ident>(
+            self $(, $param:ident: $Param:ty)*
+        ) -> $Ret:ty
+    ) => {
+        #[inline]
+        fn $method<$Gen: $Trait, $Gen2: $Trait2>(self $(, $param: $Param)*) -> $Ret {
+            self.$method($($param),*)
+        }
+    };
 }
 
 macro_rules! impl_fixed {
@@ -3266,6 +5418,26 @@ macro_rules! impl_fixed {
             trait_delegate! { fn unwrapped_to_num<Dst: FromFixed>(self) -> Dst }
             trait_delegate! { fn overflowing_from_num<Src: ToFixed>(val: Src) -> (Self, bool) }
             trait_delegate! { fn overflowing_to_num<Dst: FromFixed>(self) -> (Dst, bool) }
+            trait_delegate! {
+                fn from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Self
+            }
+            trait_delegate! { fn to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst }
+            trait_delegate! {
+                fn checked_from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Option<Self>
+            }
+            trait_delegate! {
+                fn checked_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Option<Dst>
+            }
+            trait_delegate! {
+                fn saturating_from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Self
+            }
+            trait_delegate! {
+                fn saturating_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst
+            }
+            trait_delegate! { fn from_num_stochastic<Src: Fixed>(src: Src, random: u128) -> Self }
+            trait_delegate! { fn to_num_stochastic<Dst: Fixed>(self, random: u128) -> Dst }
+            trait_delegate! { fn from_num_exact<Src: ToFixed>(src: Src) -> (Self, bool) }
+            trait_delegate! { fn to_num_exact<Dst: FromFixed>(self) -> (Dst, bool) }
             trait_delegate! { fn from_str_binary(src: &str) -> Result<Self, ParseFixedError> }
             trait_delegate! { fn from_str_octal(src: &str) -> Result<Self, ParseFixedError> }
             trait_delegate! { fn from_str_hex(src: &str) -> Result<Self, ParseFixedError> }
@@ -3305,6 +5477,99 @@ macro_rules! impl_fixed {
             trait_delegate! {
                 fn overflowing_from_str_hex(src: &str) -> Result<(Self, bool), ParseFixedError>
             }
+            trait_delegate! {
+                fn from_str_round(src: &str, mode: RoundingMode) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn from_str_binary_round(
+                    src: &str,
+                    mode: RoundingMode
+                ) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn from_str_octal_round(
+                    src: &str,
+                    mode: RoundingMode
+                ) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn from_str_hex_round(
+                    src: &str,
+                    mode: RoundingMode
+                ) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn saturating_from_str_round(
+                    src: &str,
+                    mode: RoundingMode
+                ) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn saturating_from_str_binary_round(
+                    src: &str,
+                    mode: RoundingMode
+                ) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn saturating_from_str_octal_round(
+                    src: &str,
+                    mode: RoundingMode
+                ) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn saturating_from_str_hex_round(
+                    src: &str,
+                    mode: RoundingMode
+                ) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn wrapping_from_str_round(
+                    src: &str,
+                    mode: RoundingMode
+                ) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn wrapping_from_str_binary_round(
+                    src: &str,
+                    mode: RoundingMode
+                ) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn wrapping_from_str_octal_round(
+                    src: &str,
+                    mode: RoundingMode
+                ) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn wrapping_from_str_hex_round(
+                    src: &str,
+                    mode: RoundingMode
+                ) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn overflowing_from_str_round(
+                    src: &str,
+                    mode: RoundingMode
+                ) -> Result<(Self, bool), ParseFixedError>
+            }
+            trait_delegate! {
+                fn overflowing_from_str_binary_round(
+                    src: &str,
+                    mode: RoundingMode
+                ) -> Result<(Self, bool), ParseFixedError>
+            }
+            trait_delegate! {
+                fn overflowing_from_str_octal_round(
+                    src: &str,
+                    mode: RoundingMode
+                ) -> Result<(Self, bool), ParseFixedError>
+            }
+            trait_delegate! {
+                fn overflowing_from_str_hex_round(
+                    src: &str,
+                    mode: RoundingMode
+                ) -> Result<(Self, bool), ParseFixedError>
+            }
             trait_delegate! { fn int(self) -> Self }
             trait_delegate! { fn frac(self) -> Self }
             trait_delegate! { fn ceil(self) -> Self }
@@ -3312,18 +5577,31 @@ macro_rules! impl_fixed {
             trait_delegate! { fn round_to_zero(self) -> Self }
             trait_delegate! { fn round(self) -> Self }
             trait_delegate! { fn round_ties_to_even(self) -> Self }
+            trait_delegate! { fn round_to_multiple(self, step: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn round_dp(self, dp: u32, mode: RoundingMode) -> Self }
             trait_delegate! { fn checked_ceil(self) -> Option<Self> }
             trait_delegate! { fn checked_floor(self) -> Option<Self> }
             trait_delegate! { fn checked_round(self) -> Option<Self> }
             trait_delegate! { fn checked_round_ties_to_even(self) -> Option<Self> }
+            trait_delegate! {
+                fn checked_round_to_multiple(self, step: Self, mode: RoundingMode) -> Option<Self>
+            }
+            trait_delegate! {
+                fn checked_round_dp(self, dp: u32, mode: RoundingMode) -> Option<Self>
+            }
             trait_delegate! { fn saturating_ceil(self) -> Self }
             trait_delegate! { fn saturating_floor(self) -> Self }
             trait_delegate! { fn saturating_round(self) -> Self }
             trait_delegate! { fn saturating_round_ties_to_even(self) -> Self }
+            trait_delegate! { fn saturating_round_dp(self, dp: u32, mode: RoundingMode) -> Self }
             trait_delegate! { fn wrapping_ceil(self) -> Self }
             trait_delegate! { fn wrapping_floor(self) -> Self }
             trait_delegate! { fn wrapping_round(self) -> Self }
             trait_delegate! { fn wrapping_round_ties_to_even(self) -> Self }
+            trait_delegate! {
+                fn wrapping_round_to_multiple(self, step: Self, mode: RoundingMode) -> Self
+            }
+            trait_delegate! { fn wrapping_round_dp(self, dp: u32, mode: RoundingMode) -> Self }
             trait_delegate! { fn unwrapped_ceil(self) -> Self }
             trait_delegate! { fn unwrapped_floor(self) -> Self }
             trait_delegate! { fn unwrapped_round(self) -> Self }
@@ -3332,6 +5610,16 @@ macro_rules! impl_fixed {
             trait_delegate! { fn overflowing_floor(self) -> (Self, bool) }
             trait_delegate! { fn overflowing_round(self) -> (Self, bool) }
             trait_delegate! { fn overflowing_round_ties_to_even(self) -> (Self, bool) }
+            trait_delegate! {
+                fn overflowing_round_to_multiple(
+                    self,
+                    step: Self,
+                    mode: RoundingMode
+                ) -> (Self, bool)
+            }
+            trait_delegate! {
+                fn overflowing_round_dp(self, dp: u32, mode: RoundingMode) -> (Self, bool)
+            }
             trait_delegate! { fn count_ones(self) -> u32 }
             trait_delegate! { fn count_zeros(self) -> u32 }
             trait_delegate! { fn leading_ones(self) -> u32 }
@@ -3349,23 +5637,83 @@ macro_rules! impl_fixed {
             trait_delegate! { fn dist(self, other: Self) -> Self }
             trait_delegate! { fn mean(self, other: Self) -> Self }
             trait_delegate! { fn recip(self) -> Self }
+            trait_delegate! { fn sqrt(self) -> Self }
+            trait_delegate! { fn powi(self, n: i32) -> Self }
+            trait_delegate! { fn hypot(self, other: Self) -> Self }
+            trait_delegate! { fn exp(self) -> Self }
+            trait_delegate! { fn exp2(self) -> Self }
+            trait_delegate! { fn ln(self) -> Self }
+            trait_delegate! { fn log2(self) -> Self }
+            trait_delegate! { fn log10(self) -> Self }
+            trait_delegate! { fn powf(self, exp: Self) -> Self }
+            trait_delegate! { fn cbrt(self) -> Self }
+            trait_delegate! { fn nth_root(self, n: u32) -> Self }
+            trait_delegate! { fn sinh(self) -> Self }
+            trait_delegate! { fn cosh(self) -> Self }
+            trait_delegate! { fn tanh(self) -> Self }
             trait_delegate! { fn mul_add(self, mul: Self, add: Self) -> Self }
             trait_delegate! { fn mul_acc(&mut self, a: Self, b: Self) }
+            trait_delegate! { fn mul_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn div_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn mul_floor(self, rhs: Self) -> Self }
+            trait_delegate! { fn mul_ceil(self, rhs: Self) -> Self }
+            trait_delegate! { fn div_floor(self, rhs: Self) -> Self }
+            trait_delegate! { fn div_ceil(self, rhs: Self) -> Self }
+            trait_delegate! { fn mul_exact(self, rhs: Self) -> (Self, bool) }
+            trait_delegate! { fn div_exact(self, rhs: Self) -> (Self, bool) }
+            trait_delegate! { fn mul_into<Dst: Fixed, Rhs: Fixed>(self, rhs: Rhs) -> Dst }
+            trait_delegate! { fn mul_div(self, mul: Self, div: Self) -> Self }
+            trait_delegate! {
+                fn mul_div_round(self, mul: Self, div: Self, mode: RoundingMode) -> Self
+            }
             trait_delegate! { fn div_euclid(self, rhs: Self) -> Self }
             trait_delegate! { fn rem_euclid(self, rhs: Self) -> Self }
             trait_delegate! { fn div_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn rem_euclid_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn add_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn sub_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn inv_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn checked_neg(self) -> Option<Self> }
             trait_delegate! { fn checked_add(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_sub(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_mul(self, rhs: Self) -> Option<Self> }
+            trait_delegate! {
+                fn checked_mul_into<Dst: Fixed, Rhs: Fixed>(self, rhs: Rhs) -> Option<Dst>
+            }
+            trait_delegate! { fn checked_mul_div(self, mul: Self, div: Self) -> Option<Self> }
+            trait_delegate! {
+                fn checked_mul_div_round(
+                    self,
+                    mul: Self,
+                    div: Self,
+                    mode: RoundingMode
+                ) -> Option<Self>
+            }
             trait_delegate! { fn checked_div(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_rem(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_recip(self) -> Option<Self> }
+            trait_delegate! { fn checked_sqrt(self) -> Option<Self> }
+            trait_delegate! { fn checked_powi(self, n: i32) -> Option<Self> }
+            trait_delegate! { fn checked_hypot(self, other: Self) -> Option<Self> }
+            trait_delegate! { fn checked_exp(self) -> Option<Self> }
+            trait_delegate! { fn checked_exp2(self) -> Option<Self> }
+            trait_delegate! { fn checked_ln(self) -> Option<Self> }
+            trait_delegate! { fn checked_log2(self) -> Option<Self> }
+            trait_delegate! { fn checked_log10(self) -> Option<Self> }
+            trait_delegate! { fn checked_powf(self, exp: Self) -> Option<Self> }
+            trait_delegate! { fn checked_cbrt(self) -> Option<Self> }
+            trait_delegate! { fn checked_nth_root(self, n: u32) -> Option<Self> }
+            trait_delegate! { fn checked_sinh(self) -> Option<Self> }
+            trait_delegate! { fn checked_cosh(self) -> Option<Self> }
             trait_delegate! { fn checked_mul_add(self, mul: Self, add: Self) -> Option<Self> }
             trait_delegate! { fn checked_mul_acc(&mut self, a: Self, b: Self) -> Option<()> }
+            trait_delegate! { fn checked_mul_round(self, rhs: Self, mode: RoundingMode) -> Option<Self> }
+            trait_delegate! { fn checked_div_round(self, rhs: Self, mode: RoundingMode) -> Option<Self> }
+            trait_delegate! { fn checked_mul_floor(self, rhs: Self) -> Option<Self> }
+            trait_delegate! { fn checked_mul_ceil(self, rhs: Self) -> Option<Self> }
+            trait_delegate! { fn checked_div_floor(self, rhs: Self) -> Option<Self> }
+            trait_delegate! { fn checked_div_ceil(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_div_euclid(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_rem_euclid(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_mul_int(self, rhs: Self::Bits) -> Option<Self> }
@@ -3373,6 +5721,8 @@ macro_rules! impl_fixed {
             trait_delegate! { fn checked_rem_int(self, rhs: Self::Bits) -> Option<Self> }
             trait_delegate! { fn checked_div_euclid_int(self, rhs: Self::Bits) -> Option<Self> }
             trait_delegate! { fn checked_rem_euclid_int(self, rhs: Self::Bits) -> Option<Self> }
+            trait_delegate! { fn checked_add_int(self, rhs: Self::Bits) -> Option<Self> }
+            trait_delegate! { fn checked_sub_int(self, rhs: Self::Bits) -> Option<Self> }
             trait_delegate! { fn checked_shl(self, rhs: u32) -> Option<Self> }
             trait_delegate! { fn checked_shr(self, rhs: u32) -> Option<Self> }
             trait_delegate! { fn checked_dist(self, other: Self) -> Option<Self> }
@@ -3382,14 +5732,38 @@ macro_rules! impl_fixed {
             trait_delegate! { fn saturating_add(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_sub(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_mul(self, rhs: Self) -> Self }
+            trait_delegate! { fn saturating_mul_into<Dst: Fixed, Rhs: Fixed>(self, rhs: Rhs) -> Dst }
+            trait_delegate! { fn saturating_mul_div(self, mul: Self, div: Self) -> Self }
+            trait_delegate! {
+                fn saturating_mul_div_round(self, mul: Self, div: Self, mode: RoundingMode) -> Self
+            }
             trait_delegate! { fn saturating_div(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_recip(self) -> Self }
+            trait_delegate! { fn saturating_sqrt(self) -> Self }
+            trait_delegate! { fn saturating_powi(self, n: i32) -> Self }
+            trait_delegate! { fn saturating_hypot(self, other: Self) -> Self }
+            trait_delegate! { fn saturating_exp(self) -> Self }
+            trait_delegate! { fn saturating_exp2(self) -> Self }
+            trait_delegate! { fn saturating_ln(self) -> Self }
+            trait_delegate! { fn saturating_log2(self) -> Self }
+            trait_delegate! { fn saturating_log10(self) -> Self }
+            trait_delegate! { fn saturating_powf(self, exp: Self) -> Self }
+            trait_delegate! { fn saturating_sinh(self) -> Self }
+            trait_delegate! { fn saturating_cosh(self) -> Self }
             trait_delegate! { fn saturating_mul_add(self, mul: Self, add: Self) -> Self }
             trait_delegate! { fn saturating_mul_acc(&mut self, a: Self, b: Self) }
+            trait_delegate! { fn saturating_mul_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn saturating_div_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn saturating_mul_floor(self, rhs: Self) -> Self }
+            trait_delegate! { fn saturating_mul_ceil(self, rhs: Self) -> Self }
+            trait_delegate! { fn saturating_div_floor(self, rhs: Self) -> Self }
+            trait_delegate! { fn saturating_div_ceil(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_div_euclid(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_mul_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn saturating_div_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn saturating_rem_euclid_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn saturating_add_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn saturating_sub_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn saturating_dist(self, other: Self) -> Self }
             trait_delegate! { fn saturating_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn saturating_inv_lerp(self, start: Self, end: Self) -> Self }
@@ -3399,6 +5773,8 @@ macro_rules! impl_fixed {
             trait_delegate! { fn wrapping_mul(self, rhs: Self) -> Self }
             trait_delegate! { fn wrapping_div(self, rhs: Self) -> Self }
             trait_delegate! { fn wrapping_recip(self) -> Self }
+            trait_delegate! { fn wrapping_sqrt(self) -> Self }
+            trait_delegate! { fn wrapping_powi(self, n: i32) -> Self }
             trait_delegate! { fn wrapping_mul_add(self, mul: Self, add: Self) -> Self }
             trait_delegate! { fn wrapping_mul_acc(&mut self, a: Self, b: Self) }
             trait_delegate! { fn wrapping_div_euclid(self, rhs: Self) -> Self }
@@ -3406,6 +5782,8 @@ macro_rules! impl_fixed {
             trait_delegate! { fn wrapping_div_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn wrapping_div_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn wrapping_rem_euclid_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn wrapping_add_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn wrapping_sub_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn wrapping_shl(self, rhs: u32) -> Self }
             trait_delegate! { fn wrapping_shr(self, rhs: u32) -> Self }
             trait_delegate! { fn wrapping_dist(self, other: Self) -> Self }
@@ -3418,6 +5796,8 @@ macro_rules! impl_fixed {
             trait_delegate! { fn unwrapped_div(self, rhs: Self) -> Self }
             trait_delegate! { fn unwrapped_rem(self, rhs: Self) -> Self }
             trait_delegate! { fn unwrapped_recip(self) -> Self }
+            trait_delegate! { fn unwrapped_sqrt(self) -> Self }
+            trait_delegate! { fn unwrapped_powi(self, n: i32) -> Self }
             trait_delegate! { fn unwrapped_mul_add(self, mul: Self, add: Self) -> Self }
             trait_delegate! { fn unwrapped_mul_acc(&mut self, a: Self, b: Self) }
             trait_delegate! { fn unwrapped_div_euclid(self, rhs: Self) -> Self }
@@ -3427,6 +5807,8 @@ macro_rules! impl_fixed {
             trait_delegate! { fn unwrapped_rem_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn unwrapped_div_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn unwrapped_rem_euclid_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn unwrapped_add_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn unwrapped_sub_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn unwrapped_shl(self, rhs: u32) -> Self }
             trait_delegate! { fn unwrapped_shr(self, rhs: u32) -> Self }
             trait_delegate! { fn unwrapped_dist(self, other: Self) -> Self }
@@ -3438,6 +5820,8 @@ macro_rules! impl_fixed {
             trait_delegate! { fn overflowing_mul(self, rhs: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_div(self, rhs: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_recip(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_sqrt(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_powi(self, n: i32) -> (Self, bool) }
             trait_delegate! { fn overflowing_mul_add(self, mul: Self, add: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_mul_acc(&mut self, a: Self, b: Self) -> bool }
             trait_delegate! { fn overflowing_div_euclid(self, rhs: Self) -> (Self, bool) }
@@ -3445,6 +5829,8 @@ macro_rules! impl_fixed {
             trait_delegate! { fn overflowing_div_int(self, rhs: Self::Bits) -> (Self, bool) }
             trait_delegate! { fn overflowing_div_euclid_int(self, rhs: Self::Bits) -> (Self, bool) }
             trait_delegate! { fn overflowing_rem_euclid_int(self, rhs: Self::Bits) -> (Self, bool) }
+            trait_delegate! { fn overflowing_add_int(self, rhs: Self::Bits) -> (Self, bool) }
+            trait_delegate! { fn overflowing_sub_int(self, rhs: Self::Bits) -> (Self, bool) }
             trait_delegate! { fn overflowing_shl(self, rhs: u32) -> (Self, bool) }
             trait_delegate! { fn overflowing_shr(self, rhs: u32) -> (Self, bool) }
             trait_delegate! { fn overflowing_dist(self, other: Self) -> (Self, bool) }
@@ -3575,6 +5961,69 @@ macro_rules! impl_fixed {
                     (_, true) => panic!("overflow"),
                 }
             }
+
+            /// Converts a fixed-point number, rounding as specified by `mode`.
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, panics if the value
+            /// does not fit. When debug assertions are not enabled,
+            /// the wrapped value can be returned, but it is not
+            /// considered a breaking change if in the future it
+            /// panics.
+            #[inline]
+            fn from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self {
+                let conv =
+                    src.private_to_fixed_helper_round(mode, Self::FRAC_NBITS, Self::INT_NBITS);
+                let (wrapped, overflow) = Self::private_overflowing_from_helper(conv);
+                debug_assert!(!overflow, "{} overflows", src);
+                let _ = overflow;
+                wrapped
+            }
+
+            /// Converts a fixed-point number if it fits, otherwise returns
+            /// [`None`], rounding as specified by `mode`.
+            #[inline]
+            fn checked_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Option<Self> {
+                let conv =
+                    src.private_to_fixed_helper_round(mode, Self::FRAC_NBITS, Self::INT_NBITS);
+                match Self::private_overflowing_from_helper(conv) {
+                    (_, true) => None,
+                    (wrapped, false) => Some(wrapped),
+                }
+            }
+
+            /// Converts a fixed-point number, saturating if it does not fit,
+            /// rounding as specified by `mode`.
+            #[inline]
+            fn saturating_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self {
+                let conv =
+                    src.private_to_fixed_helper_round(mode, Self::FRAC_NBITS, Self::INT_NBITS);
+                Self::private_saturating_from_helper(src < 0, conv)
+            }
+
+            /// Converts a fixed-point number, also returning whether the
+            /// conversion was inexact.
+            ///
+            /// Any extra fractional bits are discarded, which rounds towards −∞,
+            /// and the conversion is inexact if any of them are non-zero.
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, panics if the value
+            /// does not fit. When debug assertions are not enabled,
+            /// the wrapped value can be returned, but it is not
+            /// considered a breaking change if in the future it
+            /// panics.
+            #[inline]
+            fn from_fixed_exact<F: Fixed>(src: F) -> (Self, bool) {
+                let conv = src.private_to_fixed_helper(Self::FRAC_NBITS, Self::INT_NBITS);
+                let inexact = conv.dir != Ordering::Equal;
+                let (wrapped, overflow) = Self::private_overflowing_from_helper(conv);
+                debug_assert!(!overflow, "{} overflows", src);
+                let _ = overflow;
+                (wrapped, inexact)
+            }
         }
 
         impl<Frac: $LeEqU> ToFixed for $Fixed<Frac> {
@@ -3644,6 +6093,52 @@ macro_rules! impl_fixed {
             fn unwrapped_to_fixed<F: Fixed>(self) -> F {
                 FromFixed::unwrapped_from_fixed(self)
             }
+
+            /// Converts a fixed-point number, rounding as specified by `mode`.
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, panics if the value
+            /// does not fit. When debug assertions are not enabled,
+            /// the wrapped value can be returned, but it is not
+            /// considered a breaking change if in the future it
+            /// panics.
+            #[inline]
+            fn to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> F {
+                FromFixed::from_fixed_round(self, mode)
+            }
+
+            /// Converts a fixed-point number if it fits, otherwise returns
+            /// [`None`], rounding as specified by `mode`.
+            #[inline]
+            fn checked_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> Option<F> {
+                FromFixed::checked_from_fixed_round(self, mode)
+            }
+
+            /// Converts a fixed-point number, saturating if it does not fit,
+            /// rounding as specified by `mode`.
+            #[inline]
+            fn saturating_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> F {
+                FromFixed::saturating_from_fixed_round(self, mode)
+            }
+
+            /// Converts a fixed-point number, also returning whether the
+            /// conversion was inexact.
+            ///
+            /// Any extra fractional bits are discarded, which rounds towards −∞,
+            /// and the conversion is inexact if any of them are non-zero.
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, panics if the value
+            /// does not fit. When debug assertions are not enabled,
+            /// the wrapped value can be returned, but it is not
+            /// considered a breaking change if in the future it
+            /// panics.
+            #[inline]
+            fn to_fixed_exact<F: Fixed>(self) -> (F, bool) {
+                FromFixed::from_fixed_exact(self)
+            }
         }
 
         if_signed! {
@@ -3654,6 +6149,42 @@ macro_rules! impl_fixed {
                 trait_delegate! { fn unsigned_abs(self) -> Self::Unsigned }
                 trait_delegate! { fn unsigned_dist(self, other: Self) -> Self::Unsigned }
                 trait_delegate! { fn signum(self) -> Self }
+                trait_delegate! { fn sin(self) -> Self }
+                trait_delegate! { fn cos(self) -> Self }
+                trait_delegate! { fn tan(self) -> Self }
+                trait_delegate! { fn sin_cos(self) -> (Self, Self) }
+                trait_delegate! { fn atan(self) -> Self }
+                trait_delegate! { fn atan2(self, other: Self) -> Self }
+                trait_delegate! { fn asin(self) -> Self }
+                trait_delegate! { fn acos(self) -> Self }
+                trait_delegate! { fn checked_sin(self) -> Option<Self> }
+                trait_delegate! { fn checked_cos(self) -> Option<Self> }
+                trait_delegate! { fn checked_tan(self) -> Option<Self> }
+                trait_delegate! { fn checked_sin_cos(self) -> Option<(Self, Self)> }
+                trait_delegate! { fn checked_atan(self) -> Option<Self> }
+                trait_delegate! { fn checked_atan2(self, other: Self) -> Option<Self> }
+                trait_delegate! { fn checked_asin(self) -> Option<Self> }
+                trait_delegate! { fn checked_acos(self) -> Option<Self> }
+                trait_delegate! { fn saturating_sin(self) -> Self }
+                trait_delegate! { fn saturating_cos(self) -> Self }
+                trait_delegate! { fn saturating_tan(self) -> Self }
+                trait_delegate! { fn saturating_sin_cos(self) -> (Self, Self) }
+                trait_delegate! { fn erf(self) -> Self }
+                trait_delegate! { fn erfc(self) -> Self }
+                trait_delegate! { fn norm_cdf(self) -> Self }
+                trait_delegate! { fn norm_pdf(self) -> Self }
+                trait_delegate! { fn checked_erf(self) -> Option<Self> }
+                trait_delegate! { fn checked_erfc(self) -> Option<Self> }
+                trait_delegate! { fn checked_norm_cdf(self) -> Option<Self> }
+                trait_delegate! { fn saturating_erf(self) -> Self }
+                trait_delegate! { fn saturating_erfc(self) -> Self }
+                trait_delegate! { fn saturating_norm_cdf(self) -> Self }
+                trait_delegate! { fn wrapping_erf(self) -> Self }
+                trait_delegate! { fn wrapping_erfc(self) -> Self }
+                trait_delegate! { fn wrapping_norm_cdf(self) -> Self }
+                trait_delegate! { fn overflowing_erf(self) -> (Self, bool) }
+                trait_delegate! { fn overflowing_erfc(self) -> (Self, bool) }
+                trait_delegate! { fn overflowing_norm_cdf(self) -> (Self, bool) }
                 trait_delegate! { fn checked_abs(self) -> Option<Self> }
                 trait_delegate! { fn checked_signum(self) -> Option<Self> }
                 trait_delegate! { fn saturating_abs(self) -> Self }
@@ -3666,6 +6197,18 @@ macro_rules! impl_fixed {
                 trait_delegate! { fn overflowing_signum(self) -> (Self, bool) }
                 trait_delegate! { fn is_positive(self) -> bool }
                 trait_delegate! { fn is_negative(self) -> bool }
+                trait_delegate! { fn checked_add_unsigned(self, rhs: Self::Unsigned) -> Option<Self> }
+                trait_delegate! { fn checked_sub_unsigned(self, rhs: Self::Unsigned) -> Option<Self> }
+                trait_delegate! { fn saturating_add_unsigned(self, rhs: Self::Unsigned) -> Self }
+                trait_delegate! { fn saturating_sub_unsigned(self, rhs: Self::Unsigned) -> Self }
+                trait_delegate! { fn wrapping_add_unsigned(self, rhs: Self::Unsigned) -> Self }
+                trait_delegate! { fn wrapping_sub_unsigned(self, rhs: Self::Unsigned) -> Self }
+                trait_delegate! {
+                    fn overflowing_add_unsigned(self, rhs: Self::Unsigned) -> (Self, bool)
+                }
+                trait_delegate! {
+                    fn overflowing_sub_unsigned(self, rhs: Self::Unsigned) -> (Self, bool)
+                }
             }
         }
 
@@ -3679,6 +6222,12 @@ macro_rules! impl_fixed {
                 trait_delegate! { fn checked_next_power_of_two(self) -> Option<Self> }
                 trait_delegate! { fn wrapping_next_power_of_two(self) -> Self }
                 trait_delegate! { fn unwrapped_next_power_of_two(self) -> Self }
+                trait_delegate! { fn checked_add_signed(self, rhs: Self::Signed) -> Option<Self> }
+                trait_delegate! { fn saturating_add_signed(self, rhs: Self::Signed) -> Self }
+                trait_delegate! { fn wrapping_add_signed(self, rhs: Self::Signed) -> Self }
+                trait_delegate! {
+                    fn overflowing_add_signed(self, rhs: Self::Signed) -> (Self, bool)
+                }
             }
         }
     };
@@ -3694,3 +6243,106 @@ impl_fixed! { FixedU16, FixedI16, FixedU16, LeEqU16, u16, NonZeroU16, Unsigned }
 impl_fixed! { FixedU32, FixedI32, FixedU32, LeEqU32, u32, NonZeroU32, Unsigned }
 impl_fixed! { FixedU64, FixedI64, FixedU64, LeEqU64, u64, NonZeroU64, Unsigned }
 impl_fixed! { FixedU128, FixedI128, FixedU128, LeEqU128, u128, NonZeroU128, Unsigned }
+
+macro_rules! impl_fixed256_traits {
+    ($($Fixed:ident($LeEqU:ident)),*; $($Num:ident),*) => {
+        $(
+            impl<Frac: $LeEqU> ToFixed256 for $Fixed<Frac> {}
+            impl<Frac: $LeEqU> FromFixed256 for $Fixed<Frac> {}
+        )*
+        $(
+            impl ToFixed256 for $Num {}
+            impl FromFixed256 for $Num {}
+        )*
+    };
+}
+
+impl_fixed256_traits! {
+    FixedI8(LeEqU8), FixedI16(LeEqU16), FixedI32(LeEqU32), FixedI64(LeEqU64),
+    FixedI128(LeEqU128), FixedI256(LeEqU256), FixedU8(LeEqU8), FixedU16(LeEqU16),
+    FixedU32(LeEqU32), FixedU64(LeEqU64), FixedU128(LeEqU128), FixedU256(LeEqU256);
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize,
+    f16, bf16, f32, f64, F128Bits
+}
+
+macro_rules! impl_mul_output {
+    ($Fixed:ident; $($Rhs:ident => $Output:ident),*) => { $(
+        impl<Frac, RhsFrac> MulOutput<$Rhs<RhsFrac>> for $Fixed<Frac>
+        where
+            Frac: Add<RhsFrac>,
+        {
+            type Output = $Output<<Frac as Add<RhsFrac>>::Output>;
+        }
+    )* };
+}
+
+impl_mul_output! {
+    FixedI8;
+    FixedI8 => FixedI16, FixedI16 => FixedI32, FixedI32 => FixedI64, FixedI64 => FixedI128,
+    FixedI128 => FixedI256,
+    FixedU8 => FixedI16, FixedU16 => FixedI32, FixedU32 => FixedI64, FixedU64 => FixedI128,
+    FixedU128 => FixedI256
+}
+impl_mul_output! {
+    FixedI16;
+    FixedI8 => FixedI32, FixedI16 => FixedI32, FixedI32 => FixedI64, FixedI64 => FixedI128,
+    FixedI128 => FixedI256,
+    FixedU8 => FixedI32, FixedU16 => FixedI32, FixedU32 => FixedI64, FixedU64 => FixedI128,
+    FixedU128 => FixedI256
+}
+impl_mul_output! {
+    FixedI32;
+    FixedI8 => FixedI64, FixedI16 => FixedI64, FixedI32 => FixedI64, FixedI64 => FixedI128,
+    FixedI128 => FixedI256,
+    FixedU8 => FixedI64, FixedU16 => FixedI64, FixedU32 => FixedI64, FixedU64 => FixedI128,
+    FixedU128 => FixedI256
+}
+impl_mul_output! {
+    FixedI64;
+    FixedI8 => FixedI128, FixedI16 => FixedI128, FixedI32 => FixedI128, FixedI64 => FixedI128,
+    FixedI128 => FixedI256,
+    FixedU8 => FixedI128, FixedU16 => FixedI128, FixedU32 => FixedI128, FixedU64 => FixedI128,
+    FixedU128 => FixedI256
+}
+impl_mul_output! {
+    FixedI128;
+    FixedI8 => FixedI256, FixedI16 => FixedI256, FixedI32 => FixedI256, FixedI64 => FixedI256,
+    FixedI128 => FixedI256,
+    FixedU8 => FixedI256, FixedU16 => FixedI256, FixedU32 => FixedI256, FixedU64 => FixedI256,
+    FixedU128 => FixedI256
+}
+impl_mul_output! {
+    FixedU8;
+    FixedI8 => FixedI16, FixedI16 => FixedI32, FixedI32 => FixedI64, FixedI64 => FixedI128,
+    FixedI128 => FixedI256,
+    FixedU8 => FixedU16, FixedU16 => FixedU32, FixedU32 => FixedU64, FixedU64 => FixedU128,
+    FixedU128 => FixedU256
+}
+impl_mul_output! {
+    FixedU16;
+    FixedI8 => FixedI32, FixedI16 => FixedI32, FixedI32 => FixedI64, FixedI64 => FixedI128,
+    FixedI128 => FixedI256,
+    FixedU8 => FixedU32, FixedU16 => FixedU32, FixedU32 => FixedU64, FixedU64 => FixedU128,
+    FixedU128 => FixedU256
+}
+impl_mul_output! {
+    FixedU32;
+    FixedI8 => FixedI64, FixedI16 => FixedI64, FixedI32 => FixedI64, FixedI64 => FixedI128,
+    FixedI128 => FixedI256,
+    FixedU8 => FixedU64, FixedU16 => FixedU64, FixedU32 => FixedU64, FixedU64 => FixedU128,
+    FixedU128 => FixedU256
+}
+impl_mul_output! {
+    FixedU64;
+    FixedI8 => FixedI128, FixedI16 => FixedI128, FixedI32 => FixedI128, FixedI64 => FixedI128,
+    FixedI128 => FixedI256,
+    FixedU8 => FixedU128, FixedU16 => FixedU128, FixedU32 => FixedU128, FixedU64 => FixedU128,
+    FixedU128 => FixedU256
+}
+impl_mul_output! {
+    FixedU128;
+    FixedI8 => FixedI256, FixedI16 => FixedI256, FixedI32 => FixedI256, FixedI64 => FixedI256,
+    FixedI128 => FixedI256,
+    FixedU8 => FixedU256, FixedU16 => FixedU256, FixedU32 => FixedU256, FixedU64 => FixedU256,
+    FixedU128 => FixedU256
+}
@@ -0,0 +1,434 @@
+// Copyright © 2018–2021 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    int256::{self, U256},
+    wide_float::{WideBits, WideFloat},
+};
+
+// 4/τ = 0.636619… with 768 fractional bits, rounded down, least significant
+// limb first. The most significant limb is consts::FRAC_2_TAU shifted left by
+// one, that is 4/τ with 128 fractional bits.
+const FRAC_4_TAU: [u128; 6] = [
+    0x9C84_5F8B_BDF9_283B_1FF8_97FF_DE05_980F,
+    0xE99C_7026_B45F_7E41_3991_D639_8353_39F4,
+    0xFE1D_EB1C_B129_A73E_E882_35F5_2EBB_4484,
+    0xB724_6E3A_424D_D2E0_0649_2EEA_09D1_921C,
+    0xDB62_9599_3C43_9041_FE51_63AB_DEBB_C561,
+    0xA2F9_836E_4E44_1529_FC27_57D1_F534_DDC0,
+];
+
+// Returns (n, r) such that |x| = n τ/4 + r with |r| ≤ τ/8; only the lowest
+// two bits of n are returned.
+//
+// |x| × 4/τ is computed with 512 fractional bits, which is enough for r to
+// have a small relative error even when |x| is very close to a multiple of
+// τ/4, as it is for the tangent of large fixed-point numbers.
+fn reduce(x: WideFloat) -> (u32, WideFloat) {
+    if x.exp < -1 {
+        // |x| < 0.5 < τ/8
+        return (0, WideFloat { neg: false, ..x });
+    }
+    // |x| = val / 2↑frac_nbits, as x has at most 128 significant bits, and
+    // 0 ≤ frac_nbits ≤ 128
+    let val = x.mant.hi;
+    let frac_nbits = (127 - x.exp) as u32;
+
+    // |x| × 4/τ = prod / 2↑(768 + frac_nbits)
+    let mut prod = [0u128; 7];
+    let mut carry = 0;
+    for (p, &c) in prod.iter_mut().zip(FRAC_4_TAU.iter()) {
+        let wide = int256::wide_mul_u128(val, c);
+        let (lo, overflow) = wide.lo.overflowing_add(carry);
+        *p = lo;
+        carry = wide.hi + u128::from(overflow);
+    }
+    prod[6] = carry;
+
+    // shift so that there are 512 fractional bits in the lowest four limbs
+    let shift = 256 + frac_nbits;
+    let (limbs, bits) = ((shift / 128) as usize, shift % 128);
+    let get = |i: usize| prod.get(i).copied().unwrap_or(0);
+    let mut w = [0u128; 5];
+    for (i, limb) in w.iter_mut().enumerate() {
+        *limb = get(i + limbs) >> bits;
+        if bits != 0 {
+            *limb |= get(i + limbs + 1) << (128 - bits);
+        }
+    }
+    let mut n = (w[4] & 3) as u32;
+
+    // round to the nearest multiple of τ/4, so that frac is in [−0.5, 0.5]
+    let neg = w[3] >> 127 != 0;
+    if neg {
+        n = (n + 1) & 3;
+        let mut borrow = true;
+        for limb in &mut w[0..4] {
+            *limb = !*limb;
+            if borrow {
+                let (sum, overflow) = limb.overflowing_add(1);
+                *limb = sum;
+                borrow = overflow;
+            }
+        }
+    }
+
+    // normalize the fraction into a WideFloat
+    let top = match w[0..4].iter().rposition(|&limb| limb != 0) {
+        Some(top) => top,
+        None => return (n, WideFloat::ZERO),
+    };
+    let zeros = (3 - top as u32) * 128 + w[top].leading_zeros();
+    let (limbs, bits) = ((zeros / 128) as usize, zeros % 128);
+    let get = |i: usize| if i < limbs { 0 } else { w[i - limbs] };
+    let shifted = |i: usize| {
+        if bits == 0 {
+            get(i)
+        } else {
+            get(i) << bits | get(i - 1) >> (128 - bits)
+        }
+    };
+    // the fraction has its most significant bit 1 + zeros bits after the point
+    let frac = WideFloat {
+        neg,
+        exp: -1 - zeros as i32,
+        mant: U256 {
+            lo: shifted(2),
+            hi: shifted(3),
+        },
+    };
+    (n, frac.mul(WideFloat::FRAC_TAU_4))
+}
+
+// Returns (sin r, cos r) for |r| ≤ τ/8 using the Taylor series.
+fn sin_cos_reduced(r: WideFloat) -> (WideFloat, WideFloat) {
+    let minus_r2 = r.mul(r).neg();
+    let series = |first: WideFloat, mut k: u32| {
+        let mut sum = first;
+        let mut term = first;
+        loop {
+            term = term.mul(minus_r2).div_small(k * (k + 1));
+            if term.is_zero() || term.exp < sum.exp - 256 {
+                break sum;
+            }
+            sum = sum.add(term);
+            k += 2;
+        }
+    };
+    (series(r, 2), series(WideFloat::ONE, 1))
+}
+
+// Returns (sin x, cos x).
+pub fn sin_cos(x: WideFloat) -> (WideFloat, WideFloat) {
+    if x.is_zero() {
+        return (WideFloat::ZERO, WideFloat::ONE);
+    }
+    let (n, r) = reduce(x);
+    let (sin_r, cos_r) = sin_cos_reduced(r);
+    let (sin, cos) = match n {
+        0 => (sin_r, cos_r),
+        1 => (cos_r, sin_r.neg()),
+        2 => (sin_r.neg(), cos_r.neg()),
+        _ => (cos_r.neg(), sin_r),
+    };
+    if x.neg {
+        (sin.neg(), cos)
+    } else {
+        (sin, cos)
+    }
+}
+
+#[inline]
+pub fn sin_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide(sin_cos(val.to_wide(frac_nbits)).0, frac_nbits)
+}
+
+#[inline]
+pub fn cos_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide(sin_cos(val.to_wide(frac_nbits)).1, frac_nbits)
+}
+
+// Returns (sin, cos, overflow).
+#[inline]
+pub fn sin_cos_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, T, bool) {
+    let (sin, cos) = sin_cos(val.to_wide(frac_nbits));
+    let (sin, sin_overflow) = T::from_wide(sin, frac_nbits);
+    let (cos, cos_overflow) = T::from_wide(cos, frac_nbits);
+    (sin, cos, sin_overflow || cos_overflow)
+}
+
+#[inline]
+pub fn tan_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    let (sin, cos) = sin_cos(val.to_wide(frac_nbits));
+    T::from_wide(sin.div(cos), frac_nbits)
+}
+
+// Returns atan t for |t| ≤ 0.5 using the Taylor series.
+fn atan_reduced(t: WideFloat) -> WideFloat {
+    let minus_t2 = t.mul(t).neg();
+    let mut sum = t;
+    let mut pow = t;
+    let mut k = 3;
+    loop {
+        pow = pow.mul(minus_t2);
+        let term = pow.div_small(k);
+        if term.is_zero() || term.exp < sum.exp - 256 {
+            break sum;
+        }
+        sum = sum.add(term);
+        k += 2;
+    }
+}
+
+// Returns atan x.
+//
+// For |x| > 1, atan |x| = τ/4 − atan (1 / |x|), and for 0.5 ≤ |x| ≤ 1,
+// atan |x| = τ/8 + atan ((|x| − 1) / (|x| + 1)), where the argument of the
+// second arctangent is in [−⅓, 0].
+pub fn atan(x: WideFloat) -> WideFloat {
+    if x.is_zero() {
+        return WideFloat::ZERO;
+    }
+    let abs = WideFloat { neg: false, ..x };
+    let inverted = abs.exp >= 1 || (abs.exp == 0 && !abs.is_one());
+    let unit = if inverted {
+        WideFloat::ONE.div(abs)
+    } else {
+        abs
+    };
+    let atan_unit = if unit.exp >= -1 {
+        let t = unit.add(WideFloat::ONE.neg()).div(unit.add(WideFloat::ONE));
+        WideFloat::FRAC_TAU_4.mul_pow2(-1).add(atan_reduced(t))
+    } else {
+        atan_reduced(unit)
+    };
+    let atan_abs = if inverted {
+        WideFloat::FRAC_TAU_4.add(atan_unit.neg())
+    } else {
+        atan_unit
+    };
+    if x.neg {
+        atan_abs.neg()
+    } else {
+        atan_abs
+    }
+}
+
+// Returns the angle of the point (x, y), which is in [−τ/2, τ/2]. The angle of
+// the origin is zero.
+pub fn atan2(y: WideFloat, x: WideFloat) -> WideFloat {
+    if x.is_zero() {
+        return if y.is_zero() {
+            WideFloat::ZERO
+        } else {
+            WideFloat {
+                neg: y.neg,
+                ..WideFloat::FRAC_TAU_4
+            }
+        };
+    }
+    let angle = atan(y.div(x));
+    if !x.neg {
+        angle
+    } else if y.neg {
+        angle.add(WideFloat::FRAC_TAU_4.mul_pow2(1).neg())
+    } else {
+        angle.add(WideFloat::FRAC_TAU_4.mul_pow2(1))
+    }
+}
+
+// Returns √(1 − x²) = √((1 − x)(1 + x)); |x| must not be greater than one.
+fn cos_of_asin(x: WideFloat) -> WideFloat {
+    let one_minus = WideFloat::ONE.add(x.neg());
+    let one_plus = WideFloat::ONE.add(x);
+    one_minus.mul(one_plus).sqrt()
+}
+
+// Returns asin x; |x| must not be greater than one.
+#[inline]
+pub fn asin(x: WideFloat) -> WideFloat {
+    atan2(x, cos_of_asin(x))
+}
+
+// Returns acos x; |x| must not be greater than one.
+#[inline]
+pub fn acos(x: WideFloat) -> WideFloat {
+    atan2(cos_of_asin(x), x)
+}
+
+#[inline]
+pub fn atan_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide(atan(val.to_wide(frac_nbits)), frac_nbits)
+}
+
+#[inline]
+pub fn atan2_bits<T: WideBits>(y: T, x: T, frac_nbits: u32) -> (T, bool) {
+    let angle = atan2(y.to_wide(frac_nbits), x.to_wide(frac_nbits));
+    T::from_wide(angle, frac_nbits)
+}
+
+// |val| must not be greater than one
+#[inline]
+pub fn asin_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide(asin(val.to_wide(frac_nbits)), frac_nbits)
+}
+
+// |val| must not be greater than one
+#[inline]
+pub fn acos_bits<T: WideBits>(val: T, frac_nbits: u32) -> (T, bool) {
+    T::from_wide(acos(val.to_wide(frac_nbits)), frac_nbits)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trig;
+
+    // Checks that the result is within ½ + slack of the floating-point
+    // reference, in units of the last place.
+    fn check_i32(f: fn(i32, u32) -> (i32, bool), reference: fn(f64) -> f64) {
+        for &frac_nbits in &[2, 8, 16, 24, 29] {
+            let scale = f64::from(1u32 << frac_nbits);
+            let mut val = 1i32;
+            while val < i32::MAX / 3 {
+                for &val in &[val, -val] {
+                    let expected = reference(f64::from(val) / scale) * scale;
+                    let (ans, overflow) = f(val, frac_nbits);
+                    if expected.abs() < 2e9 {
+                        assert!(!overflow, "{} {}", val, frac_nbits);
+                        let err = (f64::from(ans) - expected).abs();
+                        assert!(err <= 0.5 + 1e-6, "{} {}", val, frac_nbits);
+                    } else if expected.abs() > 2.2e9 {
+                        assert!(overflow, "{} {}", val, frac_nbits);
+                    }
+                }
+                val = val * 3 + 1;
+            }
+        }
+    }
+
+    #[test]
+    fn sin_cos_tan_i32() {
+        check_i32(trig::sin_bits::<i32>, f64::sin);
+        check_i32(trig::cos_bits::<i32>, f64::cos);
+        check_i32(trig::tan_bits::<i32>, f64::tan);
+        let sin_cos = |val, frac_nbits| trig::sin_cos_bits::<i32>(val, frac_nbits);
+        assert_eq!(sin_cos(1 << 16, 16), (55_147, 35_409, false));
+    }
+
+    #[test]
+    fn overflow() {
+        // 1 does not fit when there is only one integer bit, and cos x > 0.5
+        // does not fit when there are no integer bits
+        assert_eq!(trig::cos_bits::<i8>(0, 7), (i8::MAX, true));
+        assert_eq!(trig::cos_bits::<i8>(0, 6), (64, false));
+        assert_eq!(trig::sin_bits::<i8>(50, 5), (32, false));
+        assert_eq!(trig::cos_bits::<i8>(100, 8), (i8::MAX, true));
+        assert_eq!(trig::sin_cos_bits::<i8>(0, 7), (0, i8::MAX, true));
+    }
+
+    #[test]
+    fn large_args() {
+        // reference values computed using arbitrary precision
+        let max = i128::MAX;
+        let (sin, cos, overflow) = trig::sin_cos_bits::<i128>(max, 64);
+        assert_eq!(sin, 18_445_459_750_022_980_120);
+        assert_eq!(cos, 217_672_991_481_817_039);
+        assert!(!overflow);
+        let tan = 1_563_164_419_316_612_522_953;
+        assert_eq!(trig::tan_bits::<i128>(max, 64), (tan, false));
+        assert_eq!(trig::sin_cos_bits::<i128>(max, 0), (0, 1, false));
+        let (sin, cos, overflow) = trig::sin_cos_bits::<i128>(i128::MIN, 120);
+        assert_eq!(sin, -958_423_510_815_561_033_514_280_422_226_906_632);
+        assert_eq!(cos, -921_016_524_658_683_033_445_948_146_754_690_307);
+        assert!(!overflow);
+        let tan = 1_383_214_446_523_231_918_083_873_163_334_302_299;
+        assert_eq!(trig::tan_bits::<i128>(i128::MIN, 120), (tan, false));
+    }
+
+    #[test]
+    fn near_multiples() {
+        // π/2 and π rounded down, and π/2 rounded to the nearest
+        let frac_pi_2 = 1_811_004_864_519_280_710;
+        let (sin, cos, overflow) = trig::sin_cos_bits::<i128>(frac_pi_2, 60);
+        assert_eq!((sin, cos, overflow), (1 << 60, 1, false));
+        let tan = 2_229_943_328_247_101_230_554_709_679_874_899_596;
+        assert_eq!(trig::tan_bits::<i128>(frac_pi_2, 60), (tan, false));
+        let pi = 4_175_892_906_503_776_358_826_876_457_663_557_747;
+        let (sin, cos, overflow) = trig::sin_cos_bits::<i128>(pi, 120);
+        assert_eq!((sin, cos, overflow), (0, -1 << 120, false));
+        let frac_pi_2 = 1_991_220_906_497_848_681_844_175_556_976;
+        assert_eq!(trig::tan_bits::<i128>(frac_pi_2, 100), (i128::MAX, true));
+    }
+
+    #[test]
+    fn inverse_i32() {
+        check_i32(trig::atan_bits::<i32>, f64::atan);
+        for &frac_nbits in &[2, 8, 16, 24, 29] {
+            let one = 1i32 << frac_nbits;
+            let scale = f64::from(one);
+            let step = (one >> 7).max(1);
+            let mut val = -one;
+            while val <= one {
+                let x = f64::from(val) / scale;
+                let (asin, overflow) = trig::asin_bits::<i32>(val, frac_nbits);
+                assert!(!overflow, "{} {}", val, frac_nbits);
+                let err = (f64::from(asin) - x.asin() * scale).abs();
+                assert!(err <= 0.5 + 1e-6, "{} {}", val, frac_nbits);
+                let (acos, overflow) = trig::acos_bits::<i32>(val, frac_nbits);
+                assert!(!overflow, "{} {}", val, frac_nbits);
+                let err = (f64::from(acos) - x.acos() * scale).abs();
+                assert!(err <= 0.5 + 1e-6, "{} {}", val, frac_nbits);
+                for &other in &[-one, -1, 0, 1, one / 3, one] {
+                    let (atan2, overflow) = trig::atan2_bits::<i32>(val, other, frac_nbits);
+                    assert!(!overflow, "{} {} {}", val, other, frac_nbits);
+                    let expected = x.atan2(f64::from(other) / scale) * scale;
+                    let err = (f64::from(atan2) - expected).abs();
+                    assert!(err <= 0.5 + 1e-6, "{} {} {}", val, other, frac_nbits);
+                }
+                val += step;
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_exact() {
+        // reference values computed using arbitrary precision
+        let frac_pi_4 = 66_814_286_504_060_421_741_230_023_322_616_923_956;
+        assert_eq!(trig::atan_bits::<i128>(1 << 126, 126), (frac_pi_4, false));
+        assert_eq!(trig::atan_bits::<i128>(-1 << 126, 126), (-frac_pi_4, false));
+        // atan 2↑−100 = 2↑−100 − 2↑−300 / 3 + …
+        assert_eq!(trig::atan_bits::<i128>(1 << 26, 126), (1 << 26, false));
+        let pi = 133_628_573_008_120_843_482_460_046_645_233_847_913;
+        let frac_pi_2 = 66_814_286_504_060_421_741_230_023_322_616_923_956;
+        assert_eq!(trig::atan2_bits::<i128>(0, -1, 125), (pi, false));
+        assert_eq!(trig::atan2_bits::<i128>(-1, 0, 125), (-frac_pi_2, false));
+        assert_eq!(trig::atan2_bits::<i128>(0, 0, 125), (0, false));
+        assert_eq!(trig::atan2_bits::<i128>(0, 1, 125), (0, false));
+        let minus_3_pi_4 = -3_131_919_679_877_832_269_120_157_343_247_668_310;
+        let atan2 = trig::atan2_bits::<i128>(-5, -5, 120);
+        assert_eq!(atan2, (minus_3_pi_4, false));
+        assert_eq!(trig::asin_bits::<i128>(1 << 125, 125), (frac_pi_2, false));
+        assert_eq!(trig::acos_bits::<i128>(-1 << 125, 125), (pi, false));
+        assert_eq!(trig::acos_bits::<i128>(1 << 125, 125), (0, false));
+        let frac_pi_6 = 695_982_151_083_962_726_471_146_076_277_259_625;
+        let frac_pi_3 = 1_391_964_302_167_925_452_942_292_152_554_519_249;
+        assert_eq!(trig::asin_bits::<i128>(1 << 119, 120), (frac_pi_6, false));
+        assert_eq!(trig::acos_bits::<i128>(1 << 119, 120), (frac_pi_3, false));
+        // τ/2 does not fit when there are only two integer bits
+        assert_eq!(trig::atan2_bits::<i8>(0, -1, 6), (i8::MAX, true));
+        assert_eq!(trig::acos_bits::<i8>(-64, 6), (i8::MAX, true));
+        assert_eq!(trig::asin_bits::<i8>(-64, 6), (-101, false));
+    }
+}
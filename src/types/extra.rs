@@ -22,14 +22,22 @@ These types are mostly reexports from the [*typenum* crate].
 */
 
 pub use typenum::{
-    Diff, IsLessOrEqual, Sum, True, Unsigned, U0, U1, U10, U100, U101, U102, U103, U104, U105,
-    U106, U107, U108, U109, U11, U110, U111, U112, U113, U114, U115, U116, U117, U118, U119, U12,
-    U120, U121, U122, U123, U124, U125, U126, U127, U128, U13, U14, U15, U16, U17, U18, U19, U2,
-    U20, U21, U22, U23, U24, U25, U26, U27, U28, U29, U3, U30, U31, U32, U33, U34, U35, U36, U37,
-    U38, U39, U4, U40, U41, U42, U43, U44, U45, U46, U47, U48, U49, U5, U50, U51, U52, U53, U54,
-    U55, U56, U57, U58, U59, U6, U60, U61, U62, U63, U64, U65, U66, U67, U68, U69, U7, U70, U71,
-    U72, U73, U74, U75, U76, U77, U78, U79, U8, U80, U81, U82, U83, U84, U85, U86, U87, U88, U89,
-    U9, U90, U91, U92, U93, U94, U95, U96, U97, U98, U99,
+    Diff, IsLessOrEqual, Max, Maximum, Sum, True, Unsigned, U0, U1, U10, U100, U101, U102, U103,
+    U104, U105, U106, U107, U108, U109, U11, U110, U111, U112, U113, U114, U115, U116, U117, U118,
+    U119, U12, U120, U121, U122, U123, U124, U125, U126, U127, U128, U129, U13, U130, U131, U132,
+    U133, U134, U135, U136, U137, U138, U139, U14, U140, U141, U142, U143, U144, U145, U146, U147,
+    U148, U149, U15, U150, U151, U152, U153, U154, U155, U156, U157, U158, U159, U16, U160, U161,
+    U162, U163, U164, U165, U166, U167, U168, U169, U17, U170, U171, U172, U173, U174, U175, U176,
+    U177, U178, U179, U18, U180, U181, U182, U183, U184, U185, U186, U187, U188, U189, U19, U190,
+    U191, U192, U193, U194, U195, U196, U197, U198, U199, U2, U20, U200, U201, U202, U203, U204,
+    U205, U206, U207, U208, U209, U21, U210, U211, U212, U213, U214, U215, U216, U217, U218, U219,
+    U22, U220, U221, U222, U223, U224, U225, U226, U227, U228, U229, U23, U230, U231, U232, U233,
+    U234, U235, U236, U237, U238, U239, U24, U240, U241, U242, U243, U244, U245, U246, U247, U248,
+    U249, U25, U250, U251, U252, U253, U254, U255, U256, U26, U27, U28, U29, U3, U30, U31, U32,
+    U33, U34, U35, U36, U37, U38, U39, U4, U40, U41, U42, U43, U44, U45, U46, U47, U48, U49, U5,
+    U50, U51, U52, U53, U54, U55, U56, U57, U58, U59, U6, U60, U61, U62, U63, U64, U65, U66, U67,
+    U68, U69, U7, U70, U71, U72, U73, U74, U75, U76, U77, U78, U79, U8, U80, U81, U82, U83, U84,
+    U85, U86, U87, U88, U89, U9, U90, U91, U92, U93, U94, U95, U96, U97, U98, U99,
 };
 
 /// Implemented for all [`Unsigned`] integers ≤ 8.
@@ -47,3 +55,6 @@ impl<T: Unsigned + IsLessOrEqual<U64, Output = True>> LeEqU64 for T {}
 /// Implemented for all [`Unsigned`] integers ≤ 128.
 pub trait LeEqU128: Unsigned + IsLessOrEqual<U128, Output = True> {}
 impl<T: Unsigned + IsLessOrEqual<U128, Output = True>> LeEqU128 for T {}
+/// Implemented for all [`Unsigned`] integers ≤ 256.
+pub trait LeEqU256: Unsigned + IsLessOrEqual<U256, Output = True> {}
+impl<T: Unsigned + IsLessOrEqual<U256, Output = True>> LeEqU256 for T {}
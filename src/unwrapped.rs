@@ -1084,6 +1084,68 @@ impl<F: Fixed> Unwrapped<F> {
         Unwrapped(self.0.unwrapped_recip())
     }
 
+    /// Returns the square root.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sqrt][FixedI32::unwrapped_sqrt]</code> and
+    /// <code>FixedU32::[unwrapped\_sqrt][FixedU32::unwrapped_sqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative or on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{types::I8F24, Unwrapped};
+    /// assert_eq!(Unwrapped(I8F24::from_num(6.25)).sqrt(), Unwrapped(I8F24::from_num(2.5)));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// use fixed::{types::I0F32, Unwrapped};
+    /// // no integer bits, so √0.25 = 0.5 overflows
+    /// let _overflow = Unwrapped(I0F32::from_num(0.25)).sqrt();
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn sqrt(self) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_sqrt())
+    }
+
+    /// Returns `self` raised to the integer power `n`.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_powi][FixedI32::unwrapped_powi]</code> and
+    /// <code>FixedU32::[unwrapped\_powi][FixedU32::unwrapped_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `n` is negative, or on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{types::I8F24, Unwrapped};
+    /// let x = Unwrapped(I8F24::from_num(1.5));
+    /// assert_eq!(x.powi(3), Unwrapped(I8F24::from_num(3.375)));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// use fixed::{types::I8F24, Unwrapped};
+    /// let _overflow = Unwrapped(I8F24::from_num(2)).powi(8);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn powi(self, n: i32) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_powi(n))
+    }
+
     /// Multiply and add. Returns `self` × `mul` + `add`.
     ///
     /// See also
@@ -1284,6 +1346,74 @@ impl<F: Fixed> Unwrapped<F> {
         Unwrapped(self.0.unwrapped_rem_euclid_int(divisor))
     }
 
+    /// Addition of an integer. Returns the sum.
+    ///
+    /// The integer `rhs` is scaled by 2<sup><i>f</i></sup>, where <i>f</i>
+    /// is the number of fractional bits.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_add\_int][FixedI32::unwrapped_add_int]</code> and
+    /// <code>FixedU32::[unwrapped\_add\_int][FixedU32::unwrapped_add_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// let num = Unwrapped(I16F16::from_num(7.5));
+    /// assert_eq!(num.add_int(2), Unwrapped(I16F16::from_num(9.5)));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// let _overflow = Unwrapped(I16F16::MAX).add_int(1);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn add_int(self, rhs: F::Bits) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_add_int(rhs))
+    }
+
+    /// Subtraction of an integer. Returns the difference.
+    ///
+    /// The integer `rhs` is scaled by 2<sup><i>f</i></sup>, where <i>f</i>
+    /// is the number of fractional bits.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sub\_int][FixedI32::unwrapped_sub_int]</code> and
+    /// <code>FixedU32::[unwrapped\_sub\_int][FixedU32::unwrapped_sub_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// let num = Unwrapped(I16F16::from_num(7.5));
+    /// assert_eq!(num.sub_int(2), Unwrapped(I16F16::from_num(5.5)));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// let _overflow = Unwrapped(I16F16::MIN).sub_int(1);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn sub_int(self, rhs: F::Bits) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_sub_int(rhs))
+    }
+
     /// Linear interpolation between `start` and `end`.
     ///
     /// See also
@@ -0,0 +1,548 @@
+// Copyright © 2018–2021 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    int256::{self, U256},
+    log10::IntFracLog10,
+    sqrt,
+};
+
+// A floating-point number with a 256-bit significand, used as an intermediate
+// value when computing transcendental functions. Only integer operations are
+// used, so that results are the same on all targets.
+//
+// The value is (−1)↑neg × mant × 2↑(exp − 255). mant is either zero, or
+// normalized so that its most significant bit is set; so for non-zero values
+// 2↑exp ≤ |value| < 2↑(exp + 1).
+//
+// Operations truncate the significand, so the relative error of each
+// operation is less than 2↑−254. This is far below the precision of any
+// fixed-point number, so the final rounding dominates the error.
+#[derive(Clone, Copy, Debug)]
+pub struct WideFloat {
+    pub neg: bool,
+    pub exp: i32,
+    pub mant: U256,
+}
+
+const U256_ZERO: U256 = U256 { lo: 0, hi: 0 };
+const MSB: u128 = 1 << 127;
+
+impl WideFloat {
+    pub const ZERO: WideFloat = WideFloat {
+        neg: false,
+        exp: 0,
+        mant: U256_ZERO,
+    };
+
+    pub const ONE: WideFloat = WideFloat {
+        neg: false,
+        exp: 0,
+        mant: U256 { lo: 0, hi: MSB },
+    };
+
+    // The lower 128 bits of the significands extend the constants in the
+    // consts module to 256 bits.
+
+    // ln 2 = 0.693147…
+    pub const LN_2: WideFloat = WideFloat {
+        neg: false,
+        exp: -1,
+        mant: U256 {
+            lo: 0x40F3_4326_7298_B62D_8A0D_175B_8BAA_FA2B,
+            hi: 0xB172_17F7_D1CF_79AB_C9E3_B398_03F2_F6AF,
+        },
+    };
+
+    // log₂ e = 1.442695…
+    pub const LOG2_E: WideFloat = WideFloat {
+        neg: false,
+        exp: 0,
+        mant: U256 {
+            lo: 0xEB57_7AA8_DD69_5A58_8B25_166C_D1A1_3247,
+            hi: 0xB8AA_3B29_5C17_F0BB_BE87_FED0_691D_3E88,
+        },
+    };
+
+    // τ/4 = π/2 = 1.570796…
+    pub const FRAC_TAU_4: WideFloat = WideFloat {
+        neg: false,
+        exp: 0,
+        mant: U256 {
+            lo: 0x2902_4E08_8A67_CC74_020B_BEA6_3B13_9B22,
+            hi: 0xC90F_DAA2_2168_C234_C4C6_628B_80DC_1CD1,
+        },
+    };
+
+    // 2/√π = 1.128379…
+    pub const FRAC_2_SQRT_PI: WideFloat = WideFloat {
+        neg: false,
+        exp: 0,
+        mant: U256 {
+            lo: 0x1409_A0EB_AC3E_7517_39A1_5830_CCE6_20B0,
+            hi: 0x906E_BA82_14DB_688D_71D4_8A7F_6BFE_C344,
+        },
+    };
+
+    // 1/√2 = 0.707106…
+    pub const FRAC_1_SQRT_2: WideFloat = WideFloat {
+        neg: false,
+        exp: -1,
+        mant: U256 {
+            lo: 0x1D6F_60BA_893B_A84C_ED17_AC85_8333_9915,
+            hi: 0xB504_F333_F9DE_6484_597D_89B3_754A_BE9F,
+        },
+    };
+
+    // log₁₀ 2 = 0.301029…
+    pub const LOG10_2: WideFloat = WideFloat {
+        neg: false,
+        exp: -2,
+        mant: U256 {
+            lo: 0x26AD_30C5_43D1_F349_8A5E_6F26_B7CC_63CB,
+            hi: 0x9A20_9A84_FBCF_F798_8F89_59AC_0B7C_9178,
+        },
+    };
+
+    #[inline]
+    pub fn is_zero(self) -> bool {
+        self.mant.hi == 0 && self.mant.lo == 0
+    }
+
+    // val / 2↑frac_nbits, exactly
+    #[inline]
+    pub fn from_u128(neg: bool, val: u128, frac_nbits: i32) -> WideFloat {
+        WideFloat::from_u256(neg, U256 { lo: 0, hi: val }, frac_nbits + 128)
+    }
+
+    // val / 2↑frac_nbits, exactly
+    pub fn from_u256(neg: bool, val: U256, frac_nbits: i32) -> WideFloat {
+        let zeros = int256::leading_zeros_u256(val);
+        if zeros == 256 {
+            return WideFloat::ZERO;
+        }
+        WideFloat {
+            neg,
+            exp: 255 - zeros as i32 - frac_nbits,
+            mant: int256::shl_u256(val, zeros),
+        }
+    }
+
+    #[inline]
+    pub fn from_i32(val: i32) -> WideFloat {
+        WideFloat::from_u128(val < 0, u128::from(val.unsigned_abs()), 0)
+    }
+
+    #[inline]
+    pub fn is_one(self) -> bool {
+        !self.neg && self.exp == 0 && self.mant.hi == MSB && self.mant.lo == 0
+    }
+
+    #[inline]
+    pub fn neg(self) -> WideFloat {
+        if self.is_zero() {
+            return self;
+        }
+        WideFloat {
+            neg: !self.neg,
+            ..self
+        }
+    }
+
+    // self × 2↑n, exactly
+    #[inline]
+    pub fn mul_pow2(self, n: i32) -> WideFloat {
+        if self.is_zero() {
+            return self;
+        }
+        WideFloat {
+            exp: self.exp + n,
+            ..self
+        }
+    }
+
+    pub fn mul(self, rhs: WideFloat) -> WideFloat {
+        if self.is_zero() || rhs.is_zero() {
+            return WideFloat::ZERO;
+        }
+        // the product of the significands is in [2↑510, 2↑512)
+        let (prod, low) = int256::wide_mul_u256(self.mant, rhs.mant);
+        let (mant, exp) = if prod.hi & MSB != 0 {
+            (prod, self.exp + rhs.exp + 1)
+        } else {
+            let mut mant = int256::shl_u256(prod, 1);
+            mant.lo |= low.hi >> 127;
+            (mant, self.exp + rhs.exp)
+        };
+        WideFloat {
+            neg: self.neg != rhs.neg,
+            exp,
+            mant,
+        }
+    }
+
+    #[inline]
+    pub fn abs_lt(self, rhs: WideFloat) -> bool {
+        if rhs.is_zero() {
+            false
+        } else if self.is_zero() {
+            true
+        } else {
+            self.exp < rhs.exp || (self.exp == rhs.exp && int256::lt_u256(self.mant, rhs.mant))
+        }
+    }
+
+    pub fn add(self, rhs: WideFloat) -> WideFloat {
+        let (big, small) = if self.abs_lt(rhs) {
+            (rhs, self)
+        } else {
+            (self, rhs)
+        };
+        if small.is_zero() {
+            return big;
+        }
+        let shift = (big.exp - small.exp) as u32;
+        let small_mant = int256::shr_u256(small.mant, shift);
+        if big.neg == small.neg {
+            let (sum, carry) = int256::overflowing_add_u256(big.mant, small_mant);
+            if carry {
+                let mut mant = int256::shr_u256(sum, 1);
+                mant.hi |= MSB;
+                WideFloat {
+                    neg: big.neg,
+                    exp: big.exp + 1,
+                    mant,
+                }
+            } else {
+                WideFloat { mant: sum, ..big }
+            }
+        } else {
+            let diff = int256::overflowing_sub_u256(big.mant, small_mant).0;
+            let zeros = int256::leading_zeros_u256(diff);
+            if zeros == 256 {
+                return WideFloat::ZERO;
+            }
+            WideFloat {
+                neg: big.neg,
+                exp: big.exp - zeros as i32,
+                mant: int256::shl_u256(diff, zeros),
+            }
+        }
+    }
+
+    // Returns (n, r) such that self = n + r / 2↑256, where n is an integer and
+    // 0 ≤ r < 2↑256. self must satisfy |self| < 2↑30.
+    pub fn floor_frac(self) -> (i32, U256) {
+        if self.is_zero() {
+            return (0, U256_ZERO);
+        }
+        debug_assert!(self.exp < 30);
+        let (int, frac) = if self.exp < 0 {
+            (0, int256::shr_u256(self.mant, (-1 - self.exp) as u32))
+        } else {
+            let int = int256::shr_u256(self.mant, (255 - self.exp) as u32).lo as i32;
+            (int, int256::shl_u256(self.mant, (self.exp + 1) as u32))
+        };
+        let frac_is_zero = frac.hi == 0 && frac.lo == 0;
+        if !self.neg {
+            (int, frac)
+        } else if frac_is_zero {
+            (-int, frac)
+        } else {
+            (-int - 1, int256::wrapping_neg_u256(frac))
+        }
+    }
+
+    // self / rhs; rhs must not be zero.
+    pub fn div(self, rhs: WideFloat) -> WideFloat {
+        debug_assert!(!rhs.is_zero());
+        if self.is_zero() {
+            return WideFloat::ZERO;
+        }
+        // restoring long division giving 256 quotient bits, the first of which
+        // is set
+        let mut rem = self.mant;
+        let mut quot = U256_ZERO;
+        let mut exp = self.exp - rhs.exp;
+        let nbits = if int256::lt_u256(rem, rhs.mant) {
+            exp -= 1;
+            256
+        } else {
+            rem = int256::overflowing_sub_u256(rem, rhs.mant).0;
+            quot.lo = 1;
+            255
+        };
+        for _ in 0..nbits {
+            let carry = rem.hi & MSB != 0;
+            rem = int256::shl_u256(rem, 1);
+            quot = int256::shl_u256(quot, 1);
+            if carry || !int256::lt_u256(rem, rhs.mant) {
+                rem = int256::overflowing_sub_u256(rem, rhs.mant).0;
+                quot.lo |= 1;
+            }
+        }
+        WideFloat {
+            neg: self.neg != rhs.neg,
+            exp,
+            mant: quot,
+        }
+    }
+
+    // self / d; d must not be zero.
+    pub fn div_small(self, d: u32) -> WideFloat {
+        debug_assert!(d != 0);
+        if self.is_zero() {
+            return self;
+        }
+        let d = u128::from(d);
+        let (quot, rem) = int256::div_rem_u256_u128(self.mant, d);
+        // quot has at most 32 leading zeros, which are filled with the
+        // quotient of the remainder
+        let zeros = int256::leading_zeros_u256(quot);
+        let mut mant = int256::shl_u256(quot, zeros);
+        mant.lo |= (rem << zeros) / d;
+        WideFloat {
+            neg: self.neg,
+            exp: self.exp - zeros as i32,
+            mant,
+        }
+    }
+
+    // √self; self must not be negative.
+    //
+    // The integer square root of the upper bits of the significand gives 128
+    // correct bits, and one Newton iteration y ← (y + self/y) / 2 doubles that.
+    pub fn sqrt(self) -> WideFloat {
+        debug_assert!(!self.neg);
+        if self.is_zero() {
+            return self;
+        }
+        // self = mant × 2↑(exp − 255); make the power of two even
+        let (radicand, exp) = if self.exp % 2 == 0 {
+            (int256::shr_u256(self.mant, 1), self.exp - 254)
+        } else {
+            (self.mant, self.exp - 255)
+        };
+        let root = sqrt::isqrt_rem_u256(radicand).0;
+        let y = WideFloat::from_u128(false, root, -exp / 2);
+        y.add(self.div(y)).mul_pow2(-1)
+    }
+
+    // Returns |self| × 2↑frac_nbits rounded to the nearest, with ties rounded
+    // to even, and whether it does not fit in a u128. On overflow, the lower
+    // 128 bits are returned.
+    pub fn round_abs_u128(self, frac_nbits: u32) -> (u128, bool) {
+        if self.is_zero() {
+            return (0, false);
+        }
+        // the rounded value is mant >> shift
+        let shift = 255 - i64::from(self.exp) - i64::from(frac_nbits);
+        if shift < 0 {
+            let low = if shift <= -128 {
+                0
+            } else {
+                self.mant.lo << -shift
+            };
+            return (low, true);
+        }
+        if shift > 256 {
+            return (0, false);
+        }
+        let shift = shift as u32;
+        let int = int256::shr_u256(self.mant, shift);
+        let rem = int256::shl_u256(self.mant, 256 - shift);
+        // rem holds the discarded bits, with the rounding bit as its msb
+        let round_up = rem.hi & MSB != 0 && (rem.hi != MSB || rem.lo != 0 || int.lo & 1 != 0);
+        let (low, carry) = int.lo.overflowing_add(u128::from(round_up));
+        (low, int.hi != 0 || carry)
+    }
+}
+
+// Conversions between the bits of fixed-point numbers and WideFloat.
+pub trait WideBits: Copy {
+    fn to_wide(self, frac_nbits: u32) -> WideFloat;
+
+    // Returns the value rounded to the nearest with ties rounded to even, and
+    // an overflow flag. On overflow, the saturated value is returned.
+    fn from_wide(w: WideFloat, frac_nbits: u32) -> (Self, bool);
+
+    // Like from_wide, but on overflow the wrapped value is returned. The bits
+    // that are not held in the significand of w are taken to be zero.
+    fn from_wide_wrapping(w: WideFloat, frac_nbits: u32) -> (Self, bool);
+
+    // Returns ⌊log₁₀ self⌋, computed exactly; self must be positive.
+    fn int_log10(self, frac_nbits: u32) -> i32;
+}
+
+macro_rules! wide_bits {
+    ($i:ident, $u:ident) => {
+        impl WideBits for $u {
+            #[inline]
+            fn to_wide(self, frac_nbits: u32) -> WideFloat {
+                WideFloat::from_u128(false, self as u128, frac_nbits as i32)
+            }
+
+            #[inline]
+            fn from_wide(w: WideFloat, frac_nbits: u32) -> ($u, bool) {
+                let (abs, overflow) = w.round_abs_u128(frac_nbits);
+                if w.neg {
+                    (0, overflow || abs != 0)
+                } else if overflow || abs > <$u>::MAX as u128 {
+                    (<$u>::MAX, true)
+                } else {
+                    (abs as $u, false)
+                }
+            }
+
+            #[inline]
+            fn from_wide_wrapping(w: WideFloat, frac_nbits: u32) -> ($u, bool) {
+                let (abs, overflow) = w.round_abs_u128(frac_nbits);
+                if w.neg {
+                    (abs.wrapping_neg() as $u, overflow || abs != 0)
+                } else {
+                    (abs as $u, overflow || abs > <$u>::MAX as u128)
+                }
+            }
+
+            #[inline]
+            fn int_log10(self, frac_nbits: u32) -> i32 {
+                debug_assert!(self > 0);
+                let int = self.checked_shr(frac_nbits).unwrap_or(0);
+                if int != 0 {
+                    int.int_part_log10()
+                } else {
+                    // self is positive, so frac_nbits > 0
+                    (self << (<$u>::BITS - frac_nbits)).frac_part_log10()
+                }
+            }
+        }
+
+        impl WideBits for $i {
+            #[inline]
+            fn to_wide(self, frac_nbits: u32) -> WideFloat {
+                WideFloat::from_u128(self < 0, self.unsigned_abs() as u128, frac_nbits as i32)
+            }
+
+            #[inline]
+            fn from_wide(w: WideFloat, frac_nbits: u32) -> ($i, bool) {
+                let (abs, overflow) = w.round_abs_u128(frac_nbits);
+                if w.neg {
+                    if overflow || abs > <$i>::MIN.unsigned_abs() as u128 {
+                        (<$i>::MIN, true)
+                    } else {
+                        ((abs as $i).wrapping_neg(), false)
+                    }
+                } else if overflow || abs > <$i>::MAX as u128 {
+                    (<$i>::MAX, true)
+                } else {
+                    (abs as $i, false)
+                }
+            }
+
+            #[inline]
+            fn from_wide_wrapping(w: WideFloat, frac_nbits: u32) -> ($i, bool) {
+                let (abs, overflow) = w.round_abs_u128(frac_nbits);
+                if w.neg {
+                    let overflow = overflow || abs > <$i>::MIN.unsigned_abs() as u128;
+                    (abs.wrapping_neg() as $i, overflow)
+                } else {
+                    (abs as $i, overflow || abs > <$i>::MAX as u128)
+                }
+            }
+
+            #[inline]
+            fn int_log10(self, frac_nbits: u32) -> i32 {
+                debug_assert!(self > 0);
+                (self as $u).int_log10(frac_nbits)
+            }
+        }
+    };
+}
+
+wide_bits! { i8, u8 }
+wide_bits! { i16, u16 }
+wide_bits! { i32, u32 }
+wide_bits! { i64, u64 }
+wide_bits! { i128, u128 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{consts, wide_float::WideFloat};
+
+    #[test]
+    fn consts_match() {
+        assert_eq!(WideFloat::LN_2.mant.hi, consts::LN_2.to_bits());
+        assert_eq!(WideFloat::LOG2_E.mant.hi, consts::LOG2_E.to_bits());
+        assert_eq!(WideFloat::LOG10_2.mant.hi >> 1, consts::LOG10_2.to_bits());
+        assert_eq!(WideFloat::FRAC_TAU_4.mant.hi, consts::FRAC_TAU_4.to_bits());
+        let frac_2_sqrt_pi = WideFloat::FRAC_2_SQRT_PI.mant.hi;
+        assert_eq!(frac_2_sqrt_pi, consts::FRAC_2_SQRT_PI.to_bits());
+        let frac_1_sqrt_2 = WideFloat::FRAC_1_SQRT_2.mant.hi;
+        assert_eq!(frac_1_sqrt_2, consts::FRAC_1_SQRT_2.to_bits());
+    }
+
+    #[test]
+    fn round_trip() {
+        let vals = [0u128, 1, 2, 3, 0xFF, u128::from(u64::MAX), u128::MAX];
+        for &val in &vals {
+            for &frac_nbits in &[0, 1, 64, 127, 128] {
+                let w = WideFloat::from_u128(false, val, frac_nbits);
+                assert_eq!(w.round_abs_u128(frac_nbits as u32), (val, false));
+            }
+        }
+        // 1.5 rounds to 2, 2.5 rounds to 2, 2.5 + tiny rounds to 3
+        let three_halves = WideFloat::from_u128(false, 3, 1);
+        assert_eq!(three_halves.round_abs_u128(0), (2, false));
+        let five_halves = WideFloat::from_u128(false, 5, 1);
+        assert_eq!(five_halves.round_abs_u128(0), (2, false));
+        let tiny = WideFloat::from_u128(false, 1, 200);
+        assert_eq!(five_halves.add(tiny).round_abs_u128(0), (3, false));
+        let neg_tiny = WideFloat::from_u128(true, 1, 200);
+        assert_eq!(five_halves.add(neg_tiny).round_abs_u128(0), (2, false));
+        // 0.5 rounds to 0
+        let half = WideFloat::from_u128(false, 1, 1);
+        assert_eq!(half.round_abs_u128(0), (0, false));
+        assert_eq!(half.add(tiny).round_abs_u128(0), (1, false));
+        // u128::MAX + 0.5 overflows
+        let max = WideFloat::from_u128(false, u128::MAX, 0);
+        assert_eq!(max.add(half).round_abs_u128(0), (0, true));
+    }
+
+    #[test]
+    fn floor_frac() {
+        let x = WideFloat::from_i32(-3).add(WideFloat::from_u128(false, 1, 2));
+        let (int, frac) = x.floor_frac();
+        assert_eq!(int, -3);
+        assert_eq!((frac.hi, frac.lo), (1 << 126, 0));
+        let neg_x = WideFloat::from_i32(3).add(WideFloat::from_u128(true, 1, 2));
+        let (int, frac) = neg_x.floor_frac();
+        assert_eq!(int, 2);
+        assert_eq!((frac.hi, frac.lo), (3 << 126, 0));
+        let (int, frac) = WideFloat::from_i32(-5).floor_frac();
+        assert_eq!(int, -5);
+        assert_eq!((frac.hi, frac.lo), (0, 0));
+    }
+
+    #[test]
+    fn mul() {
+        let a = WideFloat::from_u128(false, u128::MAX, 0);
+        let sq = a.mul(a);
+        // (2↑128 − 1)² = 2↑256 − 2↑129 + 1
+        assert_eq!(sq.exp, 255);
+        assert_eq!((sq.mant.hi, sq.mant.lo), (u128::MAX - 1, 1));
+        let b = WideFloat::from_i32(-3).mul(WideFloat::from_u128(false, 5, 2));
+        assert_eq!(<i32 as super::WideBits>::from_wide(b, 4), (-60, false));
+    }
+}
@@ -1015,6 +1015,59 @@ impl<F: Fixed> Wrapping<F> {
         Wrapping(self.0.wrapping_recip())
     }
 
+    /// Returns the square root.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sqrt][FixedI32::wrapping_sqrt]</code> and
+    /// <code>FixedU32::[wrapping\_sqrt][FixedU32::wrapping_sqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{
+    ///     types::{I0F32, I8F24},
+    ///     Wrapping,
+    /// };
+    /// assert_eq!(Wrapping(I8F24::from_num(6.25)).sqrt(), Wrapping(I8F24::from_num(2.5)));
+    /// // no integer bits, so √0.25 = 0.5 wraps to −0.5
+    /// let quarter = Wrapping(I0F32::from_num(0.25));
+    /// assert_eq!(quarter.sqrt(), Wrapping(I0F32::from_num(-0.5)));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn sqrt(self) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_sqrt())
+    }
+
+    /// Returns `self` raised to the integer power `n`.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_powi][FixedI32::wrapping_powi]</code> and
+    /// <code>FixedU32::[wrapping\_powi][FixedU32::wrapping_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `n` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{types::I8F24, Wrapping};
+    /// let x = Wrapping(I8F24::from_num(1.5));
+    /// assert_eq!(x.powi(3), Wrapping(I8F24::from_num(3.375)));
+    /// // 2↑8 = 256 wraps to zero
+    /// assert_eq!(Wrapping(I8F24::from_num(2)).powi(8), Wrapping(I8F24::ZERO));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn powi(self, n: i32) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_powi(n))
+    }
+
     /// Multiply and add. Returns `self` × `mul` + `add`.
     ///
     /// See also
@@ -1166,6 +1219,56 @@ impl<F: Fixed> Wrapping<F> {
         Wrapping(self.0.wrapping_rem_euclid_int(divisor))
     }
 
+    /// Addition of an integer. Returns the sum.
+    ///
+    /// The integer `rhs` is scaled by 2<sup><i>f</i></sup>, where <i>f</i>
+    /// is the number of fractional bits.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_add\_int][FixedI32::wrapping_add_int]</code> and
+    /// <code>FixedU32::[wrapping\_add\_int][FixedU32::wrapping_add_int]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{types::I16F16, Wrapping};
+    /// let num = Wrapping(I16F16::from_num(7.5));
+    /// assert_eq!(num.add_int(2), Wrapping(I16F16::from_num(9.5)));
+    /// let max = Wrapping(I16F16::MAX);
+    /// let wrapped = Wrapping(I16F16::MIN + I16F16::ONE - I16F16::DELTA);
+    /// assert_eq!(max.add_int(1), wrapped);
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn add_int(self, rhs: F::Bits) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_add_int(rhs))
+    }
+
+    /// Subtraction of an integer. Returns the difference.
+    ///
+    /// The integer `rhs` is scaled by 2<sup><i>f</i></sup>, where <i>f</i>
+    /// is the number of fractional bits.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sub\_int][FixedI32::wrapping_sub_int]</code> and
+    /// <code>FixedU32::[wrapping\_sub\_int][FixedU32::wrapping_sub_int]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{types::I16F16, Wrapping};
+    /// let num = Wrapping(I16F16::from_num(7.5));
+    /// assert_eq!(num.sub_int(2), Wrapping(I16F16::from_num(5.5)));
+    /// let min = Wrapping(I16F16::MIN);
+    /// let wrapped = Wrapping(I16F16::MAX - I16F16::ONE + I16F16::DELTA);
+    /// assert_eq!(min.sub_int(1), wrapped);
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn sub_int(self, rhs: F::Bits) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_sub_int(rhs))
+    }
+
     /// Linear interpolation between `start` and `end`.
     ///
     /// See also